@@ -61,6 +61,9 @@ pub enum ApicizeError {
     Csv {
         description: String,
     },
+    ScriptLimit {
+        description: String,
+    },
 }
 
 impl Display for ApicizeError {
@@ -134,6 +137,9 @@ impl Display for ApicizeError {
             ApicizeError::InvalidId { description } => write!(f, "Invalid ID - {description}"),
             ApicizeError::FailedTest { description } => write!(f, "Failed test - {description}"),
             ApicizeError::Csv { description } => write!(f, "CSV Error - {description}"),
+            ApicizeError::ScriptLimit { description } => {
+                write!(f, "Script limit exceeded - {description}")
+            }
         }
     }
 }
@@ -219,6 +225,13 @@ impl ApicizeError {
         }
     }
 
+    pub fn from_yaml(error: serde_yaml_ng::Error, name: String) -> ApicizeError {
+        ApicizeError::Serialization {
+            description: format!("{error}"),
+            name,
+        }
+    }
+
     pub fn from_io(error: io::Error, file_name: Option<String>) -> ApicizeError {
         ApicizeError::FileAccess {
             description: error.to_string(),
@@ -244,6 +257,7 @@ impl ApicizeError {
             ApicizeError::Serialization { .. } => "Failed Serialization/Deserialization",
             ApicizeError::InvalidId { .. } => "Invalid ID",
             ApicizeError::Csv { .. } => "CSV Error",
+            ApicizeError::ScriptLimit { .. } => "Script Limit Exceeded",
         }
     }
 }
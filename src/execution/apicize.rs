@@ -4,9 +4,9 @@
 
 use std::collections::HashMap;
 
-use crate::{Identifiable, identifiable::CloneIdentifiable};
+use crate::{ApicizeError, Identifiable, identifiable::CloneIdentifiable};
 
-use super::{ApicizeExecution, DataContext};
+use super::{ApicizeExecution, ApicizeLatencyStatistics, ApicizeLoadResult, DataContext};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use serde_with::base64::{Base64, Standard};
@@ -34,6 +34,10 @@ pub enum ApicizeRequestResultContent {
     Execution {
         execution: Box<ApicizeExecution>,
     },
+    Load {
+        /// Aggregated iterations of the request's load profile
+        load: Box<ApicizeLoadResult>,
+    },
 }
 
 /// Result for a request has nested runs or rows
@@ -69,9 +73,17 @@ pub struct ApicizeRequestResult {
     /// Request content (rows, runs or an execution)
     pub content: ApicizeRequestResultContent,
 
+    /// Distribution of response durations, if multiple runs or rows were executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<ApicizeLatencyStatistics>,
+
     /// Global logs not part of a behavioral test block
     pub logs: Option<Vec<String>>,
 
+    /// Reason the request was skipped by a run policy or condition, if skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+
     /// Success is true if all runs are successful
     pub success: bool,
     /// Number of child requests/groups with successful requests and all tests passed
@@ -111,6 +123,10 @@ pub struct ApicizeRequestResultRow {
     /// Execution result
     pub results: ApicizeRequestResultRowContent,
 
+    /// Distribution of response durations, if multiple runs were executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<ApicizeLatencyStatistics>,
+
     /// Success is true if all runs are successful
     pub success: bool,
     /// Number of child requests/groups with successful requests and all tests passed
@@ -160,6 +176,7 @@ pub enum ApicizeGroupResultContent {
     Rows { rows: Vec<ApicizeGroupResultRow> },
     Runs { runs: Vec<ApicizeGroupResultRun> },
     Results { results: Vec<ApicizeResult> },
+    Load { load: Box<ApicizeLoadResult> },
 }
 
 /// Result for a request group
@@ -191,6 +208,14 @@ pub struct ApicizeGroupResult {
     /// Global logs not part of a behavioral test block
     pub logs: Option<Vec<String>>,
 
+    /// Results of after-each and teardown scripts, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scripts: Option<Vec<ApicizeGroupScriptResult>>,
+
+    /// Reason the group was skipped by a run policy or condition, if skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+
     /// Success is true if all runs are successful
     pub success: bool,
     /// Number of child requests/groups with successful requests and all tests passed
@@ -205,6 +230,41 @@ pub struct ApicizeGroupResult {
     pub test_fail_count: usize,
 }
 
+/// Group script executed around a group's children
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ApicizeGroupScript {
+    /// Executed after each child request or group
+    AfterEach,
+    /// Executed after all children, even if they fail
+    Teardown,
+    /// Evaluated to decide whether the group is executed
+    Condition,
+}
+
+/// Result of executing a group's after-each, teardown or condition script
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeGroupScriptResult {
+    /// Script that was executed
+    pub script: ApicizeGroupScript,
+    /// Name of the child an after-each script was executed after
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub child_name: Option<String>,
+    /// Execution start (millisecond offset from start)
+    pub executed_at: u128,
+    /// Tests executed by the script, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tests: Option<Vec<ApicizeTestBehavior>>,
+    /// Global logs not part of a behavioral test block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logs: Option<Vec<String>>,
+    /// Error executing the script
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ApicizeError>,
+    /// Success is true if the script completed and all of its tests passed
+    pub success: bool,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
 #[serde(tag = "type")]
 pub enum ApicizeGroupResultRowContent {
@@ -305,6 +365,47 @@ pub enum ApicizeBody {
         #[serde_as(as = "Base64<Standard, Unpadded>")]
         data: Vec<u8>,
     },
+    Multipart {
+        parts: Vec<ApicizeMultipartPart>,
+    },
+}
+
+/// Multipart form body part sent when dispatching an Apicize Request
+#[serde_as]
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeMultipartPart {
+    /// Name of form field
+    pub name: String,
+    /// File name sent with part, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// Content type sent with part, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Value sent for text parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Data sent for file parts
+    #[serde_as(as = "Option<Base64<Standard, Unpadded>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<u8>>,
+}
+
+impl ApicizeMultipartPart {
+    /// Return the number of bytes sent for the part
+    pub fn len(&self) -> usize {
+        match (&self.text, &self.data) {
+            (Some(text), _) => text.len(),
+            (None, Some(data)) => data.len(),
+            (None, None) => 0,
+        }
+    }
+
+    /// Return true if no data was sent for the part
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Response from V8 when executing a request's tests
@@ -317,6 +418,9 @@ pub struct ApicizeTestResponse {
     pub output: Map<String, Value>,
     /// Global logs not part of a behavioral test block
     pub logs: Option<Vec<String>>,
+    /// Set to true if the test cleared the cookie jar
+    #[serde(default)]
+    pub clear_cookies: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
@@ -378,6 +482,14 @@ impl ApicizeResult {
             ApicizeResult::Group(group) => group.duration,
         }
     }
+
+    /// True if the request or group was skipped by a run policy or condition
+    pub fn is_skipped(&self) -> bool {
+        match self {
+            ApicizeResult::Request(request) => request.skip_reason.is_some(),
+            ApicizeResult::Group(group) => group.skip_reason.is_some(),
+        }
+    }
 }
 
 impl Identifiable for ApicizeResult {
@@ -6,7 +6,10 @@ use std::sync::Arc;
 
 use crate::ApicizeError;
 
-use super::{ApicizeBody, ApicizeTestBehavior, oauth2_client_tokens::TokenResult};
+use super::{
+    ApicizeBody, ApicizeCookie, ApicizeHttpTimings, ApicizeTestBehavior,
+    oauth2_client_tokens::TokenResult,
+};
 
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -43,6 +46,14 @@ pub struct ApicizeExecution {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ApicizeError>,
 
+    /// Dispatch attempts, if the request was retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<Vec<ApicizeExecutionAttempt>>,
+
+    /// Summary of polling, if the request was polled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<ApicizePollSummary>,
+
     /// Success is rrequest executes and tests all pass
     pub success: bool,
 
@@ -65,6 +76,54 @@ impl ApicizeExecution {
     }
 }
 
+/// Information about an attempt to dispatch a request under a retry policy
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeExecutionAttempt {
+    /// Ordinal attempt number
+    pub attempt: usize,
+
+    /// Attempt start (millisecond offset from start)
+    pub executed_at: u128,
+
+    /// Duration of attempt (milliseconds)
+    pub duration: u128,
+
+    /// HTTP status code, if a response was received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+
+    /// Response received, if the attempt was retried (the final attempt's
+    /// response is the execution's response)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<ApicizeHttpResponse>,
+
+    /// Error on dispatch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ApicizeError>,
+
+    /// Delay before the next attempt (milliseconds), if retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<u128>,
+}
+
+/// Summary of dispatches made while polling a request
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizePollSummary {
+    /// Number of times the request was dispatched
+    pub dispatches: usize,
+
+    /// True if the poll condition was met
+    pub condition_met: bool,
+
+    /// Time spent polling (milliseconds)
+    pub duration: u128,
+
+    /// HTTP status code of each dispatch, if a response was received
+    pub statuses: Vec<Option<u16>>,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ApicizeExecutionTestContext {
@@ -105,6 +164,9 @@ pub struct ApicizeHttpRequest {
     /// Body
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<ApicizeBody>,
+    /// Cookies sent from the cookie jar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cookies: Option<Vec<ApicizeCookie>>,
 }
 
 /// Information about the response to a dispatched Apicize request
@@ -124,6 +186,27 @@ pub struct ApicizeHttpResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Response body
     pub body: Option<ApicizeBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Cookies set by the response
+    pub cookies: Option<Vec<ApicizeCookie>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Connection the response was received on
+    pub connection: Option<ApicizeHttpConnection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Duration of each phase of the request
+    pub timings: Option<ApicizeHttpTimings>,
     /// Set to OAuth2 token result information
     pub oauth2_token: Option<TokenResult>,
 }
+
+/// Information about the connection a response was received on
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeHttpConnection {
+    /// Address of the server (or proxy) connected to
+    pub remote_address: String,
+    /// Local address of the connection
+    pub local_address: String,
+    /// True if the connection was reused from a previous request
+    pub reused: bool,
+}
@@ -0,0 +1,506 @@
+//! Apicize OpenAPI contract validation.
+//!
+//! This submodule validates HTTP responses against the responses documented
+//! for operations in an OpenAPI document
+use std::path::PathBuf;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{
+    ApicizeBody, ApicizeError, ApicizeHttpResponse, ApicizeTestBehavior,
+    get_existing_absolute_file_name, open_openapi_file, resolve_openapi_ref,
+};
+
+/// Maximum schema nesting to follow when validating values
+const MAX_SCHEMA_DEPTH: usize = 64;
+
+/// Maximum number of schema violations reported for a single value
+const MAX_SCHEMA_ERRORS: usize = 20;
+
+/// OpenAPI document used to validate responses
+pub struct OpenApiContract {
+    document: Value,
+}
+
+impl OpenApiContract {
+    /// Create a contract from a parsed OpenAPI document
+    pub fn new(document: Value) -> Self {
+        OpenApiContract { document }
+    }
+
+    /// Open a contract from an OpenAPI JSON or YAML file located in the allowed data path
+    pub fn open(
+        file_name: &str,
+        allowed_data_path: &Option<PathBuf>,
+    ) -> Result<Self, ApicizeError> {
+        let full_file_name = get_existing_absolute_file_name(file_name, allowed_data_path)?;
+        Ok(OpenApiContract::new(open_openapi_file(&full_file_name)?))
+    }
+
+    /// Return the operation matching an operationId or "METHOD /path"
+    fn find_operation(&self, operation: &str) -> Option<&Value> {
+        let paths = self.document.get("paths")?.as_object()?;
+        let (method, path) = match operation.split_once(' ') {
+            Some((method, path)) if path.starts_with('/') => {
+                (Some(method.to_lowercase()), Some(path.trim()))
+            }
+            _ => (None, None),
+        };
+
+        if let (Some(method), Some(path)) = (method, path)
+            && let Some(found) = paths
+                .get(path)
+                .map(|p| resolve_openapi_ref(&self.document, p))
+                .and_then(|p| p.get(&method))
+        {
+            return Some(found);
+        }
+
+        paths.values().find_map(|path_item| {
+            let path_item = resolve_openapi_ref(&self.document, path_item);
+            path_item
+                .as_object()?
+                .values()
+                .find(|o| o.get("operationId").and_then(|id| id.as_str()) == Some(operation))
+        })
+    }
+
+    /// Validate a response against the operation's documented responses, returning a test
+    /// behavior for each check performed
+    pub fn validate(
+        &self,
+        operation: &str,
+        response: &ApicizeHttpResponse,
+    ) -> Vec<ApicizeTestBehavior> {
+        let Some(found) = self.find_operation(operation) else {
+            return vec![contract_behavior(
+                operation,
+                "operation is documented",
+                vec![format!(
+                    "Operation \"{operation}\" is not in the OpenAPI document"
+                )],
+            )];
+        };
+
+        let status = response.status.to_string();
+        let range = format!("{}XX", &status[..1]);
+        let responses = found.get("responses").and_then(|r| r.as_object());
+        let documented = responses.and_then(|r| {
+            r.get(&status)
+                .or_else(|| {
+                    r.iter()
+                        .find(|(k, _)| k.eq_ignore_ascii_case(&range))
+                        .map(|(_, v)| v)
+                })
+                .or_else(|| r.get("default"))
+        });
+
+        let Some(documented) = documented.map(|d| resolve_openapi_ref(&self.document, d)) else {
+            return vec![contract_behavior(
+                operation,
+                "response status is documented",
+                vec![format!("Status {status} is not documented")],
+            )];
+        };
+
+        let mut behaviors = vec![contract_behavior(
+            operation,
+            "response status is documented",
+            vec![],
+        )];
+
+        if let Some(headers) = documented.get("headers").and_then(|h| h.as_object())
+            && !headers.is_empty()
+        {
+            behaviors.push(contract_behavior(
+                operation,
+                "response headers match",
+                self.validate_headers(headers, response),
+            ));
+        }
+
+        if let Some(content) = documented.get("content").and_then(|c| c.as_object())
+            && !content.is_empty()
+        {
+            behaviors.push(contract_behavior(
+                operation,
+                "response body matches schema",
+                self.validate_body(content, response),
+            ));
+        }
+
+        behaviors
+    }
+
+    fn validate_headers(
+        &self,
+        headers: &serde_json::Map<String, Value>,
+        response: &ApicizeHttpResponse,
+    ) -> Vec<String> {
+        let mut errors = Vec::<String>::new();
+        for (name, header) in headers {
+            // OpenAPI specifies that Content-Type response headers are ignored
+            if name.eq_ignore_ascii_case("content-type") {
+                continue;
+            }
+            let header = resolve_openapi_ref(&self.document, header);
+            let value = response.headers.as_ref().and_then(|h| {
+                h.iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                    .map(|(_, v)| v)
+            });
+            match value {
+                Some(value) => {
+                    if let Some(schema) = header.get("schema") {
+                        let schema = resolve_openapi_ref(&self.document, schema);
+                        let typed_value = coerce_header_value(schema, value);
+                        validate_value(
+                            &self.document,
+                            schema,
+                            &typed_value,
+                            &format!("header {name}"),
+                            &mut errors,
+                            0,
+                        );
+                    }
+                }
+                None => {
+                    if header.get("required").and_then(|r| r.as_bool()) == Some(true) {
+                        errors.push(format!("Required header {name} is missing"));
+                    }
+                }
+            }
+        }
+        errors
+    }
+
+    fn validate_body(
+        &self,
+        content: &serde_json::Map<String, Value>,
+        response: &ApicizeHttpResponse,
+    ) -> Vec<String> {
+        let content_type = response
+            .headers
+            .as_ref()
+            .and_then(|h| {
+                h.iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case("content-type"))
+                    .map(|(_, v)| v.as_str())
+            })
+            .map(|t| {
+                t.split(';')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_lowercase()
+            });
+
+        let media = match &content_type {
+            Some(content_type) => content
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(content_type))
+                .or_else(|| {
+                    let (main_type, _) = content_type.split_once('/').unwrap_or_default();
+                    content.iter().find(|(k, _)| {
+                        k.eq_ignore_ascii_case(&format!("{main_type}/*")) || *k == "*/*"
+                    })
+                }),
+            None => content.iter().next(),
+        };
+
+        let Some((media_type, media)) = media else {
+            return vec![format!(
+                "Content type {} is not documented",
+                content_type.unwrap_or_default()
+            )];
+        };
+
+        let Some(schema) = media.get("schema") else {
+            return vec![];
+        };
+
+        let is_json = |t: &str| t.ends_with("/json") || t.ends_with("+json");
+        if !is_json(media_type) && !content_type.as_deref().is_some_and(is_json) {
+            return vec![];
+        }
+
+        match &response.body {
+            Some(ApicizeBody::JSON { data, .. }) => {
+                let mut errors = Vec::<String>::new();
+                validate_value(&self.document, schema, data, "$", &mut errors, 0);
+                errors
+            }
+            _ => vec!["Response body is not JSON".to_string()],
+        }
+    }
+}
+
+/// Validate a value against an OpenAPI schema, returning any violations found
+pub fn validate_openapi_schema(document: &Value, schema: &Value, value: &Value) -> Vec<String> {
+    let mut errors = Vec::<String>::new();
+    validate_value(document, schema, value, "$", &mut errors, 0);
+    errors
+}
+
+fn contract_behavior(operation: &str, name: &str, errors: Vec<String>) -> ApicizeTestBehavior {
+    ApicizeTestBehavior {
+        name: format!("contract {name}"),
+        tag: Some(operation.to_string()),
+        success: errors.is_empty(),
+        error: if errors.is_empty() {
+            None
+        } else {
+            Some(errors.join("; "))
+        },
+        logs: None,
+    }
+}
+
+/// Convert a header value to the type specified by its schema so that it can be validated
+fn coerce_header_value(schema: &Value, value: &str) -> Value {
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("integer") | Some("number") => serde_json::from_str::<Value>(value)
+            .ok()
+            .filter(|v| v.is_number())
+            .unwrap_or_else(|| Value::String(value.to_string())),
+        Some("boolean") => match value {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => Value::String(value.to_string()),
+        },
+        _ => Value::String(value.to_string()),
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn matches_type(value: &Value, schema_type: &str) -> bool {
+    match schema_type {
+        "integer" => match value {
+            Value::Number(n) => {
+                n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+            }
+            _ => false,
+        },
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn is_valid(document: &Value, schema: &Value, value: &Value, depth: usize) -> bool {
+    let mut errors = Vec::<String>::new();
+    validate_value(document, schema, value, "$", &mut errors, depth + 1);
+    errors.is_empty()
+}
+
+fn validate_value(
+    document: &Value,
+    schema: &Value,
+    value: &Value,
+    path: &str,
+    errors: &mut Vec<String>,
+    depth: usize,
+) {
+    if depth > MAX_SCHEMA_DEPTH || errors.len() >= MAX_SCHEMA_ERRORS {
+        return;
+    }
+
+    let schema = resolve_openapi_ref(document, schema);
+    match schema {
+        Value::Object(_) => {}
+        Value::Bool(false) => {
+            errors.push(format!("{path}: no value is allowed"));
+            return;
+        }
+        _ => return,
+    }
+
+    // OpenAPI 3.0 uses "nullable" rather than a "null" type
+    if value.is_null() && schema.get("nullable").and_then(|n| n.as_bool()) == Some(true) {
+        return;
+    }
+
+    if let Some(all_of) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for sub_schema in all_of {
+            validate_value(document, sub_schema, value, path, errors, depth + 1);
+        }
+    }
+
+    if let Some(any_of) = schema.get("anyOf").and_then(|a| a.as_array())
+        && !any_of.iter().any(|s| is_valid(document, s, value, depth))
+    {
+        errors.push(format!("{path}: does not match any of the allowed schemas"));
+    }
+
+    if let Some(one_of) = schema.get("oneOf").and_then(|a| a.as_array()) {
+        let matches = one_of
+            .iter()
+            .filter(|s| is_valid(document, s, value, depth))
+            .count();
+        if matches != 1 {
+            errors.push(format!(
+                "{path}: matches {matches} schemas, expected exactly one"
+            ));
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|e| e.as_array())
+        && !allowed.contains(value)
+    {
+        errors.push(format!("{path}: {value} is not one of the allowed values"));
+    }
+
+    if let Some(constant) = schema.get("const")
+        && constant != value
+    {
+        errors.push(format!("{path}: expected {constant}, found {value}"));
+    }
+
+    let types = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(t)) => t.iter().filter_map(|t| t.as_str()).collect(),
+        _ => vec![],
+    };
+    if !types.is_empty() && !types.iter().any(|t| matches_type(value, t)) {
+        errors.push(format!(
+            "{path}: expected {}, found {}",
+            types.join(" or "),
+            json_type(value)
+        ));
+        return;
+    }
+
+    match value {
+        Value::Object(properties) => {
+            if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+                for name in required.iter().filter_map(|r| r.as_str()) {
+                    if !properties.contains_key(name) {
+                        errors.push(format!("{path}: missing required property \"{name}\""));
+                    }
+                }
+            }
+
+            let defined = schema.get("properties").and_then(|p| p.as_object());
+            for (name, property_value) in properties {
+                let property_path = format!("{path}.{name}");
+                match defined.and_then(|d| d.get(name)) {
+                    Some(property_schema) => validate_value(
+                        document,
+                        property_schema,
+                        property_value,
+                        &property_path,
+                        errors,
+                        depth + 1,
+                    ),
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => {
+                            errors.push(format!("{path}: unexpected property \"{name}\""));
+                        }
+                        Some(additional @ Value::Object(_)) => validate_value(
+                            document,
+                            additional,
+                            property_value,
+                            &property_path,
+                            errors,
+                            depth + 1,
+                        ),
+                        _ => {}
+                    },
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(min) = schema.get("minItems").and_then(|m| m.as_u64())
+                && (items.len() as u64) < min
+            {
+                errors.push(format!("{path}: expected at least {min} items"));
+            }
+            if let Some(max) = schema.get("maxItems").and_then(|m| m.as_u64())
+                && (items.len() as u64) > max
+            {
+                errors.push(format!("{path}: expected at most {max} items"));
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_value(
+                        document,
+                        item_schema,
+                        item,
+                        &format!("{path}[{index}]"),
+                        errors,
+                        depth + 1,
+                    );
+                }
+            }
+        }
+        Value::String(text) => {
+            let length = text.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(|m| m.as_u64())
+                && length < min
+            {
+                errors.push(format!("{path}: expected at least {min} characters"));
+            }
+            if let Some(max) = schema.get("maxLength").and_then(|m| m.as_u64())
+                && length > max
+            {
+                errors.push(format!("{path}: expected at most {max} characters"));
+            }
+            if let Some(pattern) = schema.get("pattern").and_then(|p| p.as_str())
+                && let Ok(regex) = Regex::new(pattern)
+                && !regex.is_match(text)
+            {
+                errors.push(format!("{path}: does not match pattern {pattern}"));
+            }
+        }
+        Value::Number(number) => {
+            let Some(n) = number.as_f64() else {
+                return;
+            };
+            let bound = |name: &str| schema.get(name).and_then(|b| b.as_f64());
+            // OpenAPI 3.0 uses boolean exclusive flags, 3.1 uses numeric exclusive bounds
+            let exclusive = |name: &str| schema.get(name).and_then(|b| b.as_bool()) == Some(true);
+
+            if let Some(minimum) = bound("minimum") {
+                if exclusive("exclusiveMinimum") && n <= minimum {
+                    errors.push(format!("{path}: expected greater than {minimum}"));
+                } else if n < minimum {
+                    errors.push(format!("{path}: expected at least {minimum}"));
+                }
+            }
+            if let Some(maximum) = bound("maximum") {
+                if exclusive("exclusiveMaximum") && n >= maximum {
+                    errors.push(format!("{path}: expected less than {maximum}"));
+                } else if n > maximum {
+                    errors.push(format!("{path}: expected at most {maximum}"));
+                }
+            }
+            if let Some(minimum) = bound("exclusiveMinimum")
+                && n <= minimum
+            {
+                errors.push(format!("{path}: expected greater than {minimum}"));
+            }
+            if let Some(maximum) = bound("exclusiveMaximum")
+                && n >= maximum
+            {
+                errors.push(format!("{path}: expected less than {maximum}"));
+            }
+        }
+        _ => {}
+    }
+}
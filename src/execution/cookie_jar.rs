@@ -0,0 +1,282 @@
+//! Apicize cookie jar.
+//!
+//! This submodule stores cookies received during a run or group so that they
+//! are sent with subsequent requests, following RFC 6265 domain and path matching
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Url;
+use reqwest::cookie::CookieStore;
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+
+tokio::task_local! {
+    /// Cookie jar of the request currently being dispatched
+    static ACTIVE_JAR: Arc<CookieJar>;
+}
+
+/// A cookie received from, or sent to, a server
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeCookie {
+    /// Cookie name
+    pub name: String,
+    /// Cookie value
+    pub value: String,
+    /// Domain the cookie is sent to
+    pub domain: String,
+    /// Path the cookie is sent to
+    pub path: String,
+    /// When the cookie expires (milliseconds since Unix epoch), session cookies have no expiration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<u128>,
+    /// If true, the cookie is only sent to the exact host that set it
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub host_only: bool,
+    /// If true, the cookie is only sent over HTTPS
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub secure: bool,
+    /// If true, the cookie is not available to browser scripts
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub http_only: bool,
+}
+
+impl ApicizeCookie {
+    /// Parse a Set-Cookie header value received from the specified URL, returning `None`
+    /// if the header is malformed or sets a domain that the URL's host does not match
+    pub fn parse(url: &Url, set_cookie: &str) -> Option<ApicizeCookie> {
+        let host = url.host_str()?.to_ascii_lowercase();
+        let mut attributes = set_cookie.split(';');
+        let (name, value) = attributes.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = ApicizeCookie {
+            name: name.to_string(),
+            value: value.trim().trim_matches('"').to_string(),
+            domain: host.clone(),
+            path: default_path(url),
+            expires: None,
+            host_only: true,
+            secure: false,
+            http_only: false,
+        };
+
+        let mut max_age: Option<i64> = None;
+        for attribute in attributes {
+            let (attribute_name, attribute_value) = match attribute.split_once('=') {
+                Some((n, v)) => (n.trim(), v.trim()),
+                None => (attribute.trim(), ""),
+            };
+            match attribute_name.to_ascii_lowercase().as_str() {
+                "domain" => {
+                    let domain = attribute_value.trim_start_matches('.').to_ascii_lowercase();
+                    if !domain.is_empty() {
+                        if !domain_matches(&host, &domain) {
+                            return None;
+                        }
+                        cookie.domain = domain;
+                        cookie.host_only = false;
+                    }
+                }
+                "path" if attribute_value.starts_with('/') => {
+                    cookie.path = attribute_value.to_string();
+                }
+                "max-age" => {
+                    if let Ok(seconds) = attribute_value.parse::<i64>() {
+                        max_age = Some(seconds);
+                    }
+                }
+                "expires" => {
+                    if let Ok(expires) = httpdate::parse_http_date(attribute_value) {
+                        cookie.expires = Some(
+                            expires
+                                .duration_since(UNIX_EPOCH)
+                                .unwrap_or_default()
+                                .as_millis(),
+                        );
+                    }
+                }
+                "secure" => {
+                    cookie.secure = true;
+                }
+                "httponly" => {
+                    cookie.http_only = true;
+                }
+                _ => {}
+            }
+        }
+
+        // Max-Age takes precedence over Expires, zero or negative values expire immediately
+        if let Some(seconds) = max_age {
+            cookie.expires = Some(if seconds <= 0 {
+                0
+            } else {
+                (SystemTime::now() + Duration::from_secs(seconds as u64))
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis()
+            });
+        }
+
+        Some(cookie)
+    }
+
+    /// Returns true if the cookie has expired
+    pub fn is_expired(&self) -> bool {
+        self.expires.is_some_and(|expires| {
+            expires
+                <= SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis()
+        })
+    }
+
+    /// Returns true if the cookie should be sent to the specified URL
+    pub fn matches(&self, url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.to_ascii_lowercase();
+
+        (if self.host_only {
+            host == self.domain
+        } else {
+            domain_matches(&host, &self.domain)
+        }) && path_matches(url.path(), &self.path)
+            && (!self.secure || url.scheme() == "https")
+            && !self.is_expired()
+    }
+}
+
+/// Cookies stored across the requests of a run or group
+#[derive(Default)]
+pub struct CookieJar {
+    cookies: Mutex<Vec<ApicizeCookie>>,
+}
+
+impl CookieJar {
+    /// Return cookies to send to the specified URL, with more specific paths first
+    pub fn get_cookies(&self, url: &Url) -> Vec<ApicizeCookie> {
+        let mut cookies = self
+            .cookies
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.matches(url))
+            .cloned()
+            .collect::<Vec<ApicizeCookie>>();
+        cookies.sort_by_key(|c| std::cmp::Reverse(c.path.len()));
+        cookies
+    }
+
+    /// Store cookies, replacing those with matching name, domain and path
+    /// and removing those which have expired
+    pub fn store(&self, cookies: &[ApicizeCookie]) {
+        let mut stored = self.cookies.lock().unwrap();
+        for cookie in cookies {
+            stored.retain(|c| {
+                !(c.name == cookie.name && c.domain == cookie.domain && c.path == cookie.path)
+            });
+            if !cookie.is_expired() {
+                stored.push(cookie.clone());
+            }
+        }
+    }
+
+    /// Return all unexpired cookies in the jar
+    pub fn list(&self) -> Vec<ApicizeCookie> {
+        self.cookies
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| !c.is_expired())
+            .cloned()
+            .collect()
+    }
+
+    /// Remove all cookies from the jar
+    pub fn clear(&self) {
+        self.cookies.lock().unwrap().clear();
+    }
+
+    /// Execute the future with the jar available to `ScopedCookieStore`, so that cookies
+    /// are sent and stored on each redirect followed while dispatching a request
+    pub async fn scope<F: Future>(jar: Option<Arc<CookieJar>>, future: F) -> F::Output {
+        match jar {
+            Some(jar) => ACTIVE_JAR.scope(jar, future).await,
+            None => future.await,
+        }
+    }
+
+    /// Format cookies as a Cookie header value
+    pub fn to_header(cookies: &[ApicizeCookie]) -> String {
+        cookies
+            .iter()
+            .map(|c| format!("{}={}", c.name, c.value))
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+}
+
+impl CookieStore for CookieJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let cookies = cookie_headers
+            .filter_map(|v| v.to_str().ok())
+            .filter_map(|v| ApicizeCookie::parse(url, v))
+            .collect::<Vec<ApicizeCookie>>();
+        self.store(&cookies);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let cookies = self.get_cookies(url);
+        if cookies.is_empty() {
+            None
+        } else {
+            HeaderValue::try_from(CookieJar::to_header(&cookies)).ok()
+        }
+    }
+}
+
+/// Cookie provider for shared HTTP clients, storing and sending cookies using the jar
+/// of the request being dispatched (see `CookieJar::scope`), if any
+pub struct ScopedCookieStore;
+
+impl CookieStore for ScopedCookieStore {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let _ = ACTIVE_JAR.try_with(|jar| jar.set_cookies(cookie_headers, url));
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        ACTIVE_JAR.try_with(|jar| jar.cookies(url)).ok().flatten()
+    }
+}
+
+/// Returns true if host is the domain or a subdomain of it
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.len() > domain.len()
+            && host.ends_with(domain)
+            && host.as_bytes()[host.len() - domain.len() - 1] == b'.')
+}
+
+/// Returns true if the request path is the cookie path or is beneath it
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/')
+                || request_path.as_bytes().get(cookie_path.len()) == Some(&b'/')))
+}
+
+/// The default cookie path is the request path up to, but not including, its last slash
+fn default_path(url: &Url) -> String {
+    let path = url.path();
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(i) => path[..i].to_string(),
+    }
+}
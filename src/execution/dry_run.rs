@@ -0,0 +1,72 @@
+//! Apicize dry runs.
+//!
+//! This submodule describes requests as they would be dispatched, rendered
+//! without opening network connections
+use serde::{Deserialize, Serialize};
+
+use super::ApicizeHttpRequest;
+use crate::{ApicizeError, Identifiable, Selection};
+
+/// Request rendered as it would be dispatched, for a single data row if it iterates a data set
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeDryRun {
+    /// ID of the request
+    pub id: String,
+
+    /// Name of the request, after substitution
+    pub name: String,
+
+    /// Optional key of the request (assigned or inherited)
+    pub key: Option<String>,
+
+    /// Data row number rendered, if the request or a group containing it iterates a data set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_number: Option<usize>,
+
+    /// Scenario selected for the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scenario: Option<Selection>,
+
+    /// Authorization selected for the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<Selection>,
+
+    /// Certificate selected for the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<Selection>,
+
+    /// Proxy selected for the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Selection>,
+
+    /// Data set selected for the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Selection>,
+
+    /// Request as it would be sent, if it could be rendered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<ApicizeHttpRequest>,
+
+    /// Placeholders in the request (or its authorization) with no value to substitute
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub unresolved: Vec<String>,
+
+    /// Authorization or URL scheme which could not be resolved without opening a connection
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub warnings: Vec<String>,
+
+    /// Error rendering the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ApicizeError>,
+}
+
+impl ApicizeDryRun {
+    /// Return a selection for the entity, if one was selected
+    pub(crate) fn to_selection<T: Identifiable>(entity: Option<&T>) -> Option<Selection> {
+        entity.map(|e| Selection {
+            id: e.get_id().to_string(),
+            name: e.get_name().to_string(),
+        })
+    }
+}
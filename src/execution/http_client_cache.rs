@@ -0,0 +1,67 @@
+//! Apicize HTTP client cache.
+//!
+//! This submodule caches HTTP clients for the duration of a test run so that
+//! requests with the same configuration share connection pools and TLS sessions
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::Client;
+
+use crate::ApicizeError;
+
+/// Effective configuration of an HTTP client, requests with matching keys share a client
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HttpClientKey {
+    /// ID of the certificate used for the client, if any
+    pub certificate_id: String,
+    /// ID of the proxy used for the client, if any
+    pub proxy_id: String,
+    /// Maximum number of redirects to follow (0 = do not follow)
+    pub number_of_redirects: usize,
+    /// If true, invalid server certificates are accepted
+    pub accept_invalid_certs: bool,
+    /// Request timeout, if any
+    pub timeout: Option<Duration>,
+    /// If true, HTTP/2 connections are kept alive while idle
+    pub keep_alive: bool,
+}
+
+/// A cached HTTP client
+pub struct CachedHttpClient {
+    /// Client used to dispatch requests
+    pub client: Client,
+}
+
+/// HTTP clients cached by configuration
+#[derive(Default)]
+pub struct HttpClientCache {
+    clients: Mutex<HashMap<HttpClientKey, Arc<CachedHttpClient>>>,
+}
+
+impl HttpClientCache {
+    /// Return the client cached for the key, building and caching one if necessary
+    pub fn get_or_build(
+        &self,
+        key: HttpClientKey,
+        build: impl FnOnce() -> Result<Client, ApicizeError>,
+    ) -> Result<Arc<CachedHttpClient>, ApicizeError> {
+        let mut clients = self.clients.lock().unwrap();
+        if let Some(cached) = clients.get(&key) {
+            return Ok(cached.clone());
+        }
+        let cached = Arc::new(CachedHttpClient { client: build()? });
+        clients.insert(key, cached.clone());
+        Ok(cached)
+    }
+
+    /// Number of clients which have been built
+    pub fn len(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+
+    /// Returns true if no clients have been built
+    pub fn is_empty(&self) -> bool {
+        self.clients.lock().unwrap().is_empty()
+    }
+}
@@ -0,0 +1,175 @@
+//! Apicize HTTP timings.
+//!
+//! This submodule records how long each phase of dispatching a request takes,
+//! hooking DNS resolution and connection establishment of cached HTTP clients
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use http::Extensions;
+use hyper_util::client::legacy::connect::{Connection, HttpInfo};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use serde::{Deserialize, Serialize};
+use tower_layer::Layer;
+use tower_service::Service;
+
+tokio::task_local! {
+    /// Recorder for the request currently being dispatched
+    static RECORDER: Arc<TimingRecorder>;
+}
+
+/// Duration of each phase of a dispatched request (milliseconds)
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeHttpTimings {
+    /// Time spent resolving the host name, not set if a connection was reused
+    /// or the host is an IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns: Option<u128>,
+    /// Time spent establishing the connection, including the TLS handshake for HTTPS,
+    /// not set if a connection was reused
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect: Option<u128>,
+    /// Time from sending the request until the response headers were received,
+    /// excluding DNS resolution and connection
+    pub ttfb: u128,
+    /// Time spent downloading the response body
+    pub download: u128,
+}
+
+/// Records the DNS resolution and connection times of a request being dispatched
+#[derive(Default)]
+pub struct TimingRecorder {
+    dns: Mutex<Option<Duration>>,
+    connect: Mutex<Option<Duration>>,
+    opened_connection: Mutex<Option<SocketAddr>>,
+}
+
+impl TimingRecorder {
+    /// Run the future, recording DNS resolution and connections made by cached clients while it runs
+    pub async fn scope<F: Future>(self: &Arc<Self>, future: F) -> F::Output {
+        RECORDER.scope(self.clone(), future).await
+    }
+
+    /// Time spent resolving the host name, if resolution took place
+    pub fn dns(&self) -> Option<Duration> {
+        *self.dns.lock().unwrap()
+    }
+
+    /// Time spent connecting, including DNS resolution, if a connection was opened
+    pub fn connect(&self) -> Option<Duration> {
+        *self.connect.lock().unwrap()
+    }
+
+    /// Local address of the connection opened while dispatching, if any.  A response
+    /// received on a connection with another address was received on a reused connection
+    pub fn opened_connection(&self) -> Option<SocketAddr> {
+        *self.opened_connection.lock().unwrap()
+    }
+
+    /// Build timings for a dispatched request, given the time until response headers
+    /// were received and the time spent downloading the body
+    pub fn to_timings(
+        &self,
+        headers_received: Duration,
+        download: Duration,
+        reused: bool,
+    ) -> ApicizeHttpTimings {
+        let (dns, connect) = if reused {
+            (None, None)
+        } else {
+            (self.dns(), self.connect())
+        };
+        let dns_ms = dns.map(|d| d.as_millis());
+        ApicizeHttpTimings {
+            dns: dns_ms,
+            connect: connect.map(|c| c.as_millis().saturating_sub(dns_ms.unwrap_or(0))),
+            ttfb: headers_received
+                .saturating_sub(connect.unwrap_or_default())
+                .as_millis(),
+            download: download.as_millis(),
+        }
+    }
+
+    fn current() -> Option<Arc<TimingRecorder>> {
+        RECORDER.try_with(|recorder| recorder.clone()).ok()
+    }
+}
+
+/// DNS resolver which records resolution time of the request being dispatched
+pub struct TimedResolver;
+
+impl Resolve for TimedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let recorder = TimingRecorder::current();
+        Box::pin(async move {
+            let started = Instant::now();
+            let addrs = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .collect::<Vec<_>>();
+            if let Some(recorder) = recorder {
+                *recorder.dns.lock().unwrap() = Some(started.elapsed());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Connector layer which records connection time of the request being dispatched
+#[derive(Clone)]
+pub struct TimedConnectLayer;
+
+impl<S> Layer<S> for TimedConnectLayer {
+    type Service = TimedConnect<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TimedConnect { inner }
+    }
+}
+
+/// Connector service which records connection time, and the local address of the connection
+/// opened, of the request being dispatched
+#[derive(Clone)]
+pub struct TimedConnect<S> {
+    inner: S,
+}
+
+impl<S, R> Service<R> for TimedConnect<S>
+where
+    S: Service<R>,
+    S::Future: Send + 'static,
+    S::Response: Connection + Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let connecting = self.inner.call(req);
+        match TimingRecorder::current() {
+            // The connection may complete in the background, so keep the recorder
+            // in scope for DNS resolution wherever the connection is polled
+            Some(recorder) => Box::pin(RECORDER.scope(recorder.clone(), async move {
+                let started = Instant::now();
+                let result = connecting.await;
+                if let Ok(connection) = &result {
+                    *recorder.connect.lock().unwrap() = Some(started.elapsed());
+                    let mut extras = Extensions::new();
+                    connection.connected().get_extras(&mut extras);
+                    *recorder.opened_connection.lock().unwrap() =
+                        extras.get::<HttpInfo>().map(HttpInfo::local_addr);
+                }
+                result
+            })),
+            None => Box::pin(connecting),
+        }
+    }
+}
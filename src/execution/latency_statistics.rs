@@ -0,0 +1,188 @@
+//! Apicize latency statistics.
+//!
+//! This submodule summarizes the distribution of response durations
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ApicizeRequestResultRow, ApicizeRequestResultRowContent, ApicizeRequestResultRun};
+
+/// Distribution of response durations (milliseconds)
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeLatencyStatistics {
+    /// Number of durations
+    pub count: usize,
+    /// Shortest duration
+    pub min: u128,
+    /// Longest duration
+    pub max: u128,
+    /// Average duration
+    pub mean: f64,
+    /// Population standard deviation of durations
+    pub std_dev: f64,
+    /// Median duration
+    pub p50: u128,
+    /// 90th percentile duration
+    pub p90: u128,
+    /// 95th percentile duration
+    pub p95: u128,
+    /// 99th percentile duration
+    pub p99: u128,
+}
+
+impl ApicizeLatencyStatistics {
+    /// Calculate statistics for the durations, returning None if there are none;
+    /// percentiles use the nearest rank
+    pub fn from_durations(durations: &[u128]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        let mean = sorted.iter().map(|d| *d as f64).sum::<f64>() / count as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (*d as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        let percentile = |p: f64| {
+            let rank = (p / 100.0 * count as f64).ceil() as usize;
+            sorted[rank.clamp(1, count) - 1]
+        };
+
+        Some(ApicizeLatencyStatistics {
+            count,
+            min: sorted[0],
+            max: sorted[count - 1],
+            mean,
+            std_dev: variance.sqrt(),
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+        })
+    }
+
+    /// Calculate statistics for the response durations of a request's runs
+    pub fn from_runs(runs: &[ApicizeRequestResultRun]) -> Option<Self> {
+        Self::from_durations(&runs.iter().map(|run| run.duration).collect::<Vec<u128>>())
+    }
+
+    /// Calculate statistics for the response durations of a request's rows,
+    /// including each run of rows with multiple runs
+    pub fn from_rows(rows: &[ApicizeRequestResultRow]) -> Option<Self> {
+        let durations = rows
+            .iter()
+            .flat_map(|row| match &row.results {
+                ApicizeRequestResultRowContent::Runs(runs) => {
+                    runs.iter().map(|run| run.duration).collect::<Vec<u128>>()
+                }
+                ApicizeRequestResultRowContent::Execution(_) => vec![row.duration],
+            })
+            .collect::<Vec<u128>>();
+        Self::from_durations(&durations)
+    }
+}
+
+/// Number of sub-buckets per power of two, durations are bucketed to within 1/128th
+const SUB_BUCKETS: u128 = 128;
+
+/// Durations below this are counted exactly, each in its own bucket
+const EXACT_LIMIT: u128 = SUB_BUCKETS * 2;
+
+/// Streaming distribution of durations, counted in fixed log-linear buckets so that
+/// memory does not grow with the number of durations recorded.  Min, max, mean and
+/// standard deviation are exact, percentiles are accurate to within 1%
+#[derive(Default)]
+pub struct LatencyHistogram {
+    buckets: BTreeMap<u32, usize>,
+    count: usize,
+    min: u128,
+    max: u128,
+    mean: f64,
+    sum_of_squares: f64,
+}
+
+impl LatencyHistogram {
+    /// Record a duration
+    pub fn record(&mut self, duration: u128) {
+        *self.buckets.entry(Self::bucket_of(duration)).or_default() += 1;
+        if self.count == 0 {
+            self.min = duration;
+            self.max = duration;
+        } else {
+            self.min = self.min.min(duration);
+            self.max = self.max.max(duration);
+        }
+
+        // Welford's algorithm, to update the mean and variance without retaining durations
+        self.count += 1;
+        let value = duration as f64;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.sum_of_squares += delta * (value - self.mean);
+    }
+
+    /// Number of durations recorded
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Calculate statistics for the recorded durations, returning None if there are none;
+    /// percentiles use the nearest rank, reported as the lowest duration of its bucket
+    pub fn statistics(&self) -> Option<ApicizeLatencyStatistics> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let percentile = |p: f64| {
+            let rank = ((p / 100.0 * self.count as f64).ceil() as usize).clamp(1, self.count);
+            let mut counted = 0;
+            for (bucket, count) in &self.buckets {
+                counted += count;
+                if counted >= rank {
+                    return Self::lowest_in_bucket(*bucket).clamp(self.min, self.max);
+                }
+            }
+            self.max
+        };
+
+        Some(ApicizeLatencyStatistics {
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            mean: self.mean,
+            std_dev: (self.sum_of_squares / self.count as f64).sqrt(),
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+        })
+    }
+
+    /// Return the bucket counting a duration; durations below the exact limit have their own
+    /// bucket, larger ones share one of 128 buckets per power of two
+    fn bucket_of(duration: u128) -> u32 {
+        if duration < EXACT_LIMIT {
+            return duration as u32;
+        }
+        let shift = (u128::BITS - duration.leading_zeros()) - EXACT_LIMIT.ilog2();
+        let sub_bucket = (duration >> shift) - SUB_BUCKETS;
+        EXACT_LIMIT as u32 + (shift - 1) * SUB_BUCKETS as u32 + sub_bucket as u32
+    }
+
+    /// Return the lowest duration counted by a bucket
+    fn lowest_in_bucket(bucket: u32) -> u128 {
+        let bucket = bucket as u128;
+        if bucket < EXACT_LIMIT {
+            return bucket;
+        }
+        let shift = (bucket - EXACT_LIMIT) / SUB_BUCKETS + 1;
+        let sub_bucket = (bucket - EXACT_LIMIT) % SUB_BUCKETS + SUB_BUCKETS;
+        sub_bucket << shift
+    }
+}
@@ -0,0 +1,97 @@
+//! Apicize load results.
+//!
+//! This submodule aggregates the iterations of a load profile, so that results do not
+//! grow with the number of iterations executed
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ApicizeLatencyStatistics, LatencyHistogram, Tallies};
+
+/// Aggregated result of executing a request or group with a load profile
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApicizeLoadResult {
+    /// Number of virtual users
+    pub virtual_users: usize,
+    /// Number of iterations completed
+    pub iterations: usize,
+    /// Time spent executing the profile (milliseconds)
+    pub duration: u128,
+    /// Iterations completed per second
+    pub throughput: f64,
+    /// Proportion of iterations with errors or failed tests (0 to 1)
+    pub error_rate: f64,
+    /// Distribution of iteration durations, percentiles are accurate to within 1%
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<ApicizeLatencyStatistics>,
+    /// Number of times each distinct error was raised
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub errors: BTreeMap<String, usize>,
+
+    /// Success is true if all iterations are successful
+    pub success: bool,
+    /// Number of requests dispatched with all tests passed
+    pub request_success_count: usize,
+    /// Number of requests dispatched with some tests failed
+    pub request_failure_count: usize,
+    /// Number of requests with errors
+    pub request_error_count: usize,
+    /// Number of passed tests
+    pub test_pass_count: usize,
+    /// Number of failed tests
+    pub test_fail_count: usize,
+}
+
+/// Accumulates iterations executed by virtual users
+#[derive(Default)]
+pub struct LoadResultBuilder {
+    durations: LatencyHistogram,
+    unsuccessful: usize,
+    errors: BTreeMap<String, usize>,
+    tallies: Tallies,
+}
+
+impl LoadResultBuilder {
+    /// Record an iteration's duration, tallies and error (if any)
+    pub fn record(&mut self, duration: u128, tallies: &Tallies, error: Option<String>) {
+        self.durations.record(duration);
+        if !tallies.success || error.is_some() {
+            self.unsuccessful += 1;
+        }
+        if let Some(error) = error {
+            *self.errors.entry(error).or_default() += 1;
+        }
+        self.tallies.add(tallies);
+    }
+
+    /// Build the aggregated result, given how long the profile was executed
+    pub fn build(self, virtual_users: usize, elapsed: Duration) -> ApicizeLoadResult {
+        let iterations = self.durations.count();
+        let seconds = elapsed.as_secs_f64();
+        ApicizeLoadResult {
+            virtual_users,
+            iterations,
+            duration: elapsed.as_millis(),
+            throughput: if seconds > 0.0 {
+                iterations as f64 / seconds
+            } else {
+                0.0
+            },
+            error_rate: if iterations > 0 {
+                self.unsuccessful as f64 / iterations as f64
+            } else {
+                0.0
+            },
+            latency: self.durations.statistics(),
+            errors: self.errors,
+            success: self.tallies.success,
+            request_success_count: self.tallies.request_success_count,
+            request_failure_count: self.tallies.request_failure_count,
+            request_error_count: self.tallies.request_error_count,
+            test_pass_count: self.tallies.test_pass_count,
+            test_fail_count: self.tallies.test_fail_count,
+        }
+    }
+}
@@ -1,17 +1,35 @@
 pub mod apicize;
 pub mod apicize_execution;
+pub mod contract;
+pub mod cookie_jar;
 pub mod data_context;
+pub mod dry_run;
+pub mod http_client_cache;
+pub mod http_timings;
+pub mod latency_statistics;
+pub mod load_result;
 pub mod oauth2_cache;
 pub mod oauth2_client_tokens;
 pub mod oauth2_pkce;
+pub mod script_limits;
+pub mod substitutions;
 pub mod tally;
 pub mod test_runner;
 
 pub use apicize::*;
 pub use apicize_execution::*;
+pub use contract::*;
+pub use cookie_jar::*;
 pub use data_context::*;
+pub use dry_run::*;
+pub use http_client_cache::*;
+pub use http_timings::*;
+pub use latency_statistics::*;
+pub use load_result::*;
 pub use oauth2_cache::*;
 pub use oauth2_client_tokens::*;
 pub use oauth2_pkce::*;
+pub use script_limits::*;
+pub use substitutions::*;
 pub use tally::*;
 pub use test_runner::*;
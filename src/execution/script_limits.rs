@@ -0,0 +1,247 @@
+//! Apicize script limits.
+//!
+//! This submodule enforces execution time and heap limits on V8 scripts,
+//! terminating scripts which exceed them or whose execution is cancelled
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicU8, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::ApicizeError;
+
+/// Interval at which the watchdog checks for cancellation
+const WATCHDOG_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+const NOT_TERMINATED: u8 = 0;
+const TERMINATED_TIMEOUT: u8 = 1;
+const TERMINATED_HEAP: u8 = 2;
+const TERMINATED_CANCELLED: u8 = 3;
+const COMPLETED: u8 = 4;
+
+/// Watchdog shared by all executing scripts, started when the first script is watched
+static WATCHDOG: LazyLock<Arc<Watchdog>> = LazyLock::new(|| {
+    let watchdog = Arc::new(Watchdog::default());
+    let running = watchdog.clone();
+    std::thread::Builder::new()
+        .name("apicize-script-watchdog".to_string())
+        .spawn(move || running.run())
+        .expect("Unable to start script watchdog");
+    watchdog
+});
+
+/// ID assigned to the next watched script
+static NEXT_WATCH_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Limits applied when executing test and setup scripts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScriptLimits {
+    /// Maximum time, in milliseconds, a script may execute (0 = unlimited)
+    pub timeout: u32,
+    /// Maximum heap size, in megabytes, a script may allocate (0 = V8 default)
+    pub max_heap_size: usize,
+}
+
+impl Default for ScriptLimits {
+    fn default() -> Self {
+        Self {
+            timeout: 30_000,
+            max_heap_size: 256,
+        }
+    }
+}
+
+impl ScriptLimits {
+    /// Apply heap limit to isolate creation parameters
+    pub(crate) fn apply(&self, params: v8::CreateParams) -> v8::CreateParams {
+        if self.max_heap_size == 0 {
+            params
+        } else {
+            params.heap_limits(0, self.max_heap_size * 1024 * 1024)
+        }
+    }
+}
+
+/// Termination state shared by the watchdog thread and the heap limit callback
+struct ScriptTermination {
+    reason: AtomicU8,
+    handle: OnceLock<v8::IsolateHandle>,
+}
+
+impl ScriptTermination {
+    fn terminate(&self, reason: u8) {
+        if self
+            .reason
+            .compare_exchange(NOT_TERMINATED, reason, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+            && let Some(handle) = self.handle.get()
+        {
+            handle.terminate_execution();
+        }
+    }
+}
+
+/// A script being watched for its deadline and cancellation
+struct WatchedScript {
+    termination: Arc<ScriptTermination>,
+    deadline: Option<Instant>,
+    cancellation: CancellationToken,
+}
+
+/// Scripts being watched, by ID, notifying the watchdog thread when one is added
+#[derive(Default)]
+struct Watchdog {
+    scripts: Mutex<HashMap<u64, WatchedScript>>,
+    added: Condvar,
+}
+
+impl Watchdog {
+    /// Terminate scripts which pass their deadlines or are cancelled, waiting until the
+    /// next deadline (or poll for cancellation) in between.  Scripts are terminated while
+    /// the lock is held, so that a script which stops being watched is never terminated
+    fn run(&self) {
+        let mut scripts = self.scripts.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            scripts.retain(|_, script| {
+                let reason = if script.cancellation.is_cancelled() {
+                    TERMINATED_CANCELLED
+                } else if script.deadline.is_some_and(|deadline| now >= deadline) {
+                    TERMINATED_TIMEOUT
+                } else {
+                    return true;
+                };
+                script.termination.terminate(reason);
+                false
+            });
+
+            scripts = if scripts.is_empty() {
+                self.added.wait(scripts).unwrap()
+            } else {
+                let wait = scripts
+                    .values()
+                    .filter_map(|script| script.deadline)
+                    .min()
+                    .map_or(WATCHDOG_POLL_INTERVAL, |deadline| {
+                        deadline
+                            .saturating_duration_since(now)
+                            .min(WATCHDOG_POLL_INTERVAL)
+                    });
+                self.added.wait_timeout(scripts, wait).unwrap().0
+            };
+        }
+    }
+}
+
+/// Terminate execution when the isolate's heap limit is near, granting
+/// additional headroom so that termination can complete without aborting
+unsafe extern "C" fn near_heap_limit_callback(
+    data: *mut c_void,
+    current_heap_limit: usize,
+    _initial_heap_limit: usize,
+) -> usize {
+    let termination = unsafe { &*(data as *const ScriptTermination) };
+    termination.terminate(TERMINATED_HEAP);
+    current_heap_limit * 2
+}
+
+/// Watches an isolate while a script executes, terminating it if limits are exceeded
+/// or execution is cancelled.  The guard must be declared before the isolate
+/// it watches so that it outlives the isolate.
+pub(crate) struct ScriptGuard {
+    limits: ScriptLimits,
+    termination: Arc<ScriptTermination>,
+    watch_id: Option<u64>,
+}
+
+impl ScriptGuard {
+    pub fn new(limits: ScriptLimits) -> Self {
+        ScriptGuard {
+            limits,
+            termination: Arc::new(ScriptTermination {
+                reason: AtomicU8::new(NOT_TERMINATED),
+                handle: OnceLock::new(),
+            }),
+            watch_id: None,
+        }
+    }
+
+    /// Start watching the isolate for limit violations and cancellation
+    pub fn watch(&mut self, isolate: &mut v8::Isolate, cancellation: &CancellationToken) {
+        let _ = self.termination.handle.set(isolate.thread_safe_handle());
+
+        if self.limits.max_heap_size > 0 {
+            isolate.add_near_heap_limit_callback(
+                near_heap_limit_callback,
+                Arc::as_ptr(&self.termination) as *mut c_void,
+            );
+        }
+
+        if cancellation.is_cancelled() {
+            self.termination.terminate(TERMINATED_CANCELLED);
+            return;
+        }
+
+        let watch_id = NEXT_WATCH_ID.fetch_add(1, Ordering::Relaxed);
+        WATCHDOG.scripts.lock().unwrap().insert(
+            watch_id,
+            WatchedScript {
+                termination: self.termination.clone(),
+                deadline: if self.limits.timeout == 0 {
+                    None
+                } else {
+                    Some(Instant::now() + Duration::from_millis(self.limits.timeout as u64))
+                },
+                cancellation: cancellation.clone(),
+            },
+        );
+        WATCHDOG.added.notify_one();
+        self.watch_id = Some(watch_id);
+    }
+
+    /// Stop watching a script which did not return, returning an error if it was terminated
+    pub fn finish(&mut self) -> Result<(), ApicizeError> {
+        self.stop();
+        match self.termination.reason.load(Ordering::SeqCst) {
+            TERMINATED_TIMEOUT => Err(ApicizeError::ScriptLimit {
+                description: format!(
+                    "Script exceeded execution time limit of {} ms",
+                    self.limits.timeout
+                ),
+            }),
+            TERMINATED_HEAP => Err(ApicizeError::ScriptLimit {
+                description: format!(
+                    "Script exceeded heap limit of {} MB",
+                    self.limits.max_heap_size
+                ),
+            }),
+            TERMINATED_CANCELLED => Err(ApicizeError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    /// Stop watching a script which returned, ignoring (and cancelling) any termination
+    /// which arrived after it returned
+    pub fn complete(&mut self) {
+        self.stop();
+        if self.termination.reason.swap(COMPLETED, Ordering::SeqCst) != NOT_TERMINATED
+            && let Some(handle) = self.termination.handle.get()
+        {
+            handle.cancel_terminate_execution();
+        }
+    }
+
+    fn stop(&mut self) {
+        if let Some(watch_id) = self.watch_id.take() {
+            WATCHDOG.scripts.lock().unwrap().remove(&watch_id);
+        }
+    }
+}
+
+impl Drop for ScriptGuard {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,264 @@
+//! Apicize handlebar substitution.
+//!
+//! This submodule replaces `{{placeholders}}` in request values with scenario, data row
+//! and output variables, supporting paths into JSON values, defaults and filters
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde_json::{Map, Value};
+
+/// Values substituted into `{{placeholders}}`.  A placeholder names a variable or a path into
+/// a JSON value (ex. `{{user.address.city}}` or `{{items[0].id}}`), optionally followed by
+/// filters (ex. `{{name | default: 'guest' | upper}}`).  Supported filters are `default`,
+/// `json`, `upper`, `lower`, `trim`, `urlencode` and `base64`.
+/// Whitespace around names and filters is ignored, so `{{ name }}` resolves like `{{name}}`.
+#[derive(Default)]
+pub struct Substitutions {
+    values: Option<Arc<Map<String, Value>>>,
+}
+
+impl Substitutions {
+    pub fn new(values: Option<Arc<Map<String, Value>>>) -> Self {
+        Substitutions { values }
+    }
+
+    /// Replace placeholders in the text, leaving those which cannot be resolved as-is
+    pub fn substitute(&self, text: &str) -> String {
+        if !text.contains("{{") {
+            return text.to_string();
+        }
+
+        let mut result = String::with_capacity(text.len());
+        let mut remaining = text;
+
+        while let Some(start) = remaining.find("{{") {
+            result.push_str(&remaining[..start]);
+            if let Some(end) = remaining[start + 2..].find("}}") {
+                let placeholder = &remaining[start..start + 2 + end + 2];
+                match self.resolve(&placeholder[2..placeholder.len() - 2]) {
+                    Some(value) => result.push_str(&value),
+                    None => result.push_str(placeholder),
+                }
+                remaining = &remaining[start + 2 + end + 2..];
+            } else {
+                // No closing }} — copy rest as-is
+                result.push_str(&remaining[start..]);
+                return result;
+            }
+        }
+        result.push_str(remaining);
+        result
+    }
+
+    /// Replace placeholders in JSON text, escaping double quotes in values substituted
+    /// inside quoted strings
+    pub fn substitute_json(&self, text: &str) -> String {
+        if !text.contains("{{") {
+            return text.to_string();
+        }
+
+        // Pass 1: replace handlebars inside quoted strings, escaping double quotes in values
+        let mut result = String::with_capacity(text.len());
+        let mut remaining = text;
+        while let Some(quote_start) = remaining.find('"') {
+            result.push_str(&remaining[..quote_start + 1]);
+            remaining = &remaining[quote_start + 1..];
+            // Scan inside the quoted string
+            loop {
+                match (remaining.find("{{"), remaining.find('"')) {
+                    (Some(hb_start), Some(q_end)) if hb_start < q_end => {
+                        if let Some(hb_end) = remaining[hb_start + 2..].find("}}") {
+                            let placeholder = &remaining[hb_start..hb_start + 2 + hb_end + 2];
+                            result.push_str(&remaining[..hb_start]);
+                            match self.resolve(&placeholder[2..placeholder.len() - 2]) {
+                                Some(value) => result.push_str(&value.replace('"', "\\\"")),
+                                None => result.push_str(placeholder),
+                            }
+                            remaining = &remaining[hb_start + 2 + hb_end + 2..];
+                        } else {
+                            result.push_str(remaining);
+                            return result;
+                        }
+                    }
+                    _ => {
+                        // No handlebars before the closing quote (or no closing quote)
+                        if let Some(q_end) = remaining.find('"') {
+                            result.push_str(&remaining[..q_end + 1]);
+                            remaining = &remaining[q_end + 1..];
+                        } else {
+                            result.push_str(remaining);
+                            return result;
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+        result.push_str(remaining);
+
+        // Pass 2: replace remaining handlebars outside quoted strings, no escaping
+        self.substitute(&result)
+    }
+
+    /// Add placeholders in the text which cannot be resolved to "unresolved"
+    pub fn find_unresolved(&self, text: &str, unresolved: &mut BTreeSet<String>) {
+        let mut remaining = text;
+        while let Some(start) = remaining.find("{{") {
+            let Some(end) = remaining[start + 2..].find("}}") else {
+                break;
+            };
+            let placeholder = &remaining[start..start + 2 + end + 2];
+            if self
+                .resolve(&placeholder[2..placeholder.len() - 2])
+                .is_none()
+            {
+                unresolved.insert(placeholder.to_string());
+            }
+            remaining = &remaining[start + 2 + end + 2..];
+        }
+    }
+
+    /// Resolve the expression of a placeholder (the text between handlebars), returning
+    /// None if it names no value (and has no default) or uses an unknown filter
+    pub fn resolve(&self, expression: &str) -> Option<String> {
+        let mut segments = split_unquoted(expression, '|').into_iter();
+        let mut value = self.lookup(segments.next()?.trim());
+        for filter in segments {
+            value = apply_filter(value, filter.trim())?;
+        }
+        value.map(|v| to_text(&v))
+    }
+
+    /// Return the value of a variable, or of a path into a variable's JSON value
+    fn lookup(&self, path: &str) -> Option<Value> {
+        let values = self.values.as_ref()?;
+
+        // Variables named with periods or brackets are matched before paths
+        if let Some(value) = values.get(path) {
+            return Some(value.clone());
+        }
+
+        let segments = path_segments(path)?;
+        let (name, segments) = segments.split_first()?;
+        let mut current = values.get(*name)?;
+        for segment in segments {
+            current = match current {
+                Value::Object(map) => map.get(*segment)?,
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(current.clone())
+    }
+}
+
+/// Convert a value to the text substituted for it, strings are not quoted
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+/// Apply a filter to a value, returning None if the filter is unknown
+fn apply_filter(value: Option<Value>, filter: &str) -> Option<Option<Value>> {
+    let (name, argument) = match filter.split_once(':') {
+        Some((name, argument)) => (name.trim(), Some(unquote(argument.trim()))),
+        None => (filter, None),
+    };
+    let map_text = |f: fn(&str) -> String| value.as_ref().map(|v| Value::String(f(&to_text(v))));
+    Some(match (name, argument) {
+        ("default", Some(default)) => {
+            let missing = match &value {
+                None | Some(Value::Null) => true,
+                Some(Value::String(s)) => s.is_empty(),
+                Some(_) => false,
+            };
+            if missing {
+                Some(Value::String(default.to_string()))
+            } else {
+                value
+            }
+        }
+        ("json", None) => value.as_ref().map(|v| Value::String(v.to_string())),
+        ("upper", None) => map_text(str::to_uppercase),
+        ("lower", None) => map_text(str::to_lowercase),
+        ("trim", None) => map_text(|s| s.trim().to_string()),
+        ("urlencode", None) => map_text(url_encode),
+        ("base64", None) => map_text(|s| STANDARD.encode(s)),
+        _ => return None,
+    })
+}
+
+/// Split text on a separator, ignoring separators inside single or double quotes
+fn split_unquoted(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None if c == separator => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            }
+            None => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Remove matching single or double quotes surrounding a filter argument
+fn unquote(argument: &str) -> &str {
+    for quote in ['\'', '"'] {
+        if let Some(unquoted) = argument
+            .strip_prefix(quote)
+            .and_then(|a| a.strip_suffix(quote))
+        {
+            return unquoted;
+        }
+    }
+    argument
+}
+
+/// Split a path into property names and array indexes (ex. `items[0].id` into `items`,
+/// `0` and `id`), returning None if brackets are unbalanced
+fn path_segments(path: &str) -> Option<Vec<&str>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (name, mut indexes) = match part.find('[') {
+            Some(index) => part.split_at(index),
+            None => (part, ""),
+        };
+        if !name.is_empty() {
+            segments.push(name);
+        }
+        while let Some(rest) = indexes.strip_prefix('[') {
+            let end = rest.find(']')?;
+            segments.push(&rest[..end]);
+            indexes = &rest[end + 1..];
+        }
+        if !indexes.is_empty() {
+            return None;
+        }
+    }
+    Some(segments)
+}
+
+/// Percent-encode all but unreserved URL characters
+fn url_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
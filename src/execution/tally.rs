@@ -1,8 +1,8 @@
 use super::{
     ApicizeExecution, ApicizeGroupResult, ApicizeGroupResultContent, ApicizeGroupResultRow,
-    ApicizeGroupResultRowContent, ApicizeGroupResultRun, ApicizeRequestResult,
-    ApicizeRequestResultContent, ApicizeRequestResultRow, ApicizeRequestResultRowContent,
-    ApicizeRequestResultRun, ApicizeResult,
+    ApicizeGroupResultRowContent, ApicizeGroupResultRun, ApicizeGroupScriptResult,
+    ApicizeLoadResult, ApicizeRequestResult, ApicizeRequestResultContent, ApicizeRequestResultRow,
+    ApicizeRequestResultRowContent, ApicizeRequestResultRun, ApicizeResult,
 };
 
 pub trait Tally {
@@ -66,6 +66,7 @@ impl Tally for ApicizeRequestResultContent {
             ApicizeRequestResultContent::Rows { rows, .. } => rows.get_tallies(),
             ApicizeRequestResultContent::Runs { runs, .. } => runs.get_tallies(),
             ApicizeRequestResultContent::Execution { execution } => execution.get_tallies(),
+            ApicizeRequestResultContent::Load { load } => load.get_tallies(),
         }
     }
 }
@@ -144,6 +145,7 @@ impl Tally for ApicizeGroupResultContent {
             ApicizeGroupResultContent::Rows { rows } => rows.get_tallies(),
             ApicizeGroupResultContent::Runs { runs } => runs.get_tallies(),
             ApicizeGroupResultContent::Results { results: entries } => entries.get_tallies(),
+            ApicizeGroupResultContent::Load { load } => load.get_tallies(),
         }
     }
 }
@@ -161,6 +163,23 @@ impl Tally for ApicizeGroupResult {
     }
 }
 
+impl Tally for ApicizeGroupScriptResult {
+    fn get_tallies(&self) -> Tallies {
+        let test_fail_count = self
+            .tests
+            .as_ref()
+            .map_or(0, |tests| tests.iter().filter(|t| !t.success).count());
+        Tallies {
+            success: self.success,
+            request_success_count: 0,
+            request_failure_count: 0,
+            request_error_count: 0,
+            test_pass_count: self.tests.as_ref().map_or(0, |tests| tests.len()) - test_fail_count,
+            test_fail_count,
+        }
+    }
+}
+
 impl Tally for ApicizeGroupResultRowContent {
     fn get_tallies(&self) -> Tallies {
         match self {
@@ -230,3 +249,16 @@ impl Tally for ApicizeExecution {
         }
     }
 }
+
+impl Tally for ApicizeLoadResult {
+    fn get_tallies(&self) -> Tallies {
+        Tallies {
+            success: self.success,
+            request_success_count: self.request_success_count,
+            request_failure_count: self.request_failure_count,
+            request_error_count: self.request_error_count,
+            test_pass_count: self.test_pass_count,
+            test_fail_count: self.test_fail_count,
+        }
+    }
+}
@@ -3,39 +3,48 @@
 //! This library supports dispatching Apicize functional web tests
 use regex::Regex;
 use reqwest::redirect::Policy;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, LazyLock, Mutex, Once};
 use std::time::{Duration, Instant};
 use xmltojson::to_json;
 
 use async_recursion::async_recursion;
 use encoding_rs::{Encoding, UTF_8};
+use hyper_util::client::legacy::connect::HttpInfo;
 use mime::Mime;
-use reqwest::{Body, Client, Response};
+use reqwest::multipart::{Form, Part};
+use reqwest::{Body, Client, Response, Url};
 use serde_json::{Map, Value};
 use tokio::select;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, SemaphorePermit};
 use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 
 use super::{
-    ApicizeBody, ApicizeExecution, ApicizeExecutionTestContext, ApicizeGroupResult,
-    ApicizeGroupResultContent, ApicizeGroupResultRow, ApicizeGroupResultRun, ApicizeHttpRequest,
-    ApicizeHttpResponse, ApicizeRequestResult, ApicizeRequestResultRun, ApicizeResult,
-    ApicizeTestBehavior, ApicizeTestResponse, ApicizeTestResult, DataContext, DataContextGenerator,
-    GetDataContext, Tally,
+    ApicizeBody, ApicizeCookie, ApicizeDryRun, ApicizeExecution, ApicizeExecutionAttempt,
+    ApicizeExecutionTestContext, ApicizeGroupResult, ApicizeGroupResultContent,
+    ApicizeGroupResultRow, ApicizeGroupResultRun, ApicizeGroupScript, ApicizeGroupScriptResult,
+    ApicizeHttpConnection, ApicizeHttpRequest, ApicizeHttpResponse, ApicizeLatencyStatistics,
+    ApicizeLoadResult, ApicizeMultipartPart, ApicizePollSummary, ApicizeRequestResult,
+    ApicizeRequestResultRun, ApicizeResult, ApicizeTestBehavior, ApicizeTestResponse,
+    ApicizeTestResult, CookieJar, DataContext, DataContextGenerator, GetDataContext,
+    HttpClientCache, HttpClientKey, LoadResultBuilder, ScopedCookieStore, ScriptGuard,
+    ScriptLimits, Substitutions, Tallies, Tally, TimedConnectLayer, TimedResolver, TimingRecorder,
 };
 use crate::authorization::AuthorizationPlain;
 use crate::oauth2_client_tokens::TokenResult;
 use crate::parameters::ParameterCipher;
 use crate::types::workspace::RequestExecutionParameters;
-use crate::workspace::RequestExecutionState;
+use crate::workspace::{AfterEachScript, RequestDataSet, RequestExecutionState};
 use crate::{
     ApicizeError, ApicizeGroupResultRowContent, ApicizeRequestResultContent,
-    ApicizeRequestResultRow, ApicizeRequestResultRowContent, Authorization, ExecutionConcurrency,
-    Identifiable, OAuth2ClientCredentialParameters, Request, RequestBody, RequestEntry,
-    RequestGroup, VariableCache, Workspace, get_oauth2_client_credentials,
-    retrieve_oauth2_token_from_cache,
+    ApicizeRequestResultRow, ApicizeRequestResultRowContent, Authorization, Disabled,
+    ExecutionConcurrency, Identifiable, LoadProfile, MultipartPart,
+    OAuth2ClientCredentialParameters, OpenApiContract, Request, RequestBody, RequestEntry,
+    RequestGroup, RunFilter, RunPolicy, SiblingDependencies, VariableCache, Workspace,
+    extract_file_data, get_oauth2_client_credentials, retrieve_oauth2_token_from_cache,
 };
 
 // #[cfg(test)]
@@ -43,34 +52,8 @@ use crate::{
 
 static V8_INIT: Once = Once::new();
 
-/// Utility function to perform string substitution based upon search/replace values in "subs"
-fn clone_and_sub(text: &str, subs: &HashMap<String, String>) -> String {
-    if subs.is_empty() || !text.contains("{{") {
-        return text.to_string();
-    }
-
-    let mut result = String::with_capacity(text.len());
-    let mut remaining = text;
-
-    while let Some(start) = remaining.find("{{") {
-        result.push_str(&remaining[..start]);
-        if let Some(end) = remaining[start + 2..].find("}}") {
-            let key = &remaining[start..start + 2 + end + 2];
-            if let Some(value) = subs.get(key) {
-                result.push_str(value);
-            } else {
-                result.push_str(key);
-            }
-            remaining = &remaining[start + 2 + end + 2..];
-        } else {
-            // No closing }} — copy rest as-is
-            result.push_str(&remaining[start..]);
-            return result;
-        }
-    }
-    result.push_str(remaining);
-    result
-}
+/// No permitted parent path for data files, the default for runs
+static NO_DATA_PATH: Option<PathBuf> = None;
 
 /// Aggregate child durations (milliseconds) according to execution concurrency:
 /// sequential executions accumulate (sum), concurrent executions overlap so the
@@ -85,72 +68,19 @@ fn aggregate_duration(
     }
 }
 
-fn clone_and_sub_json(text: &str, subs: &HashMap<String, String>) -> String {
-    if subs.is_empty() || !text.contains("{{") {
-        return text.to_string();
-    }
-
-    // Pass 1: replace handlebars inside quoted strings, escaping double quotes in values
-    let mut result = String::with_capacity(text.len());
-    let mut remaining = text;
-    while let Some(quote_start) = remaining.find('"') {
-        result.push_str(&remaining[..quote_start + 1]);
-        remaining = &remaining[quote_start + 1..];
-        // Scan inside the quoted string
-        loop {
-            match (remaining.find("{{"), remaining.find('"')) {
-                (Some(hb_start), Some(q_end)) if hb_start < q_end => {
-                    if let Some(hb_end) = remaining[hb_start + 2..].find("}}") {
-                        let key = &remaining[hb_start..hb_start + 2 + hb_end + 2];
-                        result.push_str(&remaining[..hb_start]);
-                        if let Some(value) = subs.get(key) {
-                            result.push_str(&value.replace('"', "\\\""));
-                        } else {
-                            result.push_str(key);
-                        }
-                        remaining = &remaining[hb_start + 2 + hb_end + 2..];
-                    } else {
-                        result.push_str(remaining);
-                        return result;
-                    }
-                }
-                _ => {
-                    // No handlebars before the closing quote (or no closing quote)
-                    if let Some(q_end) = remaining.find('"') {
-                        result.push_str(&remaining[..q_end + 1]);
-                        remaining = &remaining[q_end + 1..];
-                    } else {
-                        result.push_str(remaining);
-                        return result;
-                    }
-                    break;
-                }
-            }
-        }
-    }
-    result.push_str(remaining);
+/// Semaphore limiting how many children or runs execute at once, if a maximum is set
+fn concurrency_limit(max_concurrency: Option<usize>) -> Option<Arc<Semaphore>> {
+    max_concurrency
+        .filter(|max| *max > 0)
+        .map(|max| Arc::new(Semaphore::new(max)))
+}
 
-    // Pass 2: replace remaining handlebars outside quoted strings, no escaping
-    let text2 = result;
-    let mut result2 = String::with_capacity(text2.len());
-    let mut remaining2 = text2.as_str();
-    while let Some(start) = remaining2.find("{{") {
-        result2.push_str(&remaining2[..start]);
-        if let Some(end) = remaining2[start + 2..].find("}}") {
-            let key = &remaining2[start..start + 2 + end + 2];
-            if let Some(value) = subs.get(key) {
-                result2.push_str(value);
-            } else {
-                result2.push_str(key);
-            }
-            remaining2 = &remaining2[start + 2 + end + 2..];
-        } else {
-            result2.push_str(&remaining2[start..]);
-            return result2;
-        }
+/// Wait for a permit from a concurrency limit, if there is one
+async fn acquire_limit(limit: Option<Arc<Semaphore>>) -> Option<OwnedSemaphorePermit> {
+    match limit {
+        Some(limit) => limit.acquire_owned().await.ok(),
+        None => None,
     }
-    result2.push_str(remaining2);
-    result2
 }
 
 static PORT_REGEX: LazyLock<Regex> =
@@ -190,6 +120,13 @@ pub trait ApicizeRunner {
         &self,
         request_ids: Vec<String>,
     ) -> impl std::future::Future<Output = Vec<Result<ApicizeResult, ApicizeError>>> + Send;
+
+    /// Render the requests as they would be dispatched (per data row), without opening
+    /// network connections
+    fn dry_run(
+        &self,
+        request_ids: Vec<String>,
+    ) -> impl std::future::Future<Output = Vec<Result<Vec<ApicizeDryRun>, ApicizeError>>> + Send;
 }
 
 /// Information about a test run
@@ -202,12 +139,32 @@ pub struct TestRunnerContext {
     executing_request_or_group_id: String,
     /// Current values of variables (scenario, test-defined)
     value_cache: Mutex<VariableCache>,
+    /// Permitted parent path for data files
+    allowed_data_path: Option<PathBuf>,
     /// When test execution started
     tests_started: Instant,
     /// Used for interactive UI runs where a single execution is requested with no timeout
     single_run_no_timeout: bool,
     /// If true, reqwest trace will be enabled (for I/O logging)
     enable_trace: bool,
+    /// Limits applied to test and setup scripts
+    script_limits: ScriptLimits,
+    /// OpenAPI contract responses are validated against, or the error loading it
+    openapi_contract: Option<Result<OpenApiContract, String>>,
+    /// If true, requests in a run share a cookie jar
+    enable_cookie_jar: bool,
+    /// If true, requests with unresolved placeholders fail instead of being sent
+    strict_substitution: bool,
+    /// HTTP clients, reused by requests with the same configuration
+    http_clients: HttpClientCache,
+    /// Limits the number of requests dispatched at once across the run, if set
+    dispatch_limit: Option<Semaphore>,
+    /// IDs of requests and groups selected by the run's filter, all are executed if not set
+    selected_ids: Option<HashSet<String>>,
+    /// How failures and errors affect remaining requests not governed by a group's policy
+    run_policy: RunPolicy,
+    /// Set when a request errors under a policy stopping the run
+    run_stopped: AtomicBool,
     /// Optional callback mechanism to track executions
     execution_counter_callback: Option<Box<ExecutionCounterCallback>>,
 }
@@ -234,10 +191,45 @@ pub struct TestRunnerContextInit<'a> {
     pub allowed_data_path: &'a Option<PathBuf>,
     /// If true, reqwest trace will be enabled (for I/O logging)
     pub enable_trace: bool,
+    /// Limits applied to test and setup scripts
+    pub script_limits: ScriptLimits,
+    /// If true, requests in a run share a cookie jar, storing cookies received from
+    /// responses and sending them with subsequent requests
+    pub enable_cookie_jar: bool,
+    /// If true, requests with placeholders that cannot be resolved fail instead of being
+    /// sent with the placeholders as-is
+    pub strict_substitution: bool,
+    /// Maximum number of requests dispatched at once across the run, unlimited if not set
+    pub max_concurrency: Option<usize>,
+    /// Filter selecting which requests execute by tag or key, all are executed if not set
+    pub filter: Option<RunFilter>,
+    /// How failures and errors affect remaining requests, unless overridden by a group,
+    /// all requests are executed if not set
+    pub run_policy: Option<RunPolicy>,
     /// Optional callback mechanism to track executions
     pub execution_counter_callback: Option<Box<ExecutionCounterCallback>>,
 }
 
+impl Default for TestRunnerContextInit<'_> {
+    fn default() -> Self {
+        TestRunnerContextInit {
+            workspace: Workspace::default(),
+            cancellation: None,
+            executing_request_or_group_id: "",
+            single_run_no_timeout: false,
+            allowed_data_path: &NO_DATA_PATH,
+            enable_trace: false,
+            script_limits: ScriptLimits::default(),
+            enable_cookie_jar: false,
+            strict_substitution: false,
+            max_concurrency: None,
+            filter: None,
+            run_policy: None,
+            execution_counter_callback: None,
+        }
+    }
+}
+
 impl TestRunnerContext {
     pub fn new(init: TestRunnerContextInit) -> Self {
         // Ensure V8 is initialized
@@ -247,14 +239,36 @@ impl TestRunnerContext {
             v8::V8::initialize();
         });
 
+        let openapi_contract = init.workspace.openapi.as_ref().map(|file_name| {
+            OpenApiContract::open(file_name, init.allowed_data_path).map_err(|err| err.to_string())
+        });
+
+        let selected_ids = init
+            .filter
+            .as_ref()
+            .map(|filter| filter.select(&init.workspace.requests));
+
         TestRunnerContext {
             workspace: init.workspace,
             cancellation: init.cancellation.unwrap_or_default(),
             executing_request_or_group_id: init.executing_request_or_group_id.to_string(),
             value_cache: Mutex::new(VariableCache::new(init.allowed_data_path)),
+            allowed_data_path: init.allowed_data_path.clone(),
             tests_started: Instant::now(),
             single_run_no_timeout: init.single_run_no_timeout,
             enable_trace: init.enable_trace,
+            script_limits: init.script_limits,
+            openapi_contract,
+            enable_cookie_jar: init.enable_cookie_jar,
+            strict_substitution: init.strict_substitution,
+            http_clients: HttpClientCache::default(),
+            dispatch_limit: init
+                .max_concurrency
+                .filter(|max| *max > 0)
+                .map(Semaphore::new),
+            selected_ids,
+            run_policy: init.run_policy.unwrap_or_default(),
+            run_stopped: AtomicBool::new(false),
             execution_counter_callback: init.execution_counter_callback,
         }
     }
@@ -279,6 +293,27 @@ impl TestRunnerContext {
         }
     }
 
+    /// Validate a response against an operation in the workbook's OpenAPI document, returning
+    /// no behaviors if the workbook does not reference a document
+    pub fn validate_contract(
+        &self,
+        operation: &str,
+        response: &ApicizeHttpResponse,
+    ) -> Vec<ApicizeTestBehavior> {
+        let error = match &self.openapi_contract {
+            Some(Ok(contract)) => return contract.validate(operation, response),
+            Some(Err(err)) => err.clone(),
+            None => return vec![],
+        };
+        vec![ApicizeTestBehavior {
+            name: "contract document is available".to_string(),
+            tag: Some(operation.to_string()),
+            success: false,
+            error: Some(error),
+            logs: None,
+        }]
+    }
+
     pub fn get_request(&self, request_id: &str) -> Result<&Request, ApicizeError> {
         match self.workspace.requests.entities.get(request_id) {
             Some(RequestEntry::Request(request)) => Ok(request),
@@ -337,6 +372,20 @@ impl TestRunnerContext {
             None => &[],
         }
     }
+
+    /// Wait until a request may be dispatched without exceeding the run's maximum concurrency,
+    /// returning a permit to hold until the dispatch completes
+    async fn acquire_dispatch_permit(&self) -> Result<Option<SemaphorePermit<'_>>, ApicizeError> {
+        let Some(limit) = &self.dispatch_limit else {
+            return Ok(None);
+        };
+        select! {
+            _ = self.cancellation.cancelled() => Err(ApicizeError::Cancelled),
+            permit = limit.acquire() => permit.map(Some).map_err(|err| ApicizeError::Error {
+                description: err.to_string(),
+            }),
+        }
+    }
 }
 
 impl ApicizeRunner for Arc<TestRunnerContext> {
@@ -346,12 +395,21 @@ impl ApicizeRunner for Arc<TestRunnerContext> {
     ) -> Vec<Result<ApicizeResult, ApicizeError>> {
         let mut results =
             Vec::<Result<ApicizeResult, ApicizeError>>::with_capacity(request_entry_ids.len());
+        // Each requested entry executes with its own state, sharing only the run's cookie jar
+        let cookie_jar = self
+            .enable_cookie_jar
+            .then(|| Arc::new(CookieJar::default()));
         for request_entry_id in request_entry_ids {
+            let state = RequestExecutionState {
+                cookie_jar: cookie_jar.clone(),
+                ..Default::default()
+            }
+            .with_run_policy(self.run_policy);
             let result = Box::pin(run_request_entry(
                 self.clone(),
                 request_entry_id,
                 Arc::new(RequestExecutionParameters::default()),
-                Arc::new(RequestExecutionState::default()),
+                Arc::new(state),
                 true,
             ))
             .await;
@@ -365,6 +423,27 @@ impl ApicizeRunner for Arc<TestRunnerContext> {
         }
         results
     }
+
+    async fn dry_run(
+        &self,
+        request_entry_ids: Vec<String>,
+    ) -> Vec<Result<Vec<ApicizeDryRun>, ApicizeError>> {
+        let mut results = Vec::with_capacity(request_entry_ids.len());
+        let state = Arc::new(RequestExecutionState::default());
+        for request_entry_id in request_entry_ids {
+            results.push(
+                dry_run_entry(
+                    self.clone(),
+                    request_entry_id,
+                    Arc::new(RequestExecutionParameters::default()),
+                    state.clone(),
+                    true,
+                )
+                .await,
+            );
+        }
+        results
+    }
 }
 
 /// Execute a request or group
@@ -377,11 +456,54 @@ async fn run_request_entry(
 ) -> Result<Option<ApicizeResult>, ApicizeError> {
     let entry = context.get_request_entry(&request_or_group_id)?;
 
-    let new_params =
+    // Requests not selected by the run's filter, and groups without selected requests, are skipped
+    if context
+        .selected_ids
+        .as_ref()
+        .is_some_and(|selected| !selected.contains(&request_or_group_id))
+    {
+        return Ok(None);
+    }
+
+    let enabled = !entry.get_disabled() || force_run;
+
+    // Requests and groups remaining after a failure or error stopping their group or run
+    // are reported as skipped
+    if enabled {
+        let skip_reason = if context.run_stopped.load(Ordering::Relaxed) {
+            Some("Run stopped after an error")
+        } else if state.is_stopped() {
+            Some("Stopped after a failure")
+        } else {
+            None
+        };
+        if let Some(skip_reason) = skip_reason {
+            return Ok(Some(skipped_result(
+                &context,
+                entry,
+                skip_reason.to_string(),
+            )));
+        }
+    }
+
+    let mut new_params =
         context
             .workspace
             .retrieve_request_parameters(entry, &context.value_cache, &params)?;
 
+    // Requests and groups whose condition is false are reported as skipped, and those whose
+    // condition cannot be evaluated are reported with the error
+    let mut condition_error: Option<ApicizeError> = None;
+    if enabled {
+        match evaluate_entry_condition(&context, entry, &mut new_params, &state) {
+            Ok(Some(skip_reason)) => {
+                return Ok(Some(skipped_result(&context, entry, skip_reason)));
+            }
+            Ok(None) => {}
+            Err(err) => condition_error = Some(err),
+        }
+    }
+
     let row_number = state.row_number;
 
     if let Some(execution_counter) = &context.execution_counter_callback {
@@ -393,43 +515,57 @@ async fn run_request_entry(
         });
     }
 
-    let result = match entry {
-        RequestEntry::Request(request) => {
-            if request.disabled && !force_run {
-                Ok(None)
-            } else {
-                match run_request(
-                    context.clone(),
-                    &request_or_group_id,
-                    Arc::new(new_params),
-                    state,
-                )
-                .await?
-                {
-                    Some(result) => Ok(Some(ApicizeResult::Request(result))),
-                    None => Ok(None),
+    let result = if let Some(err) = condition_error {
+        Ok(Some(errored_result(&context, entry, err)))
+    } else {
+        match entry {
+            RequestEntry::Request(request) => {
+                if request.disabled && !force_run {
+                    Ok(None)
+                } else {
+                    match run_request(
+                        context.clone(),
+                        &request_or_group_id,
+                        Arc::new(new_params),
+                        state.clone(),
+                    )
+                    .await?
+                    {
+                        Some(result) => Ok(Some(ApicizeResult::Request(result))),
+                        None => Ok(None),
+                    }
                 }
             }
-        }
-        RequestEntry::Group(group) => {
-            if group.disabled && !force_run {
-                Ok(None)
-            } else {
-                match run_group(
-                    context.clone(),
-                    &request_or_group_id,
-                    Arc::new(new_params),
-                    state,
-                )
-                .await?
-                {
-                    Some(result) => Ok(Some(ApicizeResult::Group(result))),
-                    None => Ok(None),
+            RequestEntry::Group(group) => {
+                if group.disabled && !force_run {
+                    Ok(None)
+                } else {
+                    match run_group(
+                        context.clone(),
+                        &request_or_group_id,
+                        Arc::new(new_params),
+                        state.clone(),
+                    )
+                    .await?
+                    {
+                        Some(result) => Ok(Some(ApicizeResult::Group(result))),
+                        None => Ok(None),
+                    }
                 }
             }
         }
     };
 
+    if let Ok(Some(result)) = &result {
+        let tallies = result.get_tallies();
+        if !tallies.success {
+            state.stop();
+        }
+        if tallies.request_error_count > 0 && state.run_policy == RunPolicy::StopRunOnError {
+            context.run_stopped.store(true, Ordering::Relaxed);
+        }
+    }
+
     if let Some(execution_counter) = &context.execution_counter_callback {
         execution_counter(&ExecutionProgress {
             id: request_or_group_id.clone(),
@@ -442,6 +578,191 @@ async fn run_request_entry(
     result
 }
 
+/// Evaluate the condition of a request or group (if any), returning why it is skipped if the
+/// condition is false; entries iterating their own data set only retain the rows for which
+/// the condition is true
+fn evaluate_entry_condition(
+    context: &TestRunnerContext,
+    entry: &RequestEntry,
+    params: &mut RequestExecutionParameters,
+    state: &RequestExecutionState,
+) -> Result<Option<String>, ApicizeError> {
+    let Some(condition) = entry.get_condition() else {
+        return Ok(None);
+    };
+
+    if let Some(data_set) = params.data_set.as_ref() {
+        let mut data = Vec::with_capacity(data_set.data.len());
+        for (row_number, row) in (1..).zip(data_set.data.iter()) {
+            let row_state = state.with_row(row, row_number);
+            if evaluate_condition(
+                context,
+                condition,
+                &None,
+                &None,
+                &params.variables,
+                &row_state,
+            )? {
+                data.push(row.clone());
+            }
+        }
+        if data.is_empty() {
+            return Ok(Some(format!(
+                "Condition \"{condition}\" is false for every data row"
+            )));
+        }
+        params.data_set = Arc::new(Some(RequestDataSet {
+            id: data_set.id.clone(),
+            data,
+        }));
+    } else if !evaluate_condition(context, condition, &None, &None, &params.variables, state)? {
+        return Ok(Some(format!("Condition \"{condition}\" is false")));
+    }
+    Ok(None)
+}
+
+/// Generate the result of a request or group skipped by a run policy or condition
+fn skipped_result(
+    context: &TestRunnerContext,
+    entry: &RequestEntry,
+    skip_reason: String,
+) -> ApicizeResult {
+    let executed_at = context.ellapsed_in_ms();
+    let key = context.get_request_key(entry.get_id()).unwrap_or_default();
+    match entry {
+        RequestEntry::Request(request) => ApicizeResult::Request(Box::new(ApicizeRequestResult {
+            id: request.id.clone(),
+            name: request.get_title(),
+            key,
+            tag: None,
+            url: None,
+            executed_at,
+            duration: 0,
+            data_context: DataContext::default(),
+            content: ApicizeRequestResultContent::Runs { runs: vec![] },
+            latency: None,
+            logs: None,
+            skip_reason: Some(skip_reason),
+            success: true,
+            request_success_count: 0,
+            request_failure_count: 0,
+            request_error_count: 0,
+            test_pass_count: 0,
+            test_fail_count: 0,
+        })),
+        RequestEntry::Group(group) => ApicizeResult::Group(Box::new(ApicizeGroupResult {
+            id: group.id.clone(),
+            name: group.get_title(),
+            key,
+            tag: None,
+            executed_at,
+            duration: 0,
+            data_context: DataContext::default(),
+            content: ApicizeGroupResultContent::Results { results: vec![] },
+            logs: None,
+            scripts: None,
+            skip_reason: Some(skip_reason),
+            success: true,
+            request_success_count: 0,
+            request_failure_count: 0,
+            request_error_count: 0,
+            test_pass_count: 0,
+            test_fail_count: 0,
+        })),
+    }
+}
+
+/// Generate the result of a request or group whose condition could not be evaluated
+fn errored_result(
+    context: &TestRunnerContext,
+    entry: &RequestEntry,
+    error: ApicizeError,
+) -> ApicizeResult {
+    let executed_at = context.ellapsed_in_ms();
+    let key = context.get_request_key(entry.get_id()).unwrap_or_default();
+    match entry {
+        RequestEntry::Request(request) => {
+            let execution = ApicizeExecution {
+                name: request.get_title(),
+                key: key.clone(),
+                method: None,
+                url: None,
+                test_context: ApicizeExecutionTestContext {
+                    merged: None,
+                    scenario: None,
+                    output: None,
+                    data: None,
+                    request: None,
+                    response: None,
+                },
+                output_variables: None,
+                logs: None,
+                tests: None,
+                error: Some(error),
+                attempts: None,
+                poll: None,
+                success: false,
+                test_pass_count: 0,
+                test_fail_count: 0,
+            };
+            let tallies = execution.get_tallies();
+            ApicizeResult::Request(Box::new(ApicizeRequestResult {
+                id: request.id.clone(),
+                name: request.get_title(),
+                key,
+                tag: None,
+                url: None,
+                executed_at,
+                duration: 0,
+                data_context: DataContext::default(),
+                content: ApicizeRequestResultContent::Execution {
+                    execution: Box::new(execution),
+                },
+                latency: None,
+                logs: None,
+                skip_reason: None,
+                success: tallies.success,
+                request_success_count: tallies.request_success_count,
+                request_failure_count: tallies.request_failure_count,
+                request_error_count: tallies.request_error_count,
+                test_pass_count: tallies.test_pass_count,
+                test_fail_count: tallies.test_fail_count,
+            }))
+        }
+        RequestEntry::Group(group) => {
+            let script = ApicizeGroupScriptResult {
+                script: ApicizeGroupScript::Condition,
+                child_name: None,
+                executed_at,
+                tests: None,
+                logs: None,
+                error: Some(error),
+                success: false,
+            };
+            let tallies = script.get_tallies();
+            ApicizeResult::Group(Box::new(ApicizeGroupResult {
+                id: group.id.clone(),
+                name: group.get_title(),
+                key,
+                tag: None,
+                executed_at,
+                duration: 0,
+                data_context: DataContext::default(),
+                content: ApicizeGroupResultContent::Results { results: vec![] },
+                logs: None,
+                scripts: Some(vec![script]),
+                skip_reason: None,
+                success: tallies.success,
+                request_success_count: tallies.request_success_count,
+                request_failure_count: tallies.request_failure_count,
+                request_error_count: tallies.request_error_count,
+                test_pass_count: tallies.test_pass_count,
+                test_fail_count: tallies.test_fail_count,
+            }))
+        }
+    }
+}
+
 // Execute a request
 async fn run_request(
     context: Arc<TestRunnerContext>,
@@ -458,8 +779,32 @@ async fn run_request(
         return Ok(None);
     }
 
-    let (content, data_context, tallies, logs) = if params.data_set.is_some() && state.row.is_none()
+    let (content, data_context, tallies, logs) = if let Some(profile) = &request.load
+        && !context.single_run_no_timeout
     {
+        let load = run_load(
+            context.clone(),
+            LoadTarget::Request(request_id.to_string()),
+            profile,
+            params.clone(),
+            state.clone(),
+        )
+        .await?;
+        let tallies = load.get_tallies();
+        (
+            ApicizeRequestResultContent::Load {
+                load: Box::new(load),
+            },
+            DataContext {
+                scenario: params.variables.clone(),
+                output: state.output_variables.clone(),
+                data: state.row.clone(),
+                output_result: None,
+            },
+            tallies,
+            None,
+        )
+    } else if params.data_set.is_some() && state.row.is_none() {
         let mut rows =
             run_request_rows(context.clone(), request_id, params.clone(), state.clone()).await?;
         let data_context = rows.generate_data_context();
@@ -516,9 +861,13 @@ async fn run_request(
             None,
         )
     } else {
-        let execution =
-            dispatch_request_and_test(context.clone(), request_id.to_string(), params, state)
-                .await?;
+        let execution = dispatch_request_and_test(
+            context.clone(),
+            request_id.to_string(),
+            params,
+            state.clone(),
+        )
+        .await?;
         let data_context = execution.generate_data_context();
         let tallies = execution.get_tallies();
         let logs = execution.logs.clone();
@@ -539,8 +888,27 @@ async fn run_request(
             &request.multi_run_execution,
         ),
         ApicizeRequestResultContent::Rows { rows } => rows.iter().map(|r| r.duration).sum(),
+        ApicizeRequestResultContent::Load { load } => load.duration,
+    };
+
+    let latency = match &content {
+        ApicizeRequestResultContent::Execution { .. } => None,
+        ApicizeRequestResultContent::Runs { runs } => ApicizeLatencyStatistics::from_runs(runs),
+        ApicizeRequestResultContent::Rows { rows } => ApicizeLatencyStatistics::from_rows(rows),
+        ApicizeRequestResultContent::Load { load } => load.latency.clone(),
     };
 
+    // Make statistics available to subsequent tests by request ID, and by the request's own
+    // key (if set), since names and inherited keys are not unique
+    if let Some(stats) = &latency {
+        let stats = serde_json::to_value(stats).unwrap();
+        let mut cached = state.latency.lock().unwrap();
+        if let Some(key) = &request.key {
+            cached.insert(key.clone(), stats.clone());
+        }
+        cached.insert(request.id.clone(), stats);
+    }
+
     Ok(Some(Box::new(ApicizeRequestResult {
         id: request.id.clone(),
         name: request.get_title(),
@@ -551,7 +919,9 @@ async fn run_request(
         duration,
         data_context,
         content,
+        latency,
         logs,
+        skip_reason: None,
         success: tallies.success,
         request_success_count: tallies.request_success_count,
         request_failure_count: tallies.request_failure_count,
@@ -582,11 +952,7 @@ async fn run_request_rows(
 
                 let row_executed_at = context.ellapsed_in_ms();
 
-                let row_state = Arc::new(RequestExecutionState {
-                    row: Some(Arc::new(row.clone())),
-                    row_number: Some(row_number),
-                    output_variables: state.output_variables.clone(),
-                });
+                let row_state = Arc::new(state.with_row(row, row_number));
 
                 if request.runs == 1 {
                     if let Some(execution_counter) = &context.execution_counter_callback {
@@ -628,6 +994,7 @@ async fn run_request_rows(
                         duration: execution.response_duration(),
                         data_context,
                         results: ApicizeRequestResultRowContent::Execution(Box::new(execution)),
+                        latency: None,
                         success: taliles.success,
                         request_success_count: taliles.request_success_count,
                         request_failure_count: taliles.request_failure_count,
@@ -655,6 +1022,7 @@ async fn run_request_rows(
                             &request.multi_run_execution,
                         ),
                         data_context: runs.generate_data_context(),
+                        latency: ApicizeLatencyStatistics::from_runs(&runs),
                         results: ApicizeRequestResultRowContent::Runs(runs),
                         success: row_tallies.success,
                         request_success_count: row_tallies.request_success_count,
@@ -747,6 +1115,7 @@ async fn run_request_runs(
         }
         ExecutionConcurrency::Concurrent => {
             let runs_executed_at = context.ellapsed_in_ms();
+            let limit = concurrency_limit(request.max_concurrency);
             let mut executing_runs: JoinSet<Result<ApicizeRequestResultRun, ApicizeError>> =
                 JoinSet::new();
 
@@ -755,16 +1124,20 @@ async fn run_request_runs(
                 let request_id = request_id.to_string();
                 let params = params.clone();
                 let state = state.clone();
+                let limit = limit.clone();
 
                 executing_runs.spawn(async move {
                     select! {
                         _ = context.cancellation.cancelled() => Err(ApicizeError::Cancelled),
-                        result = dispatch_request_and_test(
-                            context.clone(),
-                            request_id,
-                            params,
-                            state,
-                        ) => {
+                        result = async {
+                            let _permit = acquire_limit(limit).await;
+                            dispatch_request_and_test(
+                                context.clone(),
+                                request_id,
+                                params,
+                                state,
+                            ).await
+                        } => {
                             match result {
                                 Ok(execution) => {
                                     let success = execution.success;
@@ -831,99 +1204,186 @@ async fn run_group(
 
     let child_ids = context.get_group_children(group_id);
 
+    // A group with its own cookie jar does not share cookies with the rest of the run,
+    // and its children report to its after-each script rather than an ancestor's
+    let after_each = group.after_each.as_ref().map(|script| {
+        Arc::new(AfterEachScript {
+            script: script.clone(),
+            results: Mutex::default(),
+        })
+    });
+    let mut group_state = state.with_after_each(after_each.clone());
+    if group.cookie_jar {
+        group_state.cookie_jar = Some(Arc::new(CookieJar::default()));
+    }
+    let state = Arc::new(group_state.with_run_policy(group.run_policy.unwrap_or(state.run_policy)));
+
     let (use_state, logs) = if let Some(setup) = &group.setup
-        && let Some(setup_response) = execute_request_test(
-            setup,
-            &None,
-            &None,
-            &params.variables,
-            &state.row,
-            &state.output_variables,
-            &context.tests_started,
-        )? {
+        && let Some(setup_response) =
+            execute_request_test(&context, setup, &None, &None, &params.variables, &state)?
+    {
         (
-            Arc::new(RequestExecutionState {
-                row: state.row.clone(),
-                row_number: state.row_number,
-                output_variables: Some(Arc::new(setup_response.output.clone())),
-            }),
+            Arc::new(state.with_output(Some(Arc::new(setup_response.output.clone())))),
             setup_response.logs,
         )
     } else {
         (state, None)
     };
 
-    let (content, data_context, tallies) = if params.data_set.is_some() && !child_ids.is_empty() {
-        // Apply all data rows to each child of a group
-        let mut rows =
-            run_group_rows(context.clone(), group_id, params.clone(), use_state.clone()).await?;
-        if rows.len() == 1 {
-            let row = rows.remove(0);
-            match row.content {
-                ApicizeGroupResultRowContent::Runs { runs } => {
-                    let data_context = runs.generate_data_context();
-                    let tallies = runs.get_tallies();
-                    (
-                        ApicizeGroupResultContent::Runs { runs },
-                        data_context,
-                        tallies,
-                    )
-                }
-                ApicizeGroupResultRowContent::Results { results } => {
-                    let data_context = results.generate_data_context();
-                    let tallies = results.get_tallies();
+    let outcome = async {
+        Ok::<_, ApicizeError>(
+            if let Some(profile) = &group.load
+                && !context.single_run_no_timeout
+                && !child_ids.is_empty()
+            {
+                let load = run_load(
+                    context.clone(),
+                    LoadTarget::Group(group_id.to_string()),
+                    profile,
+                    params.clone(),
+                    use_state.clone(),
+                )
+                .await?;
+                let tallies = load.get_tallies();
+                (
+                    ApicizeGroupResultContent::Load {
+                        load: Box::new(load),
+                    },
+                    DataContext {
+                        scenario: params.variables.clone(),
+                        output: use_state.output_variables.clone(),
+                        data: use_state.row.clone(),
+                        output_result: None,
+                    },
+                    tallies,
+                )
+            } else if params.data_set.is_some() && !child_ids.is_empty() {
+                // Apply all data rows to each child of a group
+                let mut rows =
+                    run_group_rows(context.clone(), group_id, params.clone(), use_state.clone())
+                        .await?;
+                if rows.len() == 1 {
+                    let row = rows.remove(0);
+                    match row.content {
+                        ApicizeGroupResultRowContent::Runs { runs } => {
+                            let data_context = runs.generate_data_context();
+                            let tallies = runs.get_tallies();
+                            (
+                                ApicizeGroupResultContent::Runs { runs },
+                                data_context,
+                                tallies,
+                            )
+                        }
+                        ApicizeGroupResultRowContent::Results { results } => {
+                            let data_context = results.generate_data_context();
+                            let tallies = results.get_tallies();
+                            (
+                                ApicizeGroupResultContent::Results { results },
+                                data_context,
+                                tallies,
+                            )
+                        }
+                    }
+                } else {
+                    let data_context = rows.generate_data_context();
+                    let tallies = rows.get_tallies();
                     (
-                        ApicizeGroupResultContent::Results { results },
+                        ApicizeGroupResultContent::Rows { rows },
                         data_context,
                         tallies,
                     )
                 }
-            }
-        } else {
-            let data_context = rows.generate_data_context();
-            let tallies = rows.get_tallies();
-            (
-                ApicizeGroupResultContent::Rows { rows },
-                data_context,
-                tallies,
-            )
-        }
-    } else if multi_run {
-        let runs =
-            run_group_runs(context.clone(), group_id, params.clone(), use_state.clone()).await?;
-        let data_context = runs.generate_data_context();
-        let tallies = runs.get_tallies();
-        (
-            ApicizeGroupResultContent::Runs { runs },
-            data_context,
-            tallies,
-        )
-    } else {
-        let results = run_group_children(
-            context.clone(),
-            child_ids,
-            params.clone(),
-            use_state.clone(),
-            &group.execution,
-        )
-        .await?;
-        let data_context = results.generate_data_context();
-        let tallies = results.get_tallies();
-        (
-            ApicizeGroupResultContent::Results { results },
-            data_context,
-            tallies,
+            } else if multi_run {
+                let runs =
+                    run_group_runs(context.clone(), group_id, params.clone(), use_state.clone())
+                        .await?;
+                let data_context = runs.generate_data_context();
+                let tallies = runs.get_tallies();
+                (
+                    ApicizeGroupResultContent::Runs { runs },
+                    data_context,
+                    tallies,
+                )
+            } else {
+                let results = run_group_children(
+                    context.clone(),
+                    child_ids,
+                    params.clone(),
+                    use_state.clone(),
+                    &group.execution,
+                    group.max_concurrency,
+                )
+                .await?;
+                let data_context = results.generate_data_context();
+                let tallies = results.get_tallies();
+                (
+                    ApicizeGroupResultContent::Results { results },
+                    data_context,
+                    tallies,
+                )
+            },
         )
+    }
+    .await;
+
+    let mut scripts = match &after_each {
+        Some(after_each) => std::mem::take(&mut *after_each.results.lock().unwrap()),
+        None => vec![],
     };
 
-    let duration = match &content {
-        ApicizeGroupResultContent::Results { results } => {
-            aggregate_duration(results.iter().map(|r| r.duration()), &group.execution)
-        }
-        ApicizeGroupResultContent::Runs { runs } => {
-            aggregate_duration(runs.iter().map(|r| r.duration), &group.multi_run_execution)
+    // Teardown runs even if children failed or errored, unless the run was cancelled
+    if let Some(teardown) = &group.teardown
+        && !matches!(outcome, Err(ApicizeError::Cancelled))
+    {
+        let mut output = use_state
+            .output_variables
+            .as_ref()
+            .map_or_else(Map::new, |o| (**o).clone());
+        if let Ok((content, data_context, _)) = &outcome {
+            match content {
+                ApicizeGroupResultContent::Results { results } => {
+                    for result in results {
+                        if let Some(o) = &result.get_data_context().output_result {
+                            output.extend((**o).clone());
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(o) = &data_context.output_result {
+                        output.extend((**o).clone());
+                    }
+                }
+            }
+        }
+        let result = execute_group_script(
+            &context,
+            ApicizeGroupScript::Teardown,
+            teardown,
+            &None,
+            &None,
+            &params.variables,
+            &use_state.with_output(Some(Arc::new(output))),
+        );
+        if let Some(ApicizeError::Cancelled) = result.error {
+            return Err(ApicizeError::Cancelled);
+        }
+        scripts.push(result);
+    }
+
+    let (content, data_context, mut tallies) = outcome?;
+    for script in &scripts {
+        tallies.add(&script.get_tallies());
+    }
+
+    let duration = match &content {
+        ApicizeGroupResultContent::Results { results } => {
+            aggregate_duration(results.iter().map(|r| r.duration()), &group.execution)
+        }
+        ApicizeGroupResultContent::Runs { runs } => {
+            aggregate_duration(runs.iter().map(|r| r.duration), &group.multi_run_execution)
         }
         ApicizeGroupResultContent::Rows { rows } => rows.iter().map(|r| r.duration).sum(),
+        ApicizeGroupResultContent::Load { load } => load.duration,
     };
 
     Ok(Some(Box::new(ApicizeGroupResult {
@@ -936,6 +1396,12 @@ async fn run_group(
         data_context,
         content,
         logs,
+        scripts: if scripts.is_empty() {
+            None
+        } else {
+            Some(scripts)
+        },
+        skip_reason: None,
         success: tallies.success,
         request_success_count: tallies.request_success_count,
         request_failure_count: tallies.request_failure_count,
@@ -953,7 +1419,26 @@ async fn run_group_children(
     params: Arc<RequestExecutionParameters>,
     state: Arc<RequestExecutionState>,
     concurrency: &ExecutionConcurrency,
+    max_concurrency: Option<usize>,
 ) -> Result<Vec<ApicizeResult>, ApicizeError> {
+    // Children declaring dependencies upon siblings execute as a dependency graph
+    if let Some(dependencies) = SiblingDependencies::new(
+        &child_ids
+            .iter()
+            .map(|id| context.get_request_entry(id))
+            .collect::<Result<Vec<&RequestEntry>, ApicizeError>>()?,
+    ) {
+        return run_group_children_graph(
+            context,
+            child_ids,
+            dependencies,
+            params,
+            state,
+            max_concurrency,
+        )
+        .await;
+    }
+
     if child_ids.is_empty() {
         Ok(vec![])
     } else {
@@ -974,18 +1459,17 @@ async fn run_group_children(
                     if let Some(r) = result {
                         let result_output_variables = &r.get_data_context().output_result;
                         if result_output_variables.is_some() {
-                            group_state = Arc::new(RequestExecutionState {
-                                row: state.row.clone(),
-                                row_number: state.row_number,
-                                output_variables: result_output_variables.clone(),
-                            });
+                            group_state =
+                                Arc::new(state.with_output(result_output_variables.clone()));
                         }
+                        run_after_each(&context, &r, &params, &group_state)?;
                         results.push(r);
                     }
                 }
                 Ok(results)
             }
             ExecutionConcurrency::Concurrent => {
+                let limit = concurrency_limit(max_concurrency);
                 let mut executing_children: JoinSet<Result<Option<ApicizeResult>, ApicizeError>> =
                     JoinSet::new();
 
@@ -994,17 +1478,25 @@ async fn run_group_children(
                     let child_id = child_id.clone();
                     let params = params.clone();
                     let state = state.clone();
+                    let limit = limit.clone();
 
                     executing_children.spawn(async move {
                         select! {
                             _ = context.cancellation.cancelled() => Err(ApicizeError::Cancelled),
-                            result = run_request_entry(
-                                context.clone(),
-                                child_id,
-                                params,
-                                state,
-                                false,
-                            ) => {
+                            result = async {
+                                let _permit = acquire_limit(limit).await;
+                                let result = run_request_entry(
+                                    context.clone(),
+                                    child_id,
+                                    params.clone(),
+                                    state.clone(),
+                                    false,
+                                ).await?;
+                                if let Some(r) = &result {
+                                    run_after_each(&context, r, &params, &state)?;
+                                }
+                                Ok::<_, ApicizeError>(result)
+                            } => {
                                 result
                             }
                         }
@@ -1047,6 +1539,146 @@ async fn run_group_children(
     }
 }
 
+/// Execute a group's children in dependency order, starting each child as soon as the siblings
+/// it depends upon complete, with the output of those siblings merged into its output variables
+async fn run_group_children_graph(
+    context: Arc<TestRunnerContext>,
+    child_ids: &[String],
+    dependencies: SiblingDependencies,
+    params: Arc<RequestExecutionParameters>,
+    state: Arc<RequestExecutionState>,
+    max_concurrency: Option<usize>,
+) -> Result<Vec<ApicizeResult>, ApicizeError> {
+    if let Some((_, name)) = dependencies.unknown.first() {
+        return Err(ApicizeError::Error {
+            description: format!("Dependency \"{name}\" is not a sibling key or ID"),
+        });
+    }
+    if !dependencies.find_cycle().is_empty() {
+        return Err(ApicizeError::Error {
+            description: "Dependencies form a cycle".to_string(),
+        });
+    }
+
+    let limit = concurrency_limit(max_concurrency);
+    let mut completed: Vec<Option<Option<ApicizeResult>>> = vec![None; child_ids.len()];
+    let mut started = vec![false; child_ids.len()];
+    let mut executing_children: JoinSet<(usize, Result<Option<ApicizeResult>, ApicizeError>)> =
+        JoinSet::new();
+
+    loop {
+        for (index, child_id) in child_ids.iter().enumerate() {
+            if started[index]
+                || !dependencies.dependencies[index]
+                    .iter()
+                    .all(|dependency| completed[*dependency].is_some())
+            {
+                continue;
+            }
+            started[index] = true;
+
+            // Merge output from each dependency, in the order declared
+            let mut output = state.output_variables.as_ref().map(|o| (**o).clone());
+            for dependency in &dependencies.dependencies[index] {
+                if let Some(Some(result)) = &completed[*dependency]
+                    && let Some(o) = &result.get_data_context().output_result
+                {
+                    output.get_or_insert_with(Map::new).extend((**o).clone());
+                }
+            }
+
+            let context = context.clone();
+            let child_id = child_id.clone();
+            let params = params.clone();
+            let state = Arc::new(state.with_output(output.map(Arc::new)));
+            let limit = limit.clone();
+
+            executing_children.spawn(async move {
+                let result = select! {
+                    _ = context.cancellation.cancelled() => Err(ApicizeError::Cancelled),
+                    result = async {
+                        let _permit = acquire_limit(limit).await;
+                        let result = run_request_entry(
+                            context.clone(),
+                            child_id,
+                            params.clone(),
+                            state.clone(),
+                            false,
+                        ).await?;
+                        if let Some(r) = &result {
+                            run_after_each(&context, r, &params, &state)?;
+                        }
+                        Ok::<_, ApicizeError>(result)
+                    } => {
+                        result
+                    }
+                };
+                (index, result)
+            });
+        }
+
+        let Some(joined) = executing_children.join_next().await else {
+            break;
+        };
+        let (index, result) = joined.map_err(|err| ApicizeError::Error {
+            description: err.to_string(),
+        })?;
+        completed[index] = Some(result?);
+    }
+
+    Ok(completed.into_iter().flatten().flatten().collect())
+}
+
+/// Execute the after-each script of the group whose children are executing, if any,
+/// following a child's execution
+fn run_after_each(
+    context: &TestRunnerContext,
+    child: &ApicizeResult,
+    params: &RequestExecutionParameters,
+    state: &RequestExecutionState,
+) -> Result<(), ApicizeError> {
+    let Some(after_each) = &state.after_each else {
+        return Ok(());
+    };
+    if child.is_skipped() {
+        return Ok(());
+    }
+
+    // A child request's request and response are available to the script
+    let (request, response) = match child {
+        ApicizeResult::Request(result) => match &result.content {
+            ApicizeRequestResultContent::Execution { execution } => (
+                execution.test_context.request.clone(),
+                execution.test_context.response.clone(),
+            ),
+            _ => (None, None),
+        },
+        ApicizeResult::Group(_) => (None, None),
+    };
+
+    let output = child
+        .get_data_context()
+        .output_result
+        .clone()
+        .or_else(|| state.output_variables.clone());
+
+    let mut result = execute_group_script(
+        context,
+        ApicizeGroupScript::AfterEach,
+        &after_each.script,
+        &request,
+        &response,
+        &params.variables,
+        &state.with_output(output),
+    );
+    if let Some(ApicizeError::Cancelled) = result.error {
+        return Err(ApicizeError::Cancelled);
+    }
+    result.child_name = Some(child.get_title());
+    after_each.results.lock().unwrap().push(result);
+    Ok(())
+}
+
 /// Execute a group with multiple rows
 async fn run_group_rows(
     context: Arc<TestRunnerContext>,
@@ -1067,17 +1699,12 @@ async fn run_group_rows(
     } else {
         let mut rows = Vec::<ApicizeGroupResultRow>::with_capacity(active_data.len());
 
-        let mut row_state = RequestExecutionState {
-            row: None,
-            row_number: None,
-            output_variables: state.output_variables.clone(),
-        };
-
+        // Output variables of each row carry over to the next
+        let mut row_state = (*state).clone();
         for (row_number, row) in (1..).zip(active_data.iter()) {
             let row_executed_at = context.ellapsed_in_ms();
 
-            row_state.row = Some(Arc::new(row.clone()));
-            row_state.row_number = Some(row_number);
+            row_state = row_state.with_row(row, row_number);
 
             let (content, tallies, data_context) = if group.runs == 1 {
                 let entries = run_group_children(
@@ -1086,6 +1713,7 @@ async fn run_group_rows(
                     params.clone(),
                     Arc::new(row_state.clone()),
                     &group.execution,
+                    group.max_concurrency,
                 )
                 .await?;
                 let tallies = entries.get_tallies();
@@ -1171,6 +1799,7 @@ async fn run_group_runs(
                     params.clone(),
                     state.clone(),
                     &group.execution,
+                    group.max_concurrency,
                 )
                 .await?;
 
@@ -1196,6 +1825,7 @@ async fn run_group_runs(
             }
         }
         ExecutionConcurrency::Concurrent => {
+            let limit = concurrency_limit(group.max_concurrency);
             let mut executing_runs: JoinSet<Result<ApicizeGroupResultRun, ApicizeError>> =
                 JoinSet::new();
 
@@ -1205,19 +1835,25 @@ async fn run_group_runs(
                 let params = params.clone();
                 let state = state.clone();
                 let execution = group.execution.clone();
+                let max_concurrency = group.max_concurrency;
+                let limit = limit.clone();
 
                 let run_executed_at = context.ellapsed_in_ms();
 
                 executing_runs.spawn(async move {
                     select! {
                         _ = context.cancellation.cancelled() => Err(ApicizeError::Cancelled),
-                        executed_results = run_group_children(
-                            context.clone(),
-                            &child_ids,
-                            params,
-                            state,
-                            &execution,
-                        ) => {
+                        executed_results = async {
+                            let _permit = acquire_limit(limit).await;
+                            run_group_children(
+                                context.clone(),
+                                &child_ids,
+                                params,
+                                state,
+                                &execution,
+                                max_concurrency,
+                            ).await
+                        } => {
                             match executed_results {
                                 Ok(results) => {
                                     let tallies = results.get_tallies();
@@ -1259,6 +1895,151 @@ async fn run_group_runs(
     Ok(runs)
 }
 
+/// Request or group executed by each iteration of a load profile
+#[derive(Clone)]
+enum LoadTarget {
+    Request(String),
+    Group(String),
+}
+
+/// Execute a request or group with a load profile, aggregating iterations instead of
+/// returning a result for each one
+async fn run_load(
+    context: Arc<TestRunnerContext>,
+    target: LoadTarget,
+    profile: &LoadProfile,
+    params: Arc<RequestExecutionParameters>,
+    state: Arc<RequestExecutionState>,
+) -> Result<ApicizeLoadResult, ApicizeError> {
+    let virtual_users = profile.get_virtual_users();
+    let throttled = profile.get_rate().is_some();
+    let started = Instant::now();
+    let issued = Arc::new(AtomicUsize::new(0));
+    let builder = Arc::new(Mutex::new(LoadResultBuilder::default()));
+    let mut executing_users: JoinSet<Result<(), ApicizeError>> = JoinSet::new();
+
+    for virtual_user in 0..virtual_users {
+        let context = context.clone();
+        let target = target.clone();
+        let profile = profile.clone();
+        let params = params.clone();
+        let state = state.clone();
+        let issued = issued.clone();
+        let builder = builder.clone();
+
+        executing_users.spawn(async move {
+            // Throttled iterations are scheduled across virtual users, otherwise each
+            // virtual user iterates continuously during its window
+            let (start, stop) = profile.virtual_user_window(virtual_user);
+            if !throttled {
+                wait_until(&context, started + start).await?;
+            }
+            loop {
+                let iteration = issued.fetch_add(1, Ordering::SeqCst);
+                if throttled {
+                    let Some(scheduled_at) = profile.scheduled_at(iteration) else {
+                        break;
+                    };
+                    wait_until(&context, started + scheduled_at).await?;
+                } else if started.elapsed() >= stop {
+                    break;
+                }
+
+                let (duration, tallies, error) = select! {
+                    _ = context.cancellation.cancelled() => Err(ApicizeError::Cancelled),
+                    result = run_load_iteration(&context, &target, &params, &state, iteration) => result,
+                }?;
+                builder.lock().unwrap().record(duration, &tallies, error);
+            }
+            Ok(())
+        });
+    }
+
+    executing_users
+        .join_all()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<()>, ApicizeError>>()?;
+
+    let builder = std::mem::take(&mut *builder.lock().unwrap());
+    Ok(builder.build(virtual_users, started.elapsed()))
+}
+
+/// Execute an iteration of a load profile, returning its duration, tallies and error (if any)
+async fn run_load_iteration(
+    context: &Arc<TestRunnerContext>,
+    target: &LoadTarget,
+    params: &Arc<RequestExecutionParameters>,
+    state: &Arc<RequestExecutionState>,
+    iteration: usize,
+) -> Result<(u128, Tallies, Option<String>), ApicizeError> {
+    // Iterations cycle through data set rows, unless a row is already being executed
+    let state = match params.data_set.as_ref() {
+        Some(data_set) if state.row.is_none() && !data_set.data.is_empty() => {
+            let index = iteration % data_set.data.len();
+            Arc::new(state.with_row(&data_set.data[index], index + 1))
+        }
+        _ => state.clone(),
+    };
+
+    match target {
+        LoadTarget::Request(request_id) => {
+            let execution = dispatch_request_and_test(
+                context.clone(),
+                request_id.clone(),
+                params.clone(),
+                state,
+            )
+            .await?;
+            Ok((
+                execution.response_duration(),
+                execution.get_tallies(),
+                execution.error.map(|err| err.to_string()),
+            ))
+        }
+        LoadTarget::Group(group_id) => {
+            // Iterations only contribute to aggregated tallies, so the group's after-each
+            // script is not executed for them (its results would otherwise accumulate)
+            let group = context.get_group(group_id)?;
+            let results = run_group_children(
+                context.clone(),
+                context.get_group_children(group_id),
+                params.clone(),
+                Arc::new(state.with_after_each(None)),
+                &group.execution,
+                group.max_concurrency,
+            )
+            .await?;
+            Ok((
+                aggregate_duration(results.iter().map(|r| r.duration()), &group.execution),
+                results.get_tallies(),
+                None,
+            ))
+        }
+    }
+}
+
+/// Sleep until the specified instant, unless execution is cancelled
+async fn wait_until(context: &TestRunnerContext, instant: Instant) -> Result<(), ApicizeError> {
+    select! {
+        _ = context.cancellation.cancelled() => Err(ApicizeError::Cancelled),
+        _ = tokio::time::sleep_until(instant.into()) => Ok(()),
+    }
+}
+
+/// Name and URL of a dispatched request, the request sent and response received,
+/// and the response body if it is a JSON object
+type DispatchResult = Result<
+    (
+        String,
+        String,
+        ApicizeHttpRequest,
+        ApicizeHttpResponse,
+        Option<Map<String, Value>>,
+    ),
+    ApicizeError,
+>;
+
 #[async_recursion]
 async fn dispatch_request_and_test(
     context: Arc<TestRunnerContext>,
@@ -1278,44 +2059,109 @@ async fn dispatch_request_and_test(
 
     let request = context.get_request(&request_id)?;
 
-    let mut merged_vars = match &params.variables {
-        Some(vars) => (**vars).clone(),
-        None => Map::new(),
-    };
-    if let Some(r) = state.output_variables.as_ref() {
-        merged_vars.extend((**r).clone());
-    }
-    if let Some(r) = state.row.as_ref() {
-        merged_vars.extend((**r).clone());
-    }
-
-    let merged = match merged_vars.is_empty() {
-        true => None,
-        false => Some(Arc::new(merged_vars)),
-    };
-
-    let subs = match &merged {
-        Some(m) => {
-            let mut subs = HashMap::with_capacity(m.len());
-            for (name, value) in m.iter() {
-                let v = if let Some(s) = value.as_str() {
-                    s.to_owned()
-                } else {
-                    value.to_string()
-                };
-                subs.insert(format!("{{{{{name}}}}}"), v);
+    // Run the pre-request script, if any, so that its output is available for substitution
+    // and to tests; an error is recorded in place of dispatching the request
+    let mut pre_request_output: Option<Arc<Map<String, Value>>> = None;
+    let mut pre_request_error: Option<ApicizeError> = None;
+    let state = match &request.pre_request {
+        Some(script) => {
+            match execute_request_test(&context, script, &None, &None, &params.variables, &state) {
+                Ok(Some(response)) => {
+                    logs = response.logs;
+                    pre_request_output = Some(Arc::new(response.output));
+                    Arc::new(state.with_output(pre_request_output.clone()))
+                }
+                Ok(None) => state,
+                Err(err) => {
+                    pre_request_error = Some(err);
+                    state
+                }
             }
-            subs
         }
-        None => HashMap::new(),
+        None => state,
     };
 
+    let (merged, subs) = build_substitutions(&params.variables, &state);
+
     let mut test_count: usize = 0;
     let mut test_fail_count: usize = 0;
     let mut method: Option<String> = None;
     let url: Option<String>;
 
-    match dispatch_request(context.clone(), &request_id, &params, &subs, &merged).await {
+    // Dispatch the request, polling until its condition holds if configured
+    let poll_started = context.ellapsed_in_ms();
+    let mut poll: Option<ApicizePollSummary> = None;
+    let mut poll_error: Option<ApicizeError> = None;
+    let (dispatched, attempts) = if let Some(err) = pre_request_error {
+        (Err(err), vec![])
+    } else {
+        loop {
+            let (dispatched, attempts) = dispatch_with_retry(
+                &context,
+                &request_id,
+                &params,
+                &subs,
+                &merged,
+                &state.cookie_jar,
+            )
+            .await;
+
+            let Some(poll_until) = &request.poll_until else {
+                break (dispatched, attempts);
+            };
+
+            let summary = poll.get_or_insert_with(|| ApicizePollSummary {
+                dispatches: 0,
+                condition_met: false,
+                duration: 0,
+                statuses: vec![],
+            });
+            summary.dispatches += 1;
+            summary
+                .statuses
+                .push(dispatched.as_ref().ok().map(|(_, _, _, r, _)| r.status));
+
+            // Dispatch errors end polling and are reported as usual
+            if let Ok((_, _, http_request, http_response, _)) = &dispatched {
+                match evaluate_condition(
+                    &context,
+                    &poll_until.condition.to_script(),
+                    &Some(http_request.clone()),
+                    &Some(http_response.clone()),
+                    &params.variables,
+                    &state,
+                ) {
+                    Ok(true) => {
+                        summary.condition_met = true;
+                    }
+                    Ok(false) => {
+                        let elapsed = context.ellapsed_in_ms() - poll_started;
+                        if elapsed + poll_until.interval as u128 <= poll_until.max_wait as u128 {
+                            select! {
+                                _ = context.cancellation.cancelled() => return Err(ApicizeError::Cancelled),
+                                _ = tokio::time::sleep(Duration::from_millis(poll_until.interval)) => {}
+                            }
+                            continue;
+                        }
+                        poll_error = Some(ApicizeError::Error {
+                            description: format!(
+                                "Poll condition was not met after {} dispatches ({elapsed} ms)",
+                                summary.dispatches
+                            ),
+                        });
+                    }
+                    Err(err) => {
+                        poll_error = Some(err);
+                    }
+                }
+            }
+
+            summary.duration = context.ellapsed_in_ms() - poll_started;
+            break (dispatched, attempts);
+        }
+    };
+
+    match dispatched {
         Ok((name_with_subs, url_called, http_request, http_response, _)) => {
             name = name_with_subs;
             url = Some(url_called);
@@ -1327,16 +2173,15 @@ async fn dispatch_request_and_test(
             match &request.test {
                 Some(t) => {
                     match execute_request_test(
-                        clone_and_sub(t, &subs).as_str(),
+                        &context,
+                        subs.substitute(t).as_str(),
                         &execution_request,
                         &execution_response,
                         &params.variables,
-                        &state.row,
-                        &state.output_variables,
-                        &context.tests_started,
+                        &state,
                     ) {
                         Ok(test_response) => {
-                            (tests, output_variables, logs) = match test_response {
+                            (tests, output_variables) = match test_response {
                                 Some(response) => {
                                     let output = if response.output.is_empty() {
                                         None
@@ -1359,9 +2204,13 @@ async fn dispatch_request_and_test(
                                         Some(behaviors)
                                     };
 
-                                    (test_results, output, response.logs)
+                                    if let Some(test_logs) = response.logs {
+                                        logs.get_or_insert_with(Vec::new).extend(test_logs);
+                                    }
+
+                                    (test_results, output)
                                 }
-                                None => (None, None, None),
+                                None => (None, None),
                             };
                         }
                         Err(err) => {
@@ -1371,7 +2220,23 @@ async fn dispatch_request_and_test(
                 }
                 None => {
                     tests = None;
-                    output_variables = None;
+                    output_variables = pre_request_output;
+                }
+            }
+
+            if let Some(operation) = &request.operation
+                && !operation.is_empty()
+                && let Some(response) = &execution_response
+            {
+                let behaviors = context.validate_contract(operation, response);
+                if !behaviors.is_empty() {
+                    for b in &behaviors {
+                        test_count += 1;
+                        if !b.success {
+                            test_fail_count += 1;
+                        }
+                    }
+                    tests.get_or_insert_with(Vec::new).extend(behaviors);
                 }
             }
         }
@@ -1382,6 +2247,10 @@ async fn dispatch_request_and_test(
         }
     }
 
+    if error.is_none() {
+        error = poll_error;
+    }
+
     // If there was a cancellation, return a cancellation error instead of recording the error in the execution
     if let Some(ApicizeError::Cancelled) = error {
         return Err(ApicizeError::Cancelled);
@@ -1406,31 +2275,144 @@ async fn dispatch_request_and_test(
         tests,
         logs,
         error,
+        attempts: if attempts.len() > 1 {
+            Some(attempts)
+        } else {
+            None
+        },
+        poll,
         success,
         test_pass_count: test_count - test_fail_count,
         test_fail_count,
     })
 }
 
-/// Dispatch the specified request (via reqwest), returning either the repsonse or error
-async fn dispatch_request(
-    context: Arc<TestRunnerContext>,
+/// Merge scenario variables with output variables and the data row (in order of precedence),
+/// returning the merged values and the handlebar substitutions from them
+fn build_substitutions(
+    variables: &Option<Arc<Map<String, Value>>>,
+    state: &RequestExecutionState,
+) -> (Option<Arc<Map<String, Value>>>, Substitutions) {
+    let mut merged_vars = match variables {
+        Some(vars) => (**vars).clone(),
+        None => Map::new(),
+    };
+    if let Some(r) = state.output_variables.as_ref() {
+        merged_vars.extend((**r).clone());
+    }
+    if let Some(r) = state.row.as_ref() {
+        merged_vars.extend((**r).clone());
+    }
+
+    let merged = match merged_vars.is_empty() {
+        true => None,
+        false => Some(Arc::new(merged_vars)),
+    };
+
+    let subs = Substitutions::new(merged.clone());
+    (merged, subs)
+}
+
+/// Dispatch the specified request, retrying transient failures if a retry policy applies,
+/// returning the final attempt's result and a record of each attempt
+async fn dispatch_with_retry(
+    context: &Arc<TestRunnerContext>,
     request_id: &str,
     params: &RequestExecutionParameters,
-    subs: &HashMap<String, String>,
+    subs: &Substitutions,
     variables: &Option<Arc<Map<String, Value>>>,
-) -> Result<
-    (
-        String,
-        String,
-        ApicizeHttpRequest,
-        ApicizeHttpResponse,
-        Option<Map<String, Value>>,
-    ),
-    ApicizeError,
-> {
-    let request = context.get_request(request_id)?;
+    cookie_jar: &Option<Arc<CookieJar>>,
+) -> (DispatchResult, Vec<ApicizeExecutionAttempt>) {
+    let mut attempts = Vec::<ApicizeExecutionAttempt>::new();
+    let dispatched = loop {
+        let permit = match context.acquire_dispatch_permit().await {
+            Ok(permit) => permit,
+            Err(err) => break Err(err),
+        };
+        let attempt_executed_at = context.ellapsed_in_ms();
+        let result = dispatch_request(
+            context.clone(),
+            request_id,
+            params,
+            subs,
+            variables,
+            cookie_jar,
+        )
+        .await;
+        drop(permit);
+
+        let Some(retry) = &params.retry else {
+            break result;
+        };
+
+        let attempt = attempts.len() + 1;
+        let response = result.as_ref().ok().map(|(_, _, _, response, _)| response);
+        let retryable = match &result {
+            Ok(_) => response.is_some_and(|r| retry.is_retryable_status(r.status)),
+            Err(err) => retry.is_retryable_error(err),
+        };
+        let delay = if retryable && attempt < retry.max_attempts {
+            Some(retry.get_delay(attempt, response.and_then(|r| r.headers.as_ref())))
+        } else {
+            None
+        };
+
+        attempts.push(ApicizeExecutionAttempt {
+            attempt,
+            executed_at: attempt_executed_at,
+            duration: context.ellapsed_in_ms() - attempt_executed_at,
+            status: response.map(|r| r.status),
+            response: if delay.is_some() {
+                response.cloned()
+            } else {
+                None
+            },
+            error: result.as_ref().err().cloned(),
+            delay: delay.map(|d| d.as_millis()),
+        });
+
+        match delay {
+            Some(delay) => {
+                select! {
+                    _ = context.cancellation.cancelled() => break Err(ApicizeError::Cancelled),
+                    _ = tokio::time::sleep(delay) => {}
+                }
+            }
+            None => break result,
+        }
+    };
+
+    (dispatched, attempts)
+}
 
+/// Request built for dispatch, along with what is recorded as sent
+struct PreparedRequest {
+    /// Name of the request, after substitution
+    name: String,
+    /// URL of the request, after substitution and including its scheme
+    url: String,
+    /// Request to execute
+    web_request: reqwest::Request,
+    /// Information about the request sent, including query string and headers
+    sent: ApicizeHttpRequest,
+    /// OAuth2 client token sent, if any
+    oauth2_token: Option<TokenResult>,
+    /// Authorization or URL scheme which could not be resolved when rendering a dry run
+    warnings: Vec<String>,
+}
+
+/// Build the specified request, substituting values into its URL, headers, query string
+/// and body.  If no client is specified, the request is rendered as a dry run, without
+/// opening connections to guess the URL's scheme or retrieve OAuth2 tokens
+async fn prepare_request(
+    context: &TestRunnerContext,
+    request: &Request,
+    params: &RequestExecutionParameters,
+    subs: &Substitutions,
+    variables: &Option<Arc<Map<String, Value>>>,
+    cookie_jar: &Option<Arc<CookieJar>>,
+    client: Option<&Client>,
+) -> Result<PreparedRequest, ApicizeError> {
     let method = match &request.method {
         Some(m) => reqwest::Method::from_bytes(m.as_bytes()).map_err(|_| ApicizeError::Http {
             context: None,
@@ -1440,66 +2422,23 @@ async fn dispatch_request(
         None => reqwest::Method::GET,
     };
 
-    let timeout = if context.single_run_no_timeout {
-        None
-    } else if let Some(t) = request.timeout {
-        if t == 0 {
-            None
-        } else {
-            Some(Duration::from_millis(t as u64))
+    // In strict mode, requests with placeholders that cannot be resolved are not sent
+    if context.strict_substitution {
+        let unresolved = unresolved_placeholders(context, request, params, subs);
+        if !unresolved.is_empty() {
+            return Err(ApicizeError::Error {
+                description: format!("Unresolved placeholders: {}", unresolved.join(", ")),
+            });
         }
-    } else {
-        Some(Duration::from_secs(30))
-    };
+    }
 
-    // Build the reqwest client and request
-    let mut reqwest_builder = Client::builder()
-        .http2_keep_alive_while_idle(request.keep_alive)
-        .danger_accept_invalid_certs(request.accept_invalid_certs)
-        .redirect(if request.number_of_redirects == 0 {
-            Policy::none()
-        } else {
-            Policy::limited(request.number_of_redirects)
-        })
-        .connection_verbose(context.enable_trace);
+    let dry_run = client.is_none();
+    let mut oauth2_token: Option<TokenResult> = None;
+    let mut warnings = Vec::<String>::new();
 
-    if let Some(t) = timeout {
-        reqwest_builder = reqwest_builder.timeout(t);
-    } else {
-        let max = Duration::from_mins(5);
-        reqwest_builder = reqwest_builder
-            .connect_timeout(max)
-            .read_timeout(max)
-            .pool_idle_timeout(max);
-        #[cfg(target_os = "linux")]
-        {
-            reqwest_builder = reqwest_builder.tcp_user_timeout(max);
-        }
-        reqwest_builder = reqwest_builder.http2_keep_alive_timeout(max);
-    }
+    let name = subs.substitute(&request.name);
 
-    // Add certificate to builder if configured
-    if let Some(certificate) = context
-        .workspace
-        .certificates
-        .get_optional(&params.certificate_id)
-    {
-        reqwest_builder = certificate.append_to_builder(reqwest_builder)?;
-    }
-
-    // Add proxy to builder if configured
-    if let Some(proxy) = context.workspace.proxies.get_optional(&params.proxy_id) {
-        reqwest_builder = proxy.append_to_builder(reqwest_builder)?;
-    }
-
-    let builder_result = reqwest_builder.build();
-    let mut oauth2_token: Option<TokenResult> = None;
-
-    let name = clone_and_sub(&request.name, subs);
-
-    let client = builder_result.map_err(|err| ApicizeError::from_reqwest(err, None))?;
-
-    let mut url = clone_and_sub(request.url.as_str(), subs).trim().to_string();
+    let mut url = subs.substitute(request.url.as_str()).trim().to_string();
 
     if url.is_empty() {
         return Err(ApicizeError::Http {
@@ -1519,18 +2458,33 @@ async fn dispatch_request(
             https = (port % 1000) == 443;
         }
 
-        if !https
-            && let Ok(Ok(_)) = tokio::time::timeout(
+        if !https {
+            if dry_run {
+                warnings.push(format!(
+                    "Scheme of {url} is not known without checking port 443, assuming http"
+                ));
+            } else if let Ok(Ok(_)) = tokio::time::timeout(
                 Duration::from_secs(2),
                 tokio::net::TcpStream::connect(format!("{url}:443")),
             )
             .await
-        {
-            https = true;
+            {
+                https = true;
+            }
         }
         url = format!("{}://{}", if https { "https" } else { "http" }, url);
     }
 
+    let default_client: Client;
+    let client = match client {
+        Some(client) => client,
+        None => {
+            default_client = Client::builder()
+                .build()
+                .map_err(|err| ApicizeError::from_reqwest(err, None))?;
+            &default_client
+        }
+    };
     let mut request_builder = client.request(method, &url);
 
     // Add headers, including authorization if applicable
@@ -1545,8 +2499,8 @@ async fn dispatch_request(
     if let Some(h) = &request.headers {
         for nvp in h {
             if nvp.disabled != Some(true) {
-                let name_str = clone_and_sub(&nvp.name, subs);
-                let value_str = clone_and_sub(&nvp.value, subs);
+                let name_str = subs.substitute(&nvp.name);
+                let value_str = subs.substitute(&nvp.value);
                 headers.insert(
                     reqwest::header::HeaderName::try_from(name_str).unwrap(),
                     reqwest::header::HeaderValue::try_from(value_str).unwrap(),
@@ -1569,15 +2523,13 @@ async fn dispatch_request(
             AuthorizationPlain::Basic {
                 username, password, ..
             } => {
-                request_builder = request_builder.basic_auth(
-                    clone_and_sub(username, subs),
-                    Some(clone_and_sub(password, subs)),
-                );
+                request_builder = request_builder
+                    .basic_auth(subs.substitute(username), Some(subs.substitute(password)));
             }
             AuthorizationPlain::ApiKey { header, value, .. } => {
                 headers.append(
-                    reqwest::header::HeaderName::try_from(clone_and_sub(header, subs)).unwrap(),
-                    reqwest::header::HeaderValue::try_from(clone_and_sub(value, subs)).unwrap(),
+                    reqwest::header::HeaderName::try_from(subs.substitute(header)).unwrap(),
+                    reqwest::header::HeaderValue::try_from(subs.substitute(value)).unwrap(),
                 );
             }
             AuthorizationPlain::OAuth2Client {
@@ -1592,35 +2544,55 @@ async fn dispatch_request(
                 selected_proxy,
                 ..
             } => {
-                let sub_token_url = clone_and_sub(access_token_url.as_str(), subs);
-                let sub_client_id = clone_and_sub(client_id.as_str(), subs);
-                let sub_client_secret = clone_and_sub(client_secret.as_str(), subs);
-                let sub_scope = clone_and_sub(scope.as_str(), subs);
-                let sub_audience = clone_and_sub(audience.as_str(), subs);
-                match get_oauth2_client_credentials(
-                    id.as_str(),
-                    OAuth2ClientCredentialParameters {
-                        token_url: sub_token_url.as_str(),
-                        client_id: sub_client_id.as_str(),
-                        client_secret: sub_client_secret.as_str(),
-                        send_credentials_in_body: send_credentials_in_body.unwrap_or(false),
-                        scope: sub_scope.as_str(),
-                        audience: sub_audience.as_str(),
-                        certificate: context
-                            .workspace
-                            .certificates
-                            .get_optional(selected_certificate.get_id()),
-                        proxy: context.workspace.proxies.get_optional(&selected_proxy.id),
-                        enable_trace: context.enable_trace,
-                    },
-                )
-                .await
-                {
-                    Ok(token_result) => {
-                        request_builder = request_builder.bearer_auth(token_result.token.clone());
-                        oauth2_token = Some(token_result);
+                let sub_token_url = subs.substitute(access_token_url.as_str());
+                let sub_client_id = subs.substitute(client_id.as_str());
+                let sub_client_secret = subs.substitute(client_secret.as_str());
+                let sub_scope = subs.substitute(scope.as_str());
+                let sub_audience = subs.substitute(audience.as_str());
+                if dry_run {
+                    // Only a cached token can be sent without retrieving one
+                    match retrieve_oauth2_token_from_cache(id).await {
+                        Some(t) => {
+                            request_builder = request_builder.bearer_auth(t.access_token.clone());
+                            oauth2_token = Some(TokenResult {
+                                token: t.access_token,
+                                cached: true,
+                                url: None,
+                                certificate: None,
+                                proxy: None,
+                            });
+                        }
+                        None => warnings.push(format!(
+                            "OAuth2 client token is not cached and would be retrieved from {sub_token_url}"
+                        )),
+                    }
+                } else {
+                    match get_oauth2_client_credentials(
+                        id.as_str(),
+                        OAuth2ClientCredentialParameters {
+                            token_url: sub_token_url.as_str(),
+                            client_id: sub_client_id.as_str(),
+                            client_secret: sub_client_secret.as_str(),
+                            send_credentials_in_body: send_credentials_in_body.unwrap_or(false),
+                            scope: sub_scope.as_str(),
+                            audience: sub_audience.as_str(),
+                            certificate: context
+                                .workspace
+                                .certificates
+                                .get_optional(selected_certificate.get_id()),
+                            proxy: context.workspace.proxies.get_optional(&selected_proxy.id),
+                            enable_trace: context.enable_trace,
+                        },
+                    )
+                    .await
+                    {
+                        Ok(token_result) => {
+                            request_builder =
+                                request_builder.bearer_auth(token_result.token.clone());
+                            oauth2_token = Some(token_result);
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
                 }
             }
             AuthorizationPlain::OAuth2Pkce { id, .. } => {
@@ -1628,6 +2600,9 @@ async fn dispatch_request(
                     Some(t) => {
                         request_builder = request_builder.bearer_auth(t.access_token.clone());
                     }
+                    None if dry_run => {
+                        warnings.push(String::from("PKCE access token is not available"));
+                    }
                     None => {
                         return Err(ApicizeError::Error {
                             description: String::from("PKCE access token is not available"),
@@ -1639,6 +2614,40 @@ async fn dispatch_request(
         None => {}
     }
 
+    // Send cookies from the jar, appending them to any Cookie header set on the request.
+    // Otherwise, the client's cookie provider sends them, so that each redirect receives
+    // the cookies in the jar at the time it is followed
+    let mut sent_cookies: Option<Vec<ApicizeCookie>> = None;
+    let mut provided_cookie_header: Option<String> = None;
+    if let Some(jar) = cookie_jar
+        && let Ok(cookie_url) = Url::parse(&url)
+    {
+        let cookies = jar.get_cookies(&cookie_url);
+        if !cookies.is_empty() {
+            let jar_cookies = CookieJar::to_header(&cookies);
+            let cookie_header = match headers
+                .get(reqwest::header::COOKIE)
+                .and_then(|v| v.to_str().ok())
+            {
+                Some(existing) => Some(format!("{existing}; {jar_cookies}")),
+                None if dry_run => Some(jar_cookies),
+                None => {
+                    provided_cookie_header = Some(jar_cookies);
+                    None
+                }
+            };
+            match cookie_header {
+                Some(cookie_header) => {
+                    if let Ok(value) = reqwest::header::HeaderValue::try_from(cookie_header) {
+                        headers.insert(reqwest::header::COOKIE, value);
+                        sent_cookies = Some(cookies);
+                    }
+                }
+                None => sent_cookies = Some(cookies),
+            }
+        }
+    }
+
     if !headers.is_empty() {
         request_builder = request_builder.headers(headers);
     }
@@ -1648,10 +2657,7 @@ async fn dispatch_request(
         let mut query: Vec<(String, String)> = vec![];
         for nvp in q {
             if nvp.disabled != Some(true) {
-                query.push((
-                    clone_and_sub(&nvp.name, subs),
-                    clone_and_sub(&nvp.value, subs),
-                ));
+                query.push((subs.substitute(&nvp.name), subs.substitute(&nvp.value)));
             }
         }
         request_builder = request_builder.query(&query);
@@ -1661,14 +2667,14 @@ async fn dispatch_request(
     let mut request_body: Option<ApicizeBody>;
     match &request.body {
         Some(RequestBody::Text { data }) => {
-            let s = clone_and_sub(data, subs);
+            let s = subs.substitute(data);
             request_body = Some(ApicizeBody::Text {
                 text: "".to_string(),
             });
             request_builder = request_builder.body(Body::from(s.clone()));
         }
         Some(RequestBody::JSON { data, .. }) => {
-            let s = clone_and_sub_json(data, subs);
+            let s = subs.substitute_json(data);
             request_body = match serde_json::from_str::<Value>(&s) {
                 Ok(data) => Some(ApicizeBody::JSON {
                     text: "".to_string(),
@@ -1679,7 +2685,7 @@ async fn dispatch_request(
             request_builder = request_builder.body(Body::from(s));
         }
         Some(RequestBody::XML { data }) => {
-            let s = clone_and_sub(data, subs);
+            let s = subs.substitute(data);
             request_body = match to_json(data) {
                 Ok(data) => Some(ApicizeBody::XML {
                     text: "".to_string(),
@@ -1690,11 +2696,11 @@ async fn dispatch_request(
             request_builder = request_builder.body(Body::from(s));
         }
         Some(RequestBody::GraphQL { data }) => {
-            let query = clone_and_sub(&data.query, subs);
+            let query = subs.substitute(&data.query);
             let extensions: Option<Value> = if let Some(ext) = &data.extensions
                 && !ext.is_empty()
             {
-                let ext_with_subs = clone_and_sub_json(ext, subs);
+                let ext_with_subs = subs.substitute_json(ext);
                 let result = serde_json::from_str::<Value>(&ext_with_subs).map_err(|err| {
                     ApicizeError::from_serde(
                         err,
@@ -1747,12 +2753,7 @@ async fn dispatch_request(
         Some(RequestBody::Form { data }) => {
             let form_data = data
                 .iter()
-                .map(|pair| {
-                    (
-                        clone_and_sub(&pair.name, subs),
-                        clone_and_sub(&pair.value, subs),
-                    )
-                })
+                .map(|pair| (subs.substitute(&pair.name), subs.substitute(&pair.value)))
                 .collect::<HashMap<String, String>>();
             request_body = Some(ApicizeBody::Form {
                 text: "".to_string(),
@@ -1764,6 +2765,83 @@ async fn dispatch_request(
             request_body = Some(ApicizeBody::Binary { data: data.clone() });
             request_builder = request_builder.body(Body::from(data.clone()));
         }
+        Some(RequestBody::Multipart { data }) => {
+            let mut form = Form::new();
+            let mut parts = Vec::<ApicizeMultipartPart>::with_capacity(data.len());
+            for part in data.iter().filter(|p| p.is_enabled()) {
+                let sent = match part {
+                    MultipartPart::Text {
+                        name,
+                        value,
+                        content_type,
+                        ..
+                    } => ApicizeMultipartPart {
+                        name: subs.substitute(name),
+                        file_name: None,
+                        content_type: content_type.as_ref().map(|c| subs.substitute(c)),
+                        text: Some(subs.substitute(value)),
+                        data: None,
+                    },
+                    MultipartPart::File {
+                        name,
+                        file_name,
+                        content_type,
+                        data,
+                        path,
+                        ..
+                    } => {
+                        let name = subs.substitute(name);
+                        let file_data = match (data, path) {
+                            (Some(data), _) => data.clone(),
+                            (None, Some(path)) => extract_file_data(
+                                &subs.substitute(path),
+                                &context.allowed_data_path,
+                            )?,
+                            (None, None) => {
+                                return Err(ApicizeError::Error {
+                                    description: format!(
+                                        "Multipart file \"{name}\" has no data or path"
+                                    ),
+                                });
+                            }
+                        };
+                        ApicizeMultipartPart {
+                            name,
+                            file_name: file_name.as_ref().map(|f| subs.substitute(f)),
+                            content_type: content_type.as_ref().map(|c| subs.substitute(c)),
+                            text: None,
+                            data: Some(file_data),
+                        }
+                    }
+                };
+
+                let mut form_part = match (&sent.text, &sent.data) {
+                    (Some(text), _) => Part::text(text.clone()),
+                    (None, Some(data)) => Part::bytes(data.clone()),
+                    (None, None) => Part::bytes(Vec::new()),
+                };
+                if let Some(file_name) = &sent.file_name {
+                    form_part = form_part.file_name(file_name.clone());
+                }
+                if let Some(content_type) = &sent.content_type {
+                    form_part =
+                        form_part
+                            .mime_str(content_type)
+                            .map_err(|err| ApicizeError::Http {
+                                context: None,
+                                description: format!(
+                                    "Invalid content type for multipart \"{}\": {err}",
+                                    sent.name
+                                ),
+                                url: None,
+                            })?;
+                }
+                form = form.part(sent.name.clone(), form_part);
+                parts.push(sent);
+            }
+            request_body = Some(ApicizeBody::Multipart { parts });
+            request_builder = request_builder.multipart(form);
+        }
         None => {
             request_body = None;
         }
@@ -1774,7 +2852,7 @@ async fn dispatch_request(
         .map_err(|err| ApicizeError::from_reqwest(err, None))?;
     // Copy value generated for the request so that we can include in the function results
     let request_url = web_request.url().to_string();
-    let request_headers = web_request
+    let mut request_headers = web_request
         .headers()
         .iter()
         .map(|(h, v)| {
@@ -1784,36 +2862,159 @@ async fn dispatch_request(
             )
         })
         .collect::<HashMap<String, String>>();
+    if let Some(cookie_header) = provided_cookie_header {
+        request_headers.insert(reqwest::header::COOKIE.to_string(), cookie_header);
+    }
     let ref_body = web_request.body_mut();
-    if let Some(data) = ref_body {
-        let bytes = data.as_bytes().unwrap();
-        if !bytes.is_empty() {
-            let request_encoding = UTF_8;
-            let data = bytes.to_vec();
-            match request_body.as_mut() {
-                None => {}
-                Some(ApicizeBody::Binary { .. }) => {}
-                Some(ApicizeBody::Form { text, .. })
-                | Some(ApicizeBody::JSON { text, .. })
-                | Some(ApicizeBody::XML { text, .. })
-                | Some(ApicizeBody::Text { text, .. }) => {
-                    let (decoded, _, malformed) = request_encoding.decode(&data);
-                    *text = if malformed {
-                        "Malformed UTF8".to_string()
-                    } else {
-                        decoded.to_string()
-                    }
+    // Streamed bodies (i.e. multipart) are not available as bytes
+    if let Some(data) = ref_body
+        && let Some(bytes) = data.as_bytes()
+        && !bytes.is_empty()
+    {
+        let request_encoding = UTF_8;
+        let data = bytes.to_vec();
+        match request_body.as_mut() {
+            None => {}
+            Some(ApicizeBody::Binary { .. }) | Some(ApicizeBody::Multipart { .. }) => {}
+            Some(ApicizeBody::Form { text, .. })
+            | Some(ApicizeBody::JSON { text, .. })
+            | Some(ApicizeBody::XML { text, .. })
+            | Some(ApicizeBody::Text { text, .. }) => {
+                let (decoded, _, malformed) = request_encoding.decode(&data);
+                *text = if malformed {
+                    "Malformed UTF8".to_string()
+                } else {
+                    decoded.to_string()
                 }
             }
         }
     }
 
+    Ok(PreparedRequest {
+        name,
+        url,
+        web_request,
+        sent: ApicizeHttpRequest {
+            url: request_url,
+            method: request.method.clone().unwrap_or_else(|| "GET".to_string()),
+            headers: request_headers,
+            body: request_body,
+            cookies: sent_cookies,
+        },
+        oauth2_token,
+        warnings,
+    })
+}
+
+/// Dispatch the specified request (via reqwest), returning either the repsonse or error
+async fn dispatch_request(
+    context: Arc<TestRunnerContext>,
+    request_id: &str,
+    params: &RequestExecutionParameters,
+    subs: &Substitutions,
+    variables: &Option<Arc<Map<String, Value>>>,
+    cookie_jar: &Option<Arc<CookieJar>>,
+) -> DispatchResult {
+    let request = context.get_request(request_id)?;
+
+    let timeout = if context.single_run_no_timeout {
+        None
+    } else if let Some(t) = request.timeout {
+        if t == 0 {
+            None
+        } else {
+            Some(Duration::from_millis(t as u64))
+        }
+    } else {
+        Some(Duration::from_secs(30))
+    };
+
+    // Retrieve the reqwest client for the request's configuration, building it if necessary
+    let client_key = HttpClientKey {
+        certificate_id: params.certificate_id.clone(),
+        proxy_id: params.proxy_id.clone(),
+        number_of_redirects: request.number_of_redirects,
+        accept_invalid_certs: request.accept_invalid_certs,
+        timeout,
+        keep_alive: request.keep_alive,
+    };
+    let client = context.http_clients.get_or_build(client_key, || {
+        let mut reqwest_builder = Client::builder()
+            .http2_keep_alive_while_idle(request.keep_alive)
+            .danger_accept_invalid_certs(request.accept_invalid_certs)
+            .redirect(if request.number_of_redirects == 0 {
+                Policy::none()
+            } else {
+                Policy::limited(request.number_of_redirects)
+            })
+            .connection_verbose(context.enable_trace)
+            .dns_resolver(Arc::new(TimedResolver))
+            .connector_layer(TimedConnectLayer)
+            .cookie_provider(Arc::new(ScopedCookieStore));
+
+        if let Some(t) = timeout {
+            reqwest_builder = reqwest_builder.timeout(t);
+        } else {
+            let max = Duration::from_mins(5);
+            reqwest_builder = reqwest_builder
+                .connect_timeout(max)
+                .read_timeout(max)
+                .pool_idle_timeout(max);
+            #[cfg(target_os = "linux")]
+            {
+                reqwest_builder = reqwest_builder.tcp_user_timeout(max);
+            }
+            reqwest_builder = reqwest_builder.http2_keep_alive_timeout(max);
+        }
+
+        // Add certificate to builder if configured
+        if let Some(certificate) = context
+            .workspace
+            .certificates
+            .get_optional(&params.certificate_id)
+        {
+            reqwest_builder = certificate.append_to_builder(reqwest_builder)?;
+        }
+
+        // Add proxy to builder if configured
+        if let Some(proxy) = context.workspace.proxies.get_optional(&params.proxy_id) {
+            reqwest_builder = proxy.append_to_builder(reqwest_builder)?;
+        }
+
+        reqwest_builder
+            .build()
+            .map_err(|err| ApicizeError::from_reqwest(err, None))
+    })?;
+
+    let PreparedRequest {
+        name,
+        url,
+        web_request,
+        sent,
+        oauth2_token,
+        ..
+    } = prepare_request(
+        &context,
+        request,
+        params,
+        subs,
+        variables,
+        cookie_jar,
+        Some(&client.client),
+    )
+    .await?;
+
     // Execute the request
     let executed_at = context.ellapsed_in_ms();
+    let recorder = Arc::new(TimingRecorder::default());
+    let started = Instant::now();
 
     let client_response: Result<Response, ApicizeError> = select! {
         _ = context.cancellation.cancelled() => Err(ApicizeError::Cancelled),
-        result = client.execute(web_request) => {
+        result = recorder.scope(CookieJar::scope(
+            cookie_jar.clone(),
+            async { client.client.execute(web_request).await },
+        )) => {
             match result {
                 Ok(response) => Ok(response),
                 Err(error) => Err(ApicizeError::from_reqwest(error, None)),
@@ -1825,6 +3026,30 @@ async fn dispatch_request(
     match client_response {
         Err(error) => Err(error),
         Ok(response) => {
+            let headers_received = started.elapsed();
+
+            // Record the connection the response was received on, and whether it was reused
+            let connection =
+                response
+                    .extensions()
+                    .get::<HttpInfo>()
+                    .map(|info| ApicizeHttpConnection {
+                        remote_address: info.remote_addr().to_string(),
+                        local_address: info.local_addr().to_string(),
+                        reused: recorder.opened_connection() != Some(info.local_addr()),
+                    });
+
+            // Collect cookies set by the response (the client's cookie provider has already
+            // stored them, and those set by any redirects, in the jar if there is one)
+            let response_url = response.url().clone();
+            let received_cookies = response
+                .headers()
+                .get_all(reqwest::header::SET_COOKIE)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .filter_map(|v| ApicizeCookie::parse(&response_url, v))
+                .collect::<Vec<ApicizeCookie>>();
+
             // Collect headers for response
             let response_headers = response.headers();
             let mut may_have_json = false;
@@ -1869,6 +3094,11 @@ async fn dispatch_request(
             // Retrieve response bytes and convert raw data to string
             match response.bytes().await {
                 Ok(bytes) => {
+                    let timings = recorder.to_timings(
+                        headers_received,
+                        started.elapsed() - headers_received,
+                        connection.as_ref().is_some_and(|c| c.reused),
+                    );
                     let mut output_variables: Option<Map<String, Value>> = None;
                     let response_body = if bytes.is_empty() {
                         None
@@ -1899,18 +3129,20 @@ async fn dispatch_request(
                     let response = (
                         name,
                         url,
-                        ApicizeHttpRequest {
-                            url: request_url,
-                            method: request.method.clone().unwrap_or_else(|| "GET".to_string()),
-                            headers: request_headers,
-                            body: request_body,
-                        },
+                        sent,
                         ApicizeHttpResponse {
                             duration: context.ellapsed_in_ms() - executed_at,
                             status: status.as_u16(),
                             status_text,
                             headers,
                             body: response_body,
+                            cookies: if received_cookies.is_empty() {
+                                None
+                            } else {
+                                Some(received_cookies)
+                            },
+                            connection,
+                            timings: Some(timings),
                             oauth2_token,
                         },
                         output_variables,
@@ -1924,6 +3156,265 @@ async fn dispatch_request(
     }
 }
 
+/// Render a request, or the requests of a group, as they would be dispatched for each data row
+#[async_recursion]
+async fn dry_run_entry(
+    context: Arc<TestRunnerContext>,
+    request_or_group_id: String,
+    params: Arc<RequestExecutionParameters>,
+    state: Arc<RequestExecutionState>,
+    force_run: bool,
+) -> Result<Vec<ApicizeDryRun>, ApicizeError> {
+    let entry = context.get_request_entry(&request_or_group_id)?;
+
+    if (entry.get_disabled() && !force_run)
+        || context
+            .selected_ids
+            .as_ref()
+            .is_some_and(|selected| !selected.contains(&request_or_group_id))
+    {
+        return Ok(vec![]);
+    }
+
+    let mut new_params =
+        context
+            .workspace
+            .retrieve_request_parameters(entry, &context.value_cache, &params)?;
+
+    // Entries whose condition is false are not rendered, as they would be skipped
+    if evaluate_entry_condition(&context, entry, &mut new_params, &state)?.is_some() {
+        return Ok(vec![]);
+    }
+
+    // Groups run their setup script so that its output is substituted into their children
+    let state = match entry {
+        RequestEntry::Group(group) => match &group.setup {
+            Some(setup) => match execute_request_test(
+                &context,
+                setup,
+                &None,
+                &None,
+                &new_params.variables,
+                &state,
+            )? {
+                Some(response) => Arc::new(state.with_output(Some(Arc::new(response.output)))),
+                None => state,
+            },
+            None => state,
+        },
+        RequestEntry::Request(_) => state,
+    };
+
+    // Entries rendered for a row of an ancestor's data set report that data set
+    if new_params.data_set.is_none() && state.row.is_some() {
+        new_params.data_set = params.data_set.clone();
+    }
+    let params = Arc::new(new_params);
+
+    // Entries iterating their own data set are rendered once per row
+    let row_states = match params.data_set.as_ref() {
+        Some(data_set) if state.row.is_none() => data_set
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, row)| Arc::new(state.with_row(row, index + 1)))
+            .collect::<Vec<Arc<RequestExecutionState>>>(),
+        _ => vec![state],
+    };
+
+    let mut dry_runs = Vec::<ApicizeDryRun>::new();
+    for row_state in row_states {
+        match entry {
+            RequestEntry::Request(_) => {
+                dry_runs.push(
+                    dry_run_request(&context, &request_or_group_id, &params, &row_state).await?,
+                );
+            }
+            RequestEntry::Group(_) => {
+                for child_id in context.get_group_children(&request_or_group_id) {
+                    dry_runs.extend(
+                        dry_run_entry(
+                            context.clone(),
+                            child_id.clone(),
+                            params.clone(),
+                            row_state.clone(),
+                            false,
+                        )
+                        .await?,
+                    );
+                }
+            }
+        }
+    }
+    Ok(dry_runs)
+}
+
+/// Render a request as it would be dispatched, running its pre-request script (if any)
+/// so that its output is substituted, but without opening network connections
+async fn dry_run_request(
+    context: &TestRunnerContext,
+    request_id: &str,
+    params: &RequestExecutionParameters,
+    state: &RequestExecutionState,
+) -> Result<ApicizeDryRun, ApicizeError> {
+    let request = context.get_request(request_id)?;
+    let key = context.get_request_key(request_id)?;
+
+    let mut pre_request_error: Option<ApicizeError> = None;
+    let state = match &request.pre_request {
+        Some(script) => {
+            match execute_request_test(context, script, &None, &None, &params.variables, state) {
+                Ok(Some(response)) => state.with_output(Some(Arc::new(response.output))),
+                Ok(None) => state.clone(),
+                Err(err) => {
+                    pre_request_error = Some(err);
+                    state.clone()
+                }
+            }
+        }
+        None => state.clone(),
+    };
+
+    let (merged, subs) = build_substitutions(&params.variables, &state);
+
+    let prepared = match pre_request_error {
+        Some(err) => Err(err),
+        None => prepare_request(context, request, params, &subs, &merged, &None, None).await,
+    };
+    let (name, sent, warnings, error) = match prepared {
+        Ok(prepared) => (prepared.name, Some(prepared.sent), prepared.warnings, None),
+        Err(err) => (subs.substitute(&request.name), None, vec![], Some(err)),
+    };
+
+    let workspace = &context.workspace;
+    Ok(ApicizeDryRun {
+        id: request_id.to_string(),
+        name,
+        key,
+        row_number: state.row_number,
+        scenario: ApicizeDryRun::to_selection(
+            workspace.scenarios.get_optional(&params.scenario_id),
+        ),
+        authorization: ApicizeDryRun::to_selection(
+            workspace
+                .authorizations
+                .get_optional(&params.authorization_id),
+        ),
+        certificate: ApicizeDryRun::to_selection(
+            workspace.certificates.get_optional(&params.certificate_id),
+        ),
+        proxy: ApicizeDryRun::to_selection(workspace.proxies.get_optional(&params.proxy_id)),
+        data: params.data_set.as_ref().as_ref().and_then(|data_set| {
+            ApicizeDryRun::to_selection(workspace.data.get_optional(&data_set.id))
+        }),
+        request: sent,
+        unresolved: unresolved_placeholders(context, request, params, &subs),
+        warnings,
+        error,
+    })
+}
+
+/// Return placeholders in the request, and the authorization selected for it, which have
+/// no value to substitute
+fn unresolved_placeholders(
+    context: &TestRunnerContext,
+    request: &Request,
+    params: &RequestExecutionParameters,
+    subs: &Substitutions,
+) -> Vec<String> {
+    let mut templates: Vec<&str> = vec![&request.url];
+    for pairs in [&request.headers, &request.query_string_params]
+        .into_iter()
+        .flatten()
+    {
+        for nvp in pairs.iter().filter(|nvp| nvp.disabled != Some(true)) {
+            templates.push(&nvp.name);
+            templates.push(&nvp.value);
+        }
+    }
+
+    match &request.body {
+        Some(RequestBody::Text { data })
+        | Some(RequestBody::JSON { data, .. })
+        | Some(RequestBody::XML { data }) => templates.push(data),
+        Some(RequestBody::GraphQL { data }) => {
+            templates.push(&data.query);
+            templates.extend(data.extensions.as_deref());
+        }
+        Some(RequestBody::Form { data }) => {
+            for nvp in data {
+                templates.push(&nvp.name);
+                templates.push(&nvp.value);
+            }
+        }
+        Some(RequestBody::Multipart { data }) => {
+            for part in data.iter().filter(|p| p.is_enabled()) {
+                match part {
+                    MultipartPart::Text {
+                        name,
+                        value,
+                        content_type,
+                        ..
+                    } => {
+                        templates.push(name);
+                        templates.push(value);
+                        templates.extend(content_type.as_deref());
+                    }
+                    MultipartPart::File {
+                        name,
+                        file_name,
+                        content_type,
+                        path,
+                        ..
+                    } => {
+                        templates.push(name);
+                        templates.extend(file_name.as_deref());
+                        templates.extend(content_type.as_deref());
+                        templates.extend(path.as_deref());
+                    }
+                }
+            }
+        }
+        Some(RequestBody::Raw { .. }) | None => {}
+    }
+
+    if let Some(Authorization::Plain(plain)) = context
+        .workspace
+        .authorizations
+        .get_optional(&params.authorization_id)
+    {
+        match plain.as_ref() {
+            AuthorizationPlain::Basic {
+                username, password, ..
+            } => templates.extend([username.as_str(), password.as_str()]),
+            AuthorizationPlain::ApiKey { header, value, .. } => {
+                templates.extend([header.as_str(), value.as_str()])
+            }
+            AuthorizationPlain::OAuth2Client {
+                access_token_url,
+                client_id,
+                client_secret,
+                audience,
+                scope,
+                ..
+            } => templates.extend([
+                access_token_url.as_str(),
+                client_id.as_str(),
+                client_secret.as_str(),
+                audience.as_str(),
+                scope.as_str(),
+            ]),
+            AuthorizationPlain::OAuth2Pkce { .. } => {}
+        }
+    }
+
+    let mut unresolved = BTreeSet::<String>::new();
+    for template in templates {
+        subs.find_unresolved(template, &mut unresolved);
+    }
+    unresolved.into_iter().collect()
+}
+
 /// Set a JSON string as a named global variable on a V8 context
 fn set_v8_global(scope: &mut v8::ContextScope<v8::HandleScope>, name: &str, json: &str) {
     let global = scope.get_current_context().global(scope);
@@ -1934,14 +3425,120 @@ fn set_v8_global(scope: &mut v8::ContextScope<v8::HandleScope>, name: &str, json
 
 /// Execute the specified request's tests
 fn execute_request_test(
+    context: &TestRunnerContext,
     test: &str,
     request: &Option<ApicizeHttpRequest>,
     response: &Option<ApicizeHttpResponse>,
     variables: &Option<Arc<Map<String, Value>>>,
-    data: &Option<Arc<Map<String, Value>>>,
-    output: &Option<Arc<Map<String, Value>>>,
-    tests_started: &Instant,
+    state: &RequestExecutionState,
 ) -> Result<Option<ApicizeTestResponse>, ApicizeError> {
+    let s = run_framework_script(
+        context,
+        request,
+        response,
+        variables,
+        state,
+        |test_offset| {
+            // Small init script that parses the globals and invokes runTestSuite
+            format!(
+                "loadCookieJar(JSON.parse(__cookies)), loadLatency(JSON.parse(__latency)), runTestSuite(JSON.parse(__request), JSON.parse(__response), JSON.parse(__variables), JSON.parse(__data), JSON.parse(__output), {}, () => {{{}\n}})",
+                test_offset, test,
+            )
+        },
+    )?;
+
+    let test_response: ApicizeTestResponse = serde_json::from_str(&s).unwrap();
+
+    if test_response.clear_cookies
+        && let Some(jar) = &state.cookie_jar
+    {
+        jar.clear();
+    }
+
+    Ok(Some(test_response))
+}
+
+/// Execute a group's after-each or teardown script, recording its tests, logs and any error
+fn execute_group_script(
+    context: &TestRunnerContext,
+    script: ApicizeGroupScript,
+    code: &str,
+    request: &Option<ApicizeHttpRequest>,
+    response: &Option<ApicizeHttpResponse>,
+    variables: &Option<Arc<Map<String, Value>>>,
+    state: &RequestExecutionState,
+) -> ApicizeGroupScriptResult {
+    let executed_at = context.ellapsed_in_ms();
+    match execute_request_test(context, code, request, response, variables, state) {
+        Ok(test_response) => {
+            let (tests, logs) = match test_response {
+                Some(test_response) => {
+                    let mut behaviors = Vec::<ApicizeTestBehavior>::new();
+                    flatten_test_results(&test_response.results, &mut behaviors, &[]);
+                    (
+                        if behaviors.is_empty() {
+                            None
+                        } else {
+                            Some(behaviors)
+                        },
+                        test_response.logs,
+                    )
+                }
+                None => (None, None),
+            };
+            let success = tests
+                .as_ref()
+                .is_none_or(|tests| tests.iter().all(|t| t.success));
+            ApicizeGroupScriptResult {
+                script,
+                child_name: None,
+                executed_at,
+                tests,
+                logs,
+                error: None,
+                success,
+            }
+        }
+        Err(err) => ApicizeGroupScriptResult {
+            script,
+            child_name: None,
+            executed_at,
+            tests: None,
+            logs: None,
+            error: Some(err),
+            success: false,
+        },
+    }
+}
+
+/// Evaluate a JavaScript condition against the request and response, returning true if it is truthy
+fn evaluate_condition(
+    context: &TestRunnerContext,
+    condition: &str,
+    request: &Option<ApicizeHttpRequest>,
+    response: &Option<ApicizeHttpResponse>,
+    variables: &Option<Arc<Map<String, Value>>>,
+    state: &RequestExecutionState,
+) -> Result<bool, ApicizeError> {
+    let s = run_framework_script(context, request, response, variables, state, |_| {
+        format!(
+            "loadLatency(JSON.parse(__latency)), evaluateCondition(JSON.parse(__request), JSON.parse(__response), JSON.parse(__variables), JSON.parse(__data), JSON.parse(__output), () => ({condition}\n))"
+        )
+    })?;
+    Ok(s == "true")
+}
+
+/// Run a script in an isolate created from the test framework snapshot, with the request,
+/// response, variables and cookies set as globals, returning the script's result as a string.
+/// The script is built from the offset of the current time from when tests started.
+fn run_framework_script(
+    context: &TestRunnerContext,
+    request: &Option<ApicizeHttpRequest>,
+    response: &Option<ApicizeHttpResponse>,
+    variables: &Option<Arc<Map<String, Value>>>,
+    state: &RequestExecutionState,
+    build_code: impl FnOnce(u128) -> String,
+) -> Result<String, ApicizeError> {
     // Force snapshot creation (which also initializes V8)
     // Borrow as &'static [u8] to avoid cloning the snapshot Vec on every call
     let snapshot_ref: &'static [u8] = &FRAMEWORK_SNAPSHOT;
@@ -1949,8 +3546,16 @@ fn execute_request_test(
     // Create a new Isolate from the snapshot — gets its own isolated heap
     // with the framework already compiled and loaded
     let startup_data: v8::StartupData = snapshot_ref.into();
-    let params = v8::CreateParams::default().snapshot_blob(startup_data);
+    let params = context
+        .script_limits
+        .apply(v8::CreateParams::default().snapshot_blob(startup_data));
+
+    // Guard is declared ahead of the isolate so that it outlives it
+    let mut guard = ScriptGuard::new(context.script_limits);
     let isolate = &mut v8::Isolate::new(params);
+    guard.watch(isolate, &context.cancellation);
+
+    let tests_started = &context.tests_started;
     v8::scope!(let scope, isolate);
     let context = v8::Context::new(scope, Default::default());
     let scope = &mut v8::ContextScope::new(scope, context);
@@ -1967,42 +3572,84 @@ fn execute_request_test(
         "__variables",
         &serde_json::to_string(&variables).unwrap(),
     );
-    set_v8_global(scope, "__data", &serde_json::to_string(&data).unwrap());
-    set_v8_global(scope, "__output", &serde_json::to_string(&output).unwrap());
+    set_v8_global(scope, "__data", &serde_json::to_string(&state.row).unwrap());
+    set_v8_global(
+        scope,
+        "__output",
+        &serde_json::to_string(&state.output_variables).unwrap(),
+    );
+    set_v8_global(
+        scope,
+        "__cookies",
+        &serde_json::to_string(&state.cookie_jar.as_ref().map(|jar| jar.list())).unwrap(),
+    );
+    set_v8_global(
+        scope,
+        "__latency",
+        &serde_json::to_string(&*state.latency.lock().unwrap()).unwrap(),
+    );
 
     let test_offset = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis()
         - tests_started.elapsed().as_millis()
         + 1;
 
-    // Small init script that parses the globals and invokes runTestSuite
-    let init_code = format!(
-        "runTestSuite(JSON.parse(__request), JSON.parse(__response), JSON.parse(__variables), JSON.parse(__data), JSON.parse(__output), {}, () => {{{}\n}})",
-        test_offset, test,
-    );
+    let init_code = build_code(test_offset);
 
     let scope = std::pin::pin!(v8::TryCatch::new(scope));
-    let scope = scope.init();
+    let mut scope = scope.init();
 
     let v8_code = v8::String::new(&scope, &init_code).unwrap();
 
     let Some(script) = v8::Script::compile(&scope, v8_code, None) else {
+        guard.finish()?;
         let message = scope.message().unwrap();
         let message = message.get(&scope).to_rust_string_lossy(&scope);
         return Err(ApicizeError::from_failed_test(message));
     };
 
-    let Some(value) = script.run(&scope) else {
-        let message = scope.message().unwrap();
-        let message = message.get(&scope).to_rust_string_lossy(&scope);
+    let Some(mut value) = script.run(&scope) else {
+        // A terminated script is reported based upon why it was terminated
+        guard.finish()?;
+        let message = match scope.message() {
+            Some(message) => message.get(&scope).to_rust_string_lossy(&scope),
+            None => "Script did not complete".to_string(),
+        };
         return Err(ApicizeError::from_failed_test(message));
     };
 
-    let result = value.to_string(&scope);
-    let s = result.unwrap().to_rust_string_lossy(&scope);
-
-    let test_response: ApicizeTestResponse = serde_json::from_str(&s).unwrap();
+    // Asynchronous tests return a promise, drain the microtask queue so that it can settle
+    if let Ok(promise) = v8::Local::<v8::Promise>::try_from(value) {
+        scope.perform_microtask_checkpoint();
+        match promise.state() {
+            v8::PromiseState::Fulfilled => {
+                value = promise.result(&scope);
+            }
+            v8::PromiseState::Rejected => {
+                guard.complete();
+                let message = promise.result(&scope).to_rust_string_lossy(&scope);
+                return Err(ApicizeError::from_failed_test(message));
+            }
+            v8::PromiseState::Pending => {
+                // Complete the test suite, failing tests whose promises never settled
+                let complete_code = v8::String::new(&scope, "completeTestSuite()").unwrap();
+                let Some(completed) = v8::Script::compile(&scope, complete_code, None)
+                    .and_then(|script| script.run(&scope))
+                else {
+                    guard.finish()?;
+                    let message = match scope.message() {
+                        Some(message) => message.get(&scope).to_rust_string_lossy(&scope),
+                        None => "Script did not complete".to_string(),
+                    };
+                    return Err(ApicizeError::from_failed_test(message));
+                };
+                value = completed;
+            }
+        }
+    }
+    guard.complete();
 
-    Ok(Some(test_response))
+    let result = value.to_string(&scope);
+    Ok(result.unwrap().to_rust_string_lossy(&scope))
 }
 
 fn flatten_test_results(
@@ -0,0 +1,294 @@
+//! HAR import submodule
+//!
+//! This submodule converts HTTP Archive (HAR) logs, such as those saved by browsers
+//! and proxies, into Apicize requests
+
+use serde::Deserialize;
+
+use crate::{
+    ApicizeError, ImportWarning, MultipartPart, NameValuePair, Request, RequestBody, RequestEntry,
+    StoredRequestEntry, Workbook, WorkbookImport,
+};
+
+/// Request headers which are set when dispatching and are not imported
+const HAR_SKIPPED_HEADERS: [&str; 4] = ["host", "content-length", "connection", "cookie2"];
+
+#[derive(Deserialize)]
+struct HarFile {
+    log: HarLog,
+}
+
+#[derive(Deserialize)]
+struct HarLog {
+    #[serde(default)]
+    entries: Vec<HarEntry>,
+}
+
+#[derive(Deserialize)]
+struct HarEntry {
+    request: HarRequest,
+    response: Option<HarResponse>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarRequest {
+    method: String,
+    url: String,
+    #[serde(default)]
+    headers: Vec<HarNameValue>,
+    #[serde(default)]
+    query_string: Vec<HarNameValue>,
+    post_data: Option<HarPostData>,
+}
+
+#[derive(Deserialize)]
+struct HarResponse {
+    status: i64,
+}
+
+#[derive(Deserialize)]
+struct HarNameValue {
+    name: String,
+    #[serde(default)]
+    value: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarPostData {
+    #[serde(default)]
+    mime_type: String,
+    text: Option<String>,
+    #[serde(default)]
+    params: Vec<HarParam>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarParam {
+    name: String,
+    value: Option<String>,
+    file_name: Option<String>,
+    content_type: Option<String>,
+}
+
+/// Import the entries of a HAR log as requests, with a test checking the recorded status
+pub fn import_har(har: &str) -> Result<WorkbookImport, ApicizeError> {
+    let har = serde_json::from_str::<HarFile>(har)
+        .map_err(|err| ApicizeError::from_serde(err, "HAR log".to_string()))?;
+
+    let mut warnings = Vec::<ImportWarning>::new();
+    let requests = har
+        .log
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let request = import_entry(entry, &mut warnings);
+            StoredRequestEntry::from(RequestEntry::Request(request))
+        })
+        .collect();
+
+    Ok(WorkbookImport {
+        workbook: Workbook {
+            version: 1.0,
+            requests,
+            scenarios: None,
+            authorizations: None,
+            certificates: None,
+            proxies: None,
+            data: None,
+            defaults: None,
+            openapi: None,
+        },
+        warnings,
+    })
+}
+
+fn import_entry(entry: HarEntry, warnings: &mut Vec<ImportWarning>) -> Request {
+    let method = entry.request.method.to_uppercase();
+    let (url, query) = match entry.request.url.split_once('?') {
+        Some((url, query)) => (url.to_string(), Some(query)),
+        None => (entry.request.url.clone(), None),
+    };
+    let url = url.split('#').next().unwrap_or_default().to_string();
+    let path = url
+        .split_once("://")
+        .and_then(|(_, rest)| rest.find('/').map(|i| &rest[i..]))
+        .unwrap_or("/");
+    let name = format!("{method} {path}");
+
+    let query_string_params = if entry.request.query_string.is_empty() {
+        query.map(|q| {
+            q.split('&')
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    let (name, value) = p.split_once('=').unwrap_or((p, ""));
+                    NameValuePair {
+                        name: name.to_string(),
+                        value: value.to_string(),
+                        disabled: None,
+                    }
+                })
+                .collect::<Vec<NameValuePair>>()
+        })
+    } else {
+        Some(
+            entry
+                .request
+                .query_string
+                .into_iter()
+                .map(|q| NameValuePair {
+                    name: q.name,
+                    value: q.value,
+                    disabled: None,
+                })
+                .collect(),
+        )
+    };
+
+    // HTTP/2 pseudo-headers (ex. ":authority") and headers set during dispatch are not imported
+    let headers = entry
+        .request
+        .headers
+        .into_iter()
+        .filter(|h| {
+            !h.name.starts_with(':')
+                && !HAR_SKIPPED_HEADERS.contains(&h.name.to_lowercase().as_str())
+        })
+        .map(|h| NameValuePair {
+            name: h.name,
+            value: h.value,
+            disabled: None,
+        })
+        .collect::<Vec<NameValuePair>>();
+
+    let body = entry
+        .request
+        .post_data
+        .and_then(|post_data| import_post_data(&name, post_data, warnings));
+
+    let test = entry.response.filter(|r| r.status > 0).map(|r| {
+        format!(
+            r#"describe('status', () => {{
+    it('equals {0}', () => {{
+        expect(response.status).to.equal({0})
+    }})
+}})"#,
+            r.status
+        )
+    });
+
+    Request {
+        name,
+        method: Some(method),
+        url,
+        query_string_params: query_string_params.filter(|q| !q.is_empty()),
+        headers: if headers.is_empty() {
+            None
+        } else {
+            Some(headers)
+        },
+        body,
+        test,
+        ..Default::default()
+    }
+}
+
+fn import_post_data(
+    path: &str,
+    post_data: HarPostData,
+    warnings: &mut Vec<ImportWarning>,
+) -> Option<RequestBody> {
+    let mime_type = post_data
+        .mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    match mime_type.as_str() {
+        "application/x-www-form-urlencoded" => Some(RequestBody::Form {
+            data: if post_data.params.is_empty() {
+                post_data
+                    .text
+                    .unwrap_or_default()
+                    .split('&')
+                    .filter(|p| !p.is_empty())
+                    .map(|p| {
+                        let (name, value) = p.split_once('=').unwrap_or((p, ""));
+                        NameValuePair {
+                            name: name.to_string(),
+                            value: value.to_string(),
+                            disabled: None,
+                        }
+                    })
+                    .collect()
+            } else {
+                post_data
+                    .params
+                    .into_iter()
+                    .map(|p| NameValuePair {
+                        name: p.name,
+                        value: p.value.unwrap_or_default(),
+                        disabled: None,
+                    })
+                    .collect()
+            },
+        }),
+        "multipart/form-data" => {
+            if post_data.params.is_empty() {
+                warnings.push(ImportWarning {
+                    path: path.to_string(),
+                    element: "postData.params".to_string(),
+                    description: "Multipart body has no recorded parts".to_string(),
+                });
+            }
+            Some(RequestBody::Multipart {
+                data: post_data
+                    .params
+                    .into_iter()
+                    .map(|p| match p.file_name {
+                        Some(file_name) => {
+                            warnings.push(ImportWarning {
+                                path: path.to_string(),
+                                element: format!("postData.params.{}", p.name),
+                                description: format!(
+                                    "File contents for \"{}\" are not recorded, select a file",
+                                    p.name
+                                ),
+                            });
+                            MultipartPart::File {
+                                name: p.name,
+                                file_name: Some(file_name),
+                                content_type: p.content_type,
+                                data: None,
+                                path: None,
+                                disabled: None,
+                            }
+                        }
+                        None => MultipartPart::Text {
+                            name: p.name,
+                            value: p.value.unwrap_or_default(),
+                            content_type: p.content_type,
+                            disabled: None,
+                        },
+                    })
+                    .collect(),
+            })
+        }
+        _ => {
+            let data = post_data.text?;
+            Some(
+                if mime_type.ends_with("/json") || mime_type.ends_with("+json") {
+                    RequestBody::JSON { data }
+                } else if mime_type.ends_with("/xml") || mime_type.ends_with("+xml") {
+                    RequestBody::XML { data }
+                } else {
+                    RequestBody::Text { data }
+                },
+            )
+        }
+    }
+}
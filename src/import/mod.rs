@@ -0,0 +1,9 @@
+pub mod har;
+pub mod openapi;
+pub mod postman;
+pub mod workbook_import;
+
+pub use har::*;
+pub use openapi::*;
+pub use postman::*;
+pub use workbook_import::*;
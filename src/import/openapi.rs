@@ -0,0 +1,866 @@
+//! OpenAPI import submodule
+//!
+//! This submodule generates Apicize workbooks from OpenAPI 3.0 and 3.1 documents
+
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+use super::value_to_string;
+use crate::{
+    ApicizeError, Authorization, Identifiable, ImportWarning, MultipartPart, NameValuePair,
+    Request, RequestBody, RequestEntry, RequestGroup, Scenario, Selection, StoredRequestEntry,
+    Variable, VariableSourceType, Workbook, WorkbookDefaultParameters, WorkbookImport,
+    authorization::AuthorizationPlain, generate_uuid, scenario::ScenarioPlain,
+};
+
+/// HTTP methods which may be defined on an OpenAPI path item
+const OPENAPI_METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Maximum schema nesting to follow when generating example bodies
+const MAX_EXAMPLE_DEPTH: usize = 10;
+
+/// Parse an OpenAPI document from JSON or YAML text, ensuring it is an OpenAPI 3 document
+pub fn parse_openapi(document: &str) -> Result<Value, ApicizeError> {
+    let value = if document.trim_start().starts_with('{') {
+        serde_json::from_str::<Value>(document)
+            .map_err(|err| ApicizeError::from_serde(err, "OpenAPI document".to_string()))?
+    } else {
+        serde_yaml_ng::from_str::<Value>(document)
+            .map_err(|err| ApicizeError::from_yaml(err, "OpenAPI document".to_string()))?
+    };
+
+    match value.get("openapi").and_then(|v| v.as_str()) {
+        Some(version) if version.starts_with("3.") => Ok(value),
+        _ => Err(ApicizeError::Serialization {
+            description: "Document is not an OpenAPI 3.0 or 3.1 specification".to_string(),
+            name: "OpenAPI document".to_string(),
+        }),
+    }
+}
+
+/// Parse an OpenAPI document from a JSON or YAML file
+pub fn open_openapi_file(file_name: &Path) -> Result<Value, ApicizeError> {
+    let document = std::fs::read_to_string(file_name)
+        .map_err(|err| ApicizeError::from_io(err, Some(file_name.to_string_lossy().to_string())))?;
+    parse_openapi(&document)
+}
+
+/// Return the value referenced by a local "$ref" (ex. "#/components/schemas/Order"),
+/// or the value itself if it is not a reference
+pub fn resolve_openapi_ref<'a>(document: &'a Value, value: &'a Value) -> &'a Value {
+    let mut resolved = value;
+    // Limit the number of references followed in case of circular references
+    for _ in 0..32 {
+        match resolved
+            .get("$ref")
+            .and_then(|r| r.as_str())
+            .and_then(|r| r.strip_prefix('#'))
+            .and_then(|pointer| document.pointer(pointer))
+        {
+            Some(target) => resolved = target,
+            None => break,
+        }
+    }
+    resolved
+}
+
+/// Generate an example value for an OpenAPI schema, using examples and defaults when available
+pub fn generate_openapi_example(document: &Value, schema: &Value) -> Value {
+    generate_example(document, schema, 0)
+}
+
+/// Generate a workbook from an OpenAPI 3.0 or 3.1 document in JSON or YAML format.
+/// Operations are grouped by their first tag, and the first server URL and parameter
+/// examples are imported as scenario variables.
+pub fn import_openapi(document: &str) -> Result<WorkbookImport, ApicizeError> {
+    Ok(OpenApiImporter::new(&parse_openapi(document)?).import())
+}
+
+/// Generate a workbook from an OpenAPI 3.0 or 3.1 JSON or YAML file
+pub fn import_openapi_file(file_name: &Path) -> Result<WorkbookImport, ApicizeError> {
+    Ok(OpenApiImporter::new(&open_openapi_file(file_name)?).import())
+}
+
+/// State maintained while importing an OpenAPI document
+struct OpenApiImporter<'a> {
+    document: &'a Value,
+    title: String,
+    authorizations: Vec<Authorization>,
+    security_schemes: IndexMap<String, Selection>,
+    default_authorization: Selection,
+    variables: Vec<Variable>,
+    warnings: Vec<ImportWarning>,
+}
+
+impl<'a> OpenApiImporter<'a> {
+    fn new(document: &'a Value) -> Self {
+        OpenApiImporter {
+            document,
+            title: document
+                .pointer("/info/title")
+                .and_then(|t| t.as_str())
+                .unwrap_or("OpenAPI")
+                .to_string(),
+            authorizations: vec![],
+            security_schemes: IndexMap::new(),
+            default_authorization: Selection::new_none(),
+            variables: vec![],
+            warnings: vec![],
+        }
+    }
+
+    fn import(mut self) -> WorkbookImport {
+        self.import_servers();
+        self.import_security_schemes();
+        if let Some(security) = self.document.get("security") {
+            let title = self.title.clone();
+            self.default_authorization = self.import_security(&title, security);
+        }
+
+        if self.document.get("webhooks").is_some_and(|w| !is_empty(w)) {
+            self.warn(
+                &self.title.clone(),
+                "webhooks",
+                "Webhooks are not imported".to_string(),
+            );
+        }
+
+        // Groups are ordered by the document's tag list, followed by tags in order of appearance
+        let mut groups = IndexMap::<String, Vec<RequestEntry>>::new();
+        if let Some(tags) = self.document.get("tags").and_then(|t| t.as_array()) {
+            for tag in tags {
+                if let Some(name) = tag.get("name").and_then(|n| n.as_str()) {
+                    groups.insert(name.to_string(), vec![]);
+                }
+            }
+        }
+        let mut ungrouped = Vec::<RequestEntry>::new();
+
+        if let Some(paths) = self.document.get("paths").and_then(|p| p.as_object()) {
+            for (path, path_item) in paths {
+                let path_item = resolve_openapi_ref(self.document, path_item);
+                for method in OPENAPI_METHODS {
+                    let Some(operation) = path_item.get(method) else {
+                        continue;
+                    };
+                    let request = self.import_operation(path, method, path_item, operation);
+                    match operation
+                        .get("tags")
+                        .and_then(|t| t.as_array())
+                        .and_then(|t| t.first())
+                        .and_then(|t| t.as_str())
+                    {
+                        Some(tag) => groups
+                            .entry(tag.to_string())
+                            .or_default()
+                            .push(RequestEntry::Request(request)),
+                        None => ungrouped.push(RequestEntry::Request(request)),
+                    }
+                }
+            }
+        }
+
+        let mut requests = groups
+            .into_iter()
+            .filter(|(_, children)| !children.is_empty())
+            .map(|(name, children)| {
+                RequestEntry::Group(RequestGroup {
+                    name,
+                    children: Some(children),
+                    ..Default::default()
+                })
+            })
+            .collect::<Vec<RequestEntry>>();
+        requests.append(&mut ungrouped);
+
+        let scenario = ScenarioPlain {
+            name: self.title.clone(),
+            variables: Some(self.variables),
+            ..Default::default()
+        };
+
+        WorkbookImport {
+            workbook: Workbook {
+                version: 1.0,
+                requests: requests.into_iter().map(StoredRequestEntry::from).collect(),
+                defaults: Some(WorkbookDefaultParameters {
+                    selected_scenario: Selection {
+                        id: scenario.id.clone(),
+                        name: scenario.name.clone(),
+                    },
+                    selected_authorization: self.default_authorization,
+                    ..Default::default()
+                }),
+                scenarios: Some(vec![Scenario::Plain(Box::new(scenario))]),
+                authorizations: if self.authorizations.is_empty() {
+                    None
+                } else {
+                    Some(self.authorizations)
+                },
+                certificates: None,
+                proxies: None,
+                data: None,
+                openapi: None,
+            },
+            warnings: self.warnings,
+        }
+    }
+
+    fn warn(&mut self, path: &str, element: &str, description: String) {
+        self.warnings.push(ImportWarning {
+            path: path.to_string(),
+            element: element.to_string(),
+            description,
+        });
+    }
+
+    /// Add a scenario variable if one with the same name has not already been added
+    fn add_variable(&mut self, name: &str, value: &Value) {
+        if self.variables.iter().any(|v| v.name == name) {
+            return;
+        }
+        self.variables.push(Variable {
+            name: name.to_string(),
+            source_type: match value {
+                Value::Object(_) | Value::Array(_) => VariableSourceType::JSON,
+                _ => VariableSourceType::Text,
+            },
+            value: value_to_string(Some(value)),
+            disabled: None,
+        });
+    }
+
+    /// Import the first server as the "baseUrl" variable, substituting server variable defaults
+    fn import_servers(&mut self) {
+        let title = self.title.clone();
+        let servers = self
+            .document
+            .get("servers")
+            .and_then(|s| s.as_array())
+            .map(|s| s.as_slice())
+            .unwrap_or_default();
+
+        let base_url = match servers.first() {
+            Some(server) => {
+                if servers.len() > 1 {
+                    self.warn(
+                        &title,
+                        "servers",
+                        "Only the first server is imported".to_string(),
+                    );
+                }
+                let mut url = server
+                    .get("url")
+                    .and_then(|u| u.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                if let Some(variables) = server.get("variables").and_then(|v| v.as_object()) {
+                    for (name, variable) in variables {
+                        let default = variable
+                            .get("default")
+                            .map(value_to_string)
+                            .unwrap_or_default();
+                        url = url.replace(&format!("{{{name}}}"), &default);
+                    }
+                }
+                url.trim_end_matches('/').to_string()
+            }
+            None => {
+                self.warn(
+                    &title,
+                    "servers",
+                    "No servers are defined, set the baseUrl variable".to_string(),
+                );
+                String::default()
+            }
+        };
+
+        self.add_variable("baseUrl", &Value::String(base_url));
+    }
+
+    fn import_security_schemes(&mut self) {
+        let Some(schemes) = self
+            .document
+            .pointer("/components/securitySchemes")
+            .and_then(|s| s.as_object())
+        else {
+            return;
+        };
+
+        for (name, scheme) in schemes {
+            let scheme = resolve_openapi_ref(self.document, scheme);
+            let path = format!("securitySchemes / {name}");
+            let get = |pointer: &str| {
+                scheme
+                    .pointer(pointer)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let scopes = |flow: &str| {
+                scheme
+                    .pointer(&format!("/flows/{flow}/scopes"))
+                    .and_then(|s| s.as_object())
+                    .map(|s| s.keys().cloned().collect::<Vec<String>>().join(" "))
+                    .unwrap_or_default()
+            };
+
+            let authorization = match (
+                get("/type").as_str(),
+                get("/scheme").to_lowercase().as_str(),
+            ) {
+                ("http", "basic") => Some(AuthorizationPlain::Basic {
+                    id: generate_uuid(),
+                    name: name.clone(),
+                    username: String::default(),
+                    password: String::default(),
+                    validation_state: Default::default(),
+                    validation_warnings: None,
+                    validation_errors: None,
+                }),
+                ("http", "bearer") => Some(AuthorizationPlain::ApiKey {
+                    id: generate_uuid(),
+                    name: name.clone(),
+                    header: "Authorization".to_string(),
+                    value: "Bearer ".to_string(),
+                    validation_state: Default::default(),
+                    validation_warnings: None,
+                    validation_errors: None,
+                }),
+                ("apiKey", _) if get("/in") == "header" => Some(AuthorizationPlain::ApiKey {
+                    id: generate_uuid(),
+                    name: name.clone(),
+                    header: get("/name"),
+                    value: String::default(),
+                    validation_state: Default::default(),
+                    validation_warnings: None,
+                    validation_errors: None,
+                }),
+                ("oauth2", _) if scheme.pointer("/flows/clientCredentials").is_some() => {
+                    Some(AuthorizationPlain::OAuth2Client {
+                        id: generate_uuid(),
+                        name: name.clone(),
+                        access_token_url: get("/flows/clientCredentials/tokenUrl"),
+                        client_id: String::default(),
+                        client_secret: String::default(),
+                        audience: String::default(),
+                        scope: scopes("clientCredentials"),
+                        selected_certificate: Selection::new_none(),
+                        selected_proxy: Selection::new_none(),
+                        send_credentials_in_body: None,
+                        validation_state: Default::default(),
+                        validation_warnings: None,
+                        validation_errors: None,
+                    })
+                }
+                ("oauth2", _) if scheme.pointer("/flows/authorizationCode").is_some() => {
+                    Some(AuthorizationPlain::OAuth2Pkce {
+                        id: generate_uuid(),
+                        name: name.clone(),
+                        authorize_url: get("/flows/authorizationCode/authorizationUrl"),
+                        access_token_url: get("/flows/authorizationCode/tokenUrl"),
+                        client_id: String::default(),
+                        scope: scopes("authorizationCode"),
+                        send_credentials_in_body: None,
+                        token: None,
+                        refresh_token: None,
+                        expiration: None,
+                        validation_state: Default::default(),
+                        validation_warnings: None,
+                        validation_errors: None,
+                    })
+                }
+                ("apiKey", _) => {
+                    self.warn(
+                        &path,
+                        "in",
+                        format!("API keys sent in {} are not supported", get("/in")),
+                    );
+                    None
+                }
+                ("oauth2", _) => {
+                    self.warn(
+                        &path,
+                        "flows",
+                        "Only client credentials and authorization code flows are supported"
+                            .to_string(),
+                    );
+                    None
+                }
+                (scheme_type, _) => {
+                    self.warn(
+                        &path,
+                        "type",
+                        format!("Security scheme type \"{scheme_type}\" is not supported"),
+                    );
+                    None
+                }
+            };
+
+            if let Some(authorization) = authorization {
+                self.security_schemes.insert(
+                    name.clone(),
+                    Selection {
+                        id: authorization.get_id().to_string(),
+                        name: authorization.get_name().to_string(),
+                    },
+                );
+                self.authorizations
+                    .push(Authorization::Plain(Box::new(authorization)));
+            }
+        }
+    }
+
+    /// Return the authorization for a security requirement list, using the first requirement
+    fn import_security(&mut self, path: &str, security: &Value) -> Selection {
+        let Some(requirement) = security
+            .as_array()
+            .and_then(|s| s.first())
+            .and_then(|r| r.as_object())
+        else {
+            return Selection::new_none();
+        };
+
+        if requirement.len() > 1 {
+            self.warn(
+                path,
+                "security",
+                "Only the first of multiple required security schemes is applied".to_string(),
+            );
+        }
+
+        match requirement.keys().next() {
+            Some(name) => self
+                .security_schemes
+                .get(name)
+                .cloned()
+                .unwrap_or_else(Selection::new_none),
+            None => Selection::new_none(),
+        }
+    }
+
+    fn import_operation(
+        &mut self,
+        path: &str,
+        method: &str,
+        path_item: &Value,
+        operation: &Value,
+    ) -> Request {
+        let method = method.to_uppercase();
+        let operation_id = operation.get("operationId").and_then(|o| o.as_str());
+        let name = operation
+            .get("summary")
+            .and_then(|s| s.as_str())
+            .filter(|s| !s.is_empty())
+            .or(operation_id)
+            .map_or_else(|| format!("{method} {path}"), |n| n.to_string());
+        let warning_path = format!("{method} {path}");
+
+        let mut request = Request {
+            name,
+            key: operation_id.map(|o| o.to_string()),
+            operation: Some(operation_id.map_or_else(|| warning_path.clone(), |o| o.to_string())),
+            method: Some(method),
+            url: format!("{{{{baseUrl}}}}{}", to_handlebars(path)),
+            test: self.import_success_test(&warning_path, operation),
+            ..Default::default()
+        };
+
+        if let Some(security) = operation.get("security") {
+            let selection = self.import_security(&warning_path, security);
+            if selection != self.default_authorization {
+                request.selected_authorization = selection;
+            }
+        }
+
+        if operation.get("servers").is_some_and(|s| !is_empty(s))
+            || path_item.get("servers").is_some_and(|s| !is_empty(s))
+        {
+            self.warn(
+                &warning_path,
+                "servers",
+                "Operation servers are not imported, the baseUrl variable is used".to_string(),
+            );
+        }
+
+        if operation.get("callbacks").is_some_and(|c| !is_empty(c)) {
+            self.warn(
+                &warning_path,
+                "callbacks",
+                "Callbacks are not imported".to_string(),
+            );
+        }
+
+        self.import_parameters(&warning_path, path_item, operation, &mut request);
+
+        if let Some(body) = operation.get("requestBody") {
+            let body = resolve_openapi_ref(self.document, body);
+            request.body = self.import_body(&warning_path, body);
+        }
+
+        request
+    }
+
+    /// Import path, query and header parameters, with operation parameters overriding path
+    /// item parameters of the same name and location
+    fn import_parameters(
+        &mut self,
+        path: &str,
+        path_item: &Value,
+        operation: &Value,
+        request: &mut Request,
+    ) {
+        let mut parameters = IndexMap::<(String, String), &Value>::new();
+        for source in [path_item, operation] {
+            for parameter in source
+                .get("parameters")
+                .and_then(|p| p.as_array())
+                .map(|p| p.as_slice())
+                .unwrap_or_default()
+            {
+                let parameter = resolve_openapi_ref(self.document, parameter);
+                let (Some(name), Some(location)) = (
+                    parameter.get("name").and_then(|n| n.as_str()),
+                    parameter.get("in").and_then(|i| i.as_str()),
+                ) else {
+                    continue;
+                };
+                parameters.insert((name.to_string(), location.to_string()), parameter);
+            }
+        }
+
+        let mut query = Vec::<NameValuePair>::new();
+        let mut headers = Vec::<NameValuePair>::new();
+
+        for ((name, location), parameter) in parameters {
+            // Path parameters are required to build the URL, so always generate a variable for them
+            match self.parameter_example(parameter) {
+                Some(example) => self.add_variable(&name, &example),
+                None if location == "path" => {
+                    let example = parameter
+                        .get("schema")
+                        .map(|s| generate_openapi_example(self.document, s))
+                        .unwrap_or(Value::Null);
+                    self.add_variable(&name, &example);
+                }
+                None => {}
+            }
+
+            let pair = NameValuePair {
+                name: name.clone(),
+                value: format!("{{{{{name}}}}}"),
+                disabled: if parameter.get("required").and_then(|r| r.as_bool()) == Some(true) {
+                    None
+                } else {
+                    Some(true)
+                },
+            };
+
+            match location.as_str() {
+                "path" => {}
+                "query" => query.push(pair),
+                "header" => {
+                    // OpenAPI ignores header parameters for these, they are set by content and security
+                    if !["accept", "content-type", "authorization"]
+                        .contains(&name.to_lowercase().as_str())
+                    {
+                        headers.push(pair);
+                    }
+                }
+                _ => self.warn(
+                    path,
+                    &format!("parameters.{name}"),
+                    format!("Parameters in {location} are not supported"),
+                ),
+            }
+        }
+
+        if !query.is_empty() {
+            request.query_string_params = Some(query);
+        }
+        if !headers.is_empty() {
+            request.headers = Some(headers);
+        }
+    }
+
+    fn parameter_example(&self, parameter: &Value) -> Option<Value> {
+        if let Some(example) = parameter.get("example") {
+            return Some(example.clone());
+        }
+        if let Some(example) = first_example(self.document, parameter.get("examples")) {
+            return Some(example);
+        }
+        let schema = resolve_openapi_ref(self.document, parameter.get("schema")?);
+        schema
+            .get("example")
+            .or_else(|| schema.get("examples").and_then(|e| e.get(0)))
+            .or_else(|| schema.get("default"))
+            .or_else(|| schema.get("enum").and_then(|e| e.get(0)))
+            .cloned()
+    }
+
+    /// Import the request body from the preferred content type
+    fn import_body(&mut self, path: &str, body: &Value) -> Option<RequestBody> {
+        let content = body.get("content").and_then(|c| c.as_object())?;
+        let is_json = |t: &str| t == "application/json" || t.ends_with("+json");
+        let content_type = content
+            .keys()
+            .find(|t| is_json(t))
+            .or_else(|| {
+                content
+                    .keys()
+                    .find(|t| *t == "application/x-www-form-urlencoded")
+            })
+            .or_else(|| content.keys().find(|t| *t == "multipart/form-data"))
+            .or_else(|| content.keys().next())?;
+        let media = resolve_openapi_ref(self.document, &content[content_type]);
+        let schema = media
+            .get("schema")
+            .map(|s| resolve_openapi_ref(self.document, s));
+
+        if content.len() > 1 {
+            self.warn(
+                path,
+                "requestBody.content",
+                format!("Only the \"{content_type}\" request body is imported"),
+            );
+        }
+
+        let example = media
+            .get("example")
+            .cloned()
+            .or_else(|| first_example(self.document, media.get("examples")))
+            .or_else(|| schema.map(|s| generate_openapi_example(self.document, s)));
+
+        match content_type.as_str() {
+            t if is_json(t) => Some(RequestBody::JSON {
+                data: serde_json::to_string_pretty(&example.unwrap_or(Value::Null))
+                    .unwrap_or_default(),
+            }),
+            "application/x-www-form-urlencoded" => Some(RequestBody::Form {
+                data: match example {
+                    Some(Value::Object(values)) => values
+                        .iter()
+                        .map(|(name, value)| NameValuePair {
+                            name: name.clone(),
+                            value: value_to_string(Some(value)),
+                            disabled: None,
+                        })
+                        .collect(),
+                    _ => vec![],
+                },
+            }),
+            "multipart/form-data" => {
+                let properties = schema
+                    .and_then(|s| s.get("properties"))
+                    .and_then(|p| p.as_object());
+                let mut parts = Vec::<MultipartPart>::new();
+                for (name, property) in properties.into_iter().flatten() {
+                    let property = resolve_openapi_ref(self.document, property);
+                    let is_file = matches!(
+                        property.get("format").and_then(|f| f.as_str()),
+                        Some("binary") | Some("base64")
+                    ) || property.get("contentMediaType").is_some();
+                    if is_file {
+                        self.warn(
+                            path,
+                            &format!("requestBody.{name}"),
+                            format!("Select a file for multipart part \"{name}\""),
+                        );
+                        parts.push(MultipartPart::File {
+                            name: name.clone(),
+                            file_name: None,
+                            content_type: None,
+                            data: None,
+                            path: None,
+                            disabled: None,
+                        });
+                    } else {
+                        let value = example
+                            .as_ref()
+                            .and_then(|e| e.get(name))
+                            .map(value_to_string)
+                            .unwrap_or_default();
+                        parts.push(MultipartPart::Text {
+                            name: name.clone(),
+                            value,
+                            content_type: None,
+                            disabled: None,
+                        });
+                    }
+                }
+                Some(RequestBody::Multipart { data: parts })
+            }
+            t if t.ends_with("/xml") || t.ends_with("+xml") => Some(RequestBody::XML {
+                data: match example {
+                    Some(Value::String(s)) => s,
+                    _ => String::default(),
+                },
+            }),
+            t if t.starts_with("text/") => Some(RequestBody::Text {
+                data: match example {
+                    Some(Value::String(s)) => s,
+                    Some(Value::Null) | None => String::default(),
+                    Some(v) => v.to_string(),
+                },
+            }),
+            t => {
+                self.warn(
+                    path,
+                    "requestBody.content",
+                    format!("Request body content type \"{t}\" is not supported"),
+                );
+                None
+            }
+        }
+    }
+
+    /// Generate a test checking the documented success status
+    fn import_success_test(&mut self, path: &str, operation: &Value) -> Option<String> {
+        let responses = operation
+            .get("responses")
+            .and_then(|r| r.as_object())
+            .map(|r| r.keys().map(|k| k.as_str()).collect::<Vec<&str>>())
+            .unwrap_or_default();
+
+        let status = responses
+            .iter()
+            .filter_map(|r| r.parse::<u16>().ok())
+            .filter(|s| (200..300).contains(s))
+            .min();
+
+        match status {
+            Some(status) => Some(format!(
+                r#"describe('status', () => {{
+    it('equals {status}', () => {{
+        expect(response.status).to.equal({status})
+    }})
+}})"#
+            )),
+            None if responses.iter().any(|r| r.eq_ignore_ascii_case("2XX")) => Some(
+                r#"describe('status', () => {
+    it('is 2XX', () => {
+        expect(response.status).to.be.within(200, 299)
+    })
+})"#
+                .to_string(),
+            ),
+            None => {
+                self.warn(
+                    path,
+                    "responses",
+                    "No success response is documented, no test generated".to_string(),
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Return the value of the first entry in an OpenAPI "examples" map
+fn first_example(document: &Value, examples: Option<&Value>) -> Option<Value> {
+    let example = examples?.as_object()?.values().next()?;
+    resolve_openapi_ref(document, example).get("value").cloned()
+}
+
+fn generate_example(document: &Value, schema: &Value, depth: usize) -> Value {
+    let schema = resolve_openapi_ref(document, schema);
+    if depth > MAX_EXAMPLE_DEPTH {
+        return Value::Null;
+    }
+
+    if let Some(example) = schema
+        .get("example")
+        .or_else(|| schema.get("examples").and_then(|e| e.get(0)))
+        .or_else(|| schema.get("default"))
+        .or_else(|| schema.get("const"))
+        .or_else(|| schema.get("enum").and_then(|e| e.get(0)))
+    {
+        return example.clone();
+    }
+
+    if let Some(all_of) = schema.get("allOf").and_then(|a| a.as_array()) {
+        let mut merged = Map::new();
+        for sub_schema in all_of {
+            match generate_example(document, sub_schema, depth + 1) {
+                Value::Object(values) => merged.extend(values),
+                other if all_of.len() == 1 => return other,
+                _ => {}
+            }
+        }
+        return Value::Object(merged);
+    }
+
+    if let Some(sub_schema) = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(|s| s.get(0))
+    {
+        return generate_example(document, sub_schema, depth + 1);
+    }
+
+    // OpenAPI 3.1 allows a list of types, use the first non-null type
+    let schema_type = match schema.get("type") {
+        Some(Value::String(t)) => Some(t.as_str()),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(|t| t.as_str())
+            .find(|t| *t != "null"),
+        _ => None,
+    };
+
+    match schema_type {
+        Some("object") | None if schema.get("properties").is_some() => Value::Object(
+            schema
+                .get("properties")
+                .and_then(|p| p.as_object())
+                .into_iter()
+                .flatten()
+                .map(|(name, property)| {
+                    (
+                        name.clone(),
+                        generate_example(document, property, depth + 1),
+                    )
+                })
+                .collect(),
+        ),
+        Some("object") => Value::Object(Map::new()),
+        Some("array") => match schema.get("items") {
+            Some(items) => Value::Array(vec![generate_example(document, items, depth + 1)]),
+            None => Value::Array(vec![]),
+        },
+        Some("string") => Value::String(
+            match schema.get("format").and_then(|f| f.as_str()) {
+                Some("date-time") => "2024-01-01T00:00:00Z",
+                Some("date") => "2024-01-01",
+                Some("time") => "00:00:00",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("email") => "user@example.com",
+                Some("uri") | Some("url") => "https://example.com",
+                _ => "string",
+            }
+            .to_string(),
+        ),
+        Some("integer") | Some("number") => Value::from(0),
+        Some("boolean") => Value::Bool(false),
+        _ => Value::Null,
+    }
+}
+
+/// Convert OpenAPI path templates (ex. "/orders/{id}") to handlebars (ex. "/orders/{{id}}")
+fn to_handlebars(path: &str) -> String {
+    path.replace('{', "{{").replace('}', "}}")
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Object(o) => o.is_empty(),
+        Value::Array(a) => a.is_empty(),
+        Value::Null => true,
+        _ => false,
+    }
+}
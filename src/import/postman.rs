@@ -0,0 +1,965 @@
+//! Postman import submodule
+//!
+//! This submodule converts Postman v2.1 collections, and optionally Postman
+//! environments, into Apicize workbooks
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::value_to_string;
+use crate::{
+    ApicizeError, Authorization, GraphQLData, Identifiable, ImportWarning, MultipartPart,
+    NameValuePair, Request, RequestBody, RequestEntry, RequestGroup, Scenario, Selection,
+    StoredRequestEntry, Variable, VariableSourceType, Workbook, WorkbookDefaultParameters,
+    WorkbookImport, authorization::AuthorizationPlain, generate_uuid, scenario::ScenarioPlain,
+};
+
+/// Matches the start of a `pm.test` call, capturing its name
+static PM_TEST_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"pm\.test\(\s*(?:"((?:[^"\\]|\\.)*)"|'((?:[^'\\]|\\.)*)'|`([^`]*)`)\s*,"#).unwrap()
+});
+
+/// Postman collection (v2.1 format)
+#[derive(Deserialize)]
+struct PostmanCollection {
+    info: PostmanInfo,
+    #[serde(default)]
+    item: Vec<PostmanItem>,
+    auth: Option<PostmanAuth>,
+    #[serde(default)]
+    variable: Vec<PostmanKeyValue>,
+    #[serde(default)]
+    event: Vec<PostmanEvent>,
+}
+
+#[derive(Deserialize)]
+struct PostmanInfo {
+    name: String,
+    schema: Option<String>,
+}
+
+/// Postman item, which is a folder if it has child items, otherwise a request
+#[derive(Deserialize)]
+struct PostmanItem {
+    #[serde(default)]
+    name: String,
+    item: Option<Vec<PostmanItem>>,
+    request: Option<PostmanRequestOrUrl>,
+    auth: Option<PostmanAuth>,
+    #[serde(default)]
+    event: Vec<PostmanEvent>,
+    #[serde(default)]
+    variable: Vec<PostmanKeyValue>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PostmanRequestOrUrl {
+    Request(Box<PostmanRequest>),
+    Url(String),
+}
+
+#[derive(Deserialize)]
+struct PostmanRequest {
+    url: Option<PostmanUrl>,
+    method: Option<String>,
+    header: Option<PostmanHeaders>,
+    body: Option<PostmanBody>,
+    auth: Option<PostmanAuth>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PostmanHeaders {
+    List(Vec<PostmanKeyValue>),
+    Text(String),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PostmanUrl {
+    Text(String),
+    Detail(Box<PostmanUrlDetail>),
+}
+
+#[derive(Deserialize)]
+struct PostmanUrlDetail {
+    raw: Option<String>,
+    protocol: Option<String>,
+    host: Option<Value>,
+    port: Option<String>,
+    path: Option<Value>,
+    #[serde(default)]
+    query: Vec<PostmanKeyValue>,
+    #[serde(default)]
+    variable: Vec<PostmanKeyValue>,
+}
+
+/// Key/value used by Postman for variables, headers, query parameters and form data
+#[derive(Deserialize)]
+struct PostmanKeyValue {
+    key: Option<String>,
+    value: Option<Value>,
+    disabled: Option<bool>,
+    #[serde(rename = "type")]
+    value_type: Option<String>,
+    src: Option<Value>,
+    #[serde(rename = "contentType")]
+    content_type: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PostmanBody {
+    mode: Option<String>,
+    raw: Option<String>,
+    urlencoded: Option<Vec<PostmanKeyValue>>,
+    formdata: Option<Vec<PostmanKeyValue>>,
+    graphql: Option<PostmanGraphQL>,
+    options: Option<Value>,
+    disabled: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct PostmanGraphQL {
+    query: Option<String>,
+    variables: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PostmanAuth {
+    #[serde(rename = "type")]
+    auth_type: String,
+    #[serde(flatten)]
+    parameters: HashMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct PostmanEvent {
+    listen: String,
+    script: Option<PostmanScript>,
+    disabled: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct PostmanScript {
+    exec: Option<Value>,
+}
+
+/// Postman environment
+#[derive(Deserialize)]
+struct PostmanEnvironment {
+    name: String,
+    #[serde(default)]
+    values: Vec<PostmanEnvironmentValue>,
+}
+
+#[derive(Deserialize)]
+struct PostmanEnvironmentValue {
+    key: String,
+    value: Option<Value>,
+    enabled: Option<bool>,
+}
+
+/// Authorization selected for a Postman collection, folder or request
+enum PostmanAuthSelection {
+    /// Inherit authorization from parent
+    Inherit,
+    /// Do not send authorization
+    None,
+    /// Send specified authorization
+    Selected(Selection),
+}
+
+impl PostmanAuthSelection {
+    fn to_selection(&self) -> Selection {
+        match self {
+            PostmanAuthSelection::Inherit => Selection::default(),
+            PostmanAuthSelection::None => Selection::new_none(),
+            PostmanAuthSelection::Selected(selection) => selection.clone(),
+        }
+    }
+}
+
+/// State maintained while importing a collection
+struct PostmanImporter {
+    authorizations: Vec<Authorization>,
+    authorization_ids: HashMap<String, Selection>,
+    warnings: Vec<ImportWarning>,
+}
+
+/// Import a Postman v2.1 collection, and optionally Postman environments, as a workbook.
+/// Collection variables are imported as a scenario, or merged into a scenario for each
+/// environment if environments are specified.
+pub fn import_postman_collection(
+    collection: &str,
+    environments: &[&str],
+) -> Result<WorkbookImport, ApicizeError> {
+    let collection = serde_json::from_str::<PostmanCollection>(collection)
+        .map_err(|err| ApicizeError::from_serde(err, "Postman collection".to_string()))?;
+
+    let mut importer = PostmanImporter {
+        authorizations: vec![],
+        authorization_ids: HashMap::new(),
+        warnings: vec![],
+    };
+
+    if let Some(schema) = &collection.info.schema
+        && !schema.contains("v2.1")
+    {
+        importer.warn(
+            &collection.info.name,
+            "info.schema",
+            format!("Collection schema \"{schema}\" is not v2.1, some elements may not import"),
+        );
+    }
+
+    let collection_auth = match &collection.auth {
+        Some(auth) => importer.import_auth(&collection.info.name, auth),
+        None => PostmanAuthSelection::None,
+    };
+    importer.warn_events(&collection.info.name, &collection.event, false);
+
+    let requests = collection
+        .item
+        .iter()
+        .map(|item| importer.import_item(item, &[]))
+        .collect::<Vec<RequestEntry>>();
+
+    let collection_variables = collection
+        .variable
+        .iter()
+        .filter_map(|variable| {
+            variable.key.as_ref().map(|key| Variable {
+                name: key.to_string(),
+                source_type: variable_source_type(&variable.value),
+                value: value_to_string(variable.value.as_ref()),
+                disabled: variable.disabled.filter(|d| *d),
+            })
+        })
+        .collect::<Vec<Variable>>();
+
+    let mut scenarios = Vec::<Scenario>::new();
+    if environments.is_empty() {
+        if !collection_variables.is_empty() {
+            scenarios.push(Scenario::Plain(Box::new(ScenarioPlain {
+                name: collection.info.name.clone(),
+                variables: Some(collection_variables),
+                ..Default::default()
+            })));
+        }
+    } else {
+        for environment in environments {
+            let environment = serde_json::from_str::<PostmanEnvironment>(environment)
+                .map_err(|err| ApicizeError::from_serde(err, "Postman environment".to_string()))?;
+
+            let mut variables = collection_variables.clone();
+            for value in environment.values {
+                let variable = Variable {
+                    name: value.key,
+                    source_type: variable_source_type(&value.value),
+                    value: value_to_string(value.value.as_ref()),
+                    disabled: if value.enabled == Some(false) {
+                        Some(true)
+                    } else {
+                        None
+                    },
+                };
+                match variables.iter_mut().find(|v| v.name == variable.name) {
+                    Some(existing) => *existing = variable,
+                    None => variables.push(variable),
+                }
+            }
+
+            scenarios.push(Scenario::Plain(Box::new(ScenarioPlain {
+                name: environment.name,
+                variables: Some(variables),
+                ..Default::default()
+            })));
+        }
+    }
+
+    let defaults = WorkbookDefaultParameters {
+        selected_scenario: scenarios.first().map_or(Selection::new_none(), |s| {
+            let Scenario::Plain(scenario) = s else {
+                return Selection::new_none();
+            };
+            Selection {
+                id: scenario.id.clone(),
+                name: scenario.name.clone(),
+            }
+        }),
+        selected_authorization: match collection_auth {
+            PostmanAuthSelection::Selected(selection) => selection,
+            _ => Selection::new_none(),
+        },
+        ..Default::default()
+    };
+
+    Ok(WorkbookImport {
+        workbook: Workbook {
+            version: 1.0,
+            requests: requests.into_iter().map(StoredRequestEntry::from).collect(),
+            scenarios: if scenarios.is_empty() {
+                None
+            } else {
+                Some(scenarios)
+            },
+            authorizations: if importer.authorizations.is_empty() {
+                None
+            } else {
+                Some(importer.authorizations)
+            },
+            certificates: None,
+            proxies: None,
+            data: None,
+            openapi: None,
+            defaults: Some(defaults),
+        },
+        warnings: importer.warnings,
+    })
+}
+
+impl PostmanImporter {
+    fn warn(&mut self, path: &str, element: &str, description: String) {
+        self.warnings.push(ImportWarning {
+            path: path.to_string(),
+            element: element.to_string(),
+            description,
+        });
+    }
+
+    /// Warn about scripts which cannot be mapped, test scripts are only mapped for requests
+    fn warn_events(&mut self, path: &str, events: &[PostmanEvent], is_request: bool) {
+        for event in events {
+            if event.disabled == Some(true) || script_text(event).trim().is_empty() {
+                continue;
+            }
+            if event.listen == "prerequest" {
+                self.warn(
+                    path,
+                    "event.prerequest",
+                    "Pre-request scripts are not supported".to_string(),
+                );
+            } else if event.listen != "test" || !is_request {
+                self.warn(
+                    path,
+                    &format!("event.{}", event.listen),
+                    "Scripts are only imported for requests".to_string(),
+                );
+            }
+        }
+    }
+
+    fn import_item(&mut self, item: &PostmanItem, parent_names: &[&str]) -> RequestEntry {
+        let mut names = Vec::from(parent_names);
+        names.push(&item.name);
+        let path = names.join(" / ");
+
+        if !item.variable.is_empty() {
+            self.warn(
+                &path,
+                "variable",
+                "Folder and request variables are not supported, add them to a scenario"
+                    .to_string(),
+            );
+        }
+
+        let selected_authorization = match &item.auth {
+            Some(auth) => self.import_auth(&path, auth).to_selection(),
+            None => Selection::default(),
+        };
+
+        match &item.item {
+            Some(children) => {
+                self.warn_events(&path, &item.event, false);
+                RequestEntry::Group(RequestGroup {
+                    name: item.name.clone(),
+                    children: Some(
+                        children
+                            .iter()
+                            .map(|child| self.import_item(child, &names))
+                            .collect(),
+                    ),
+                    selected_authorization,
+                    ..Default::default()
+                })
+            }
+            None => {
+                self.warn_events(&path, &item.event, true);
+                let mut request = Request {
+                    name: item.name.clone(),
+                    test: item
+                        .event
+                        .iter()
+                        .find(|e| e.listen == "test" && e.disabled != Some(true))
+                        .map(script_text)
+                        .filter(|script| !script.trim().is_empty())
+                        .map(|script| self.import_test(&path, &item.name, &script)),
+                    selected_authorization,
+                    ..Default::default()
+                };
+
+                match &item.request {
+                    Some(PostmanRequestOrUrl::Request(source)) => {
+                        self.import_request(&path, source, &mut request)
+                    }
+                    Some(PostmanRequestOrUrl::Url(url)) => {
+                        request.url = url.clone();
+                        request.method = Some("GET".to_string());
+                    }
+                    None => {
+                        self.warn(&path, "request", "Item has no request".to_string());
+                    }
+                }
+
+                RequestEntry::Request(request)
+            }
+        }
+    }
+
+    fn import_request(&mut self, path: &str, source: &PostmanRequest, request: &mut Request) {
+        request.method = Some(
+            source
+                .method
+                .as_deref()
+                .unwrap_or("GET")
+                .to_uppercase()
+                .to_string(),
+        );
+
+        if let Some(auth) = &source.auth {
+            request.selected_authorization = self.import_auth(path, auth).to_selection();
+        }
+
+        match &source.url {
+            Some(PostmanUrl::Text(url)) => {
+                let (url, query) = split_query(url);
+                request.url = url;
+                request.query_string_params = query;
+            }
+            Some(PostmanUrl::Detail(detail)) => {
+                let (url, query) = split_query(&url_from_detail(detail));
+                request.url = url;
+                request.query_string_params = if detail.query.is_empty() {
+                    query
+                } else {
+                    Some(to_name_value_pairs(&detail.query))
+                };
+            }
+            None => {}
+        }
+
+        request.headers = match &source.header {
+            Some(PostmanHeaders::List(headers)) if !headers.is_empty() => {
+                Some(to_name_value_pairs(headers))
+            }
+            Some(PostmanHeaders::Text(headers)) if !headers.trim().is_empty() => Some(
+                headers
+                    .lines()
+                    .filter_map(|line| {
+                        line.split_once(':').map(|(name, value)| NameValuePair {
+                            name: name.trim().to_string(),
+                            value: value.trim().to_string(),
+                            disabled: None,
+                        })
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        };
+
+        if let Some(body) = &source.body
+            && body.disabled != Some(true)
+        {
+            request.body = self.import_body(path, body);
+        }
+    }
+
+    fn import_body(&mut self, path: &str, body: &PostmanBody) -> Option<RequestBody> {
+        match body.mode.as_deref() {
+            Some("raw") => {
+                let data = body.raw.clone().unwrap_or_default();
+                let language = body
+                    .options
+                    .as_ref()
+                    .and_then(|o| o.pointer("/raw/language"))
+                    .and_then(|l| l.as_str());
+                Some(match language {
+                    Some("json") => RequestBody::JSON { data },
+                    Some("xml") => RequestBody::XML { data },
+                    _ => RequestBody::Text { data },
+                })
+            }
+            Some("urlencoded") => Some(RequestBody::Form {
+                data: to_name_value_pairs(body.urlencoded.as_deref().unwrap_or_default()),
+            }),
+            Some("formdata") => {
+                let mut parts = Vec::<MultipartPart>::new();
+                for field in body.formdata.as_deref().unwrap_or_default() {
+                    let name = field.key.clone().unwrap_or_default();
+                    let disabled = field.disabled.filter(|d| *d);
+                    if field.value_type.as_deref() == Some("file") {
+                        let src = match &field.src {
+                            Some(Value::String(src)) => Some(src.clone()),
+                            Some(Value::Array(srcs)) => {
+                                if srcs.len() > 1 {
+                                    self.warn(
+                                        path,
+                                        "body.formdata",
+                                        format!(
+                                            "Only the first of multiple files for \"{name}\" is imported"
+                                        ),
+                                    );
+                                }
+                                srcs.first().and_then(|s| s.as_str()).map(|s| s.to_string())
+                            }
+                            _ => None,
+                        };
+                        self.warn(
+                            path,
+                            "body.formdata",
+                            format!(
+                                "File for \"{name}\" must be relative to the workbook's data path"
+                            ),
+                        );
+                        parts.push(MultipartPart::File {
+                            name,
+                            file_name: src
+                                .as_ref()
+                                .and_then(|s| s.rsplit(['/', '\\']).next().map(|f| f.to_string())),
+                            content_type: field.content_type.clone(),
+                            data: None,
+                            path: src,
+                            disabled,
+                        });
+                    } else {
+                        parts.push(MultipartPart::Text {
+                            name,
+                            value: value_to_string(field.value.as_ref()),
+                            content_type: field.content_type.clone(),
+                            disabled,
+                        });
+                    }
+                }
+                Some(RequestBody::Multipart { data: parts })
+            }
+            Some("graphql") => {
+                let graphql = body.graphql.as_ref();
+                if let Some(variables) = graphql.and_then(|g| g.variables.as_ref())
+                    && !variables.trim().is_empty()
+                {
+                    self.warn(
+                        path,
+                        "body.graphql.variables",
+                        "GraphQL variables are not supported".to_string(),
+                    );
+                }
+                Some(RequestBody::GraphQL {
+                    data: GraphQLData {
+                        query: graphql.and_then(|g| g.query.clone()).unwrap_or_default(),
+                        extensions: None,
+                    },
+                })
+            }
+            Some("file") => {
+                self.warn(
+                    path,
+                    "body.file",
+                    "Binary file bodies are not supported".to_string(),
+                );
+                None
+            }
+            Some(mode) => {
+                self.warn(
+                    path,
+                    "body.mode",
+                    format!("Body mode \"{mode}\" is not supported"),
+                );
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Import authorization, reusing an existing authorization if one matches
+    fn import_auth(&mut self, path: &str, auth: &PostmanAuth) -> PostmanAuthSelection {
+        let parameters = auth_parameters(auth);
+        let get = |name: &str| parameters.get(name).cloned().unwrap_or_default();
+
+        let authorization = match auth.auth_type.as_str() {
+            "noauth" => return PostmanAuthSelection::None,
+            "inherit" => return PostmanAuthSelection::Inherit,
+            "basic" => AuthorizationPlain::Basic {
+                id: generate_uuid(),
+                name: format!("{path} (Basic)"),
+                username: get("username"),
+                password: get("password"),
+                validation_state: Default::default(),
+                validation_warnings: None,
+                validation_errors: None,
+            },
+            "apikey" => {
+                if parameters.get("in").is_some_and(|i| i == "query") {
+                    self.warn(
+                        path,
+                        "auth.apikey",
+                        "API keys sent in the query string are not supported".to_string(),
+                    );
+                    return PostmanAuthSelection::Inherit;
+                }
+                AuthorizationPlain::ApiKey {
+                    id: generate_uuid(),
+                    name: format!("{path} (API Key)"),
+                    header: get("key"),
+                    value: get("value"),
+                    validation_state: Default::default(),
+                    validation_warnings: None,
+                    validation_errors: None,
+                }
+            }
+            "bearer" => AuthorizationPlain::ApiKey {
+                id: generate_uuid(),
+                name: format!("{path} (Bearer)"),
+                header: "Authorization".to_string(),
+                value: format!("Bearer {}", get("token")),
+                validation_state: Default::default(),
+                validation_warnings: None,
+                validation_errors: None,
+            },
+            "oauth2" => {
+                let send_credentials_in_body = if get("client_authentication") == "body" {
+                    Some(true)
+                } else {
+                    None
+                };
+                match parameters.get("grant_type").map(|g| g.as_str()) {
+                    Some("client_credentials") => AuthorizationPlain::OAuth2Client {
+                        id: generate_uuid(),
+                        name: format!("{path} (OAuth2 Client)"),
+                        access_token_url: get("accessTokenUrl"),
+                        client_id: get("clientId"),
+                        client_secret: get("clientSecret"),
+                        audience: get("audience"),
+                        scope: get("scope"),
+                        selected_certificate: Selection::new_none(),
+                        selected_proxy: Selection::new_none(),
+                        send_credentials_in_body,
+                        validation_state: Default::default(),
+                        validation_warnings: None,
+                        validation_errors: None,
+                    },
+                    Some("authorization_code_with_pkce") => AuthorizationPlain::OAuth2Pkce {
+                        id: generate_uuid(),
+                        name: format!("{path} (OAuth2 PKCE)"),
+                        authorize_url: get("authUrl"),
+                        access_token_url: get("accessTokenUrl"),
+                        client_id: get("clientId"),
+                        scope: get("scope"),
+                        send_credentials_in_body,
+                        token: None,
+                        refresh_token: None,
+                        expiration: None,
+                        validation_state: Default::default(),
+                        validation_warnings: None,
+                        validation_errors: None,
+                    },
+                    grant_type => {
+                        self.warn(
+                            path,
+                            "auth.oauth2",
+                            format!(
+                                "OAuth2 grant type \"{}\" is not supported",
+                                grant_type.unwrap_or("authorization_code")
+                            ),
+                        );
+                        return PostmanAuthSelection::Inherit;
+                    }
+                }
+            }
+            auth_type => {
+                self.warn(
+                    path,
+                    &format!("auth.{auth_type}"),
+                    format!("Authorization type \"{auth_type}\" is not supported"),
+                );
+                return PostmanAuthSelection::Inherit;
+            }
+        };
+
+        // Authorizations with the same type and parameters are shared
+        let mut sorted_parameters = parameters.into_iter().collect::<Vec<(String, String)>>();
+        sorted_parameters.sort();
+        let key = format!("{}:{:?}", auth.auth_type, sorted_parameters);
+        if let Some(selection) = self.authorization_ids.get(&key) {
+            return PostmanAuthSelection::Selected(selection.clone());
+        }
+
+        let selection = Selection {
+            id: authorization.get_id().to_string(),
+            name: authorization.get_name().to_string(),
+        };
+        self.authorization_ids.insert(key, selection.clone());
+        self.authorizations
+            .push(Authorization::Plain(Box::new(authorization)));
+        PostmanAuthSelection::Selected(selection)
+    }
+
+    /// Convert a Postman test script into Apicize test stubs, with each `pm.test`
+    /// becoming a commented-out test case
+    fn import_test(&mut self, path: &str, request_name: &str, script: &str) -> String {
+        let mut stubs = Vec::<String>::new();
+        let mut remainder = String::new();
+        let mut position = 0;
+
+        for captures in PM_TEST_REGEX.captures_iter(script) {
+            let start = captures.get(0).unwrap().start();
+            if start < position {
+                continue;
+            }
+            let name = captures
+                .get(1)
+                .or(captures.get(2))
+                .or(captures.get(3))
+                .map_or("", |m| m.as_str());
+            let (body, end) = extract_block(script, captures.get(0).unwrap().end());
+
+            remainder.push_str(&script[position..start]);
+            position = end;
+
+            let mut stub = format!("    it('{}', () => {{\n", escape_js_string(name));
+            for line in body.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+                stub.push_str(&format!("        // {line}\n"));
+            }
+            stub.push_str("    })\n");
+            stubs.push(stub);
+        }
+        remainder.push_str(&script[position.min(script.len())..]);
+
+        if stubs.is_empty() {
+            self.warn(
+                path,
+                "event.test",
+                "No pm.test calls found, script imported as comments".to_string(),
+            );
+        }
+
+        let mut test = format!("describe('{}', () => {{\n", escape_js_string(request_name));
+        test.push_str("    // Imported from Postman, convert to Apicize tests before use\n");
+        for line in remainder
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && *l != ");" && *l != ")")
+        {
+            test.push_str(&format!("    // {line}\n"));
+        }
+        for stub in stubs {
+            test.push_str(&stub);
+        }
+        test.push_str("})");
+        test
+    }
+}
+
+/// Return authorization parameters, which Postman stores either as a list of
+/// key/value pairs (v2.1) or as an object (v2.0)
+fn auth_parameters(auth: &PostmanAuth) -> HashMap<String, String> {
+    let mut parameters = HashMap::new();
+    match auth.parameters.get(&auth.auth_type) {
+        Some(Value::Array(values)) => {
+            for value in values {
+                if let Some(key) = value.get("key").and_then(|k| k.as_str()) {
+                    parameters.insert(key.to_string(), value_to_string(value.get("value")));
+                }
+            }
+        }
+        Some(Value::Object(values)) => {
+            for (key, value) in values {
+                parameters.insert(key.to_string(), value_to_string(Some(value)));
+            }
+        }
+        _ => {}
+    }
+    parameters
+}
+
+/// Return script text from event, which Postman stores as a string or list of lines
+fn script_text(event: &PostmanEvent) -> String {
+    match event.script.as_ref().and_then(|s| s.exec.as_ref()) {
+        Some(Value::String(script)) => script.clone(),
+        Some(Value::Array(lines)) => lines
+            .iter()
+            .map(|l| l.as_str().unwrap_or_default())
+            .collect::<Vec<&str>>()
+            .join("\n"),
+        _ => String::default(),
+    }
+}
+
+/// Return the body of the function block starting at or after position,
+/// along with the position after the end of the enclosing call
+fn extract_block(script: &str, position: usize) -> (&str, usize) {
+    let Some(open) = script[position..].find('{').map(|i| i + position) else {
+        return ("", script.len());
+    };
+
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in script[open..].char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let close = open + i;
+                    // Skip past the closing parenthesis and semicolon of the pm.test call
+                    let mut end = close + 1;
+                    let rest = &script[end..];
+                    let trimmed = rest.trim_start();
+                    if trimmed.starts_with(')') {
+                        end += rest.len() - trimmed.len() + 1;
+                        if script[end..].starts_with(';') {
+                            end += 1;
+                        }
+                    }
+                    return (&script[open + 1..close], end);
+                }
+            }
+            _ => {}
+        }
+    }
+    (&script[open + 1..], script.len())
+}
+
+fn escape_js_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// Build URL from Postman URL detail, using raw value if available
+fn url_from_detail(detail: &PostmanUrlDetail) -> String {
+    let mut url = match &detail.raw {
+        Some(raw) => raw.clone(),
+        None => {
+            let mut url = String::new();
+            if let Some(protocol) = &detail.protocol {
+                url.push_str(protocol);
+                url.push_str("://");
+            }
+            url.push_str(&join_segments(&detail.host, "."));
+            if let Some(port) = &detail.port {
+                url.push(':');
+                url.push_str(port);
+            }
+            let path = join_segments(&detail.path, "/");
+            if !path.is_empty() {
+                url.push('/');
+                url.push_str(&path);
+            }
+            url
+        }
+    };
+
+    // Postman path variables (ex. ":id") are replaced with their value or a handlebar
+    for variable in &detail.variable {
+        if let Some(key) = &variable.key {
+            let value = value_to_string(variable.value.as_ref());
+            let replacement = if value.is_empty() {
+                format!("{{{{{key}}}}}")
+            } else {
+                value
+            };
+            let re = Regex::new(&format!(r"/:{}(/|\?|#|$)", regex::escape(key))).unwrap();
+            url = re
+                .replace_all(&url, |caps: &regex::Captures| {
+                    format!("/{}{}", replacement, &caps[1])
+                })
+                .to_string();
+        }
+    }
+    url
+}
+
+fn join_segments(segments: &Option<Value>, separator: &str) -> String {
+    match segments {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .map(|p| match p {
+                Value::String(s) => s.clone(),
+                Value::Object(o) => o
+                    .get("value")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                _ => String::default(),
+            })
+            .collect::<Vec<String>>()
+            .join(separator),
+        _ => String::default(),
+    }
+}
+
+/// Split query string parameters from URL
+fn split_query(url: &str) -> (String, Option<Vec<NameValuePair>>) {
+    match url.split_once('?') {
+        Some((base, query)) => {
+            let query = query.split('#').next().unwrap_or_default();
+            let params = query
+                .split('&')
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    let (name, value) = p.split_once('=').unwrap_or((p, ""));
+                    NameValuePair {
+                        name: name.to_string(),
+                        value: value.to_string(),
+                        disabled: None,
+                    }
+                })
+                .collect::<Vec<NameValuePair>>();
+            (
+                base.to_string(),
+                if params.is_empty() {
+                    None
+                } else {
+                    Some(params)
+                },
+            )
+        }
+        None => (url.to_string(), None),
+    }
+}
+
+fn to_name_value_pairs(values: &[PostmanKeyValue]) -> Vec<NameValuePair> {
+    values
+        .iter()
+        .filter_map(|v| {
+            v.key.as_ref().map(|key| NameValuePair {
+                name: key.clone(),
+                value: value_to_string(v.value.as_ref()),
+                disabled: v.disabled.filter(|d| *d),
+            })
+        })
+        .collect()
+}
+
+fn variable_source_type(value: &Option<Value>) -> VariableSourceType {
+    match value {
+        Some(Value::Object(_)) | Some(Value::Array(_)) => VariableSourceType::JSON,
+        _ => VariableSourceType::Text,
+    }
+}
@@ -0,0 +1,38 @@
+//! Workbook import submodule
+//!
+//! This submodule defines the results returned when importing workbooks from other formats
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Workbook;
+
+/// Information about a source element which could not be imported, or was only partially imported
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportWarning {
+    /// Location of the source element (ex. "Folder / Request")
+    pub path: String,
+    /// Source property that could not be mapped (ex. "auth.digest")
+    pub element: String,
+    /// Description of why the element could not be mapped
+    pub description: String,
+}
+
+/// Workbook generated by an import, including warnings for elements which could not be mapped
+#[derive(Serialize, Deserialize, PartialEq)]
+pub struct WorkbookImport {
+    /// Imported workbook
+    pub workbook: Workbook,
+    /// Warnings for elements which could not be mapped
+    pub warnings: Vec<ImportWarning>,
+}
+
+/// Convert an imported JSON value to text, with strings unquoted and missing or null values empty
+pub(crate) fn value_to_string(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Null) | None => String::default(),
+        Some(v) => v.to_string(),
+    }
+}
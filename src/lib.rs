@@ -4,6 +4,7 @@
 pub mod encryption;
 pub mod errors;
 pub mod execution;
+pub mod import;
 pub mod results;
 pub mod serialization;
 pub mod types;
@@ -12,6 +13,7 @@ pub mod utility;
 pub use encryption::*;
 pub use errors::*;
 pub use execution::*;
+pub use import::*;
 pub use results::*;
 pub use scenario::*;
 pub use serialization::*;
@@ -4,9 +4,10 @@ use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     ApicizeBody, ApicizeError, ApicizeExecution, ApicizeGroupResult, ApicizeGroupResultContent,
-    ApicizeGroupResultRow, ApicizeGroupResultRowContent, ApicizeGroupResultRun,
-    ApicizeRequestResult, ApicizeRequestResultContent, ApicizeRequestResultRow,
-    ApicizeRequestResultRowContent, ApicizeRequestResultRun, ApicizeResult, ExecutionResultDetail,
+    ApicizeGroupResultRow, ApicizeGroupResultRowContent, ApicizeGroupResultRun, ApicizeGroupScript,
+    ApicizeGroupScriptResult, ApicizeHttpTimings, ApicizeRequestResult,
+    ApicizeRequestResultContent, ApicizeRequestResultRow, ApicizeRequestResultRowContent,
+    ApicizeRequestResultRun, ApicizeResult, ApicizeTestBehavior, ExecutionResultDetail,
     ExecutionResultDetailGroup, ExecutionResultDetailRequest, ExecutionResultSuccess,
     ExecutionResultSummary, Identifiable, Tally, TestRunnerContext,
 };
@@ -101,6 +102,50 @@ impl ExecutionResultBuilder {
         }
     }
 
+    /// Merge the logs, tests and first error of a group's after-each, teardown and condition
+    /// scripts into those reported for the group
+    fn generate_group_script_results(
+        logs: Option<Vec<String>>,
+        scripts: Option<Vec<ApicizeGroupScriptResult>>,
+    ) -> (
+        Option<Vec<String>>,
+        Option<Vec<ApicizeTestBehavior>>,
+        Option<ApicizeError>,
+    ) {
+        let Some(scripts) = scripts else {
+            return (logs, None, None);
+        };
+
+        let mut logs = logs;
+        let mut test_results: Option<Vec<ApicizeTestBehavior>> = None;
+        let mut error: Option<ApicizeError> = None;
+        for script in scripts {
+            let prefix = match (script.script, &script.child_name) {
+                (ApicizeGroupScript::AfterEach, Some(child_name)) => {
+                    format!("after each {child_name}")
+                }
+                (ApicizeGroupScript::AfterEach, None) => "after each".to_string(),
+                (ApicizeGroupScript::Teardown, _) => "teardown".to_string(),
+                (ApicizeGroupScript::Condition, _) => "condition".to_string(),
+            };
+            if let Some(script_logs) = script.logs {
+                logs.get_or_insert_with(Vec::new).extend(script_logs);
+            }
+            if let Some(tests) = script.tests {
+                test_results
+                    .get_or_insert_with(Vec::new)
+                    .extend(tests.into_iter().map(|test| ApicizeTestBehavior {
+                        name: format!("{prefix} {}", test.name),
+                        ..test
+                    }));
+            }
+            if error.is_none() {
+                error = script.error;
+            }
+        }
+        (logs, test_results, error)
+    }
+
     fn append_result(
         &mut self,
         context: &TestRunnerContext,
@@ -139,7 +184,11 @@ impl ExecutionResultBuilder {
         active_request_ids: &IndexSet<String>,
         updated_request_ids: &mut IndexSet<String>,
     ) -> usize {
-        let success = success_from_tallies(&result);
+        let success = if result.skip_reason.is_some() {
+            ExecutionResultSuccess::Skipped
+        } else {
+            success_from_tallies(&result)
+        };
 
         let request_id = result.get_id();
         let executing_request_or_group_id = context.get_executing_request_or_group_id();
@@ -184,6 +233,9 @@ impl ExecutionResultBuilder {
             url: None,
             executed_at: result.executed_at,
             duration: result.duration,
+            timings: None,
+            load: None,
+            latency: None,
             logs: result.logs,
             status: None,
             status_text: None,
@@ -191,6 +243,7 @@ impl ExecutionResultBuilder {
             response_body_length: None,
             success,
             error: None,
+            skip_reason: result.skip_reason.clone(),
             request_success_count: 0,
             request_failure_count: 0,
             request_error_count: 0,
@@ -215,6 +268,7 @@ impl ExecutionResultBuilder {
                 summary.request_failure_count = result.request_failure_count;
                 summary.request_error_count = result.request_error_count;
                 summary.output = Self::generate_output(&result.data_context.output);
+                summary.latency = result.latency.clone();
                 detail = ExecutionResultDetail::Grouped(Box::new(ExecutionResultDetailGroup {
                     exec_ctr,
                     group_id: identifiers.id.clone(),
@@ -249,6 +303,33 @@ impl ExecutionResultBuilder {
                 summary.request_failure_count = result.request_failure_count;
                 summary.request_error_count = result.request_error_count;
                 summary.output = Self::generate_output(&result.data_context.output);
+                summary.latency = result.latency.clone();
+                detail = ExecutionResultDetail::Grouped(Box::new(ExecutionResultDetailGroup {
+                    exec_ctr,
+                    group_id: identifiers.id.clone(),
+                    name: identifiers.title.clone(),
+                    key: identifiers.key.clone(),
+                    tag: identifiers.tag.clone(),
+                    row_number: None,
+                    run_number: None,
+                    executed_at: result.executed_at,
+                    duration: result.duration,
+                    data_context: result.data_context.clone(),
+                    success,
+                    request_success_count: result.request_success_count,
+                    request_failure_count: result.request_failure_count,
+                    request_error_count: result.request_error_count,
+                    test_pass_count: result.test_pass_count,
+                    test_fail_count: result.test_fail_count,
+                }));
+            }
+            ApicizeRequestResultContent::Load { load } => {
+                summary.request_success_count = result.request_success_count;
+                summary.request_failure_count = result.request_failure_count;
+                summary.request_error_count = result.request_error_count;
+                summary.output = Self::generate_output(&result.data_context.output);
+                summary.latency = load.latency.clone();
+                summary.load = Some(load);
                 detail = ExecutionResultDetail::Grouped(Box::new(ExecutionResultDetailGroup {
                     exec_ctr,
                     group_id: identifiers.id.clone(),
@@ -278,6 +359,7 @@ impl ExecutionResultBuilder {
                 summary.status_text = status_text;
                 summary.has_response_headers = has_response_headers;
                 summary.response_body_length = response_body_length;
+                summary.timings = get_response_timings(&execution);
                 summary.error = execution.error.clone();
                 summary.test_results = execution.tests.clone();
                 summary.request_success_count = result.request_success_count;
@@ -303,6 +385,8 @@ impl ExecutionResultBuilder {
                         .map(|arc| (**arc).clone()),
                     tests: execution.tests,
                     error: execution.error,
+                    attempts: execution.attempts,
+                    poll: execution.poll,
                     success,
                     request_success_count: result.request_success_count,
                     request_failure_count: result.request_failure_count,
@@ -326,7 +410,11 @@ impl ExecutionResultBuilder {
         active_request_ids: &IndexSet<String>,
         updated_request_ids: &mut IndexSet<String>,
     ) -> usize {
-        let success = success_from_tallies(&result);
+        let success = if result.skip_reason.is_some() {
+            ExecutionResultSuccess::Skipped
+        } else {
+            success_from_tallies(&result)
+        };
 
         let group_id = result.get_id();
         let executing_request_or_group_id = context.get_executing_request_or_group_id();
@@ -357,6 +445,9 @@ impl ExecutionResultBuilder {
             updated_request_ids,
         };
 
+        let (logs, test_results, error) =
+            Self::generate_group_script_results(result.logs, result.scripts);
+
         self.results.insert(
             exec_ctr,
             (
@@ -373,17 +464,21 @@ impl ExecutionResultBuilder {
                     url: None,
                     executed_at: result.executed_at,
                     duration: result.duration,
-                    logs: result.logs,
+                    timings: None,
+                    load: None,
+                    latency: None,
+                    logs,
                     status: None,
                     status_text: None,
                     has_response_headers: false,
                     response_body_length: None,
                     success,
-                    error: None,
+                    error,
+                    skip_reason: result.skip_reason.clone(),
                     request_success_count: result.request_success_count,
                     request_failure_count: result.request_failure_count,
                     request_error_count: result.request_error_count,
-                    test_results: None,
+                    test_results,
                     run_number: None,
                     run_count: None,
                     row_number: None,
@@ -431,6 +526,12 @@ impl ExecutionResultBuilder {
                     )
                 })
                 .collect(),
+            ApicizeGroupResultContent::Load { load } => {
+                let summary = &mut self.results.get_mut(&exec_ctr).unwrap().0;
+                summary.latency = load.latency.clone();
+                summary.load = Some(load);
+                vec![]
+            }
         };
 
         if !child_exec_ctrs.is_empty() {
@@ -493,6 +594,9 @@ impl ExecutionResultBuilder {
                                 tag: identifiers.tag.clone(),
                                 executed_at: row.executed_at,
                                 duration: row.duration,
+                                timings: None,
+                                load: None,
+                                latency: row.latency.clone(),
                                 logs: None,
                                 status: None,
                                 status_text: None,
@@ -500,6 +604,7 @@ impl ExecutionResultBuilder {
                                 response_body_length: None,
                                 success,
                                 error: None,
+                                skip_reason: None,
                                 request_success_count: row.request_success_count,
                                 request_failure_count: row.request_failure_count,
                                 request_error_count: row.request_error_count,
@@ -579,6 +684,9 @@ impl ExecutionResultBuilder {
                                 url: execution.url.clone(),
                                 executed_at: row.executed_at,
                                 duration: row.duration,
+                                timings: get_response_timings(&execution),
+                                load: None,
+                                latency: None,
                                 logs: execution.logs,
                                 status,
                                 status_text,
@@ -586,6 +694,7 @@ impl ExecutionResultBuilder {
                                 response_body_length,
                                 success,
                                 error: execution.error.clone(),
+                                skip_reason: None,
                                 request_success_count: row.request_success_count,
                                 request_failure_count: row.request_failure_count,
                                 request_error_count: row.request_error_count,
@@ -616,6 +725,8 @@ impl ExecutionResultBuilder {
                                         .map(|arc| (**arc).clone()),
                                     tests: execution.tests,
                                     error: execution.error,
+                                    attempts: execution.attempts,
+                                    poll: execution.poll,
                                     success,
                                     request_success_count: row.request_success_count,
                                     request_failure_count: row.request_failure_count,
@@ -689,6 +800,20 @@ impl ExecutionResultBuilder {
         summaries
     }
 
+    /// Get execution details for the specified execution and its children
+    pub fn get_result_details(&self, exec_ctr: &usize) -> IndexMap<usize, ExecutionResultDetail> {
+        let mut summaries = IndexMap::<usize, ExecutionResultSummary>::new();
+        self.collect_summaries(exec_ctr, &mut summaries);
+        summaries
+            .keys()
+            .filter_map(|exec_ctr| {
+                self.results
+                    .get(exec_ctr)
+                    .map(|(_, detail)| (*exec_ctr, detail.clone()))
+            })
+            .collect()
+    }
+
     /// Get execution details
     pub fn get_detail(&self, exec_ctr: &usize) -> Result<&ExecutionResultDetail, ApicizeError> {
         match self.results.get(exec_ctr) {
@@ -822,6 +947,9 @@ impl ExecutionResultBuilder {
                         url: run.execution.url.clone(),
                         executed_at: run.executed_at,
                         duration: run.duration,
+                        timings: get_response_timings(&run.execution),
+                        load: None,
+                        latency: None,
                         logs: None,
                         status,
                         status_text,
@@ -829,6 +957,7 @@ impl ExecutionResultBuilder {
                         response_body_length,
                         success,
                         error: run.execution.error.clone(),
+                        skip_reason: None,
                         request_success_count: run.request_success_count,
                         request_failure_count: run.request_failure_count,
                         request_error_count: run.request_error_count,
@@ -859,6 +988,8 @@ impl ExecutionResultBuilder {
                             .map(|arc| (**arc).clone()),
                         tests: run.execution.tests,
                         error: run.execution.error,
+                        attempts: run.execution.attempts,
+                        poll: run.execution.poll,
                         success,
                         request_success_count: run.request_success_count,
                         request_failure_count: run.request_failure_count,
@@ -922,6 +1053,9 @@ impl ExecutionResultBuilder {
                         url: None,
                         executed_at: row.executed_at,
                         duration: row.duration,
+                        timings: None,
+                        load: None,
+                        latency: None,
                         logs: None,
                         status: None,
                         status_text: None,
@@ -929,6 +1063,7 @@ impl ExecutionResultBuilder {
                         response_body_length: None,
                         success,
                         error: None,
+                        skip_reason: None,
                         request_success_count: row.request_success_count,
                         request_failure_count: row.request_failure_count,
                         request_error_count: row.request_error_count,
@@ -1049,6 +1184,9 @@ impl ExecutionResultBuilder {
                         url: None,
                         executed_at: run.executed_at,
                         duration: run.duration,
+                        timings: None,
+                        load: None,
+                        latency: None,
                         logs: None,
                         status: None,
                         status_text: None,
@@ -1056,6 +1194,7 @@ impl ExecutionResultBuilder {
                         response_body_length: None,
                         success,
                         error: None,
+                        skip_reason: None,
                         request_success_count: run.request_success_count,
                         request_failure_count: run.request_failure_count,
                         request_error_count: run.request_error_count,
@@ -1142,6 +1281,7 @@ fn get_response_info(
                     ApicizeBody::XML { text, .. } => text.len(),
                     ApicizeBody::Form { text, .. } => text.len(),
                     ApicizeBody::Binary { data } => data.len(),
+                    ApicizeBody::Multipart { parts } => parts.iter().map(|p| p.len()).sum(),
                 },
                 None => 0,
             };
@@ -1156,14 +1296,23 @@ fn get_response_info(
     }
 }
 
+fn get_response_timings(execution: &ApicizeExecution) -> Option<ApicizeHttpTimings> {
+    execution
+        .test_context
+        .response
+        .as_ref()
+        .and_then(|response| response.timings.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         ApicizeBody, ApicizeExecution, ApicizeExecutionTestContext, ApicizeGroupResult,
-        ApicizeGroupResultContent, ApicizeHttpResponse, ApicizeRequestResult,
-        ApicizeRequestResultContent, ApicizeRequestResultRow, ApicizeRequestResultRowContent,
-        ApicizeRequestResultRun, ApicizeResult, DataContext, IndexedEntities, Request,
+        ApicizeGroupResultContent, ApicizeHttpResponse, ApicizeLatencyStatistics,
+        ApicizeRequestResult, ApicizeRequestResultContent, ApicizeRequestResultRow,
+        ApicizeRequestResultRowContent, ApicizeRequestResultRun, ApicizeResult,
+        ApicizeTestBehavior, DataContext, ExecutionReportFormat, IndexedEntities, Request,
         RequestEntry, TestRunnerContext, TestRunnerContextInit, WorkbookDefaultParameters,
         Workspace, workspace::ParameterLockStatus,
     };
@@ -1191,18 +1340,15 @@ mod tests {
             proxies: IndexedEntities::default(),
             data: IndexedEntities::default(),
             defaults: WorkbookDefaultParameters::default(),
+            openapi: None,
             private_encryption: None,
             vault_encryption: None,
         };
 
         TestRunnerContext::new(TestRunnerContextInit {
             workspace,
-            cancellation: None,
             executing_request_or_group_id: "test-exec",
-            single_run_no_timeout: false,
-            allowed_data_path: &None,
-            enable_trace: false,
-            execution_counter_callback: None,
+            ..Default::default()
         })
     }
 
@@ -1221,6 +1367,9 @@ mod tests {
             body: Some(ApicizeBody::Text {
                 text: "response body".to_string(),
             }),
+            cookies: None,
+            connection: None,
+            timings: None,
             oauth2_token: None,
         });
 
@@ -1241,6 +1390,8 @@ mod tests {
             logs: None,
             tests: None,
             error: None,
+            attempts: None,
+            poll: None,
             success: true,
             test_pass_count: 0,
             test_fail_count: 0,
@@ -1264,7 +1415,9 @@ mod tests {
             content: ApicizeRequestResultContent::Execution {
                 execution: Box::new(execution),
             },
+            latency: None,
             logs: None,
+            skip_reason: None,
             success: true,
             request_success_count: 1,
             request_failure_count: 0,
@@ -1281,6 +1434,7 @@ mod tests {
     ) -> ApicizeRequestResult {
         let request_success_count = runs.iter().filter(|r| r.success).count();
         let request_failure_count = runs.len() - request_success_count;
+        let latency = ApicizeLatencyStatistics::from_runs(&runs);
 
         ApicizeRequestResult {
             id: id.to_string(),
@@ -1292,7 +1446,9 @@ mod tests {
             duration: 150,
             data_context: DataContext::default(),
             content: ApicizeRequestResultContent::Runs { runs },
+            latency,
             logs: None,
+            skip_reason: None,
             success: request_failure_count == 0,
             request_success_count,
             request_failure_count,
@@ -1309,6 +1465,7 @@ mod tests {
     ) -> ApicizeRequestResult {
         let request_success_count = rows.iter().filter(|r| r.success).count();
         let request_failure_count = rows.len() - request_success_count;
+        let latency = ApicizeLatencyStatistics::from_rows(&rows);
 
         ApicizeRequestResult {
             id: id.to_string(),
@@ -1320,7 +1477,9 @@ mod tests {
             duration: 200,
             data_context: DataContext::default(),
             content: ApicizeRequestResultContent::Rows { rows },
+            latency,
             logs: None,
+            skip_reason: None,
             success: request_failure_count == 0,
             request_success_count,
             request_failure_count,
@@ -1345,6 +1504,8 @@ mod tests {
             data_context: DataContext::default(),
             content: ApicizeGroupResultContent::Results { results },
             logs: None,
+            scripts: None,
+            skip_reason: None,
             success: true,
             request_success_count: 1,
             request_failure_count: 0,
@@ -1382,6 +1543,7 @@ mod tests {
                 Some(200),
                 100,
             ))),
+            latency: None,
             success: true,
             request_success_count: 1,
             request_failure_count: 0,
@@ -1650,6 +1812,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_append_request_result_with_runs_includes_latency() {
+        let mut builder = ExecutionResultBuilder::default();
+        let context = make_test_context();
+        let mut runs = vec![make_request_run(1, true), make_request_run(2, true)];
+        runs[1].duration = 30;
+        let request = make_request_result_with_runs("req-1", "Test Request", runs);
+
+        let exec_ctr = builder.append_request_result(
+            &context,
+            request,
+            0,
+            None,
+            &IndexSet::new(),
+            &mut IndexSet::new(),
+        );
+
+        let (summary, _) = builder.results.get(&exec_ctr).unwrap();
+        let latency: &ApicizeLatencyStatistics = summary.latency.as_ref().unwrap();
+        assert_eq!(latency.count, 2);
+        assert_eq!(latency.min, 10);
+        assert_eq!(latency.max, 30);
+        assert_eq!(latency.mean, 20.0);
+        assert_eq!(latency.p95, 30);
+
+        // Individual runs do not have statistics
+        let child_ctr = summary.child_exec_ctrs.as_ref().unwrap()[0];
+        assert!(builder.results.get(&child_ctr).unwrap().0.latency.is_none());
+
+        // CSV rows for each run report the request's statistics, in the trailing columns
+        let summaries = builder.get_result_summaries(&exec_ctr);
+        let report =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::CSV).unwrap();
+        let lines = report.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with("Latency P95,Latency P99"));
+        assert!(
+            lines[1..]
+                .iter()
+                .all(|line| line.ends_with(",10,30,20.0,10.0,10,30,30,30"))
+        );
+    }
+
     #[test]
     fn test_append_request_result_with_rows_creates_child_entries() {
         let mut builder = ExecutionResultBuilder::default();
@@ -1749,6 +1954,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_append_group_result_includes_script_results() {
+        let mut builder = ExecutionResultBuilder::default();
+        let context = make_test_context();
+
+        let mut group = make_group_result_with_results("group-1", "Test Group", vec![]);
+        group.logs = Some(vec!["setup".to_string()]);
+        group.scripts = Some(vec![
+            ApicizeGroupScriptResult {
+                script: ApicizeGroupScript::AfterEach,
+                child_name: Some("Create user".to_string()),
+                executed_at: 100,
+                tests: Some(vec![ApicizeTestBehavior {
+                    name: "user exists".to_string(),
+                    tag: None,
+                    success: true,
+                    error: None,
+                    logs: None,
+                }]),
+                logs: Some(vec!["after each".to_string()]),
+                error: None,
+                success: true,
+            },
+            ApicizeGroupScriptResult {
+                script: ApicizeGroupScript::Teardown,
+                child_name: None,
+                executed_at: 200,
+                tests: None,
+                logs: None,
+                error: Some(ApicizeError::from_failed_test("cleanup failed".to_string())),
+                success: false,
+            },
+        ]);
+
+        let exec_ctr = builder.append_group_result(
+            &context,
+            group,
+            0,
+            None,
+            &IndexSet::new(),
+            &mut IndexSet::new(),
+        );
+
+        let (summary, _) = builder.results.get(&exec_ctr).unwrap();
+        assert_eq!(
+            summary.logs,
+            Some(vec!["setup".to_string(), "after each".to_string()])
+        );
+        let tests = summary.test_results.as_ref().unwrap();
+        assert_eq!(tests.len(), 1);
+        assert_eq!(tests[0].name, "after each Create user user exists");
+        assert!(summary.error.is_some());
+    }
+
     #[test]
     fn test_append_group_result_with_nested_groups() {
         let mut builder = ExecutionResultBuilder::default();
@@ -2165,4 +2424,322 @@ mod tests {
         assert_eq!(summary.request_success_count, 2);
         assert_eq!(summary.request_failure_count, 1);
     }
+
+    // ========================================================================
+    // Report generation tests
+    // ========================================================================
+
+    #[test]
+    fn test_generate_junit_report_from_group() {
+        let mut builder = ExecutionResultBuilder::default();
+        let context = make_test_context();
+
+        let mut execution = make_execution(
+            "Tested Request",
+            Some("GET"),
+            Some("http://test.com"),
+            Some(200),
+            100,
+        );
+        execution.tests = Some(vec![
+            ApicizeTestBehavior {
+                name: "status is 200".to_string(),
+                tag: None,
+                success: true,
+                error: None,
+                logs: Some(vec!["checked <status>".to_string()]),
+            },
+            ApicizeTestBehavior {
+                name: "body is JSON".to_string(),
+                tag: None,
+                success: false,
+                error: Some("expected \"json\" & got text".to_string()),
+                logs: None,
+            },
+        ]);
+        let tested_request = make_request_result_execution("req-1", "Tested Request", execution);
+        let runs = vec![make_request_run(1, true), make_request_run(2, true)];
+        let run_request = make_request_result_with_runs("req-2", "Run Request", runs);
+        let group = make_group_result_with_results(
+            "group-1",
+            "Test Group",
+            vec![
+                ApicizeResult::Request(Box::new(tested_request)),
+                ApicizeResult::Request(Box::new(run_request)),
+            ],
+        );
+
+        let exec_ctr = builder.append_group_result(
+            &context,
+            group,
+            0,
+            None,
+            &IndexSet::new(),
+            &mut IndexSet::new(),
+        );
+
+        let summaries = builder.get_result_summaries(&exec_ctr);
+        let report =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::JUNIT)
+                .unwrap();
+
+        assert!(report.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(report.contains("<testsuites tests=\"4\" failures=\"1\" errors=\"0\""));
+        assert!(report.contains(
+            "<testsuite name=\"Test Group\" tests=\"4\" failures=\"1\" errors=\"0\" time=\"0.300\">"
+        ));
+        assert!(report.contains(
+            "<testcase name=\"status is 200\" classname=\"Test Group / Tested Request\" time=\"0.050\">"
+        ));
+        assert!(report.contains("<system-out>checked &lt;status&gt;</system-out>"));
+        assert!(report.contains("<failure message=\"expected &quot;json&quot; &amp; got text\">"));
+        assert!(report.contains("<testcase name=\"Run Request (Run 1 of 2)\""));
+        assert!(report.contains("<testcase name=\"Run Request (Run 2 of 2)\""));
+    }
+
+    #[test]
+    fn test_generate_junit_report_totals_top_level_durations() {
+        let mut builder = ExecutionResultBuilder::default();
+        let context = make_test_context();
+
+        let make_request = |id: &str, name: &str| {
+            let execution =
+                make_execution(name, Some("GET"), Some("http://test.com"), Some(200), 50);
+            ApicizeResult::Request(Box::new(make_request_result_execution(id, name, execution)))
+        };
+        let mut inner = make_group_result_with_results(
+            "group-2",
+            "Inner Group",
+            vec![make_request("req-2", "Inner Request")],
+        );
+        inner.duration = 200;
+        let outer = make_group_result_with_results(
+            "group-1",
+            "Outer Group",
+            vec![
+                make_request("req-1", "Outer Request"),
+                ApicizeResult::Group(Box::new(inner)),
+            ],
+        );
+
+        let exec_ctr = builder.append_group_result(
+            &context,
+            outer,
+            0,
+            None,
+            &IndexSet::new(),
+            &mut IndexSet::new(),
+        );
+
+        let summaries = builder.get_result_summaries(&exec_ctr);
+        let report =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::JUNIT)
+                .unwrap();
+
+        // The inner group's duration is already included in the outer group's
+        assert!(
+            report.contains("<testsuites tests=\"2\" failures=\"0\" errors=\"0\" time=\"0.300\">")
+        );
+        assert!(report.contains(
+            "<testsuite name=\"Outer Group / Inner Group\" tests=\"1\" failures=\"0\" errors=\"0\" time=\"0.200\">"
+        ));
+    }
+
+    #[test]
+    fn test_reports_include_children_of_group_with_failing_teardown() {
+        let mut builder = ExecutionResultBuilder::default();
+        let context = make_test_context();
+
+        let execution = make_execution(
+            "Create User",
+            Some("POST"),
+            Some("http://test.com"),
+            Some(200),
+            100,
+        );
+        let request = make_request_result_execution("req-1", "Create User", execution);
+        let mut group = make_group_result_with_results(
+            "group-1",
+            "Test Group",
+            vec![ApicizeResult::Request(Box::new(request))],
+        );
+        group.scripts = Some(vec![ApicizeGroupScriptResult {
+            script: ApicizeGroupScript::Teardown,
+            child_name: None,
+            executed_at: 200,
+            tests: Some(vec![ApicizeTestBehavior {
+                name: "user removed".to_string(),
+                tag: None,
+                success: false,
+                error: Some("status was 500".to_string()),
+                logs: None,
+            }]),
+            logs: None,
+            error: Some(ApicizeError::from_failed_test("cleanup failed".to_string())),
+            success: false,
+        }]);
+
+        let exec_ctr = builder.append_group_result(
+            &context,
+            group,
+            0,
+            None,
+            &IndexSet::new(),
+            &mut IndexSet::new(),
+        );
+        let summaries = builder.get_result_summaries(&exec_ctr);
+
+        let report =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::JSON).unwrap();
+        let report = serde_json::from_str::<serde_json::Value>(&report).unwrap();
+        assert_eq!(report[0]["children"][0]["name"], "Create User");
+        assert_eq!(report[0]["testResults"][0]["name"], "teardown user removed");
+        assert_eq!(report[0]["error"], "Failed test - cleanup failed");
+
+        // Teardown results follow those of the group's children
+        let report =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::CSV).unwrap();
+        let lines = report.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("\"Test Group, Create User\""));
+        assert!(lines[2].starts_with("Test Group,"));
+        assert!(lines[2].contains(",teardown user removed,"));
+        assert!(lines[2].contains(",Failed test - cleanup failed,status was 500,"));
+
+        let report =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::JUNIT)
+                .unwrap();
+        assert!(report.contains("<testsuites tests=\"3\" failures=\"1\" errors=\"1\""));
+        assert!(report.contains(
+            "<testcase name=\"Create User\" classname=\"Test Group / Create User\" time=\"0.050\"/>"
+        ));
+        assert!(report.contains(
+            "<testcase name=\"teardown user removed\" classname=\"Test Group\" time=\"0.000\">\n            <failure message=\"status was 500\">"
+        ));
+        assert!(report.contains(
+            "<testcase name=\"Test Group\" classname=\"Test Group\" time=\"0.000\">\n            <error message=\"Failed test - cleanup failed\""
+        ));
+    }
+
+    #[test]
+    fn test_skipped_results_are_summarized_and_reported() {
+        let mut builder = ExecutionResultBuilder::default();
+        let context = make_test_context();
+
+        let execution = make_execution(
+            "Executed Request",
+            Some("GET"),
+            Some("http://test.com"),
+            Some(200),
+            100,
+        );
+        let executed = make_request_result_execution("req-1", "Executed Request", execution);
+        let mut skipped = make_request_result_with_runs("req-2", "Skipped Request", vec![]);
+        skipped.skip_reason = Some("Condition is false".to_string());
+        skipped.duration = 0;
+        let group = make_group_result_with_results(
+            "group-1",
+            "Test Group",
+            vec![
+                ApicizeResult::Request(Box::new(executed)),
+                ApicizeResult::Request(Box::new(skipped)),
+            ],
+        );
+
+        let exec_ctr = builder.append_group_result(
+            &context,
+            group,
+            0,
+            None,
+            &IndexSet::new(),
+            &mut IndexSet::new(),
+        );
+
+        let summaries = builder.get_result_summaries(&exec_ctr);
+        let skipped_summary = summaries
+            .values()
+            .find(|s| s.request_or_group_id == "req-2")
+            .unwrap();
+        assert_eq!(skipped_summary.success, ExecutionResultSuccess::Skipped);
+
+        let report =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::JUNIT)
+                .unwrap();
+        assert!(
+            report.contains("<testsuites tests=\"2\" failures=\"0\" errors=\"0\" skipped=\"1\"")
+        );
+        assert!(report.contains(
+            "<testcase name=\"Skipped Request\" classname=\"Test Group / Skipped Request\" time=\"0.000\">\n            <skipped message=\"Condition is false\"/>"
+        ));
+    }
+
+    #[test]
+    fn test_generate_html_report_includes_details() {
+        let mut builder = ExecutionResultBuilder::default();
+        let context = make_test_context();
+
+        let mut execution = make_execution(
+            "Tested Request",
+            Some("GET"),
+            Some("http://test.com"),
+            Some(200),
+            100,
+        );
+        execution.tests = Some(vec![ApicizeTestBehavior {
+            name: "status is 200".to_string(),
+            tag: None,
+            success: false,
+            error: Some("expected 200 <OK>".to_string()),
+            logs: None,
+        }]);
+        execution.test_context.response.as_mut().unwrap().headers = Some(HashMap::from([
+            ("content-type".to_string(), "text/plain".to_string()),
+            ("set-cookie".to_string(), "session=secret".to_string()),
+            ("X-Api-Key".to_string(), "key-secret".to_string()),
+        ]));
+        let request = make_request_result_execution("req-1", "Tested Request", execution);
+        let group = make_group_result_with_results(
+            "group-1",
+            "Test Group",
+            vec![ApicizeResult::Request(Box::new(request))],
+        );
+
+        let exec_ctr = builder.append_group_result(
+            &context,
+            group,
+            0,
+            None,
+            &IndexSet::new(),
+            &mut IndexSet::new(),
+        );
+
+        let summaries = builder.get_result_summaries(&exec_ctr);
+        let details = builder.get_result_details(&exec_ctr);
+        assert_eq!(details.len(), 2);
+
+        let report = Workspace::generate_detailed_report(
+            &exec_ctr,
+            &summaries,
+            Some(&details),
+            &["x-api-key".to_string()],
+            ExecutionReportFormat::HTML,
+        )
+        .unwrap();
+
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("<span class=\"name\">Test Group</span>"));
+        assert!(report.contains("<span class=\"badge failure\">FAIL</span> status is 200"));
+        assert!(report.contains("expected 200 &lt;OK&gt;"));
+        assert!(report.contains("<summary>Response</summary>"));
+        assert!(report.contains("<pre class=\"body\">response body</pre>"));
+        assert!(report.contains("<tr><td>content-type</td><td>text/plain</td></tr>"));
+        assert!(report.contains("<tr><td>set-cookie</td><td>(Redacted)</td></tr>"));
+        assert!(report.contains("<tr><td>X-Api-Key</td><td>(Redacted)</td></tr>"));
+        assert!(!report.contains("secret"));
+        assert!(report.contains("class=\"bar\" style=\"left:0.00%;width:"));
+
+        let summary_only =
+            Workspace::generate_report(&exec_ctr, &summaries, ExecutionReportFormat::HTML).unwrap();
+        assert!(!summary_only.contains("<summary>Response</summary>"));
+    }
 }
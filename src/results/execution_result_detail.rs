@@ -2,8 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
 use crate::{
-    ApicizeError, ApicizeExecutionTestContext, ApicizeTestBehavior, DataContext,
-    ExecutionResultSuccess,
+    ApicizeError, ApicizeExecutionAttempt, ApicizeExecutionTestContext, ApicizePollSummary,
+    ApicizeTestBehavior, DataContext, ExecutionResultSuccess,
 };
 
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
@@ -70,6 +70,14 @@ pub struct ExecutionResultDetailRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ApicizeError>,
 
+    /// Dispatch attempts, if the request was retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<Vec<ApicizeExecutionAttempt>>,
+
+    /// Summary of polling, if the request was polled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<ApicizePollSummary>,
+
     /// Indicates level of call success
     pub success: ExecutionResultSuccess,
 
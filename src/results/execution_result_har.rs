@@ -0,0 +1,387 @@
+//! HAR export submodule
+//!
+//! This submodule exports executed requests as HTTP Archive (HAR) 1.2 logs,
+//! which can be shared and replayed in browser developer tools
+use std::net::SocketAddr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+
+use crate::{ApicizeBody, ApicizeCookie, ApicizeError, ExecutionResultDetailRequest};
+
+#[derive(Serialize)]
+struct Har {
+    log: HarLog,
+}
+
+#[derive(Serialize)]
+struct HarLog {
+    version: &'static str,
+    creator: HarCreator,
+    entries: Vec<HarEntry>,
+}
+
+#[derive(Serialize)]
+struct HarCreator {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarEntry {
+    started_date_time: String,
+    time: u128,
+    request: HarRequest,
+    response: HarResponse,
+    cache: HarCache,
+    timings: HarTimings,
+    #[serde(rename = "serverIPAddress", skip_serializing_if = "Option::is_none")]
+    server_ip_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connection: Option<String>,
+    comment: String,
+    #[serde(rename = "_error", skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarRequest {
+    method: String,
+    url: String,
+    http_version: &'static str,
+    cookies: Vec<HarCookie>,
+    headers: Vec<HarNameValue>,
+    query_string: Vec<HarNameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_data: Option<HarPostData>,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarResponse {
+    status: u16,
+    status_text: String,
+    http_version: &'static str,
+    cookies: Vec<HarCookie>,
+    headers: Vec<HarNameValue>,
+    content: HarContent,
+    #[serde(rename = "redirectURL")]
+    redirect_url: String,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+struct HarNameValue {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarCookie {
+    name: String,
+    value: String,
+    path: String,
+    domain: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires: Option<String>,
+    http_only: bool,
+    secure: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarPostData {
+    mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    params: Vec<HarParam>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarParam {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarContent {
+    size: i64,
+    mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct HarCache {}
+
+#[derive(Serialize)]
+struct HarTimings {
+    blocked: i64,
+    dns: i64,
+    connect: i64,
+    send: u128,
+    wait: u128,
+    receive: u128,
+    ssl: i64,
+}
+
+/// Generate a HAR 1.2 log from executed requests. Entry start times are calculated by
+/// adding each request's `executed_at` offset to `started_at`, the time execution started.
+/// Requests which were not dispatched (i.e. failed before sending) are not included.
+pub fn generate_har<'a>(
+    details: impl IntoIterator<Item = &'a ExecutionResultDetailRequest>,
+    started_at: SystemTime,
+) -> Result<String, ApicizeError> {
+    let entries = details
+        .into_iter()
+        .filter_map(|detail| generate_har_entry(detail, started_at))
+        .collect();
+
+    let har = Har {
+        log: HarLog {
+            version: "1.2",
+            creator: HarCreator {
+                name: "Apicize",
+                version: env!("CARGO_PKG_VERSION"),
+            },
+            entries,
+        },
+    };
+
+    serde_json::to_string_pretty(&har)
+        .map_err(|err| ApicizeError::from_serde(err, "HAR".to_string()))
+}
+
+fn generate_har_entry(
+    detail: &ExecutionResultDetailRequest,
+    started_at: SystemTime,
+) -> Option<HarEntry> {
+    let request = detail.test_context.request.as_ref()?;
+    let response = detail.test_context.response.as_ref();
+
+    let request_headers = to_har_headers(Some(&request.headers));
+    let request_mime_type = find_header(&request_headers, "content-type");
+    let (post_data, request_body_size) = match &request.body {
+        Some(body) => {
+            let (text, params) = match body {
+                ApicizeBody::Multipart { parts } => (
+                    None,
+                    parts
+                        .iter()
+                        .map(|p| HarParam {
+                            name: p.name.clone(),
+                            value: p.text.clone(),
+                            file_name: p.file_name.clone(),
+                            content_type: p.content_type.clone(),
+                        })
+                        .collect(),
+                ),
+                ApicizeBody::Form { text, data } => {
+                    let mut params = data
+                        .iter()
+                        .map(|(name, value)| HarParam {
+                            name: name.clone(),
+                            value: Some(value.clone()),
+                            file_name: None,
+                            content_type: None,
+                        })
+                        .collect::<Vec<HarParam>>();
+                    params.sort_by(|a, b| a.name.cmp(&b.name));
+                    (Some(text.clone()), params)
+                }
+                ApicizeBody::Binary { data } => {
+                    (Some(String::from_utf8_lossy(data).to_string()), vec![])
+                }
+                ApicizeBody::Text { text }
+                | ApicizeBody::JSON { text, .. }
+                | ApicizeBody::XML { text, .. } => (Some(text.clone()), vec![]),
+            };
+            (
+                Some(HarPostData {
+                    mime_type: request_mime_type.unwrap_or_default(),
+                    text,
+                    params,
+                }),
+                body_size(body),
+            )
+        }
+        None => (None, 0),
+    };
+
+    let response_headers = to_har_headers(response.and_then(|r| r.headers.as_ref()));
+    let response_body = response.and_then(|r| r.body.as_ref());
+    let content = HarContent {
+        size: response_body.map_or(0, body_size),
+        mime_type: find_header(&response_headers, "content-type").unwrap_or_default(),
+        text: response_body.map(|body| match body {
+            ApicizeBody::Binary { data } => STANDARD.encode(data),
+            ApicizeBody::Multipart { .. } => String::default(),
+            ApicizeBody::Text { text }
+            | ApicizeBody::JSON { text, .. }
+            | ApicizeBody::XML { text, .. }
+            | ApicizeBody::Form { text, .. } => text.clone(),
+        }),
+        encoding: match response_body {
+            Some(ApicizeBody::Binary { .. }) => Some("base64"),
+            _ => None,
+        },
+    };
+
+    Some(HarEntry {
+        started_date_time: format_iso8601(
+            started_at + Duration::from_millis(detail.executed_at as u64),
+        ),
+        time: detail.duration,
+        request: HarRequest {
+            method: request.method.clone(),
+            url: request.url.clone(),
+            http_version: "HTTP/1.1",
+            cookies: to_har_cookies(request.cookies.as_deref()),
+            query_string: request
+                .url
+                .split_once('?')
+                .map(|(_, query)| {
+                    query
+                        .split('#')
+                        .next()
+                        .unwrap_or_default()
+                        .split('&')
+                        .filter(|p| !p.is_empty())
+                        .map(|p| {
+                            let (name, value) = p.split_once('=').unwrap_or((p, ""));
+                            HarNameValue {
+                                name: name.to_string(),
+                                value: value.to_string(),
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            headers: request_headers,
+            post_data,
+            headers_size: -1,
+            body_size: request_body_size,
+        },
+        response: HarResponse {
+            status: response.map_or(0, |r| r.status),
+            status_text: response.map_or(String::default(), |r| r.status_text.clone()),
+            http_version: "HTTP/1.1",
+            cookies: to_har_cookies(response.and_then(|r| r.cookies.as_deref())),
+            redirect_url: find_header(&response_headers, "location").unwrap_or_default(),
+            headers: response_headers,
+            content,
+            headers_size: -1,
+            body_size: response_body.map_or(-1, body_size),
+        },
+        cache: HarCache {},
+        // HAR connect times include the TLS handshake, which is not measured separately
+        timings: match response.and_then(|r| r.timings.as_ref()) {
+            Some(timings) => HarTimings {
+                blocked: -1,
+                dns: timings.dns.map_or(-1, |dns| dns as i64),
+                connect: timings.connect.map_or(-1, |connect| connect as i64),
+                send: 0,
+                wait: timings.ttfb,
+                receive: timings.download,
+                ssl: -1,
+            },
+            None => HarTimings {
+                blocked: -1,
+                dns: -1,
+                connect: -1,
+                send: 0,
+                wait: detail.duration,
+                receive: 0,
+                ssl: -1,
+            },
+        },
+        // The connection is identified by its local port, which HAR readers use to group requests
+        server_ip_address: response
+            .and_then(|r| r.connection.as_ref())
+            .and_then(|c| c.remote_address.parse::<SocketAddr>().ok())
+            .map(|addr| addr.ip().to_string()),
+        connection: response
+            .and_then(|r| r.connection.as_ref())
+            .and_then(|c| c.local_address.parse::<SocketAddr>().ok())
+            .map(|addr| addr.port().to_string()),
+        comment: detail.name.clone(),
+        error: detail.error.as_ref().map(|e| e.to_string()),
+    })
+}
+
+/// Return headers sorted by name, since they are stored unordered
+fn to_har_headers(
+    headers: Option<&std::collections::HashMap<String, String>>,
+) -> Vec<HarNameValue> {
+    let mut results = headers
+        .map(|h| {
+            h.iter()
+                .map(|(name, value)| HarNameValue {
+                    name: name.clone(),
+                    value: value.clone(),
+                })
+                .collect::<Vec<HarNameValue>>()
+        })
+        .unwrap_or_default();
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+fn to_har_cookies(cookies: Option<&[ApicizeCookie]>) -> Vec<HarCookie> {
+    cookies
+        .unwrap_or_default()
+        .iter()
+        .map(|cookie| HarCookie {
+            name: cookie.name.clone(),
+            value: cookie.value.clone(),
+            path: cookie.path.clone(),
+            domain: cookie.domain.clone(),
+            expires: cookie
+                .expires
+                .map(|expires| format_iso8601(UNIX_EPOCH + Duration::from_millis(expires as u64))),
+            http_only: cookie.http_only,
+            secure: cookie.secure,
+        })
+        .collect()
+}
+
+fn find_header(headers: &[HarNameValue], name: &str) -> Option<String> {
+    headers
+        .iter()
+        .find(|h| h.name.eq_ignore_ascii_case(name))
+        .map(|h| h.value.clone())
+}
+
+fn body_size(body: &ApicizeBody) -> i64 {
+    (match body {
+        ApicizeBody::Binary { data } => data.len(),
+        ApicizeBody::Multipart { parts } => parts.iter().map(|p| p.len()).sum(),
+        ApicizeBody::Text { text }
+        | ApicizeBody::JSON { text, .. }
+        | ApicizeBody::XML { text, .. }
+        | ApicizeBody::Form { text, .. } => text.len(),
+    }) as i64
+}
+
+/// Format a time as an ISO 8601 UTC timestamp with milliseconds (ex. "2024-01-31T12:00:00.000Z")
+fn format_iso8601(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Millis, true)
+}
@@ -1,5 +1,7 @@
 pub mod execution_result;
 pub mod execution_result_detail;
+pub mod execution_result_har;
 
 pub use execution_result::*;
 pub use execution_result_detail::*;
+pub use execution_result_har::*;
@@ -0,0 +1,78 @@
+use std::collections::VecDeque;
+
+use crate::{Identifiable, RequestEntry};
+
+/// Dependencies declared between sibling requests and groups, by sibling position
+#[derive(PartialEq, Clone, Debug)]
+pub struct SiblingDependencies {
+    /// Positions of the siblings each sibling depends upon
+    pub dependencies: Vec<Vec<usize>>,
+    /// Dependencies which do not match a sibling's key or ID, as sibling position and name
+    pub unknown: Vec<(usize, String)>,
+}
+
+impl SiblingDependencies {
+    /// Resolve each sibling's dependencies to other siblings by key or ID,
+    /// returning None if no sibling declares any dependencies
+    pub fn new(siblings: &[&RequestEntry]) -> Option<Self> {
+        if siblings
+            .iter()
+            .all(|sibling| sibling.get_depends_on().is_none_or(|d| d.is_empty()))
+        {
+            return None;
+        }
+
+        let mut dependencies = Vec::with_capacity(siblings.len());
+        let mut unknown = Vec::new();
+        for (index, sibling) in siblings.iter().enumerate() {
+            let mut resolved = Vec::new();
+            for name in sibling.get_depends_on().into_iter().flatten() {
+                match siblings
+                    .iter()
+                    .position(|s| s.get_id() == name || s.get_key().is_some_and(|key| key == name))
+                {
+                    Some(position) => {
+                        if !resolved.contains(&position) {
+                            resolved.push(position);
+                        }
+                    }
+                    None => unknown.push((index, name.clone())),
+                }
+            }
+            dependencies.push(resolved);
+        }
+
+        Some(SiblingDependencies {
+            dependencies,
+            unknown,
+        })
+    }
+
+    /// Positions of siblings in, or depending upon, a dependency cycle; empty if there are none
+    pub fn find_cycle(&self) -> Vec<usize> {
+        let count = self.dependencies.len();
+        let mut remaining = self
+            .dependencies
+            .iter()
+            .map(|d| d.len())
+            .collect::<Vec<usize>>();
+        let mut ready = (0..count)
+            .filter(|i| remaining[*i] == 0)
+            .collect::<VecDeque<usize>>();
+        let mut sorted = vec![false; count];
+
+        while let Some(index) = ready.pop_front() {
+            sorted[index] = true;
+            for (dependent, dependencies) in self.dependencies.iter().enumerate() {
+                if dependencies.contains(&index) {
+                    remaining[dependent] -= 1;
+                    if remaining[dependent] == 0 {
+                        ready.push_back(dependent);
+                    }
+                }
+            }
+        }
+
+        (0..count).filter(|i| !sorted[*i]).collect()
+    }
+}
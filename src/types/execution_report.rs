@@ -3,7 +3,10 @@ use std::{collections::HashMap, fmt::Display};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::{ApicizeTestBehavior, ExecutionResultSuccess, ExecutionResultSummary};
+use crate::{
+    ApicizeError, ApicizeHttpTimings, ApicizeLatencyStatistics, ApicizeLoadResult,
+    ApicizeTestBehavior, ExecutionResultSuccess, ExecutionResultSummary,
+};
 
 #[derive(Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -11,6 +14,8 @@ pub enum ExecutionReportFormat {
     #[default]
     JSON,
     CSV,
+    JUNIT,
+    HTML,
 }
 
 impl Display for ExecutionReportFormat {
@@ -18,6 +23,8 @@ impl Display for ExecutionReportFormat {
         match self {
             ExecutionReportFormat::JSON => f.write_str("JSON"),
             ExecutionReportFormat::CSV => f.write_str("CSV"),
+            ExecutionReportFormat::JUNIT => f.write_str("JUNIT"),
+            ExecutionReportFormat::HTML => f.write_str("HTML"),
         }
     }
 }
@@ -42,6 +49,18 @@ pub struct ExecutionReportJson {
     /// Duration of execution (milliseconds)
     pub duration: u128,
 
+    /// Duration of each phase of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timings: Option<ApicizeHttpTimings>,
+
+    /// Aggregated iterations, if executed with a load profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<Box<ApicizeLoadResult>>,
+
+    /// Distribution of response durations, if multiple runs, rows or iterations were executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<ApicizeLatencyStatistics>,
+
     /// Ordinal run number, if mult-run result
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_number: Option<usize>,
@@ -160,6 +179,26 @@ pub struct ExecutionReportCsv {
 
     /// Output variables generated during tests
     pub output: Option<HashMap<String, Value>>,
+
+    /// Time spent resolving the host name (milliseconds)
+    #[serde(rename = "DNS")]
+    pub dns: Option<u128>,
+
+    /// Time spent connecting, including TLS handshake (milliseconds)
+    #[serde(rename = "Connect")]
+    pub connect: Option<u128>,
+
+    /// Time until the response headers were received (milliseconds)
+    #[serde(rename = "TTFB")]
+    pub ttfb: Option<u128>,
+
+    /// Time spent downloading the response body (milliseconds)
+    #[serde(rename = "Download")]
+    pub download: Option<u128>,
+
+    /// Distribution of response durations, if multiple runs, rows or iterations were executed
+    #[serde(rename = "Latency")]
+    pub latency: Option<ApicizeLatencyStatistics>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -225,6 +264,94 @@ pub struct ExecutionReportCsvSingleRun {
 
     /// Output variables generated during tests
     pub output: Option<HashMap<String, Value>>,
+
+    /// Time spent resolving the host name (milliseconds)
+    #[serde(rename = "DNS")]
+    pub dns: Option<u128>,
+
+    /// Time spent connecting, including TLS handshake (milliseconds)
+    #[serde(rename = "Connect")]
+    pub connect: Option<u128>,
+
+    /// Time until the response headers were received (milliseconds)
+    #[serde(rename = "TTFB")]
+    pub ttfb: Option<u128>,
+
+    /// Time spent downloading the response body (milliseconds)
+    #[serde(rename = "Download")]
+    pub download: Option<u128>,
+
+    /// Distribution of response durations, if multiple runs, rows or iterations were executed
+    #[serde(rename = "Latency")]
+    pub latency: Option<ApicizeLatencyStatistics>,
+}
+
+/// JUnit test suite, generated for each executed group (or request not in a group)
+#[derive(Clone)]
+pub struct ExecutionReportJUnitSuite {
+    /// Fully qualified group (or request) name
+    pub name: String,
+
+    /// Duration of execution (milliseconds)
+    pub duration: u128,
+
+    /// True if the suite is for a group nested in another suite's group, whose duration
+    /// already includes this suite's
+    pub nested: bool,
+
+    /// Global logs not part of a behavioral test block
+    pub logs: Option<Vec<String>>,
+
+    /// Test cases executed for the suite
+    pub test_cases: Vec<ExecutionReportJUnitCase>,
+}
+
+/// JUnit test case, generated for each test behavior (or request without tests)
+#[derive(Clone)]
+pub struct ExecutionReportJUnitCase {
+    /// Test name, including row and run numbers if applicable
+    pub name: String,
+
+    /// Fully qualified request name
+    pub class_name: String,
+
+    /// Duration of request execution (milliseconds)
+    pub duration: u128,
+
+    /// Failure message, if the test failed
+    pub failure: Option<String>,
+
+    /// Error on dispatch or error execution
+    pub error: Option<ApicizeError>,
+
+    /// Reason the request was skipped, if skipped
+    pub skip_reason: Option<String>,
+
+    /// Console I/O generated during the test
+    pub logs: Option<Vec<String>>,
+}
+
+impl ExecutionReportJUnitSuite {
+    /// Number of test cases which failed
+    pub fn failure_count(&self) -> usize {
+        self.test_cases
+            .iter()
+            .filter(|c| c.failure.is_some())
+            .count()
+    }
+
+    /// Number of test cases with errors
+    pub fn error_count(&self) -> usize {
+        self.test_cases.iter().filter(|c| c.error.is_some()).count()
+    }
+
+    /// Number of test cases skipped
+    pub fn skipped_count(&self) -> usize {
+        self.test_cases
+            .iter()
+            .filter(|c| c.skip_reason.is_some())
+            .count()
+    }
 }
 
 impl ExecutionReportCsvSingleRun {
@@ -232,6 +359,11 @@ impl ExecutionReportCsvSingleRun {
         ExecutionReportCsvSingleRun {
             name: csv.name,
             duration: csv.duration,
+            dns: csv.dns,
+            connect: csv.connect,
+            ttfb: csv.ttfb,
+            download: csv.download,
+            latency: csv.latency,
             key: csv.key,
             executed_at: csv.executed_at,
             method: csv.method,
@@ -265,6 +397,9 @@ impl ExecutionReportJson {
             url: summary.url.clone(),
             executed_at: summary.executed_at,
             duration: summary.duration,
+            timings: summary.timings.clone(),
+            load: summary.load.clone(),
+            latency: summary.latency.clone(),
             success: summary.success,
             status: summary.status,
             status_text: summary.status_text.clone(),
@@ -279,3 +414,22 @@ impl ExecutionReportJson {
         }
     }
 }
+
+/// Escape text for inclusion in XML or HTML content and attributes, dropping
+/// control characters which are not valid in XML 1.0
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,411 @@
+//! Execution report HTML submodule
+//!
+//! This submodule renders execution summaries, and details when available,
+//! as a self-contained HTML document which can be viewed offline
+
+use indexmap::IndexMap;
+
+use crate::{
+    ApicizeBody, ApicizeError, ApicizeTestBehavior, ExecutionResultDetail, ExecutionResultSuccess,
+    ExecutionResultSummary, execution_report::escape_xml,
+};
+use std::collections::HashMap;
+
+const HTML_REPORT_STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Roboto, Helvetica, Arial, sans-serif; font-size: 14px; color: #1f2328; margin: 24px; }
+h1 { font-size: 22px; margin: 0 0 12px 0; }
+h2 { font-size: 18px; margin: 24px 0 8px 0; }
+.totals { display: flex; gap: 16px; margin-bottom: 16px; }
+.totals div { padding: 8px 12px; border-radius: 6px; background: #f6f8fa; }
+details.entry { border-left: 2px solid #d0d7de; margin: 4px 0 4px 8px; padding-left: 8px; }
+details.entry > summary { cursor: pointer; display: flex; align-items: center; gap: 8px; padding: 4px 0; }
+.badge { display: inline-block; min-width: 44px; text-align: center; font-size: 11px; font-weight: bold; color: #fff; border-radius: 10px; padding: 2px 6px; }
+.badge.success { background: #1a7f37; }
+.badge.failure { background: #cf222e; }
+.badge.error { background: #9a6700; }
+.badge.skipped { background: #6e7781; }
+.name { font-weight: 600; }
+.method { font-family: monospace; font-weight: bold; }
+.url, .status, .duration { color: #57606a; }
+.url { font-family: monospace; overflow: hidden; text-overflow: ellipsis; white-space: nowrap; max-width: 40%; }
+.timeline { position: relative; flex: 1; min-width: 120px; height: 8px; background: #eaeef2; border-radius: 4px; margin-left: auto; }
+.timeline .bar { position: absolute; top: 0; height: 8px; border-radius: 4px; background: #0969da; min-width: 2px; }
+.content { padding: 4px 0 8px 8px; }
+.error-text { color: #9a6700; font-weight: 600; }
+.skip-text { color: #6e7781; }
+ul.tests { list-style: none; padding-left: 0; margin: 4px 0; }
+ul.tests li { margin: 2px 0; }
+ul.tests .test-error { color: #cf222e; margin-left: 56px; }
+pre { background: #f6f8fa; border-radius: 6px; padding: 8px; margin: 4px 0; overflow: auto; max-height: 400px; }
+details.http { margin: 4px 0; }
+details.http > summary { cursor: pointer; font-weight: 600; }
+table.headers { border-collapse: collapse; margin: 4px 0; font-family: monospace; font-size: 12px; }
+table.headers td { border: 1px solid #d0d7de; padding: 2px 6px; vertical-align: top; }
+"#;
+
+/// Headers whose values are always redacted from HTML reports
+const REDACTED_HEADERS: [&str; 4] = [
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+];
+
+/// Executed results to render in an HTML report
+pub(crate) struct ExecutionReportHtmlRun<'a> {
+    /// Application run number, rendered as a heading for multi-run reports
+    pub run_number: Option<usize>,
+    /// Execution counters of top-level entries
+    pub exec_ctrs: &'a [usize],
+    /// Summaries indexed by execution counter
+    pub summaries: &'a IndexMap<usize, ExecutionResultSummary>,
+    /// Details indexed by execution counter, if available
+    pub details: Option<&'a IndexMap<usize, ExecutionResultDetail>>,
+    /// Additional headers whose values are redacted (ex. API key headers)
+    pub redacted_headers: &'a [String],
+}
+
+/// Time span used to position timing bars
+struct Timeline {
+    start: u128,
+    span: u128,
+}
+
+impl Timeline {
+    fn new(run: &ExecutionReportHtmlRun) -> Self {
+        let start = run
+            .summaries
+            .values()
+            .map(|s| s.executed_at)
+            .min()
+            .unwrap_or_default();
+        let end = run
+            .summaries
+            .values()
+            .map(|s| s.executed_at + s.duration)
+            .max()
+            .unwrap_or_default();
+        Timeline {
+            start,
+            span: (end - start).max(1),
+        }
+    }
+
+    fn render(&self, summary: &ExecutionResultSummary) -> String {
+        let left =
+            (summary.executed_at.saturating_sub(self.start)) as f64 * 100.0 / self.span as f64;
+        let width = summary.duration as f64 * 100.0 / self.span as f64;
+        format!(
+            "<span class=\"timeline\" title=\"Started at {} ms, duration {} ms\"><span class=\"bar\" style=\"left:{:.2}%;width:{:.2}%\"></span></span>",
+            summary.executed_at, summary.duration, left, width
+        )
+    }
+}
+
+/// Generate a self-contained HTML report for the specified runs
+pub(crate) fn generate_html_report(
+    runs: &[ExecutionReportHtmlRun],
+) -> Result<String, ApicizeError> {
+    let mut request_success_count = 0;
+    let mut request_failure_count = 0;
+    let mut request_error_count = 0;
+    let mut test_pass_count = 0;
+    let mut test_fail_count = 0;
+    let mut duration = 0;
+
+    for run in runs {
+        for exec_ctr in run.exec_ctrs {
+            let summary = get_summary(exec_ctr, run.summaries)?;
+            request_success_count += summary.request_success_count;
+            request_failure_count += summary.request_failure_count;
+            request_error_count += summary.request_error_count;
+            duration += summary.duration;
+        }
+        for test_result in run
+            .summaries
+            .values()
+            .filter_map(|s| s.test_results.as_ref())
+            .flatten()
+        {
+            if test_result.success {
+                test_pass_count += 1;
+            } else {
+                test_fail_count += 1;
+            }
+        }
+    }
+
+    let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    html.push_str("<meta charset=\"utf-8\">\n<title>Apicize Execution Report</title>\n");
+    html.push_str(&format!("<style>{HTML_REPORT_STYLE}</style>\n"));
+    html.push_str("</head>\n<body>\n<h1>Apicize Execution Report</h1>\n");
+    html.push_str(&format!(
+        "<div class=\"totals\"><div>Requests succeeded: {request_success_count}</div><div>Requests failed: {request_failure_count}</div><div>Requests with errors: {request_error_count}</div><div>Tests passed: {test_pass_count}</div><div>Tests failed: {test_fail_count}</div><div>Duration: {duration} ms</div></div>\n"
+    ));
+
+    for run in runs {
+        if let Some(run_number) = run.run_number {
+            html.push_str(&format!("<h2>Run {run_number}</h2>\n"));
+        }
+        let timeline = Timeline::new(run);
+        for exec_ctr in run.exec_ctrs {
+            append_entry(exec_ctr, run, &timeline, &mut html)?;
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+    Ok(html)
+}
+
+fn get_summary<'a>(
+    exec_ctr: &usize,
+    summaries: &'a IndexMap<usize, ExecutionResultSummary>,
+) -> Result<&'a ExecutionResultSummary, ApicizeError> {
+    summaries.get(exec_ctr).ok_or_else(|| ApicizeError::Error {
+        description: format!("Invalid execution counter ({exec_ctr})"),
+    })
+}
+
+fn success_class(success: &ExecutionResultSuccess) -> &'static str {
+    match success {
+        ExecutionResultSuccess::Success => "success",
+        ExecutionResultSuccess::Failure => "failure",
+        ExecutionResultSuccess::Error => "error",
+        ExecutionResultSuccess::Skipped => "skipped",
+    }
+}
+
+fn render_badge(success: &ExecutionResultSuccess) -> String {
+    let label = match success {
+        ExecutionResultSuccess::Success => "PASS",
+        ExecutionResultSuccess::Failure => "FAIL",
+        ExecutionResultSuccess::Error => "ERROR",
+        ExecutionResultSuccess::Skipped => "SKIP",
+    };
+    format!(
+        "<span class=\"badge {}\">{}</span>",
+        success_class(success),
+        label
+    )
+}
+
+/// Append specified index, including children, to the report
+fn append_entry(
+    exec_ctr: &usize,
+    run: &ExecutionReportHtmlRun,
+    timeline: &Timeline,
+    html: &mut String,
+) -> Result<(), ApicizeError> {
+    let summary = get_summary(exec_ctr, run.summaries)?;
+    let children = summary.child_exec_ctrs.as_deref().unwrap_or_default();
+
+    // Groups, rows and runs are expanded by default, requests only if unsuccessful
+    let open = !children.is_empty()
+        || !matches!(
+            summary.success,
+            ExecutionResultSuccess::Success | ExecutionResultSuccess::Skipped
+        );
+
+    html.push_str(&format!(
+        "<details class=\"entry {}\"{}>\n<summary>{}<span class=\"name\">{}</span>",
+        success_class(&summary.success),
+        if open { " open" } else { "" },
+        render_badge(&summary.success),
+        escape_xml(&summary.name)
+    ));
+    if let Some(method) = &summary.method {
+        html.push_str(&format!(
+            "<span class=\"method\">{}</span>",
+            escape_xml(method)
+        ));
+    }
+    if let Some(url) = &summary.url {
+        html.push_str(&format!(
+            "<span class=\"url\" title=\"{0}\">{0}</span>",
+            escape_xml(url)
+        ));
+    }
+    if let Some(status) = summary.status {
+        html.push_str(&format!(
+            "<span class=\"status\">{} {}</span>",
+            status,
+            escape_xml(summary.status_text.as_deref().unwrap_or_default())
+        ));
+    }
+    html.push_str(&format!(
+        "<span class=\"duration\">{} ms</span>{}</summary>\n<div class=\"content\">\n",
+        summary.duration,
+        timeline.render(summary)
+    ));
+
+    if let Some(error) = &summary.error {
+        html.push_str(&format!(
+            "<div class=\"error-text\">{}: {}</div>\n",
+            escape_xml(error.get_label()),
+            escape_xml(&error.to_string())
+        ));
+    }
+
+    if let Some(skip_reason) = &summary.skip_reason {
+        html.push_str(&format!(
+            "<div class=\"skip-text\">Skipped: {}</div>\n",
+            escape_xml(skip_reason)
+        ));
+    }
+
+    if let Some(logs) = &summary.logs
+        && !logs.is_empty()
+    {
+        append_logs(logs, html);
+    }
+
+    if let Some(test_results) = &summary.test_results
+        && !test_results.is_empty()
+    {
+        append_tests(test_results, html);
+    }
+
+    if let Some(details) = run.details
+        && let Some(ExecutionResultDetail::Request(detail)) = details.get(exec_ctr)
+    {
+        if let Some(request) = &detail.test_context.request {
+            append_http(
+                "Request",
+                Some(&request.headers),
+                request.body.as_ref(),
+                run.redacted_headers,
+                html,
+            );
+        }
+        if let Some(response) = &detail.test_context.response {
+            append_http(
+                "Response",
+                response.headers.as_ref(),
+                response.body.as_ref(),
+                run.redacted_headers,
+                html,
+            );
+        }
+    }
+
+    for child_exec_ctr in children {
+        append_entry(child_exec_ctr, run, timeline, html)?;
+    }
+
+    html.push_str("</div>\n</details>\n");
+    Ok(())
+}
+
+fn append_logs(logs: &[String], html: &mut String) {
+    html.push_str(&format!(
+        "<pre class=\"logs\">{}</pre>\n",
+        escape_xml(&logs.join("\n"))
+    ));
+}
+
+fn append_tests(test_results: &[ApicizeTestBehavior], html: &mut String) {
+    html.push_str("<ul class=\"tests\">\n");
+    for test_result in test_results {
+        let success = if test_result.success {
+            ExecutionResultSuccess::Success
+        } else {
+            ExecutionResultSuccess::Failure
+        };
+        html.push_str(&format!(
+            "<li>{} {}",
+            render_badge(&success),
+            escape_xml(&test_result.name)
+        ));
+        if let Some(error) = &test_result.error {
+            html.push_str(&format!(
+                "<div class=\"test-error\">{}</div>",
+                escape_xml(error)
+            ));
+        }
+        if let Some(logs) = &test_result.logs
+            && !logs.is_empty()
+        {
+            append_logs(logs, html);
+        }
+        html.push_str("</li>\n");
+    }
+    html.push_str("</ul>\n");
+}
+
+fn append_http(
+    title: &str,
+    headers: Option<&HashMap<String, String>>,
+    body: Option<&ApicizeBody>,
+    redacted_headers: &[String],
+    html: &mut String,
+) {
+    html.push_str(&format!(
+        "<details class=\"http\">\n<summary>{title}</summary>\n"
+    ));
+
+    if let Some(headers) = headers
+        && !headers.is_empty()
+    {
+        let mut names = headers.keys().collect::<Vec<&String>>();
+        names.sort();
+        html.push_str("<table class=\"headers\">\n");
+        for name in names {
+            let redacted = REDACTED_HEADERS
+                .iter()
+                .any(|h| name.eq_ignore_ascii_case(h))
+                || redacted_headers
+                    .iter()
+                    .any(|h| name.eq_ignore_ascii_case(h));
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td></tr>\n",
+                escape_xml(name),
+                if redacted {
+                    "(Redacted)".to_string()
+                } else {
+                    escape_xml(&headers[name])
+                }
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    if let Some(body) = body {
+        html.push_str(&format!(
+            "<pre class=\"body\">{}</pre>\n",
+            escape_xml(&render_body(body))
+        ));
+    }
+
+    html.push_str("</details>\n");
+}
+
+fn render_body(body: &ApicizeBody) -> String {
+    match body {
+        ApicizeBody::JSON { text, data } => {
+            serde_json::to_string_pretty(data).unwrap_or_else(|_| text.clone())
+        }
+        ApicizeBody::Text { text }
+        | ApicizeBody::XML { text, .. }
+        | ApicizeBody::Form { text, .. } => text.clone(),
+        ApicizeBody::Binary { data } => format!("({} bytes of binary data)", data.len()),
+        ApicizeBody::Multipart { parts } => parts
+            .iter()
+            .map(|part| {
+                let mut description = format!("[{}]", part.name);
+                if let Some(file_name) = &part.file_name {
+                    description.push_str(&format!(" file: {file_name}"));
+                }
+                if let Some(content_type) = &part.content_type {
+                    description.push_str(&format!(" ({content_type})"));
+                }
+                if let Some(text) = &part.text {
+                    description.push_str(&format!("\n{text}"));
+                } else {
+                    description.push_str(&format!("\n({} bytes)", part.len()));
+                }
+                description
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n"),
+    }
+}
@@ -8,6 +8,7 @@ pub enum ExecutionResultSuccess {
     Success,
     Failure,
     Error,
+    Skipped,
 }
 
 impl Display for ExecutionResultSuccess {
@@ -16,6 +17,7 @@ impl Display for ExecutionResultSuccess {
             ExecutionResultSuccess::Success => write!(f, "SUCCESS"),
             ExecutionResultSuccess::Failure => write!(f, "FAILURE"),
             ExecutionResultSuccess::Error => write!(f, "ERROR"),
+            ExecutionResultSuccess::Skipped => write!(f, "SKIPPED"),
         }
     }
 }
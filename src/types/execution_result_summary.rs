@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::{ApicizeError, ApicizeTestBehavior};
+use crate::{
+    ApicizeError, ApicizeHttpTimings, ApicizeLatencyStatistics, ApicizeLoadResult,
+    ApicizeTestBehavior,
+};
 
 use super::execution_result_success::ExecutionResultSuccess;
 
@@ -53,6 +56,18 @@ pub struct ExecutionResultSummary {
     /// Duration of execution (milliseconds)
     pub duration: u128,
 
+    /// Duration of each phase of the request, if dispatched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timings: Option<ApicizeHttpTimings>,
+
+    /// Aggregated iterations, if executed with a load profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<Box<ApicizeLoadResult>>,
+
+    /// Distribution of response durations, if multiple runs, rows or iterations were executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<ApicizeLatencyStatistics>,
+
     /// HTTP status code
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<u16>,
@@ -86,6 +101,10 @@ pub struct ExecutionResultSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ApicizeError>,
 
+    /// Reason the request or group was skipped, if skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+
     /// Executed test results, if any
     #[serde(skip_serializing_if = "Option::is_none")]
     pub test_results: Option<Vec<ApicizeTestBehavior>>,
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+fn default_virtual_users() -> usize {
+    1
+}
+
+/// Load profile which executes a request or group repeatedly for a period of time,
+/// aggregating results instead of recording each iteration
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadProfile {
+    /// Number of virtual users executing iterations at once
+    #[serde(default = "default_virtual_users")]
+    pub virtual_users: usize,
+    /// Target number of iterations started per second across all virtual users,
+    /// if not set each virtual user starts its next iteration as soon as the last one completes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+    /// Time spent ramping up to the target rate or number of virtual users (milliseconds)
+    #[serde(default)]
+    pub ramp_up: u64,
+    /// Time spent at the target rate or number of virtual users (milliseconds)
+    pub duration: u64,
+    /// Time spent ramping down from the target rate or number of virtual users (milliseconds)
+    #[serde(default)]
+    pub ramp_down: u64,
+}
+
+impl LoadProfile {
+    /// Number of virtual users, at least one
+    pub fn get_virtual_users(&self) -> usize {
+        self.virtual_users.max(1)
+    }
+
+    /// Target rate, if iterations are throttled
+    pub fn get_rate(&self) -> Option<f64> {
+        self.rate.filter(|rate| *rate > 0.0)
+    }
+
+    /// Total time of the profile, including ramp up and ramp down
+    pub fn total_duration(&self) -> Duration {
+        Duration::from_millis(self.ramp_up + self.duration + self.ramp_down)
+    }
+
+    /// Offset from the start of the profile when the specified iteration (starting at 0)
+    /// is scheduled to start, ramping the rate linearly up and down; returns None if
+    /// iterations are not throttled or the iteration falls after the end of the profile
+    pub fn scheduled_at(&self, iteration: usize) -> Option<Duration> {
+        let rate = self.get_rate()?;
+        let ramp_up = self.ramp_up as f64 / 1000.0;
+        let steady = self.duration as f64 / 1000.0;
+        let ramp_down = self.ramp_down as f64 / 1000.0;
+        let iteration = iteration as f64;
+
+        // Number of iterations scheduled by the end of each stage
+        let ramped_up = rate * ramp_up / 2.0;
+        let steadied = ramped_up + rate * steady;
+        let ramped_down = steadied + rate * ramp_down / 2.0;
+
+        let seconds = if iteration < ramped_up {
+            (2.0 * ramp_up * iteration / rate).sqrt()
+        } else if iteration < steadied {
+            ramp_up + (iteration - ramped_up) / rate
+        } else if iteration < ramped_down {
+            let remaining = 1.0 - 2.0 * (iteration - steadied) / (rate * ramp_down);
+            ramp_up + steady + ramp_down * (1.0 - remaining.max(0.0).sqrt())
+        } else {
+            return None;
+        };
+
+        Some(Duration::from_secs_f64(seconds))
+    }
+
+    /// Offsets from the start of the profile when the specified virtual user (starting at 0)
+    /// starts and stops iterating, staggering virtual users linearly during ramp up and ramp down
+    pub fn virtual_user_window(&self, virtual_user: usize) -> (Duration, Duration) {
+        let virtual_users = self.get_virtual_users() as u64;
+        let virtual_user = (virtual_user as u64).min(virtual_users - 1);
+        let start = self.ramp_up * virtual_user / virtual_users;
+        let stop = self.ramp_up
+            + self.duration
+            + self.ramp_down * (virtual_users - virtual_user) / virtual_users;
+        (Duration::from_millis(start), Duration::from_millis(stop))
+    }
+}
@@ -1,17 +1,23 @@
 pub mod authorization;
 pub mod certificate;
 pub mod data_set;
+pub mod dependencies;
 pub mod disabled;
 pub mod execution_report;
+pub(crate) mod execution_report_html;
 pub mod execution_result_success;
 pub mod execution_result_summary;
 pub mod execution_state;
 pub mod identifiable;
 pub mod indexed_entities;
+pub mod load_profile;
 pub mod name_value_pair;
 pub mod parameters;
+pub mod poll_until;
 pub mod proxy;
 pub mod request;
+pub mod retry_policy;
+pub mod run_filter;
 pub mod scenario;
 pub mod selected_parameters;
 pub mod selection;
@@ -28,6 +34,7 @@ pub mod editing;
 pub use authorization::Authorization;
 pub use certificate::Certificate;
 pub use data_set::*;
+pub use dependencies::*;
 pub use disabled::*;
 pub use execution_report::*;
 pub use execution_result_success::*;
@@ -35,10 +42,14 @@ pub use execution_result_summary::*;
 pub use execution_state::*;
 pub use identifiable::Identifiable;
 pub use indexed_entities::IndexedEntities;
+pub use load_profile::*;
 pub use name_value_pair::NameValuePair;
 pub use parameters::Parameters;
+pub use poll_until::*;
 pub use proxy::Proxy;
 pub use request::*;
+pub use retry_policy::*;
+pub use run_filter::RunFilter;
 pub use scenario::Scenario;
 pub use selected_parameters::SelectedParameters;
 pub use selection::Selection;
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+fn default_interval() -> u64 {
+    1000
+}
+
+fn default_max_wait() -> u64 {
+    60_000
+}
+
+/// Condition which ends polling of a request
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum PollCondition {
+    /// JavaScript expression, with the same globals as tests, which is truthy when polling should stop
+    Script {
+        /// Expression to evaluate
+        script: String,
+    },
+    /// JSONPath into the response body, which stops polling when it matches the value,
+    /// or when it matches anything if no value is specified
+    #[serde(rename = "JSONPath")]
+    JsonPath {
+        /// JSONPath expression (ex. "$.job.status")
+        path: String,
+        /// Value to match
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<Value>,
+    },
+}
+
+impl PollCondition {
+    /// JavaScript expression used to evaluate the condition
+    pub fn to_script(&self) -> String {
+        match self {
+            PollCondition::Script { script } => script.clone(),
+            PollCondition::JsonPath { path, value } => format!(
+                "jsonPathMatches({}, {})",
+                Value::String(path.clone()),
+                value
+                    .as_ref()
+                    .map_or("undefined".to_string(), |v| v.to_string())
+            ),
+        }
+    }
+}
+
+/// Re-dispatch a request until a condition on its response holds, or until the maximum wait runs out
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PollUntil {
+    /// Condition which ends polling
+    pub condition: PollCondition,
+    /// Delay between dispatches (milliseconds)
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+    /// Maximum time to poll before giving up (milliseconds)
+    #[serde(default = "default_max_wait")]
+    pub max_wait: u64,
+}
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use super::identifiable::CloneIdentifiable;
-use super::{NameValuePair, Selection};
+use super::{LoadProfile, NameValuePair, PollUntil, RetryPolicy, Selection};
 use crate::{
     Disabled, Identifiable, SelectedParameters, Validated, ValidationState, add_validation_error,
     remove_validation_error, utility::*,
@@ -62,6 +62,65 @@ pub enum RequestBody {
         #[serde_as(as = "Base64<Standard, Unpadded>")]
         data: Vec<u8>,
     },
+    /// Multipart form body data
+    Multipart {
+        /// Text and file parts of form data
+        data: Vec<MultipartPart>,
+    },
+}
+
+/// Part of a multipart form body
+#[serde_as]
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "type")]
+pub enum MultipartPart {
+    /// Text value
+    #[serde(rename_all = "camelCase")]
+    Text {
+        /// Name of form field
+        name: String,
+        /// Value of form field
+        value: String,
+        /// Content type of part, if not text/plain
+        #[serde(skip_serializing_if = "Option::is_none")]
+        content_type: Option<String>,
+        /// If set to true, part will not be included when dispatching Apicize Requests
+        #[serde(skip_serializing_if = "Option::is_none")]
+        disabled: Option<bool>,
+    },
+    /// File content, either inline or loaded from the workbook's data path
+    #[serde(rename_all = "camelCase")]
+    File {
+        /// Name of form field
+        name: String,
+        /// File name to send with part
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_name: Option<String>,
+        /// Content type of part
+        #[serde(skip_serializing_if = "Option::is_none")]
+        content_type: Option<String>,
+        /// Base-64 encoded inline file data
+        #[serde_as(as = "Option<Base64<Standard, Unpadded>>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        data: Option<Vec<u8>>,
+        /// File name, relative to the workbook's data path, to load data from
+        #[serde(skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        /// If set to true, part will not be included when dispatching Apicize Requests
+        #[serde(skip_serializing_if = "Option::is_none")]
+        disabled: Option<bool>,
+    },
+}
+
+impl MultipartPart {
+    /// Return true if part should be dispatched
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            MultipartPart::Text { disabled, .. } | MultipartPart::File { disabled, .. } => {
+                *disabled != Some(true)
+            }
+        }
+    }
 }
 
 /// Indicator on  request execution order
@@ -75,6 +134,23 @@ pub enum ExecutionConcurrency {
     Concurrent,
 }
 
+/// Indicator on how failures and errors affect the remaining requests of a group or run
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Default, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RunPolicy {
+    /// Remaining requests are executed regardless of failures or errors
+    #[default]
+    Continue,
+    /// Remaining requests in the group, including those of nested groups, are skipped
+    /// after the first failure or error
+    StopGroupOnFailure,
+    /// Remaining requests in the run are skipped after the first error
+    StopRunOnError,
+    /// Remaining siblings are skipped after a child fails or errors, while children
+    /// of nested groups already executing continue
+    SkipRemainingSiblings,
+}
+
 /// Information required to dispatch and test an Apicize Request
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -93,6 +169,16 @@ pub struct Request {
     /// Optional identifier for the Apicize Requset
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
+    /// Labels used to select what to execute with a run filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Keys or IDs of sibling requests or groups which must complete before executing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Vec<String>>,
+    /// JavaScript expression evaluated against scenario, data row and output variables
+    /// (as `$`) before executing, which is skipped if the expression is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
     /// URL to dispatch the HTTP request to
     pub url: String,
     /// HTTP method
@@ -125,9 +211,27 @@ pub struct Request {
     /// Execution of multiple runs
     #[serde(default)]
     pub multi_run_execution: ExecutionConcurrency,
+    /// Maximum number of runs executed at once when concurrent, unlimited if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<usize>,
+    /// Load profile to execute instead of a fixed number of runs, if set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<LoadProfile>,
+    /// Retry policy for transient failures, if not inherited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+    /// Re-dispatch the request until a condition on its response holds, if set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_until: Option<Box<PollUntil>>,
+    /// Script to execute before dispatching request, its output available for substitution
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_request: Option<String>,
     /// Test to execute after dispatching request and receiving response
     #[serde(skip_serializing_if = "Option::is_none")]
     pub test: Option<String>,
+    /// OpenAPI operation (operationId or "METHOD /path") the response is validated against
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
     /// Selected scenario, if applicable
     #[serde(default = "Selection::default")]
     pub selected_scenario: Selection,
@@ -172,6 +276,16 @@ pub struct RequestGroup {
     /// Optional identifier for the Apicize Group,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
+    /// Labels used to select what to execute with a run filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Keys or IDs of sibling requests or groups which must complete before executing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Vec<String>>,
+    /// JavaScript expression evaluated against scenario, data row and output variables
+    /// (as `$`) before executing, which is skipped if the expression is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
     /// Child items
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<RequestEntry>>,
@@ -184,9 +298,30 @@ pub struct RequestGroup {
     /// Execution of multiple runs
     #[serde(default)]
     pub multi_run_execution: ExecutionConcurrency,
+    /// Maximum number of children or runs executed at once when concurrent, unlimited if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<usize>,
+    /// Load profile to execute instead of a fixed number of runs, if set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<LoadProfile>,
     /// Setup script to execute before running child requests
     #[serde(skip_serializing_if = "Option::is_none")]
     pub setup: Option<String>,
+    /// Script to execute after each child request or group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_each: Option<String>,
+    /// Teardown script to execute after running child requests, even if they fail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teardown: Option<String>,
+    /// If set to True, child requests share a cookie jar scoped to the group
+    #[serde(skip_serializing_if = "std::ops::Not::not", default = "bool::default")]
+    pub cookie_jar: bool,
+    /// Retry policy for transient failures, if not inherited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+    /// How failures and errors affect remaining requests, if not inherited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_policy: Option<RunPolicy>,
     /// Selected scenario, if applicable
     #[serde(default = "Selection::default")]
     pub selected_scenario: Selection,
@@ -326,8 +461,12 @@ impl Default for Request {
             name: Default::default(),
             disabled: false,
             key: Default::default(),
+            tags: None,
+            depends_on: None,
+            condition: None,
             validation_state: Default::default(),
             // execution_state: Default::default(),
+            pre_request: None,
             test: Some(
                 r#"describe('status', () => {
     it('equals 200', () => {
@@ -336,6 +475,7 @@ impl Default for Request {
 })"#
                 .to_string(),
             ),
+            operation: Default::default(),
             url: Default::default(),
             method: Default::default(),
             timeout: Default::default(),
@@ -345,6 +485,10 @@ impl Default for Request {
             keep_alive: Default::default(),
             runs: 1,
             multi_run_execution: ExecutionConcurrency::Sequential,
+            max_concurrency: None,
+            load: None,
+            retry: None,
+            poll_until: None,
             selected_scenario: Default::default(),
             selected_authorization: Default::default(),
             selected_certificate: Default::default(),
@@ -488,12 +632,22 @@ impl Default for RequestGroup {
             name: Default::default(),
             disabled: false,
             key: Default::default(),
+            tags: None,
+            depends_on: None,
+            condition: None,
             // execution_state: Default::default(),
             children: Default::default(),
             execution: ExecutionConcurrency::Sequential,
             runs: 1,
             multi_run_execution: ExecutionConcurrency::Sequential,
+            max_concurrency: None,
+            load: None,
             setup: None,
+            after_each: None,
+            teardown: None,
+            cookie_jar: false,
+            retry: None,
+            run_policy: None,
             selected_scenario: Default::default(),
             selected_authorization: Default::default(),
             selected_certificate: Default::default(),
@@ -565,6 +719,46 @@ impl RequestEntry {
         }
     }
 
+    /// Retrieve request entry key, if set
+    pub fn get_key(&self) -> Option<&String> {
+        match self {
+            RequestEntry::Request(info) => info.key.as_ref(),
+            RequestEntry::Group(group) => group.key.as_ref(),
+        }
+    }
+
+    /// Retrieve request entry tags, if set
+    pub fn get_tags(&self) -> Option<&Vec<String>> {
+        match self {
+            RequestEntry::Request(info) => info.tags.as_ref(),
+            RequestEntry::Group(group) => group.tags.as_ref(),
+        }
+    }
+
+    /// Retrieve keys or IDs of siblings the request entry depends upon, if set
+    pub fn get_depends_on(&self) -> Option<&Vec<String>> {
+        match self {
+            RequestEntry::Request(info) => info.depends_on.as_ref(),
+            RequestEntry::Group(group) => group.depends_on.as_ref(),
+        }
+    }
+
+    /// Retrieve request entry condition, if set
+    pub fn get_condition(&self) -> Option<&String> {
+        match self {
+            RequestEntry::Request(info) => info.condition.as_ref(),
+            RequestEntry::Group(group) => group.condition.as_ref(),
+        }
+    }
+
+    /// Retrieve request entry retry policy, if set
+    pub fn get_retry(&self) -> Option<&RetryPolicy> {
+        match self {
+            RequestEntry::Request(info) => info.retry.as_ref(),
+            RequestEntry::Group(group) => group.retry.as_ref(),
+        }
+    }
+
     /// Set number of runs
     pub fn set_runs(&mut self, runs: usize) {
         match self {
@@ -698,6 +892,11 @@ pub enum StoredRequestBody {
         #[serde_as(as = "Base64<Standard, Unpadded>")]
         data: Vec<u8>,
     },
+    /// Multipart form body data
+    Multipart {
+        /// Text and file parts of form data
+        data: Vec<MultipartPart>,
+    },
 }
 
 /// Information required to dispatch and test an Apicize Request
@@ -715,9 +914,25 @@ pub struct StoredRequest {
     /// Optional identifier for the Apicize Request
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
+    /// Labels used to select what to execute with a run filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Keys or IDs of sibling requests or groups which must complete before executing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Vec<String>>,
+    /// JavaScript expression evaluated against scenario, data row and output variables
+    /// (as `$`) before executing, which is skipped if the expression is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    /// Script to execute before dispatching request, its output available for substitution
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_request: Option<String>,
     /// Test to execute after dispatching request and receiving response
     #[serde(skip_serializing_if = "Option::is_none")]
     pub test: Option<String>,
+    /// OpenAPI operation (operationId or "METHOD /path") the response is validated against
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
     /// URL to dispatch the HTTP request to
     pub url: String,
     /// HTTP method
@@ -750,6 +965,18 @@ pub struct StoredRequest {
     /// Execution of multiple runs
     #[serde(default)]
     pub multi_run_execution: ExecutionConcurrency,
+    /// Maximum number of runs executed at once when concurrent, unlimited if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<usize>,
+    /// Load profile to execute instead of a fixed number of runs, if set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<LoadProfile>,
+    /// Retry policy for transient failures, if not inherited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+    /// Re-dispatch the request until a condition on its response holds, if set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_until: Option<Box<PollUntil>>,
     /// Selected scenario, if applicable
     #[serde(
         skip_serializing_if = "Selection::is_default",
@@ -797,6 +1024,16 @@ pub struct StoredRequestGroup {
     /// Optional identifier for the Apicize Group
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
+    /// Labels used to select what to execute with a run filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Keys or IDs of sibling requests or groups which must complete before executing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Vec<String>>,
+    /// JavaScript expression evaluated against scenario, data row and output variables
+    /// (as `$`) before executing, which is skipped if the expression is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
     /// Child items
     pub children: Option<Vec<StoredRequestEntry>>,
     /// Execution of children
@@ -808,9 +1045,30 @@ pub struct StoredRequestGroup {
     /// Execution of multiple runs
     #[serde(default)]
     pub multi_run_execution: ExecutionConcurrency,
+    /// Maximum number of children or runs executed at once when concurrent, unlimited if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<usize>,
+    /// Load profile to execute instead of a fixed number of runs, if set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<LoadProfile>,
     /// Setup script to execute before running child requests
     #[serde(skip_serializing_if = "Option::is_none")]
     pub setup: Option<String>,
+    /// Script to execute after each child request or group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_each: Option<String>,
+    /// Teardown script to execute after running child requests, even if they fail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teardown: Option<String>,
+    /// If set to True, child requests share a cookie jar scoped to the group
+    #[serde(skip_serializing_if = "std::ops::Not::not", default = "bool::default")]
+    pub cookie_jar: bool,
+    /// Retry policy for transient failures, if not inherited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+    /// How failures and errors affect remaining requests, if not inherited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_policy: Option<RunPolicy>,
     /// Selected scenario, if applicable
     #[serde(
         skip_serializing_if = "Selection::is_default",
@@ -863,7 +1121,12 @@ impl From<RequestEntry> for StoredRequestEntry {
                     name: request.name,
                     disabled: request.disabled,
                     key: request.key,
+                    tags: request.tags,
+                    depends_on: request.depends_on,
+                    condition: request.condition,
+                    pre_request: request.pre_request,
                     test: request.test,
+                    operation: request.operation,
                     url: request.url,
                     method: request.method,
                     timeout: request.timeout,
@@ -905,6 +1168,9 @@ impl From<RequestEntry> for StoredRequestEntry {
                             }),
                             RequestBody::Form { data } => Some(StoredRequestBody::Form { data }),
                             RequestBody::Raw { data } => Some(StoredRequestBody::Raw { data }),
+                            RequestBody::Multipart { data } => {
+                                Some(StoredRequestBody::Multipart { data })
+                            }
                         },
                         None => None,
                     },
@@ -913,6 +1179,10 @@ impl From<RequestEntry> for StoredRequestEntry {
                     number_of_redirects: request.number_of_redirects,
                     runs: request.runs,
                     multi_run_execution: request.multi_run_execution,
+                    max_concurrency: request.max_concurrency,
+                    load: request.load,
+                    retry: request.retry,
+                    poll_until: request.poll_until,
                     selected_scenario: request.selected_scenario,
                     selected_authorization: request.selected_authorization,
                     selected_certificate: request.selected_certificate,
@@ -925,6 +1195,9 @@ impl From<RequestEntry> for StoredRequestEntry {
                 name: group.name,
                 disabled: group.disabled,
                 key: group.key,
+                tags: group.tags,
+                depends_on: group.depends_on,
+                condition: group.condition,
                 children: if let Some(children) = group.children
                     && !children.is_empty()
                 {
@@ -935,7 +1208,14 @@ impl From<RequestEntry> for StoredRequestEntry {
                 execution: group.execution,
                 runs: group.runs,
                 multi_run_execution: group.multi_run_execution,
+                max_concurrency: group.max_concurrency,
+                load: group.load,
                 setup: group.setup,
+                after_each: group.after_each,
+                teardown: group.teardown,
+                cookie_jar: group.cookie_jar,
+                retry: group.retry,
+                run_policy: group.run_policy,
                 selected_scenario: group.selected_scenario,
                 selected_authorization: group.selected_authorization,
                 selected_certificate: group.selected_certificate,
@@ -954,9 +1234,14 @@ impl From<StoredRequestEntry> for RequestEntry {
                 name: stored_request.name,
                 disabled: stored_request.disabled,
                 key: stored_request.key,
+                tags: stored_request.tags,
+                depends_on: stored_request.depends_on,
+                condition: stored_request.condition,
                 validation_state: Default::default(),
                 // execution_state: Default::default(),
+                pre_request: stored_request.pre_request,
                 test: stored_request.test,
+                operation: stored_request.operation,
                 url: stored_request.url,
                 method: stored_request.method,
                 timeout: stored_request.timeout,
@@ -1000,6 +1285,9 @@ impl From<StoredRequestEntry> for RequestEntry {
                         }
                         StoredRequestBody::Form { data } => Some(RequestBody::Form { data }),
                         StoredRequestBody::Raw { data } => Some(RequestBody::Raw { data }),
+                        StoredRequestBody::Multipart { data } => {
+                            Some(RequestBody::Multipart { data })
+                        }
                     },
                     None => None,
                 },
@@ -1008,6 +1296,10 @@ impl From<StoredRequestEntry> for RequestEntry {
                 number_of_redirects: stored_request.number_of_redirects,
                 runs: stored_request.runs,
                 multi_run_execution: stored_request.multi_run_execution,
+                max_concurrency: stored_request.max_concurrency,
+                load: stored_request.load,
+                retry: stored_request.retry,
+                poll_until: stored_request.poll_until,
                 selected_scenario: stored_request.selected_scenario,
                 selected_authorization: stored_request.selected_authorization,
                 selected_certificate: stored_request.selected_certificate,
@@ -1021,6 +1313,9 @@ impl From<StoredRequestEntry> for RequestEntry {
                 name: stored_group.name,
                 disabled: stored_group.disabled,
                 key: stored_group.key,
+                tags: stored_group.tags,
+                depends_on: stored_group.depends_on,
+                condition: stored_group.condition,
                 validation_state: Default::default(),
                 // execution_state: Default::default(),
                 children: if let Some(children) = stored_group.children
@@ -1033,7 +1328,14 @@ impl From<StoredRequestEntry> for RequestEntry {
                 execution: stored_group.execution,
                 runs: stored_group.runs,
                 multi_run_execution: stored_group.multi_run_execution,
+                max_concurrency: stored_group.max_concurrency,
+                load: stored_group.load,
                 setup: stored_group.setup,
+                after_each: stored_group.after_each,
+                teardown: stored_group.teardown,
+                cookie_jar: stored_group.cookie_jar,
+                retry: stored_group.retry,
+                run_policy: stored_group.run_policy,
                 selected_scenario: stored_group.selected_scenario,
                 selected_authorization: stored_group.selected_authorization,
                 selected_certificate: stored_group.selected_certificate,
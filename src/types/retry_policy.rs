@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ApicizeError;
+
+fn default_max_attempts() -> usize {
+    3
+}
+
+fn default_retry_statuses() -> Vec<u16> {
+    vec![408, 429, 502, 503, 504]
+}
+
+fn default_delay() -> u64 {
+    500
+}
+
+fn default_max_delay() -> u64 {
+    30_000
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// How the delay between attempts grows
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Default, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RetryBackoff {
+    /// Wait the same delay between each attempt
+    Fixed,
+    /// Double the delay after each attempt
+    #[default]
+    Exponential,
+}
+
+/// Policy for retrying requests that fail with transient errors; requests
+/// without a policy inherit their nearest ancestor group's policy
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryPolicy {
+    /// If true, requests are not retried, overriding any policy of an ancestor group
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+    /// Maximum number of attempts, including the first (1 = do not retry)
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: usize,
+    /// HTTP status codes which are retried
+    #[serde(default = "default_retry_statuses")]
+    pub retry_statuses: Vec<u16>,
+    /// If true, requests failing to connect or send are retried
+    #[serde(default = "default_true")]
+    pub retry_on_error: bool,
+    /// If true, requests which time out are retried
+    #[serde(default = "default_true")]
+    pub retry_on_timeout: bool,
+    /// How the delay between attempts grows
+    #[serde(default)]
+    pub backoff: RetryBackoff,
+    /// Delay before the first retry (milliseconds)
+    #[serde(default = "default_delay")]
+    pub delay: u64,
+    /// Maximum delay between attempts (milliseconds)
+    #[serde(default = "default_max_delay")]
+    pub max_delay: u64,
+    /// If true, delays are randomized between half and all of the calculated delay
+    #[serde(default = "default_true")]
+    pub jitter: bool,
+    /// If true, the delay requested by a response's Retry-After header is used
+    #[serde(default = "default_true")]
+    pub honor_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            disabled: false,
+            max_attempts: default_max_attempts(),
+            retry_statuses: default_retry_statuses(),
+            retry_on_error: true,
+            retry_on_timeout: true,
+            backoff: RetryBackoff::default(),
+            delay: default_delay(),
+            max_delay: default_max_delay(),
+            jitter: true,
+            honor_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns true if a response with the specified status should be retried
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retry_statuses.contains(&status)
+    }
+
+    /// Returns true if a dispatch error should be retried.  HTTP errors without a URL
+    /// are raised building the request (ex. missing URL) and would fail again
+    pub fn is_retryable_error(&self, error: &ApicizeError) -> bool {
+        match error {
+            ApicizeError::Http { url, .. } => self.retry_on_error && url.is_some(),
+            ApicizeError::Timeout { .. } => self.retry_on_timeout,
+            _ => false,
+        }
+    }
+
+    /// Delay to wait after the specified attempt (starting at 1) before the next one,
+    /// using the response's headers (if any) to honor Retry-After
+    pub fn get_delay(
+        &self,
+        attempt: usize,
+        response_headers: Option<&HashMap<String, String>>,
+    ) -> Duration {
+        let max_delay = Duration::from_millis(self.max_delay);
+
+        if self.honor_retry_after
+            && let Some(retry_after) = response_headers
+                .and_then(|headers| {
+                    headers
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+                })
+                .and_then(|(_, value)| parse_retry_after(value))
+        {
+            return retry_after.min(max_delay);
+        }
+
+        let delay = match self.backoff {
+            RetryBackoff::Fixed => Duration::from_millis(self.delay),
+            RetryBackoff::Exponential => {
+                let factor = 2u64.saturating_pow(attempt.saturating_sub(1) as u32);
+                Duration::from_millis(self.delay.saturating_mul(factor))
+            }
+        }
+        .min(max_delay);
+
+        if self.jitter && !delay.is_zero() {
+            let half = delay.as_millis() as u64 / 2;
+            Duration::from_millis(half + rand::random_range(0..=delay.as_millis() as u64 - half))
+        } else {
+            delay
+        }
+    }
+}
+
+/// Parse a Retry-After header value, either a number of seconds or an HTTP date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        Some(Duration::from_secs(seconds))
+    } else {
+        httpdate::parse_http_date(value).ok().map(|when| {
+            when.duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO)
+        })
+    }
+}
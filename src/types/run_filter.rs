@@ -0,0 +1,226 @@
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use crate::{ApicizeError, IndexedEntities, RequestEntry};
+
+/// Term or operation of a run filter expression
+#[derive(Clone, Debug)]
+enum FilterExpression {
+    /// Matches requests tagged with the name, directly or by an ancestor group
+    Tag(String),
+    /// Matches requests whose key, or an ancestor group's key, matches the glob
+    Key(Regex),
+    Not(Box<FilterExpression>),
+    And(Box<FilterExpression>, Box<FilterExpression>),
+    Or(Box<FilterExpression>, Box<FilterExpression>),
+}
+
+impl FilterExpression {
+    fn matches(&self, tags: &[&str], keys: &[&str]) -> bool {
+        match self {
+            FilterExpression::Tag(tag) => tags.contains(&tag.as_str()),
+            FilterExpression::Key(glob) => keys.iter().any(|key| glob.is_match(key)),
+            FilterExpression::Not(expr) => !expr.matches(tags, keys),
+            FilterExpression::And(left, right) => {
+                left.matches(tags, keys) && right.matches(tags, keys)
+            }
+            FilterExpression::Or(left, right) => {
+                left.matches(tags, keys) || right.matches(tags, keys)
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+enum FilterToken {
+    Term(String),
+    Not,
+    And,
+    Or,
+    Open,
+    Close,
+}
+
+/// Expression selecting which requests in a run execute, combining tags and key globs
+/// with `&&`, `||`, `!` and parentheses (ex. `smoke && !slow`, `orders.*`).  Terms containing
+/// `*` or `?` are key globs, terms prefixed with `key:` or `tag:` are explicitly a key glob
+/// or tag, and any other term is a tag.
+#[derive(Clone, Debug)]
+pub struct RunFilter {
+    expression: FilterExpression,
+}
+
+impl RunFilter {
+    /// Parse a filter expression
+    pub fn parse(filter: &str) -> Result<RunFilter, ApicizeError> {
+        let tokens = Self::tokenize(filter);
+        let mut position = 0;
+        let expression = Self::parse_or(&tokens, &mut position)
+            .and_then(|expression| match tokens.get(position) {
+                None => Ok(expression),
+                Some(token) => Err(format!("unexpected {token:?}")),
+            })
+            .map_err(|err| ApicizeError::Error {
+                description: format!("Invalid filter \"{filter}\": {err}"),
+            })?;
+        Ok(RunFilter { expression })
+    }
+
+    /// Returns true if a request with the specified tags and keys (including those of
+    /// ancestor groups) is selected
+    pub fn matches(&self, tags: &[&str], keys: &[&str]) -> bool {
+        self.expression.matches(tags, keys)
+    }
+
+    /// Return the IDs of requests selected by the filter, along with the groups containing them
+    pub fn select(&self, requests: &IndexedEntities<RequestEntry>) -> HashSet<String> {
+        let mut selected = HashSet::new();
+        self.select_entries(&requests.top_level_ids, &[], &[], requests, &mut selected);
+        selected
+    }
+
+    fn select_entries<'a>(
+        &self,
+        ids: &[String],
+        tags: &[&'a str],
+        keys: &[&'a str],
+        requests: &'a IndexedEntities<RequestEntry>,
+        selected: &mut HashSet<String>,
+    ) -> bool {
+        let mut any_selected = false;
+        for id in ids {
+            let Some(entry) = requests.entities.get(id) else {
+                continue;
+            };
+
+            let mut entry_tags = tags.to_vec();
+            entry_tags.extend(entry.get_tags().into_iter().flatten().map(String::as_str));
+            let mut entry_keys = keys.to_vec();
+            entry_keys.extend(entry.get_key().map(String::as_str));
+
+            let is_selected = match entry {
+                RequestEntry::Request(_) => self.matches(&entry_tags, &entry_keys),
+                RequestEntry::Group(_) => self.select_entries(
+                    requests.child_ids.get(id).map_or(&[], |c| c.as_slice()),
+                    &entry_tags,
+                    &entry_keys,
+                    requests,
+                    selected,
+                ),
+            };
+            if is_selected {
+                selected.insert(id.clone());
+                any_selected = true;
+            }
+        }
+        any_selected
+    }
+
+    fn tokenize(filter: &str) -> Vec<FilterToken> {
+        let mut tokens = Vec::new();
+        let mut chars = filter.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                c if c.is_whitespace() => {}
+                '!' => tokens.push(FilterToken::Not),
+                '(' => tokens.push(FilterToken::Open),
+                ')' => tokens.push(FilterToken::Close),
+                '&' | '|' => {
+                    // Accept single or doubled operators
+                    if chars.peek() == Some(&c) {
+                        chars.next();
+                    }
+                    tokens.push(if c == '&' {
+                        FilterToken::And
+                    } else {
+                        FilterToken::Or
+                    });
+                }
+                _ => {
+                    let mut term = c.to_string();
+                    while let Some(next) = chars.peek() {
+                        if next.is_whitespace() || "!()&|".contains(*next) {
+                            break;
+                        }
+                        term.push(*next);
+                        chars.next();
+                    }
+                    tokens.push(FilterToken::Term(term));
+                }
+            }
+        }
+        tokens
+    }
+
+    fn parse_or(tokens: &[FilterToken], position: &mut usize) -> Result<FilterExpression, String> {
+        let mut expression = Self::parse_and(tokens, position)?;
+        while tokens.get(*position) == Some(&FilterToken::Or) {
+            *position += 1;
+            let right = Self::parse_and(tokens, position)?;
+            expression = FilterExpression::Or(Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_and(tokens: &[FilterToken], position: &mut usize) -> Result<FilterExpression, String> {
+        let mut expression = Self::parse_unary(tokens, position)?;
+        while tokens.get(*position) == Some(&FilterToken::And) {
+            *position += 1;
+            let right = Self::parse_unary(tokens, position)?;
+            expression = FilterExpression::And(Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_unary(
+        tokens: &[FilterToken],
+        position: &mut usize,
+    ) -> Result<FilterExpression, String> {
+        let Some(token) = tokens.get(*position) else {
+            return Err("unexpected end of expression".to_string());
+        };
+        *position += 1;
+        match token {
+            FilterToken::Not => Ok(FilterExpression::Not(Box::new(Self::parse_unary(
+                tokens, position,
+            )?))),
+            FilterToken::Open => {
+                let expression = Self::parse_or(tokens, position)?;
+                if tokens.get(*position) != Some(&FilterToken::Close) {
+                    return Err("missing closing parenthesis".to_string());
+                }
+                *position += 1;
+                Ok(expression)
+            }
+            FilterToken::Term(term) => {
+                if let Some(tag) = term.strip_prefix("tag:") {
+                    Ok(FilterExpression::Tag(tag.to_string()))
+                } else if let Some(glob) = term.strip_prefix("key:") {
+                    Ok(FilterExpression::Key(glob_to_regex(glob)?))
+                } else if term.contains(['*', '?']) {
+                    Ok(FilterExpression::Key(glob_to_regex(term)?))
+                } else {
+                    Ok(FilterExpression::Tag(term.clone()))
+                }
+            }
+            token => Err(format!("unexpected {token:?}")),
+        }
+    }
+}
+
+/// Convert a glob, where `*` matches any characters and `?` matches a single character,
+/// into a regular expression matching the entire value
+fn glob_to_regex(glob: &str) -> Result<Regex, String> {
+    let mut pattern = String::with_capacity(glob.len() + 2);
+    pattern.push('^');
+    for c in glob.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    Regex::new(&pattern).map_err(|err| err.to_string())
+}
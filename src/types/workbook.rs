@@ -27,4 +27,7 @@ pub struct Workbook {
     /// Workbook defaults
     #[serde(skip_serializing_if = "Option::is_none")]
     pub defaults: Option<WorkbookDefaultParameters>,
+    /// OpenAPI document (relative to the workbook) that responses are validated against
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub openapi: Option<String>,
 }
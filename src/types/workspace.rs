@@ -3,17 +3,23 @@
 //! This submodule defines modules used to manage workspaces
 
 use crate::{
-    ApicizeError, Authorization, Certificate, DataSet, DataSourceType, ExecutionReportCsv,
-    ExecutionReportCsvSingleRun, ExecutionReportFormat, ExecutionReportJson,
+    ApicizeError, ApicizeGroupScriptResult, ApicizeLatencyStatistics, ApicizeTestBehavior,
+    Authorization, Certificate, CookieJar, DataSet, DataSourceType, ExecutionReportCsv,
+    ExecutionReportCsvSingleRun, ExecutionReportFormat, ExecutionReportJUnitCase,
+    ExecutionReportJUnitSuite, ExecutionReportJson, ExecutionResultDetail, ExecutionResultSuccess,
     ExecutionResultSummary, Identifiable, IndexedEntities, Parameters, PersistedIndex, Proxy,
-    RequestEntry, Scenario, SelectedParameters, Selection, SerializationSaveSuccess,
-    StoredRequestEntry, Validated, VariableCache, Workbook, WorkbookDefaultParameters,
+    RequestEntry, RetryPolicy, RunPolicy, Scenario, SelectedParameters, Selection,
+    SerializationSaveSuccess, SiblingDependencies, StoredRequestEntry, Validated, VariableCache,
+    Workbook, WorkbookDefaultParameters,
     authorization::AuthorizationPlain,
+    execution_report::escape_xml,
+    execution_report_html::{ExecutionReportHtmlRun, generate_html_report},
     open_data_file, open_data_stream,
     parameters::{EncryptableParameter, ParameterEncryption},
     save_data_file,
     selected_parameters::SelectableParameters,
     selection::SelectionIfInvalid,
+    validation_state::{add_validation_error, remove_validation_error},
 };
 
 use csv::WriterBuilder;
@@ -26,13 +32,17 @@ use std::{
     ffi::OsStr,
     io::stdin,
     path::{Path, PathBuf},
-    sync::{Arc, Mutex},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 /// Lock status of workbook opened in workspace, indicating whether entries can be accessed
-#[derive(Clone, Copy, Debug, Serialize_repr, Deserialize_repr, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Serialize_repr, Deserialize_repr, PartialEq)]
 #[repr(u8)]
 pub enum ParameterLockStatus {
+    #[default]
     UnlockedNoPassword = 0,
     UnlockedWithEnvVar = 1,
     UnlockedWithPassword = 2,
@@ -70,7 +80,7 @@ pub struct OpenWorkbookOptions {
 
 /// Data type for entities used by Apicize during testing and editing.  This will be
 /// the combination of ,  credential and global settings values
-#[derive(Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Workspace {
     /// Requests for the workspace
@@ -94,6 +104,9 @@ pub struct Workspace {
     /// Default values for requests and groups
     pub defaults: WorkbookDefaultParameters,
 
+    /// OpenAPI document (relative to the workbook) that responses are validated against
+    pub openapi: Option<String>,
+
     /// Indicates the Vault file's password protection status
     pub private_lock_status: ParameterLockStatus,
 
@@ -139,6 +152,7 @@ impl Workspace {
             proxies: IndexedEntities::<Proxy>::new(None, None, global_parameters.proxies),
             data: IndexedEntities::default(),
             defaults: WorkbookDefaultParameters::default(),
+            openapi: None,
             private_lock_status: ParameterLockStatus::UnlockedNoPassword,
             vault_lock_status: ParameterLockStatus::UnlockedNoPassword,
             private_password: None,
@@ -412,6 +426,7 @@ impl Workspace {
             ),
             data: IndexedEntities::<DataSet>::new(workbook.data),
             defaults: workbook.defaults.unwrap_or_default(),
+            openapi: workbook.openapi,
             private_lock_status,
             vault_lock_status,
             private_password,
@@ -601,10 +616,54 @@ impl Workspace {
             .for_each(|entity| entity.perform_validation());
 
         self.validate_selections();
+        self.validate_dependencies();
 
         self.defaults.perform_validation();
     }
 
+    /// Validate dependencies declared between sibling requests and groups,
+    /// flagging dependencies that are not siblings and dependency cycles
+    pub fn validate_dependencies(&mut self) {
+        let sibling_sets = std::iter::once(&self.requests.top_level_ids)
+            .chain(self.requests.child_ids.values())
+            .cloned()
+            .collect::<Vec<Vec<String>>>();
+
+        for sibling_ids in sibling_sets {
+            let siblings = sibling_ids
+                .iter()
+                .filter_map(|id| self.requests.entities.get(id))
+                .collect::<Vec<&RequestEntry>>();
+            let mut errors = vec![None; siblings.len()];
+            if let Some(dependencies) = SiblingDependencies::new(&siblings) {
+                for index in dependencies.find_cycle() {
+                    errors[index] = Some("Dependencies form a cycle".to_string());
+                }
+                for (index, name) in dependencies.unknown {
+                    errors[index] = Some(format!("\"{name}\" is not a sibling key or ID"));
+                }
+            }
+
+            let ids = siblings
+                .iter()
+                .map(|sibling| sibling.get_id().to_string())
+                .collect::<Vec<String>>();
+            for (id, error) in ids.iter().zip(errors) {
+                let Some(entity) = self.requests.entities.get_mut(id) else {
+                    continue;
+                };
+                let mut validation_errors = entity.get_validation_errors().clone();
+                match error {
+                    Some(error) => {
+                        add_validation_error(&mut validation_errors, "dependsOn", &error)
+                    }
+                    None => remove_validation_error(&mut validation_errors, "dependsOn"),
+                }
+                entity.set_validation_errors(validation_errors);
+            }
+        }
+    }
+
     pub fn validate_selections(&mut self) -> Option<InvalidSelections> {
         let selectables = self.get_selectables();
         let invalid_request_ids = self
@@ -817,6 +876,7 @@ impl Workspace {
                         Some(stored_data)
                     },
                     defaults: stored_defaults,
+                    openapi: self.openapi.clone(),
                 };
 
                 {
@@ -864,6 +924,28 @@ impl Workspace {
         Ok(successes)
     }
 
+    /// Return the headers API key authorizations are sent in, so that their values can be
+    /// redacted from reports
+    pub fn get_api_key_headers(&self) -> Vec<String> {
+        let mut headers = self
+            .authorizations
+            .entities
+            .values()
+            .filter_map(|authorization| match authorization {
+                Authorization::Plain(plain) => match plain.as_ref() {
+                    AuthorizationPlain::ApiKey { header, .. } if !header.is_empty() => {
+                        Some(header.to_ascii_lowercase())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+        headers.sort();
+        headers.dedup();
+        headers
+    }
+
     /// Retrieve the parameters IDs and scenario variables for the specified request,
     /// merging in the variables and data to scenario (if specified)
     pub fn retrieve_request_parameters(
@@ -1088,6 +1170,9 @@ impl Workspace {
                 Some(Arc::new(variables))
             },
             data_set,
+            scenario_id: scenario.map_or(Selection::DEFAULT_SELECTION_ID.to_string(), |s| {
+                s.get_id().to_string()
+            }),
             authorization_id: authorization
                 .map_or(Selection::DEFAULT_SELECTION_ID.to_string(), |s| {
                     s.get_id().to_string()
@@ -1101,9 +1186,29 @@ impl Workspace {
             auth_certificate_id: auth_certificate_id
                 .unwrap_or(Selection::DEFAULT_SELECTION_ID.to_string()),
             auth_proxy_id: auth_proxy_id.unwrap_or(Selection::DEFAULT_SELECTION_ID.to_string()),
+            retry: self.retrieve_retry_policy(request).cloned(),
         })
     }
 
+    /// Retrieve the retry policy of the request, or of its nearest ancestor with one,
+    /// returning None if that policy is disabled
+    fn retrieve_retry_policy(&self, request: &RequestEntry) -> Option<&RetryPolicy> {
+        let mut current = request;
+        let mut encountered_ids = HashSet::<&str>::new();
+        loop {
+            if let Some(retry) = current.get_retry() {
+                return if retry.disabled { None } else { Some(retry) };
+            }
+            encountered_ids.insert(current.get_id());
+            current = self
+                .requests
+                .parent_ids
+                .get(current.get_id())
+                .and_then(|parent_id| self.requests.entities.get(parent_id))
+                .filter(|parent| !encountered_ids.contains(parent.get_id()))?;
+        }
+    }
+
     /// Append specified index, including children, to the results
     fn generate_json(
         exec_ctr: &usize,
@@ -1116,20 +1221,22 @@ impl Workspace {
             });
         };
 
-        if summary.error.is_some() {
+        if let Some(child_exec_ctrs) = &summary.child_exec_ctrs
+            && !child_exec_ctrs.is_empty()
+        {
+            // Deal with "parent" summaries, including the tests and error of group scripts
+            let mut children = Vec::<ExecutionReportJson>::new();
+            for child_exec_ctr in child_exec_ctrs {
+                Self::generate_json(child_exec_ctr, summaries, &mut children)?;
+            }
+            report.push(ExecutionReportJson::from_summary(
+                summary,
+                Some(children),
+                summary.test_results.clone(),
+            ));
+        } else if summary.error.is_some() {
             // Deal with summaries with errors
             report.push(ExecutionReportJson::from_summary(summary, None, None));
-        } else if let Some(child_exec_ctrs) = &summary.child_exec_ctrs {
-            let children = if child_exec_ctrs.is_empty() {
-                None
-            } else {
-                let mut children = Vec::<ExecutionReportJson>::new();
-                for child_exec_ctr in child_exec_ctrs {
-                    Self::generate_json(child_exec_ctr, summaries, &mut children)?;
-                }
-                Some(children)
-            };
-            report.push(ExecutionReportJson::from_summary(summary, children, None));
         } else {
             // Deal with executed behavior results
             report.push(ExecutionReportJson::from_summary(
@@ -1148,6 +1255,7 @@ impl Workspace {
         exec_ctr: &usize,
         summaries: &IndexMap<usize, ExecutionResultSummary>,
         parent_names: &[&str],
+        parent_latency: Option<&ApicizeLatencyStatistics>,
         report: &mut Vec<ExecutionReportCsv>,
         processed_exec_ctrs: &mut HashSet<usize>,
     ) -> Result<(), ApicizeError> {
@@ -1181,28 +1289,40 @@ impl Workspace {
 
         name_parts.push(name_part);
 
-        if summary.error.is_some() {
-            // Deal with summaries with errors
-            report.push(ExecutionReportCsv {
-                run_number,
-                name: name_parts.join(", "),
-                key: summary.key.clone(),
-                executed_at: summary.executed_at,
-                duration: summary.duration,
-                method: summary.method.clone(),
-                url: summary.url.clone(),
-                success: summary.success,
-                status: summary.status,
-                status_text: summary.status_text.clone(),
-                test_name: None,
-                test_tag: None,
-                test_success: None,
-                test_logs: None,
-                test_error: None,
-                error: summary.error.as_ref().map(|e| e.to_string()),
-                output: None,
-            });
-        } else if let Some(child_exec_ctrs) = &summary.child_exec_ctrs
+        // Runs, rows and children are reported with the latency statistics of their parent
+        let latency = summary.latency.as_ref().or(parent_latency).cloned();
+
+        let row = |test_result: Option<&ApicizeTestBehavior>| ExecutionReportCsv {
+            run_number,
+            name: name_parts.join(", "),
+            key: summary.key.clone(),
+            executed_at: summary.executed_at,
+            duration: summary.duration,
+            dns: summary.timings.as_ref().and_then(|t| t.dns),
+            connect: summary.timings.as_ref().and_then(|t| t.connect),
+            ttfb: summary.timings.as_ref().map(|t| t.ttfb),
+            download: summary.timings.as_ref().map(|t| t.download),
+            latency: latency.clone(),
+            method: summary.method.clone(),
+            url: summary.url.clone(),
+            success: summary.success,
+            status: summary.status,
+            status_text: summary.status_text.clone(),
+            error: summary.error.as_ref().map(|e| e.to_string()),
+            test_name: test_result.map(|t| t.name.clone()),
+            test_tag: test_result.and_then(|t| t.tag.clone()),
+            test_success: test_result.map(|t| t.success),
+            test_logs: test_result.and_then(|t| t.logs.as_ref().map(|l| l.join("; "))),
+            test_error: test_result.and_then(|t| t.error.clone()),
+            output: test_result.and_then(|_| summary.output.clone()),
+        };
+
+        let test_results = summary
+            .test_results
+            .as_ref()
+            .filter(|test_results| !test_results.is_empty());
+
+        if let Some(child_exec_ctrs) = &summary.child_exec_ctrs
             && !child_exec_ctrs.is_empty()
         {
             // Deal with "parent" scenarois
@@ -1212,57 +1332,268 @@ impl Workspace {
                     child_exec_ctr,
                     summaries,
                     &name_parts,
+                    latency.as_ref(),
                     report,
                     processed_exec_ctrs,
                 )?;
             }
-        } else if let Some(test_results) = &summary.test_results
-            && !test_results.is_empty()
-        {
-            // Deal with executed behavior results with tests
-            for test_result in test_results {
-                report.push(ExecutionReportCsv {
-                    run_number,
-                    name: name_parts.join(", "),
-                    key: summary.key.clone(),
-                    executed_at: summary.executed_at,
-                    duration: summary.duration,
-                    method: summary.method.clone(),
-                    url: summary.url.clone(),
-                    success: summary.success,
-                    status: summary.status,
-                    status_text: summary.status_text.clone(),
-                    error: summary.error.as_ref().map(|e| e.to_string()),
-                    test_name: Some(test_result.name.clone()),
-                    test_tag: test_result.tag.clone(),
-                    test_success: Some(test_result.success),
-                    test_logs: test_result.logs.as_ref().map(|l| l.join("; ")),
-                    test_error: test_result.error.clone(),
-                    output: summary.output.clone(),
-                });
+
+            // Group script (after each and teardown) tests and errors follow the children
+            if let Some(test_results) = test_results {
+                report.extend(test_results.iter().map(|t| row(Some(t))));
+            } else if summary.error.is_some() {
+                report.push(row(None));
             }
+        } else if summary.error.is_some() {
+            // Deal with summaries with errors
+            report.push(row(None));
+        } else if let Some(test_results) = test_results {
+            // Deal with executed behavior results with tests
+            report.extend(test_results.iter().map(|t| row(Some(t))));
         } else {
             // Deal with executed behavior results without tests
-            report.push(ExecutionReportCsv {
-                run_number,
-                name: name_parts.join(", "),
-                key: summary.key.clone(),
-                executed_at: summary.executed_at,
+            report.push(row(None));
+        }
+        Ok(())
+    }
+
+    /// Append JUnit test suites for specified index, including children.  Groups
+    /// are appended as suites, and request test behaviors as cases of the suite
+    /// for the request's parent group.  Rows and runs are appended to the case
+    /// names of the requests they iterate.
+    fn generate_junit(
+        exec_ctr: &usize,
+        summaries: &IndexMap<usize, ExecutionResultSummary>,
+        parent_names: &[&str],
+        base_name: Option<&str>,
+        qualifiers: &[String],
+        suite_index: Option<usize>,
+        report: &mut Vec<ExecutionReportJUnitSuite>,
+    ) -> Result<(), ApicizeError> {
+        let Some(summary) = summaries.get(exec_ctr) else {
+            return Err(ApicizeError::Error {
+                description: format!("Invalid execution counter ({exec_ctr})").to_string(),
+            });
+        };
+
+        let mut children = Vec::<&ExecutionResultSummary>::new();
+        if let Some(child_exec_ctrs) = &summary.child_exec_ctrs {
+            for child_exec_ctr in child_exec_ctrs {
+                let Some(child) = summaries.get(child_exec_ctr) else {
+                    return Err(ApicizeError::Error {
+                        description: format!("Invalid execution counter ({child_exec_ctr})")
+                            .to_string(),
+                    });
+                };
+                children.push(child);
+            }
+        }
+
+        // Row and run names include their ordinals, so use the name of the iterated entry
+        let name = base_name.unwrap_or(&summary.name);
+        let qualified_name = if qualifiers.is_empty() {
+            name.to_string()
+        } else {
+            format!("{} ({})", name, qualifiers.join(", "))
+        };
+
+        if children
+            .iter()
+            .any(|c| c.request_or_group_id == summary.request_or_group_id)
+        {
+            // Deal with rows and runs, which share the ID of the request or group they iterate
+            for child in children {
+                let mut child_qualifiers = Vec::from(qualifiers);
+                if let Some(row_number) = child.row_number
+                    && let Some(row_count) = child.row_count
+                {
+                    child_qualifiers.push(format!("Row {row_number} of {row_count}"));
+                } else if let Some(run_number) = child.run_number
+                    && let Some(run_count) = child.run_count
+                {
+                    child_qualifiers.push(format!("Run {run_number} of {run_count}"));
+                }
+                Self::generate_junit(
+                    &child.exec_ctr,
+                    summaries,
+                    parent_names,
+                    Some(name),
+                    &child_qualifiers,
+                    suite_index,
+                    report,
+                )?;
+            }
+        } else if summary.success != ExecutionResultSuccess::Skipped
+            && (!children.is_empty()
+                || (summary.method.is_none()
+                    && summary.error.is_none()
+                    && summary.test_results.is_none()))
+        {
+            // Deal with groups, which are appended as suites
+            let mut name_parts = Vec::from(parent_names);
+            name_parts.push(&qualified_name);
+
+            let index = report.len();
+            report.push(ExecutionReportJUnitSuite {
+                name: name_parts.join(" / "),
                 duration: summary.duration,
-                method: summary.method.clone(),
-                url: summary.url.clone(),
-                success: summary.success,
-                status: summary.status,
-                status_text: summary.status_text.clone(),
-                error: summary.error.as_ref().map(|e| e.to_string()),
-                test_name: None,
-                test_tag: None,
-                test_success: None,
-                test_logs: None,
-                test_error: None,
-                output: None,
+                nested: suite_index.is_some(),
+                logs: summary.logs.clone(),
+                test_cases: vec![],
             });
+
+            for child in children {
+                Self::generate_junit(
+                    &child.exec_ctr,
+                    summaries,
+                    &name_parts,
+                    None,
+                    &[],
+                    Some(index),
+                    report,
+                )?;
+            }
+
+            // Group script (after each and teardown) tests and errors follow the children
+            let class_name = report[index].name.clone();
+            let test_cases = &mut report[index].test_cases;
+            for test_result in summary.test_results.iter().flatten() {
+                test_cases.push(ExecutionReportJUnitCase {
+                    name: test_result.name.clone(),
+                    class_name: class_name.clone(),
+                    duration: 0,
+                    failure: if test_result.success {
+                        None
+                    } else {
+                        Some(
+                            test_result
+                                .error
+                                .clone()
+                                .unwrap_or_else(|| "Test failed".to_string()),
+                        )
+                    },
+                    error: None,
+                    skip_reason: None,
+                    logs: test_result.logs.clone(),
+                });
+            }
+            if summary.error.is_some() {
+                test_cases.push(ExecutionReportJUnitCase {
+                    name: qualified_name.clone(),
+                    class_name,
+                    duration: 0,
+                    failure: None,
+                    error: summary.error.clone(),
+                    skip_reason: None,
+                    logs: None,
+                });
+            }
+        } else {
+            // Deal with executed requests, appending a suite if not in a group
+            // (rows and runs of the same request share that suite)
+            let mut name_parts = Vec::from(parent_names);
+            name_parts.push(name);
+            let class_name = name_parts.join(" / ");
+
+            let index = match suite_index {
+                Some(index) => index,
+                None => match report.last_mut() {
+                    Some(suite) if !qualifiers.is_empty() && suite.name == class_name => {
+                        suite.duration += summary.duration;
+                        report.len() - 1
+                    }
+                    _ => {
+                        report.push(ExecutionReportJUnitSuite {
+                            name: class_name.clone(),
+                            duration: summary.duration,
+                            nested: false,
+                            logs: None,
+                            test_cases: vec![],
+                        });
+                        report.len() - 1
+                    }
+                },
+            };
+
+            let case_name = |case: &str| {
+                if qualifiers.is_empty() {
+                    case.to_string()
+                } else {
+                    format!("{} ({})", case, qualifiers.join(", "))
+                }
+            };
+
+            let test_cases = &mut report[index].test_cases;
+            if summary.success == ExecutionResultSuccess::Skipped {
+                // Deal with requests and groups skipped by a run policy or condition
+                test_cases.push(ExecutionReportJUnitCase {
+                    name: case_name(name),
+                    class_name,
+                    duration: summary.duration,
+                    failure: None,
+                    error: None,
+                    skip_reason: Some(summary.skip_reason.clone().unwrap_or_default()),
+                    logs: None,
+                });
+            } else if summary.error.is_some() {
+                // Deal with summaries with errors
+                test_cases.push(ExecutionReportJUnitCase {
+                    name: case_name(name),
+                    class_name,
+                    duration: summary.duration,
+                    failure: None,
+                    error: summary.error.clone(),
+                    skip_reason: None,
+                    logs: summary.logs.clone(),
+                });
+            } else if let Some(test_results) = &summary.test_results
+                && !test_results.is_empty()
+            {
+                // Deal with executed behavior results with tests
+                for test_result in test_results {
+                    test_cases.push(ExecutionReportJUnitCase {
+                        name: case_name(&test_result.name),
+                        class_name: class_name.clone(),
+                        duration: summary.duration,
+                        failure: if test_result.success {
+                            None
+                        } else {
+                            Some(
+                                test_result
+                                    .error
+                                    .clone()
+                                    .unwrap_or_else(|| "Test failed".to_string()),
+                            )
+                        },
+                        error: None,
+                        skip_reason: None,
+                        logs: test_result.logs.clone(),
+                    });
+                }
+            } else {
+                // Deal with executed behavior results without tests
+                test_cases.push(ExecutionReportJUnitCase {
+                    name: case_name(name),
+                    class_name,
+                    duration: summary.duration,
+                    failure: if summary.success == ExecutionResultSuccess::Success {
+                        None
+                    } else {
+                        Some(format!(
+                            "Request failed{}",
+                            summary.status.map_or(String::default(), |s| format!(
+                                " with status {s} {}",
+                                summary.status_text.as_deref().unwrap_or_default()
+                            ))
+                        ))
+                    },
+                    error: None,
+                    skip_reason: None,
+                    logs: summary.logs.clone(),
+                });
+            }
         }
+
         Ok(())
     }
 
@@ -1271,6 +1602,20 @@ impl Workspace {
         exec_ctr: &usize,
         summaries: &IndexMap<usize, ExecutionResultSummary>,
         format: ExecutionReportFormat,
+    ) -> Result<String, ApicizeError> {
+        Self::generate_detailed_report(exec_ctr, summaries, None, &[], format)
+    }
+
+    /// Generate a report from summarized execution results, including execution
+    /// details (request and response information) in formats which support them.
+    /// Authorization and cookie header values are redacted from details, along with
+    /// those of any additional headers specified (see `get_api_key_headers`)
+    pub fn generate_detailed_report(
+        exec_ctr: &usize,
+        summaries: &IndexMap<usize, ExecutionResultSummary>,
+        details: Option<&IndexMap<usize, ExecutionResultDetail>>,
+        redacted_headers: &[String],
+        format: ExecutionReportFormat,
     ) -> Result<String, ApicizeError> {
         match format {
             ExecutionReportFormat::JSON => {
@@ -1289,11 +1634,24 @@ impl Workspace {
                     exec_ctr,
                     summaries,
                     &[],
+                    None,
                     &mut data,
                     &mut HashSet::<usize>::new(),
                 )?;
                 Self::generate_csv_text(data, false)
             }
+            ExecutionReportFormat::JUNIT => {
+                let mut data = Vec::<ExecutionReportJUnitSuite>::new();
+                Self::generate_junit(exec_ctr, summaries, &[], None, &[], None, &mut data)?;
+                Ok(Self::generate_junit_text(data))
+            }
+            ExecutionReportFormat::HTML => generate_html_report(&[ExecutionReportHtmlRun {
+                run_number: None,
+                exec_ctrs: &[*exec_ctr],
+                summaries,
+                details,
+                redacted_headers,
+            }]),
         }
     }
 
@@ -1301,6 +1659,18 @@ impl Workspace {
     pub fn generate_multirun_report(
         all_run_summaries: &IndexMap<usize, (Vec<usize>, IndexMap<usize, ExecutionResultSummary>)>,
         format: &ExecutionReportFormat,
+    ) -> Result<String, ApicizeError> {
+        Self::generate_detailed_multirun_report(all_run_summaries, None, &[], format)
+    }
+
+    /// Generate a report from summarized execution results, including execution
+    /// details (indexed by run number) in formats which support them, redacting
+    /// header values as described for `generate_detailed_report`
+    pub fn generate_detailed_multirun_report(
+        all_run_summaries: &IndexMap<usize, (Vec<usize>, IndexMap<usize, ExecutionResultSummary>)>,
+        all_run_details: Option<&IndexMap<usize, IndexMap<usize, ExecutionResultDetail>>>,
+        redacted_headers: &[String],
+        format: &ExecutionReportFormat,
     ) -> Result<String, ApicizeError> {
         match format {
             ExecutionReportFormat::JSON => {
@@ -1334,6 +1704,7 @@ impl Workspace {
                             exec_ctr,
                             run_summaries,
                             &[],
+                            None,
                             &mut run_data,
                             &mut processed_exec_ctrs,
                         )?;
@@ -1342,9 +1713,153 @@ impl Workspace {
 
                 Self::generate_csv_text(run_data, all_run_summaries.len() > 1)
             }
+            ExecutionReportFormat::JUNIT => {
+                let multi_run = all_run_summaries.len() > 1;
+                let mut data = Vec::<ExecutionReportJUnitSuite>::new();
+
+                for (run_number, (exec_ctrs, run_summaries)) in all_run_summaries {
+                    let run_name = format!("Run {run_number}");
+                    let parent_names = if multi_run {
+                        vec![run_name.as_str()]
+                    } else {
+                        vec![]
+                    };
+                    for exec_ctr in exec_ctrs {
+                        Self::generate_junit(
+                            exec_ctr,
+                            run_summaries,
+                            &parent_names,
+                            None,
+                            &[],
+                            None,
+                            &mut data,
+                        )?;
+                    }
+                }
+
+                Ok(Self::generate_junit_text(data))
+            }
+            ExecutionReportFormat::HTML => {
+                let multi_run = all_run_summaries.len() > 1;
+                let runs = all_run_summaries
+                    .iter()
+                    .map(
+                        |(run_number, (exec_ctrs, run_summaries))| ExecutionReportHtmlRun {
+                            run_number: if multi_run { Some(*run_number) } else { None },
+                            exec_ctrs,
+                            summaries: run_summaries,
+                            details: all_run_details.and_then(|d| d.get(run_number)),
+                            redacted_headers,
+                        },
+                    )
+                    .collect::<Vec<ExecutionReportHtmlRun>>();
+                generate_html_report(&runs)
+            }
         }
     }
 
+    fn generate_junit_text(suites: Vec<ExecutionReportJUnitSuite>) -> String {
+        // Durations of nested groups are included in their ancestors', so only top-level
+        // suites (including those without test cases of their own) are totaled
+        let (tests, failures, errors, skipped, duration) = suites.iter().fold(
+            (0, 0, 0, 0, 0),
+            |(tests, failures, errors, skipped, duration), s| {
+                (
+                    tests + s.test_cases.len(),
+                    failures + s.failure_count(),
+                    errors + s.error_count(),
+                    skipped + s.skipped_count(),
+                    if s.nested {
+                        duration
+                    } else {
+                        duration + s.duration
+                    },
+                )
+            },
+        );
+
+        let suites = suites
+            .into_iter()
+            .filter(|s| !s.test_cases.is_empty())
+            .collect::<Vec<ExecutionReportJUnitSuite>>();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites tests=\"{tests}\" failures=\"{failures}\" errors=\"{errors}\"{} time=\"{}\">\n",
+            format_junit_skipped(skipped),
+            format_junit_time(duration)
+        ));
+
+        for suite in suites {
+            xml.push_str(&format!(
+                "    <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\"{} time=\"{}\">\n",
+                escape_xml(&suite.name),
+                suite.test_cases.len(),
+                suite.failure_count(),
+                suite.error_count(),
+                format_junit_skipped(suite.skipped_count()),
+                format_junit_time(suite.duration)
+            ));
+
+            for case in suite.test_cases {
+                xml.push_str(&format!(
+                    "        <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+                    escape_xml(&case.name),
+                    escape_xml(&case.class_name),
+                    format_junit_time(case.duration)
+                ));
+
+                if case.failure.is_none()
+                    && case.error.is_none()
+                    && case.skip_reason.is_none()
+                    && case.logs.is_none()
+                {
+                    xml.push_str("/>\n");
+                    continue;
+                }
+
+                xml.push_str(">\n");
+                if let Some(skip_reason) = &case.skip_reason {
+                    xml.push_str(&format!(
+                        "            <skipped message=\"{}\"/>\n",
+                        escape_xml(skip_reason)
+                    ));
+                }
+                if let Some(failure) = &case.failure {
+                    let failure = escape_xml(failure);
+                    xml.push_str(&format!(
+                        "            <failure message=\"{failure}\">{failure}</failure>\n"
+                    ));
+                }
+                if let Some(error) = &case.error {
+                    let description = escape_xml(&error.to_string());
+                    xml.push_str(&format!(
+                        "            <error message=\"{description}\" type=\"{}\">{description}</error>\n",
+                        escape_xml(error.get_label())
+                    ));
+                }
+                if let Some(logs) = &case.logs {
+                    xml.push_str(&format!(
+                        "            <system-out>{}</system-out>\n",
+                        escape_xml(&logs.join("\n"))
+                    ));
+                }
+                xml.push_str("        </testcase>\n");
+            }
+
+            if let Some(logs) = &suite.logs {
+                xml.push_str(&format!(
+                    "        <system-out>{}</system-out>\n",
+                    escape_xml(&logs.join("\n"))
+                ));
+            }
+            xml.push_str("    </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
     fn generate_csv_text(
         run_data: Vec<ExecutionReportCsv>,
         multi_run: bool,
@@ -1384,7 +1899,22 @@ impl Workspace {
             fields.push(output_field.as_str());
         }
 
-        fields.extend_from_slice(&["Error", "Test Error"]);
+        fields.extend_from_slice(&[
+            "Error",
+            "Test Error",
+            "DNS",
+            "Connect",
+            "TTFB",
+            "Download",
+            "Latency Min",
+            "Latency Max",
+            "Latency Mean",
+            "Latency Std Dev",
+            "Latency P50",
+            "Latency P90",
+            "Latency P95",
+            "Latency P99",
+        ]);
 
         let mut writer = WriterBuilder::new().from_writer(Vec::new());
 
@@ -1430,6 +1960,8 @@ impl Workspace {
                     d.error.unwrap_or_default(),
                     d.test_error.unwrap_or_default(),
                 ]);
+                record.extend(timing_columns([d.dns, d.connect, d.ttfb, d.download]));
+                record.extend(latency_columns(&d.latency));
 
                 writer.serialize(&record)?;
                 record.clear();
@@ -1472,6 +2004,8 @@ impl Workspace {
                     d.error.unwrap_or_default(),
                     d.test_error.unwrap_or_default(),
                 ]);
+                record.extend(timing_columns([d.dns, d.connect, d.ttfb, d.download]));
+                record.extend(latency_columns(&d.latency));
 
                 writer.serialize(&record)?;
                 record.clear();
@@ -1482,17 +2016,55 @@ impl Workspace {
     }
 }
 
+/// Format DNS, connect, TTFB and download timings as CSV columns, empty if not recorded
+fn timing_columns(timings: [Option<u128>; 4]) -> [String; 4] {
+    timings.map(|t| t.map_or(String::default(), |t| t.to_string()))
+}
+
+/// Format latency statistics as CSV columns, empty if there are no statistics
+fn latency_columns(latency: &Option<ApicizeLatencyStatistics>) -> [String; 8] {
+    match latency {
+        Some(l) => [
+            l.min.to_string(),
+            l.max.to_string(),
+            format!("{:.1}", l.mean),
+            format!("{:.1}", l.std_dev),
+            l.p50.to_string(),
+            l.p90.to_string(),
+            l.p95.to_string(),
+            l.p99.to_string(),
+        ],
+        None => Default::default(),
+    }
+}
+
+/// Format a duration in milliseconds as JUnit seconds
+fn format_junit_time(duration: u128) -> String {
+    format!("{:.3}", duration as f64 / 1000.0)
+}
+
+/// Format the skipped attribute of a JUnit suite, omitted if nothing was skipped
+fn format_junit_skipped(skipped: usize) -> String {
+    if skipped == 0 {
+        String::default()
+    } else {
+        format!(" skipped=\"{skipped}\"")
+    }
+}
+
 /// Parameters to use when executing a request/group,
 /// these should not change during execution
 #[derive(Clone, Default)]
 pub struct RequestExecutionParameters {
     pub data_set: Arc<Option<RequestDataSet>>,
     pub variables: Option<Arc<Map<String, Value>>>,
+    pub scenario_id: String,
     pub authorization_id: String,
     pub certificate_id: String,
     pub proxy_id: String,
     pub auth_certificate_id: String,
     pub auth_proxy_id: String,
+    pub retry: Option<RetryPolicy>,
 }
 
 /// Thse values may change during the execution of a request/group
@@ -1501,6 +2073,85 @@ pub struct RequestExecutionState {
     pub row: Option<Arc<RequestDataRow>>,
     pub row_number: Option<usize>,
     pub output_variables: Option<Arc<RequestDataRow>>,
+    pub cookie_jar: Option<Arc<CookieJar>>,
+    /// Latency statistics of requests with multiple runs or rows completed during the run,
+    /// by request ID (and key, if set)
+    pub latency: Arc<Mutex<RequestDataRow>>,
+    /// After-each script of the group whose children are executing, if any
+    pub after_each: Option<Arc<AfterEachScript>>,
+    /// How failures and errors affect the remaining requests of the group or run executing
+    pub run_policy: RunPolicy,
+    /// Flags of ancestor groups stopping on failure, set when a descendant fails
+    pub stop_flags: Vec<Arc<AtomicBool>>,
+    /// Flag shared by siblings which are skipped after one of them fails, if applicable
+    pub sibling_stop: Option<Arc<AtomicBool>>,
+}
+
+impl RequestExecutionState {
+    /// State of a child executing with the specified output variables
+    pub fn with_output(&self, output_variables: Option<Arc<RequestDataRow>>) -> Self {
+        RequestExecutionState {
+            output_variables,
+            ..self.clone()
+        }
+    }
+
+    /// State of a child executing for a row of a data set (numbered from 1)
+    pub fn with_row(&self, row: &RequestDataRow, row_number: usize) -> Self {
+        RequestExecutionState {
+            row: Some(Arc::new(row.clone())),
+            row_number: Some(row_number),
+            ..self.clone()
+        }
+    }
+
+    /// State of the children of a group, executing with the group's after-each script (if any)
+    pub fn with_after_each(&self, after_each: Option<Arc<AfterEachScript>>) -> Self {
+        RequestExecutionState {
+            after_each,
+            ..self.clone()
+        }
+    }
+
+    /// Apply the run policy of a group (or run) to the state its children execute with
+    pub fn with_run_policy(self, run_policy: RunPolicy) -> Self {
+        let mut stop_flags = self.stop_flags;
+        let mut sibling_stop = None;
+        match run_policy {
+            RunPolicy::StopGroupOnFailure => stop_flags.push(Arc::new(AtomicBool::new(false))),
+            RunPolicy::SkipRemainingSiblings => {
+                sibling_stop = Some(Arc::new(AtomicBool::new(false)))
+            }
+            RunPolicy::Continue | RunPolicy::StopRunOnError => {}
+        }
+        RequestExecutionState {
+            run_policy,
+            stop_flags,
+            sibling_stop,
+            ..self
+        }
+    }
+
+    /// Returns true if a failure has stopped an ancestor group or the remaining siblings
+    pub fn is_stopped(&self) -> bool {
+        self.stop_flags
+            .iter()
+            .chain(self.sibling_stop.iter())
+            .any(|stopped| stopped.load(Ordering::Relaxed))
+    }
+
+    /// Record a failure, stopping ancestor groups and siblings whose policies require it
+    pub fn stop(&self) {
+        for stopped in self.stop_flags.iter().chain(self.sibling_stop.iter()) {
+            stopped.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// A group's after-each script and the results of executing it after each child
+pub struct AfterEachScript {
+    pub script: String,
+    pub results: Mutex<Vec<ApicizeGroupScriptResult>>,
 }
 
 pub type RequestDataRow = Map<String, Value>;
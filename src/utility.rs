@@ -76,6 +76,28 @@ pub fn extract_csv(
     }
 }
 
+/// Return the contents of a data file, ensuring it resides within the allowed path
+pub fn extract_file_data(
+    file_name: &str,
+    allowed_path: &Option<PathBuf>,
+) -> Result<Vec<u8>, ApicizeError> {
+    let full_file_name = get_existing_absolute_file_name(file_name, allowed_path)?;
+    if let Some(parent) = allowed_path {
+        let contained = match (full_file_name.canonicalize(), parent.canonicalize()) {
+            (Ok(file), Ok(parent)) => file.starts_with(parent),
+            _ => false,
+        };
+        if !contained {
+            return Err(ApicizeError::FileAccess {
+                description: "File is not located in the workbook's data directory".to_string(),
+                file_name: Some(file_name.to_string()),
+            });
+        }
+    }
+    std::fs::read(&full_file_name)
+        .map_err(|err| ApicizeError::from_io(err, Some(file_name.to_string())))
+}
+
 /// Return the absolute file name, ensuring it exists and that it is form the same directory as our workbook
 pub fn get_existing_absolute_file_name(
     file_name: &str,
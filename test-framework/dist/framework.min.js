@@ -1 +1 @@
-(()=>{var e={5237:(e,t,r)=>{process={env:{}};const n=r(8324),o=r(537).format,i=r(274);let a=0;function s(e){if("object"==typeof e)return i.JSONPath({...e,json:this});if("string"==typeof e)return i.JSONPath({path:e,json:this});throw new Error("Argument for jp must be either a JSON path (string) or named parameters")}request={},response={},variables={},scenario={},data={},$={},outputVars={},assert=n.assert,expect=n.expect,should=n.should,jsonpath=i.JSONPath,Object.prototype.jp=s,Array.prototype.jp=s,String.prototype.jp=s,Number.prototype.jp=s,console={log:(e,...t)=>l.appendLog("log",e,...t),info:(e,...t)=>l.appendLog("info",e,...t),warn:(e,...t)=>l.appendLog("warn",e,...t),error:(e,...t)=>l.appendLog("error",e,...t),trace:(e,...t)=>l.appendLog("trace",e,...t),debug:(e,...t)=>l.appendLog("debug",e,...t)},BodyType={JSON:"JSON",XML:"XML",Text:"Text",Form:"Form",Binary:"Binary"};class c{constructor(e){this.type="Scenario",this.name=e,this.tag=void 0,this.success=!0,this.children=[],this.testCount=0,this.testFailCount=0}}class u{constructor(e){this.type="Behavior",this.name=e,this.tag=void 0,this.success=!0,this.testCount=0,this.testFailCount=0}succeed(){this.success=!0,this.testCount=1}fail(e){this.success=!1,this.testCount=1,this.testFailCount=1,this.error=e}}let l=new class{constructor(){this.results=[],this.logs=[],this.currentResult=null,this.inScenario=!1,this.inBehavior=!1}appendLog(e,t,...r){const n=function(e,t=null){if(0===e&&t)return t;const r=Math.floor(e/6e4);e-=6e4*r;const n=Math.floor(e/1e3);return e-=1e3*n,`${r.toString().padStart(2,"0")}:${n.toString().padStart(2,"0")}${.1.toString()[1]}${e.toString().padEnd(3,"0")}`}(Date.now()-a);let i;this.currentResult?(this.currentResult.logs||(this.currentResult.logs=[]),i=this.currentResult.logs):(this.logs||(this.logs=[]),i=this.logs),i.push(`${n} [${e}] ${o(t,...r)}`)}push(e){null==this.currentResult?this.results.push(e):(e.parent=this.currentResult,e.parent.children?e.parent.children.push(e):e.parent.children=[e]),this.currentResult=e}pop(){const e=this.currentResult;e&&(e.parent?(e.parent.tag&&(e.tag=e.parent.tag+(e.tag?"."+e.tag:"")),e.parent.success=e.success&&e.parent.success,e.parent.testCount+=e.testCount,e.parent.testFailCount+=e.testFailCount,this.currentResult=e.parent,e.parent=void 0):this.currentResult=null)}enterScenario(e){const t=new c(e);return this.push(t),t}exitScenario(){this.pop()}enterBehavior(e){if("Scenario"!==this.currentResult?.type)throw new Error('"it" must be called from within a "describe" block');const t=new u(e);return this.push(t),t}exitBehavior(){this.pop()}};describe=(e,t)=>{l.enterScenario(e);try{t()}finally{l.exitScenario()}},it=(e,t)=>{const r=l.enterBehavior(e);try{t(),r.succeed()}catch(e){r.fail(e.message)}finally{l.exitBehavior()}},tag=e=>{l.currentResult.tag=function(e){let t;switch(typeof e){case"boolean":case"undefined":case"array":case"function":throw new Error('Invalid parameter for "tag"');default:if(null!==e){const r=`${e}`;r.length>0&&(t=r)}}if(t){const e=t.matchAll(/\{\{(.*?)\}\}/g);for(const[r,n]of e){const e=$[n];if(!e)throw new Error(`"${n}" is not available for use in a tag`);t=t.replaceAll(r,$[n]?`${e}`:"")}}return t}(e)},output=(e,t)=>{switch(typeof t){case"function":throw new Error("Functions cannot be output");case"symbol":throw new Error("Symbols cannot be output");case"undefined":delete outputVars[e];break;default:outputVars[e]=t}},runTestSuite=(e,t,r,n,o,i,s)=>(request=e,response=t,scenario=r??{},data=n??{},outputVars=o??{},$={...outputVars,...scenario,...data},variables=$,a=i,s(),JSON.stringify({results:l.results,output:outputVars,logs:l.logs})),e.exports=runTestSuite},9296:e=>{function t(){var e=[].slice.call(arguments);function t(t,r){Object.keys(r).forEach((function(n){~e.indexOf(n)||(t[n]=r[n])}))}return function(){for(var e=[].slice.call(arguments),r=0,n={};r<e.length;r++)t(n,e[r]);return n}}function r(e,n,o){var i=t("name","message","stack","constructor","toJSON")(n||{});for(var a in this.message=e||"Unspecified AssertionError",this.showDiff=!1,i)this[a]=i[a];if(o=o||r,Error.captureStackTrace)Error.captureStackTrace(this,o);else try{throw new Error}catch(e){this.stack=e.stack}}e.exports=r,r.prototype=Object.create(Error.prototype),r.prototype.name="AssertionError",r.prototype.constructor=r,r.prototype.toJSON=function(e){var r=t("constructor","toJSON","stack")({name:this.name},this);return!1!==e&&this.stack&&(r.stack=this.stack),r}},8075:(e,t,r)=>{"use strict";var n=r(453),o=r(487),i=o(n("String.prototype.indexOf"));e.exports=function(e,t){var r=n(e,!!t);return"function"==typeof r&&i(e,".prototype.")>-1?o(r):r}},487:(e,t,r)=>{"use strict";var n=r(6743),o=r(453),i=r(6897),a=r(9675),s=o("%Function.prototype.apply%"),c=o("%Function.prototype.call%"),u=o("%Reflect.apply%",!0)||n.call(c,s),l=r(655),p=o("%Math.max%");e.exports=function(e){if("function"!=typeof e)throw new a("a function is required");var t=u(n,c,arguments);return i(t,1+p(0,e.length-(arguments.length-1)),!0)};var f=function(){return u(n,s,arguments)};l?l(e.exports,"apply",{value:f}):e.exports.apply=f},8324:(e,t,r)=>{e.exports=r(9731)},9731:(e,t,r)=>{var n=[];t.version="4.3.8",t.AssertionError=r(9296);var o=r(1118);t.use=function(e){return~n.indexOf(e)||(e(t,o),n.push(e)),t},t.util=o;var i=r(752);t.config=i;var a=r(3896);t.use(a);var s=r(9289);t.use(s);var c=r(6667);t.use(c);var u=r(705);t.use(u);var l=r(4740);t.use(l)},3896:(e,t,r)=>{var n=r(752);e.exports=function(e,t){var r=e.AssertionError,o=t.flag;function i(e,r,a,s){return o(this,"ssfi",a||i),o(this,"lockSsfi",s),o(this,"object",e),o(this,"message",r),o(this,"eql",n.deepEqual||t.eql),t.proxify(this)}e.Assertion=i,Object.defineProperty(i,"includeStack",{get:function(){return console.warn("Assertion.includeStack is deprecated, use chai.config.includeStack instead."),n.includeStack},set:function(e){console.warn("Assertion.includeStack is deprecated, use chai.config.includeStack instead."),n.includeStack=e}}),Object.defineProperty(i,"showDiff",{get:function(){return console.warn("Assertion.showDiff is deprecated, use chai.config.showDiff instead."),n.showDiff},set:function(e){console.warn("Assertion.showDiff is deprecated, use chai.config.showDiff instead."),n.showDiff=e}}),i.addProperty=function(e,r){t.addProperty(this.prototype,e,r)},i.addMethod=function(e,r){t.addMethod(this.prototype,e,r)},i.addChainableMethod=function(e,r,n){t.addChainableMethod(this.prototype,e,r,n)},i.overwriteProperty=function(e,r){t.overwriteProperty(this.prototype,e,r)},i.overwriteMethod=function(e,r){t.overwriteMethod(this.prototype,e,r)},i.overwriteChainableMethod=function(e,r,n){t.overwriteChainableMethod(this.prototype,e,r,n)},i.prototype.assert=function(e,i,a,s,c,u){var l=t.test(this,arguments);if(!1!==u&&(u=!0),void 0===s&&void 0===c&&(u=!1),!0!==n.showDiff&&(u=!1),!l){i=t.getMessage(this,arguments);var p={actual:t.getActual(this,arguments),expected:s,showDiff:u},f=t.getOperator(this,arguments);throw f&&(p.operator=f),new r(i,p,n.includeStack?this.assert:o(this,"ssfi"))}},Object.defineProperty(i.prototype,"_obj",{get:function(){return o(this,"object")},set:function(e){o(this,"object",e)}})}},752:e=>{e.exports={includeStack:!1,showDiff:!0,truncateThreshold:40,useProxy:!0,proxyExcludedKeys:["then","catch","inspect","toJSON"],deepEqual:null}},9289:e=>{e.exports=function(e,t){var r=e.Assertion,n=e.AssertionError,o=t.flag;function i(e,r){r&&o(this,"message",r),e=e.toLowerCase();var n=o(this,"object"),i=~["a","e","i","o","u"].indexOf(e.charAt(0))?"an ":"a ";this.assert(e===t.type(n).toLowerCase(),"expected #{this} to be "+i+e,"expected #{this} not to be "+i+e)}function a(e,r){return t.isNaN(e)&&t.isNaN(r)||e===r}function s(){o(this,"contains",!0)}function c(e,i){i&&o(this,"message",i);var s=o(this,"object"),c=t.type(s).toLowerCase(),u=o(this,"message"),l=o(this,"negate"),p=o(this,"ssfi"),f=o(this,"deep"),h=f?"deep ":"",d=f?o(this,"eql"):a;u=u?u+": ":"";var y=!1;switch(c){case"string":y=-1!==s.indexOf(e);break;case"weakset":if(f)throw new n(u+"unable to use .deep.include with WeakSet",void 0,p);y=s.has(e);break;case"map":s.forEach((function(t){y=y||d(t,e)}));break;case"set":f?s.forEach((function(t){y=y||d(t,e)})):y=s.has(e);break;case"array":y=f?s.some((function(t){return d(t,e)})):-1!==s.indexOf(e);break;default:if(e!==Object(e))throw new n(u+"the given combination of arguments ("+c+" and "+t.type(e).toLowerCase()+") is invalid for this assertion. You can use an array, a map, an object, a set, a string, or a weakset instead of a "+t.type(e).toLowerCase(),void 0,p);var b=Object.keys(e),g=null,m=0;if(b.forEach((function(i){var a=new r(s);if(t.transferFlags(this,a,!0),o(a,"lockSsfi",!0),l&&1!==b.length)try{a.property(i,e[i])}catch(e){if(!t.checkError.compatibleConstructor(e,n))throw e;null===g&&(g=e),m++}else a.property(i,e[i])}),this),l&&b.length>1&&m===b.length)throw g;return}this.assert(y,"expected #{this} to "+h+"include "+t.inspect(e),"expected #{this} to not "+h+"include "+t.inspect(e))}function u(){var e=o(this,"object");this.assert(null!=e,"expected #{this} to exist","expected #{this} to not exist")}function l(){var e=o(this,"object"),r=t.type(e);this.assert("Arguments"===r,"expected #{this} to be arguments but got "+r,"expected #{this} to not be arguments")}function p(e,t){t&&o(this,"message",t);var r=o(this,"object");if(o(this,"deep")){var n=o(this,"lockSsfi");o(this,"lockSsfi",!0),this.eql(e),o(this,"lockSsfi",n)}else this.assert(e===r,"expected #{this} to equal #{exp}","expected #{this} to not equal #{exp}",e,this._obj,!0)}function f(e,t){t&&o(this,"message",t);var r=o(this,"eql");this.assert(r(e,o(this,"object")),"expected #{this} to deeply equal #{exp}","expected #{this} to not deeply equal #{exp}",e,this._obj,!0)}function h(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to above must be a number";else a=l+"the argument to above must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y>e,"expected #{this} to have a "+b+" above #{exp} but got #{act}","expected #{this} to not have a "+b+" above #{exp}",e,y)}else this.assert(s>e,"expected #{this} to be above #{exp}","expected #{this} to be at most #{exp}",e)}function d(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to least must be a number";else a=l+"the argument to least must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y>=e,"expected #{this} to have a "+b+" at least #{exp} but got #{act}","expected #{this} to have a "+b+" below #{exp}",e,y)}else this.assert(s>=e,"expected #{this} to be at least #{exp}","expected #{this} to be below #{exp}",e)}function y(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to below must be a number";else a=l+"the argument to below must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y<e,"expected #{this} to have a "+b+" below #{exp} but got #{act}","expected #{this} to not have a "+b+" below #{exp}",e,y)}else this.assert(s<e,"expected #{this} to be below #{exp}","expected #{this} to be at least #{exp}",e)}function b(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to most must be a number";else a=l+"the argument to most must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y<=e,"expected #{this} to have a "+b+" at most #{exp} but got #{act}","expected #{this} to have a "+b+" above #{exp}",e,y)}else this.assert(s<=e,"expected #{this} to be at most #{exp}","expected #{this} to be above #{exp}",e)}function g(e,r){r&&o(this,"message",r);var i=o(this,"object"),a=o(this,"ssfi"),s=o(this,"message");try{var c=i instanceof e}catch(r){if(r instanceof TypeError)throw new n((s=s?s+": ":"")+"The instanceof assertion needs a constructor but "+t.type(e)+" was given.",void 0,a);throw r}var u=t.getName(e);null===u&&(u="an unnamed constructor"),this.assert(c,"expected #{this} to be an instance of "+u,"expected #{this} to not be an instance of "+u)}function m(e,r,i){i&&o(this,"message",i);var a=o(this,"nested"),s=o(this,"own"),c=o(this,"message"),u=o(this,"object"),l=o(this,"ssfi"),p=typeof e;if(c=c?c+": ":"",a){if("string"!==p)throw new n(c+"the argument to property must be a string when using nested syntax",void 0,l)}else if("string"!==p&&"number"!==p&&"symbol"!==p)throw new n(c+"the argument to property must be a string, number, or symbol",void 0,l);if(a&&s)throw new n(c+'The "nested" and "own" flags cannot be combined.',void 0,l);if(null==u)throw new n(c+"Target cannot be null or undefined.",void 0,l);var f,h=o(this,"deep"),d=o(this,"negate"),y=a?t.getPathInfo(u,e):null,b=a?y.value:u[e],g=h?o(this,"eql"):(e,t)=>e===t,m="";h&&(m+="deep "),s&&(m+="own "),a&&(m+="nested "),m+="property ",f=s?Object.prototype.hasOwnProperty.call(u,e):a?y.exists:t.hasProperty(u,e),d&&1!==arguments.length||this.assert(f,"expected #{this} to have "+m+t.inspect(e),"expected #{this} to not have "+m+t.inspect(e)),arguments.length>1&&this.assert(f&&g(r,b),"expected #{this} to have "+m+t.inspect(e)+" of #{exp}, but got #{act}","expected #{this} to not have "+m+t.inspect(e)+" of #{act}",r,b),o(this,"object",b)}function v(e,t,r){o(this,"own",!0),m.apply(this,arguments)}function w(e,r,n){"string"==typeof r&&(n=r,r=null),n&&o(this,"message",n);var i=o(this,"object"),a=Object.getOwnPropertyDescriptor(Object(i),e),s=o(this,"eql");a&&r?this.assert(s(r,a),"expected the own property descriptor for "+t.inspect(e)+" on #{this} to match "+t.inspect(r)+", got "+t.inspect(a),"expected the own property descriptor for "+t.inspect(e)+" on #{this} to not match "+t.inspect(r),r,a,!0):this.assert(a,"expected #{this} to have an own property descriptor for "+t.inspect(e),"expected #{this} to not have an own property descriptor for "+t.inspect(e)),o(this,"object",a)}function x(){o(this,"doLength",!0)}function O(e,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=t.type(a).toLowerCase(),c=o(this,"message"),u=o(this,"ssfi"),l="length";switch(s){case"map":case"set":l="size",i=a.size;break;default:new r(a,c,u,!0).to.have.property("length"),i=a.length}this.assert(i==e,"expected #{this} to have a "+l+" of #{exp} but got #{act}","expected #{this} to not have a "+l+" of #{act}",e,i)}function E(e,t){t&&o(this,"message",t);var r=o(this,"object");this.assert(e.exec(r),"expected #{this} to match "+e,"expected #{this} not to match "+e)}function A(e){var r,i,a=o(this,"object"),s=t.type(a),c=t.type(e),u=o(this,"ssfi"),l=o(this,"deep"),p="",f=!0,h=o(this,"message"),d=(h=h?h+": ":"")+"when testing keys against an object or an array you must give a single Array|Object|String argument or multiple String arguments";if("Map"===s||"Set"===s)p=l?"deeply ":"",i=[],a.forEach((function(e,t){i.push(t)})),"Array"!==c&&(e=Array.prototype.slice.call(arguments));else{switch(i=t.getOwnEnumerableProperties(a),c){case"Array":if(arguments.length>1)throw new n(d,void 0,u);break;case"Object":if(arguments.length>1)throw new n(d,void 0,u);e=Object.keys(e);break;default:e=Array.prototype.slice.call(arguments)}e=e.map((function(e){return"symbol"==typeof e?e:String(e)}))}if(!e.length)throw new n(h+"keys required",void 0,u);var y=e.length,b=o(this,"any"),g=o(this,"all"),m=e,v=l?o(this,"eql"):(e,t)=>e===t;if(b||g||(g=!0),b&&(f=m.some((function(e){return i.some((function(t){return v(e,t)}))}))),g&&(f=m.every((function(e){return i.some((function(t){return v(e,t)}))})),o(this,"contains")||(f=f&&e.length==i.length)),y>1){var w=(e=e.map((function(e){return t.inspect(e)}))).pop();g&&(r=e.join(", ")+", and "+w),b&&(r=e.join(", ")+", or "+w)}else r=t.inspect(e[0]);r=(y>1?"keys ":"key ")+r,r=(o(this,"contains")?"contain ":"have ")+r,this.assert(f,"expected #{this} to "+p+r,"expected #{this} to not "+p+r,m.slice(0).sort(t.compareByInspect),i.sort(t.compareByInspect),!0)}function S(e,n,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"ssfi"),u=o(this,"message"),l=o(this,"negate")||!1;new r(s,u,c,!0).is.a("function"),(e instanceof RegExp||"string"==typeof e)&&(n=e,e=null);try{s()}catch(e){a=e}var p=void 0===e&&void 0===n,f=Boolean(e&&n),h=!1,d=!1;if(p||!p&&!l){var y="an error";e instanceof Error?y="#{exp}":e&&(y=t.checkError.getConstructorName(e)),this.assert(a,"expected #{this} to throw "+y,"expected #{this} to not throw an error but #{act} was thrown",e&&e.toString(),a instanceof Error?a.toString():"string"==typeof a?a:a&&t.checkError.getConstructorName(a))}if(e&&a){if(e instanceof Error)t.checkError.compatibleInstance(a,e)===l&&(f&&l?h=!0:this.assert(l,"expected #{this} to throw #{exp} but #{act} was thrown","expected #{this} to not throw #{exp}"+(a&&!l?" but #{act} was thrown":""),e.toString(),a.toString()));t.checkError.compatibleConstructor(a,e)===l&&(f&&l?h=!0:this.assert(l,"expected #{this} to throw #{exp} but #{act} was thrown","expected #{this} to not throw #{exp}"+(a?" but #{act} was thrown":""),e instanceof Error?e.toString():e&&t.checkError.getConstructorName(e),a instanceof Error?a.toString():a&&t.checkError.getConstructorName(a)))}if(a&&null!=n){var b="including";n instanceof RegExp&&(b="matching"),t.checkError.compatibleMessage(a,n)===l&&(f&&l?d=!0:this.assert(l,"expected #{this} to throw error "+b+" #{exp} but got #{act}","expected #{this} to throw error not "+b+" #{exp}",n,t.checkError.getMessage(a)))}h&&d&&this.assert(l,"expected #{this} to throw #{exp} but #{act} was thrown","expected #{this} to not throw #{exp}"+(a?" but #{act} was thrown":""),e instanceof Error?e.toString():e&&t.checkError.getConstructorName(e),a instanceof Error?a.toString():a&&t.checkError.getConstructorName(a)),o(this,"object",a)}function j(e,r){r&&o(this,"message",r);var n=o(this,"object"),i=o(this,"itself"),a="function"!=typeof n||i?n[e]:n.prototype[e];this.assert("function"==typeof a,"expected #{this} to respond to "+t.inspect(e),"expected #{this} to not respond to "+t.inspect(e))}function P(e,r){r&&o(this,"message",r);var n=e(o(this,"object"));this.assert(n,"expected #{this} to satisfy "+t.objDisplay(e),"expected #{this} to not satisfy"+t.objDisplay(e),!o(this,"negate"),n)}function _(e,t,i){i&&o(this,"message",i);var a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");if(new r(a,s,c,!0).is.a("number"),"number"!=typeof e||"number"!=typeof t)throw new n((s=s?s+": ":"")+"the arguments to closeTo or approximately must be numbers"+(void 0===t?", and a delta is required":""),void 0,c);this.assert(Math.abs(a-e)<=t,"expected #{this} to be close to "+e+" +/- "+t,"expected #{this} not to be close to "+e+" +/- "+t)}function k(e,t,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");new r(a,s,c,!0).is.a("function"),t?(new r(e,s,c,!0).to.have.property(t),i=e[t]):(new r(e,s,c,!0).is.a("function"),i=e()),a();var u=null==t?e():e[t],l=null==t?i:"."+t;o(this,"deltaMsgObj",l),o(this,"initialDeltaValue",i),o(this,"finalDeltaValue",u),o(this,"deltaBehavior","change"),o(this,"realDelta",u!==i),this.assert(i!==u,"expected "+l+" to change","expected "+l+" to not change")}function M(e,t,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");new r(a,s,c,!0).is.a("function"),t?(new r(e,s,c,!0).to.have.property(t),i=e[t]):(new r(e,s,c,!0).is.a("function"),i=e()),new r(i,s,c,!0).is.a("number"),a();var u=null==t?e():e[t],l=null==t?i:"."+t;o(this,"deltaMsgObj",l),o(this,"initialDeltaValue",i),o(this,"finalDeltaValue",u),o(this,"deltaBehavior","increase"),o(this,"realDelta",u-i),this.assert(u-i>0,"expected "+l+" to increase","expected "+l+" to not increase")}function N(e,t,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");new r(a,s,c,!0).is.a("function"),t?(new r(e,s,c,!0).to.have.property(t),i=e[t]):(new r(e,s,c,!0).is.a("function"),i=e()),new r(i,s,c,!0).is.a("number"),a();var u=null==t?e():e[t],l=null==t?i:"."+t;o(this,"deltaMsgObj",l),o(this,"initialDeltaValue",i),o(this,"finalDeltaValue",u),o(this,"deltaBehavior","decrease"),o(this,"realDelta",i-u),this.assert(u-i<0,"expected "+l+" to decrease","expected "+l+" to not decrease")}["to","be","been","is","and","has","have","with","that","which","at","of","same","but","does","still","also"].forEach((function(e){r.addProperty(e)})),r.addProperty("not",(function(){o(this,"negate",!0)})),r.addProperty("deep",(function(){o(this,"deep",!0)})),r.addProperty("nested",(function(){o(this,"nested",!0)})),r.addProperty("own",(function(){o(this,"own",!0)})),r.addProperty("ordered",(function(){o(this,"ordered",!0)})),r.addProperty("any",(function(){o(this,"any",!0),o(this,"all",!1)})),r.addProperty("all",(function(){o(this,"all",!0),o(this,"any",!1)})),r.addChainableMethod("an",i),r.addChainableMethod("a",i),r.addChainableMethod("include",c,s),r.addChainableMethod("contain",c,s),r.addChainableMethod("contains",c,s),r.addChainableMethod("includes",c,s),r.addProperty("ok",(function(){this.assert(o(this,"object"),"expected #{this} to be truthy","expected #{this} to be falsy")})),r.addProperty("true",(function(){this.assert(!0===o(this,"object"),"expected #{this} to be true","expected #{this} to be false",!o(this,"negate"))})),r.addProperty("false",(function(){this.assert(!1===o(this,"object"),"expected #{this} to be false","expected #{this} to be true",!!o(this,"negate"))})),r.addProperty("null",(function(){this.assert(null===o(this,"object"),"expected #{this} to be null","expected #{this} not to be null")})),r.addProperty("undefined",(function(){this.assert(void 0===o(this,"object"),"expected #{this} to be undefined","expected #{this} not to be undefined")})),r.addProperty("NaN",(function(){this.assert(t.isNaN(o(this,"object")),"expected #{this} to be NaN","expected #{this} not to be NaN")})),r.addProperty("exist",u),r.addProperty("exists",u),r.addProperty("empty",(function(){var e,r=o(this,"object"),i=o(this,"ssfi"),a=o(this,"message");switch(a=a?a+": ":"",t.type(r).toLowerCase()){case"array":case"string":e=r.length;break;case"map":case"set":e=r.size;break;case"weakmap":case"weakset":throw new n(a+".empty was passed a weak collection",void 0,i);case"function":var s=a+".empty was passed a function "+t.getName(r);throw new n(s.trim(),void 0,i);default:if(r!==Object(r))throw new n(a+".empty was passed non-string primitive "+t.inspect(r),void 0,i);e=Object.keys(r).length}this.assert(0===e,"expected #{this} to be empty","expected #{this} not to be empty")})),r.addProperty("arguments",l),r.addProperty("Arguments",l),r.addMethod("equal",p),r.addMethod("equals",p),r.addMethod("eq",p),r.addMethod("eql",f),r.addMethod("eqls",f),r.addMethod("above",h),r.addMethod("gt",h),r.addMethod("greaterThan",h),r.addMethod("least",d),r.addMethod("gte",d),r.addMethod("greaterThanOrEqual",d),r.addMethod("below",y),r.addMethod("lt",y),r.addMethod("lessThan",y),r.addMethod("most",b),r.addMethod("lte",b),r.addMethod("lessThanOrEqual",b),r.addMethod("within",(function(e,i,a){a&&o(this,"message",a);var s,c=o(this,"object"),u=o(this,"doLength"),l=o(this,"message"),p=l?l+": ":"",f=o(this,"ssfi"),h=t.type(c).toLowerCase(),d=t.type(e).toLowerCase(),y=t.type(i).toLowerCase(),b=!0,g="date"===d&&"date"===y?e.toISOString()+".."+i.toISOString():e+".."+i;if(u&&"map"!==h&&"set"!==h&&new r(c,l,f,!0).to.have.property("length"),u||"date"!==h||"date"===d&&"date"===y)if("number"===d&&"number"===y||!u&&"number"!==h)if(u||"date"===h||"number"===h)b=!1;else{s=p+"expected "+("string"===h?"'"+c+"'":c)+" to be a number or a date"}else s=p+"the arguments to within must be numbers";else s=p+"the arguments to within must be dates";if(b)throw new n(s,void 0,f);if(u){var m,v="length";"map"===h||"set"===h?(v="size",m=c.size):m=c.length,this.assert(m>=e&&m<=i,"expected #{this} to have a "+v+" within "+g,"expected #{this} to not have a "+v+" within "+g)}else this.assert(c>=e&&c<=i,"expected #{this} to be within "+g,"expected #{this} to not be within "+g)})),r.addMethod("instanceof",g),r.addMethod("instanceOf",g),r.addMethod("property",m),r.addMethod("ownProperty",v),r.addMethod("haveOwnProperty",v),r.addMethod("ownPropertyDescriptor",w),r.addMethod("haveOwnPropertyDescriptor",w),r.addChainableMethod("length",O,x),r.addChainableMethod("lengthOf",O,x),r.addMethod("match",E),r.addMethod("matches",E),r.addMethod("string",(function(e,n){n&&o(this,"message",n);var i=o(this,"object"),a=o(this,"message"),s=o(this,"ssfi");new r(i,a,s,!0).is.a("string"),this.assert(~i.indexOf(e),"expected #{this} to contain "+t.inspect(e),"expected #{this} to not contain "+t.inspect(e))})),r.addMethod("keys",A),r.addMethod("key",A),r.addMethod("throw",S),r.addMethod("throws",S),r.addMethod("Throw",S),r.addMethod("respondTo",j),r.addMethod("respondsTo",j),r.addProperty("itself",(function(){o(this,"itself",!0)})),r.addMethod("satisfy",P),r.addMethod("satisfies",P),r.addMethod("closeTo",_),r.addMethod("approximately",_),r.addMethod("members",(function(e,t){t&&o(this,"message",t);var n=o(this,"object"),i=o(this,"message"),a=o(this,"ssfi");new r(n,i,a,!0).to.be.an("array"),new r(e,i,a,!0).to.be.an("array");var s,c,u,l=o(this,"contains"),p=o(this,"ordered");l?(c="expected #{this} to be "+(s=p?"an ordered superset":"a superset")+" of #{exp}",u="expected #{this} to not be "+s+" of #{exp}"):(c="expected #{this} to have the same "+(s=p?"ordered members":"members")+" as #{exp}",u="expected #{this} to not have the same "+s+" as #{exp}");var f=o(this,"deep")?o(this,"eql"):void 0;this.assert(function(e,t,r,n,o){if(!n){if(e.length!==t.length)return!1;t=t.slice()}return e.every((function(e,i){if(o)return r?r(e,t[i]):e===t[i];if(!r){var a=t.indexOf(e);return-1!==a&&(n||t.splice(a,1),!0)}return t.some((function(o,i){return!!r(e,o)&&(n||t.splice(i,1),!0)}))}))}(e,n,f,l,p),c,u,e,n,!0)})),r.addMethod("oneOf",(function(e,t){t&&o(this,"message",t);var n=o(this,"object"),i=o(this,"message"),a=o(this,"ssfi"),s=o(this,"contains"),c=o(this,"deep"),u=o(this,"eql");new r(e,i,a,!0).to.be.an("array"),s?this.assert(e.some((function(e){return n.indexOf(e)>-1})),"expected #{this} to contain one of #{exp}","expected #{this} to not contain one of #{exp}",e,n):c?this.assert(e.some((function(e){return u(n,e)})),"expected #{this} to deeply equal one of #{exp}","expected #{this} to deeply equal one of #{exp}",e,n):this.assert(e.indexOf(n)>-1,"expected #{this} to be one of #{exp}","expected #{this} to not be one of #{exp}",e,n)})),r.addMethod("change",k),r.addMethod("changes",k),r.addMethod("increase",M),r.addMethod("increases",M),r.addMethod("decrease",N),r.addMethod("decreases",N),r.addMethod("by",(function(e,t){t&&o(this,"message",t);var r,n=o(this,"deltaMsgObj"),i=o(this,"initialDeltaValue"),a=o(this,"finalDeltaValue"),s=o(this,"deltaBehavior"),c=o(this,"realDelta");r="change"===s?Math.abs(a-i)===Math.abs(e):c===Math.abs(e),this.assert(r,"expected "+n+" to "+s+" by "+e,"expected "+n+" to not "+s+" by "+e)})),r.addProperty("extensible",(function(){var e=o(this,"object"),t=e===Object(e)&&Object.isExtensible(e);this.assert(t,"expected #{this} to be extensible","expected #{this} to not be extensible")})),r.addProperty("sealed",(function(){var e=o(this,"object"),t=e!==Object(e)||Object.isSealed(e);this.assert(t,"expected #{this} to be sealed","expected #{this} to not be sealed")})),r.addProperty("frozen",(function(){var e=o(this,"object"),t=e!==Object(e)||Object.isFrozen(e);this.assert(t,"expected #{this} to be frozen","expected #{this} to not be frozen")})),r.addProperty("finite",(function(e){var t=o(this,"object");this.assert("number"==typeof t&&isFinite(t),"expected #{this} to be a finite number","expected #{this} to not be a finite number")}))}},4740:e=>{e.exports=function(e,t){var r=e.Assertion,n=t.flag,o=e.assert=function(t,n){new r(null,null,e.assert,!0).assert(t,n,"[ negation message unavailable ]")};o.fail=function(t,r,n,i){throw arguments.length<2&&(n=t,t=void 0),n=n||"assert.fail()",new e.AssertionError(n,{actual:t,expected:r,operator:i},o.fail)},o.isOk=function(e,t){new r(e,t,o.isOk,!0).is.ok},o.isNotOk=function(e,t){new r(e,t,o.isNotOk,!0).is.not.ok},o.equal=function(e,t,i){var a=new r(e,i,o.equal,!0);a.assert(t==n(a,"object"),"expected #{this} to equal #{exp}","expected #{this} to not equal #{act}",t,e,!0)},o.notEqual=function(e,t,i){var a=new r(e,i,o.notEqual,!0);a.assert(t!=n(a,"object"),"expected #{this} to not equal #{exp}","expected #{this} to equal #{act}",t,e,!0)},o.strictEqual=function(e,t,n){new r(e,n,o.strictEqual,!0).to.equal(t)},o.notStrictEqual=function(e,t,n){new r(e,n,o.notStrictEqual,!0).to.not.equal(t)},o.deepEqual=o.deepStrictEqual=function(e,t,n){new r(e,n,o.deepEqual,!0).to.eql(t)},o.notDeepEqual=function(e,t,n){new r(e,n,o.notDeepEqual,!0).to.not.eql(t)},o.isAbove=function(e,t,n){new r(e,n,o.isAbove,!0).to.be.above(t)},o.isAtLeast=function(e,t,n){new r(e,n,o.isAtLeast,!0).to.be.least(t)},o.isBelow=function(e,t,n){new r(e,n,o.isBelow,!0).to.be.below(t)},o.isAtMost=function(e,t,n){new r(e,n,o.isAtMost,!0).to.be.most(t)},o.isTrue=function(e,t){new r(e,t,o.isTrue,!0).is.true},o.isNotTrue=function(e,t){new r(e,t,o.isNotTrue,!0).to.not.equal(!0)},o.isFalse=function(e,t){new r(e,t,o.isFalse,!0).is.false},o.isNotFalse=function(e,t){new r(e,t,o.isNotFalse,!0).to.not.equal(!1)},o.isNull=function(e,t){new r(e,t,o.isNull,!0).to.equal(null)},o.isNotNull=function(e,t){new r(e,t,o.isNotNull,!0).to.not.equal(null)},o.isNaN=function(e,t){new r(e,t,o.isNaN,!0).to.be.NaN},o.isNotNaN=function(e,t){new r(e,t,o.isNotNaN,!0).not.to.be.NaN},o.exists=function(e,t){new r(e,t,o.exists,!0).to.exist},o.notExists=function(e,t){new r(e,t,o.notExists,!0).to.not.exist},o.isUndefined=function(e,t){new r(e,t,o.isUndefined,!0).to.equal(void 0)},o.isDefined=function(e,t){new r(e,t,o.isDefined,!0).to.not.equal(void 0)},o.isFunction=function(e,t){new r(e,t,o.isFunction,!0).to.be.a("function")},o.isNotFunction=function(e,t){new r(e,t,o.isNotFunction,!0).to.not.be.a("function")},o.isObject=function(e,t){new r(e,t,o.isObject,!0).to.be.a("object")},o.isNotObject=function(e,t){new r(e,t,o.isNotObject,!0).to.not.be.a("object")},o.isArray=function(e,t){new r(e,t,o.isArray,!0).to.be.an("array")},o.isNotArray=function(e,t){new r(e,t,o.isNotArray,!0).to.not.be.an("array")},o.isString=function(e,t){new r(e,t,o.isString,!0).to.be.a("string")},o.isNotString=function(e,t){new r(e,t,o.isNotString,!0).to.not.be.a("string")},o.isNumber=function(e,t){new r(e,t,o.isNumber,!0).to.be.a("number")},o.isNotNumber=function(e,t){new r(e,t,o.isNotNumber,!0).to.not.be.a("number")},o.isFinite=function(e,t){new r(e,t,o.isFinite,!0).to.be.finite},o.isBoolean=function(e,t){new r(e,t,o.isBoolean,!0).to.be.a("boolean")},o.isNotBoolean=function(e,t){new r(e,t,o.isNotBoolean,!0).to.not.be.a("boolean")},o.typeOf=function(e,t,n){new r(e,n,o.typeOf,!0).to.be.a(t)},o.notTypeOf=function(e,t,n){new r(e,n,o.notTypeOf,!0).to.not.be.a(t)},o.instanceOf=function(e,t,n){new r(e,n,o.instanceOf,!0).to.be.instanceOf(t)},o.notInstanceOf=function(e,t,n){new r(e,n,o.notInstanceOf,!0).to.not.be.instanceOf(t)},o.include=function(e,t,n){new r(e,n,o.include,!0).include(t)},o.notInclude=function(e,t,n){new r(e,n,o.notInclude,!0).not.include(t)},o.deepInclude=function(e,t,n){new r(e,n,o.deepInclude,!0).deep.include(t)},o.notDeepInclude=function(e,t,n){new r(e,n,o.notDeepInclude,!0).not.deep.include(t)},o.nestedInclude=function(e,t,n){new r(e,n,o.nestedInclude,!0).nested.include(t)},o.notNestedInclude=function(e,t,n){new r(e,n,o.notNestedInclude,!0).not.nested.include(t)},o.deepNestedInclude=function(e,t,n){new r(e,n,o.deepNestedInclude,!0).deep.nested.include(t)},o.notDeepNestedInclude=function(e,t,n){new r(e,n,o.notDeepNestedInclude,!0).not.deep.nested.include(t)},o.ownInclude=function(e,t,n){new r(e,n,o.ownInclude,!0).own.include(t)},o.notOwnInclude=function(e,t,n){new r(e,n,o.notOwnInclude,!0).not.own.include(t)},o.deepOwnInclude=function(e,t,n){new r(e,n,o.deepOwnInclude,!0).deep.own.include(t)},o.notDeepOwnInclude=function(e,t,n){new r(e,n,o.notDeepOwnInclude,!0).not.deep.own.include(t)},o.match=function(e,t,n){new r(e,n,o.match,!0).to.match(t)},o.notMatch=function(e,t,n){new r(e,n,o.notMatch,!0).to.not.match(t)},o.property=function(e,t,n){new r(e,n,o.property,!0).to.have.property(t)},o.notProperty=function(e,t,n){new r(e,n,o.notProperty,!0).to.not.have.property(t)},o.propertyVal=function(e,t,n,i){new r(e,i,o.propertyVal,!0).to.have.property(t,n)},o.notPropertyVal=function(e,t,n,i){new r(e,i,o.notPropertyVal,!0).to.not.have.property(t,n)},o.deepPropertyVal=function(e,t,n,i){new r(e,i,o.deepPropertyVal,!0).to.have.deep.property(t,n)},o.notDeepPropertyVal=function(e,t,n,i){new r(e,i,o.notDeepPropertyVal,!0).to.not.have.deep.property(t,n)},o.ownProperty=function(e,t,n){new r(e,n,o.ownProperty,!0).to.have.own.property(t)},o.notOwnProperty=function(e,t,n){new r(e,n,o.notOwnProperty,!0).to.not.have.own.property(t)},o.ownPropertyVal=function(e,t,n,i){new r(e,i,o.ownPropertyVal,!0).to.have.own.property(t,n)},o.notOwnPropertyVal=function(e,t,n,i){new r(e,i,o.notOwnPropertyVal,!0).to.not.have.own.property(t,n)},o.deepOwnPropertyVal=function(e,t,n,i){new r(e,i,o.deepOwnPropertyVal,!0).to.have.deep.own.property(t,n)},o.notDeepOwnPropertyVal=function(e,t,n,i){new r(e,i,o.notDeepOwnPropertyVal,!0).to.not.have.deep.own.property(t,n)},o.nestedProperty=function(e,t,n){new r(e,n,o.nestedProperty,!0).to.have.nested.property(t)},o.notNestedProperty=function(e,t,n){new r(e,n,o.notNestedProperty,!0).to.not.have.nested.property(t)},o.nestedPropertyVal=function(e,t,n,i){new r(e,i,o.nestedPropertyVal,!0).to.have.nested.property(t,n)},o.notNestedPropertyVal=function(e,t,n,i){new r(e,i,o.notNestedPropertyVal,!0).to.not.have.nested.property(t,n)},o.deepNestedPropertyVal=function(e,t,n,i){new r(e,i,o.deepNestedPropertyVal,!0).to.have.deep.nested.property(t,n)},o.notDeepNestedPropertyVal=function(e,t,n,i){new r(e,i,o.notDeepNestedPropertyVal,!0).to.not.have.deep.nested.property(t,n)},o.lengthOf=function(e,t,n){new r(e,n,o.lengthOf,!0).to.have.lengthOf(t)},o.hasAnyKeys=function(e,t,n){new r(e,n,o.hasAnyKeys,!0).to.have.any.keys(t)},o.hasAllKeys=function(e,t,n){new r(e,n,o.hasAllKeys,!0).to.have.all.keys(t)},o.containsAllKeys=function(e,t,n){new r(e,n,o.containsAllKeys,!0).to.contain.all.keys(t)},o.doesNotHaveAnyKeys=function(e,t,n){new r(e,n,o.doesNotHaveAnyKeys,!0).to.not.have.any.keys(t)},o.doesNotHaveAllKeys=function(e,t,n){new r(e,n,o.doesNotHaveAllKeys,!0).to.not.have.all.keys(t)},o.hasAnyDeepKeys=function(e,t,n){new r(e,n,o.hasAnyDeepKeys,!0).to.have.any.deep.keys(t)},o.hasAllDeepKeys=function(e,t,n){new r(e,n,o.hasAllDeepKeys,!0).to.have.all.deep.keys(t)},o.containsAllDeepKeys=function(e,t,n){new r(e,n,o.containsAllDeepKeys,!0).to.contain.all.deep.keys(t)},o.doesNotHaveAnyDeepKeys=function(e,t,n){new r(e,n,o.doesNotHaveAnyDeepKeys,!0).to.not.have.any.deep.keys(t)},o.doesNotHaveAllDeepKeys=function(e,t,n){new r(e,n,o.doesNotHaveAllDeepKeys,!0).to.not.have.all.deep.keys(t)},o.throws=function(e,t,i,a){("string"==typeof t||t instanceof RegExp)&&(i=t,t=null);var s=new r(e,a,o.throws,!0).to.throw(t,i);return n(s,"object")},o.doesNotThrow=function(e,t,n,i){("string"==typeof t||t instanceof RegExp)&&(n=t,t=null),new r(e,i,o.doesNotThrow,!0).to.not.throw(t,n)},o.operator=function(i,a,s,c){var u;switch(a){case"==":u=i==s;break;case"===":u=i===s;break;case">":u=i>s;break;case">=":u=i>=s;break;case"<":u=i<s;break;case"<=":u=i<=s;break;case"!=":u=i!=s;break;case"!==":u=i!==s;break;default:throw c=c?c+": ":c,new e.AssertionError(c+'Invalid operator "'+a+'"',void 0,o.operator)}var l=new r(u,c,o.operator,!0);l.assert(!0===n(l,"object"),"expected "+t.inspect(i)+" to be "+a+" "+t.inspect(s),"expected "+t.inspect(i)+" to not be "+a+" "+t.inspect(s))},o.closeTo=function(e,t,n,i){new r(e,i,o.closeTo,!0).to.be.closeTo(t,n)},o.approximately=function(e,t,n,i){new r(e,i,o.approximately,!0).to.be.approximately(t,n)},o.sameMembers=function(e,t,n){new r(e,n,o.sameMembers,!0).to.have.same.members(t)},o.notSameMembers=function(e,t,n){new r(e,n,o.notSameMembers,!0).to.not.have.same.members(t)},o.sameDeepMembers=function(e,t,n){new r(e,n,o.sameDeepMembers,!0).to.have.same.deep.members(t)},o.notSameDeepMembers=function(e,t,n){new r(e,n,o.notSameDeepMembers,!0).to.not.have.same.deep.members(t)},o.sameOrderedMembers=function(e,t,n){new r(e,n,o.sameOrderedMembers,!0).to.have.same.ordered.members(t)},o.notSameOrderedMembers=function(e,t,n){new r(e,n,o.notSameOrderedMembers,!0).to.not.have.same.ordered.members(t)},o.sameDeepOrderedMembers=function(e,t,n){new r(e,n,o.sameDeepOrderedMembers,!0).to.have.same.deep.ordered.members(t)},o.notSameDeepOrderedMembers=function(e,t,n){new r(e,n,o.notSameDeepOrderedMembers,!0).to.not.have.same.deep.ordered.members(t)},o.includeMembers=function(e,t,n){new r(e,n,o.includeMembers,!0).to.include.members(t)},o.notIncludeMembers=function(e,t,n){new r(e,n,o.notIncludeMembers,!0).to.not.include.members(t)},o.includeDeepMembers=function(e,t,n){new r(e,n,o.includeDeepMembers,!0).to.include.deep.members(t)},o.notIncludeDeepMembers=function(e,t,n){new r(e,n,o.notIncludeDeepMembers,!0).to.not.include.deep.members(t)},o.includeOrderedMembers=function(e,t,n){new r(e,n,o.includeOrderedMembers,!0).to.include.ordered.members(t)},o.notIncludeOrderedMembers=function(e,t,n){new r(e,n,o.notIncludeOrderedMembers,!0).to.not.include.ordered.members(t)},o.includeDeepOrderedMembers=function(e,t,n){new r(e,n,o.includeDeepOrderedMembers,!0).to.include.deep.ordered.members(t)},o.notIncludeDeepOrderedMembers=function(e,t,n){new r(e,n,o.notIncludeDeepOrderedMembers,!0).to.not.include.deep.ordered.members(t)},o.oneOf=function(e,t,n){new r(e,n,o.oneOf,!0).to.be.oneOf(t)},o.changes=function(e,t,n,i){3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.changes,!0).to.change(t,n)},o.changesBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.changesBy,!0).to.change(t,n).by(i)},o.doesNotChange=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.doesNotChange,!0).to.not.change(t,n)},o.changesButNotBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.changesButNotBy,!0).to.change(t,n).but.not.by(i)},o.increases=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.increases,!0).to.increase(t,n)},o.increasesBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.increasesBy,!0).to.increase(t,n).by(i)},o.doesNotIncrease=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.doesNotIncrease,!0).to.not.increase(t,n)},o.increasesButNotBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.increasesButNotBy,!0).to.increase(t,n).but.not.by(i)},o.decreases=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.decreases,!0).to.decrease(t,n)},o.decreasesBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.decreasesBy,!0).to.decrease(t,n).by(i)},o.doesNotDecrease=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.doesNotDecrease,!0).to.not.decrease(t,n)},o.doesNotDecreaseBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);return new r(e,a,o.doesNotDecreaseBy,!0).to.not.decrease(t,n).by(i)},o.decreasesButNotBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.decreasesButNotBy,!0).to.decrease(t,n).but.not.by(i)},o.ifError=function(e){if(e)throw e},o.isExtensible=function(e,t){new r(e,t,o.isExtensible,!0).to.be.extensible},o.isNotExtensible=function(e,t){new r(e,t,o.isNotExtensible,!0).to.not.be.extensible},o.isSealed=function(e,t){new r(e,t,o.isSealed,!0).to.be.sealed},o.isNotSealed=function(e,t){new r(e,t,o.isNotSealed,!0).to.not.be.sealed},o.isFrozen=function(e,t){new r(e,t,o.isFrozen,!0).to.be.frozen},o.isNotFrozen=function(e,t){new r(e,t,o.isNotFrozen,!0).to.not.be.frozen},o.isEmpty=function(e,t){new r(e,t,o.isEmpty,!0).to.be.empty},o.isNotEmpty=function(e,t){new r(e,t,o.isNotEmpty,!0).to.not.be.empty},function e(t,r){return o[r]=o[t],e}("isOk","ok")("isNotOk","notOk")("throws","throw")("throws","Throw")("isExtensible","extensible")("isNotExtensible","notExtensible")("isSealed","sealed")("isNotSealed","notSealed")("isFrozen","frozen")("isNotFrozen","notFrozen")("isEmpty","empty")("isNotEmpty","notEmpty")}},6667:e=>{e.exports=function(e,t){e.expect=function(t,r){return new e.Assertion(t,r)},e.expect.fail=function(t,r,n,o){throw arguments.length<2&&(n=t,t=void 0),n=n||"expect.fail()",new e.AssertionError(n,{actual:t,expected:r,operator:o},e.expect.fail)}}},705:e=>{e.exports=function(e,t){var r=e.Assertion;function n(){Object.defineProperty(Object.prototype,"should",{set:function(e){Object.defineProperty(this,"should",{value:e,enumerable:!0,configurable:!0,writable:!0})},get:function e(){return this instanceof String||this instanceof Number||this instanceof Boolean||"function"==typeof Symbol&&this instanceof Symbol||"function"==typeof BigInt&&this instanceof BigInt?new r(this.valueOf(),null,e):new r(this,null,e)},configurable:!0});var t={fail:function(r,n,o,i){throw arguments.length<2&&(o=r,r=void 0),o=o||"should.fail()",new e.AssertionError(o,{actual:r,expected:n,operator:i},t.fail)},equal:function(e,t,n){new r(e,n).to.equal(t)},Throw:function(e,t,n,o){new r(e,o).to.Throw(t,n)},exist:function(e,t){new r(e,t).to.exist},not:{}};return t.not.equal=function(e,t,n){new r(e,n).to.not.equal(t)},t.not.Throw=function(e,t,n,o){new r(e,o).to.not.Throw(t,n)},t.not.exist=function(e,t){new r(e,t).to.not.exist},t.throw=t.Throw,t.not.throw=t.not.Throw,t}e.should=n,e.Should=n}},1949:(e,t,r)=>{var n=r(5100),o=r(9731),i=r(3226),a=r(8515),s=r(8316),c="function"==typeof Object.setPrototypeOf,u=function(){},l=Object.getOwnPropertyNames(u).filter((function(e){var t=Object.getOwnPropertyDescriptor(u,e);return"object"!=typeof t||!t.configurable})),p=Function.prototype.call,f=Function.prototype.apply;e.exports=function(e,t,r,u){"function"!=typeof u&&(u=function(){});var h={method:r,chainingBehavior:u};e.__methods||(e.__methods={}),e.__methods[t]=h,Object.defineProperty(e,t,{get:function(){h.chainingBehavior.call(this);var r=function(){i(this,"lockSsfi")||i(this,"ssfi",r);var e=h.method.apply(this,arguments);if(void 0!==e)return e;var t=new o.Assertion;return s(this,t),t};if(n(r,t,!0),c){var u=Object.create(this);u.call=p,u.apply=f,Object.setPrototypeOf(r,u)}else{Object.getOwnPropertyNames(e).forEach((function(t){if(-1===l.indexOf(t)){var n=Object.getOwnPropertyDescriptor(e,t);Object.defineProperty(r,t,n)}}))}return s(this,r),a(r)},configurable:!0})}},5100:e=>{var t=Object.getOwnPropertyDescriptor((function(){}),"length");e.exports=function(e,r,n){return t.configurable?(Object.defineProperty(e,"length",{get:function(){if(n)throw Error("Invalid Chai property: "+r+'.length. Due to a compatibility issue, "length" cannot directly follow "'+r+'". Use "'+r+'.lengthOf" instead.');throw Error("Invalid Chai property: "+r+'.length. See docs for proper usage of "'+r+'".')}}),e):e}},7180:(e,t,r)=>{var n=r(5100),o=r(9731),i=r(3226),a=r(8515),s=r(8316);e.exports=function(e,t,r){var c=function(){i(this,"lockSsfi")||i(this,"ssfi",c);var e=r.apply(this,arguments);if(void 0!==e)return e;var t=new o.Assertion;return s(this,t),t};n(c,t,!1),e[t]=a(c,t)}},7444:(e,t,r)=>{var n=r(9731),o=r(3226),i=r(3993),a=r(8316);e.exports=function(e,t,r){r=void 0===r?function(){}:r,Object.defineProperty(e,t,{get:function e(){i()||o(this,"lockSsfi")||o(this,"ssfi",e);var t=r.call(this);if(void 0!==t)return t;var s=new n.Assertion;return a(this,s),s},configurable:!0})}},5682:(e,t,r)=>{var n=r(154);e.exports=function(e,t){return n(e)<n(t)?-1:1}},5964:(e,t,r)=>{var n=r(9296),o=r(3226),i=r(5855);e.exports=function(e,t){var r=o(e,"message"),a=o(e,"ssfi");r=r?r+": ":"",e=o(e,"object"),(t=t.map((function(e){return e.toLowerCase()}))).sort();var s=t.map((function(e,r){var n=~["a","e","i","o","u"].indexOf(e.charAt(0))?"an":"a";return(t.length>1&&r===t.length-1?"or ":"")+n+" "+e})).join(", "),c=i(e).toLowerCase();if(!t.some((function(e){return c===e})))throw new n(r+"object tested must be "+s+", but "+c+" given",void 0,a)}},3226:e=>{e.exports=function(e,t,r){var n=e.__flags||(e.__flags=Object.create(null));if(3!==arguments.length)return n[t];n[t]=r}},8808:e=>{e.exports=function(e,t){return t.length>4?t[4]:e._obj}},7807:(e,t,r)=>{var n=r(3226),o=r(8808),i=r(3917);e.exports=function(e,t){var r=n(e,"negate"),a=n(e,"object"),s=t[3],c=o(e,t),u=r?t[2]:t[1],l=n(e,"message");return"function"==typeof u&&(u=u()),u=(u=u||"").replace(/#\{this\}/g,(function(){return i(a)})).replace(/#\{act\}/g,(function(){return i(c)})).replace(/#\{exp\}/g,(function(){return i(s)})),l?l+": "+u:u}},7602:(e,t,r)=>{var n=r(5855),o=r(3226);e.exports=function(e,t){var r=o(e,"operator"),i=o(e,"negate"),a=t[3],s=i?t[2]:t[1];if(r)return r;if("function"==typeof s&&(s=s()),(s=s||"")&&!/\shave\s/.test(s)){var c=function(e){var t=n(e);return-1!==["Array","Object","function"].indexOf(t)}(a);return/\snot\s/.test(s)?c?"notDeepStrictEqual":"notStrictEqual":c?"deepStrictEqual":"strictEqual"}}},6491:(e,t,r)=>{var n=r(7806);e.exports=function(e){return Object.keys(e).concat(n(e))}},7806:e=>{e.exports=function(e){return"function"!=typeof Object.getOwnPropertySymbols?[]:Object.getOwnPropertySymbols(e).filter((function(t){return Object.getOwnPropertyDescriptor(e,t).enumerable}))}},6807:e=>{e.exports=function(e){var t=Object.getOwnPropertyNames(e);function r(e){-1===t.indexOf(e)&&t.push(e)}for(var n=Object.getPrototypeOf(e);null!==n;)Object.getOwnPropertyNames(n).forEach(r),n=Object.getPrototypeOf(n);return t}},1118:(e,t,r)=>{var n=r(7893);t.test=r(4608),t.type=r(5855),t.expectTypes=r(5964),t.getMessage=r(7807),t.getActual=r(8808),t.inspect=r(154),t.objDisplay=r(3917),t.flag=r(3226),t.transferFlags=r(8316),t.eql=r(4964),t.getPathInfo=n.getPathInfo,t.hasProperty=n.hasProperty,t.getName=r(6708),t.addProperty=r(7444),t.addMethod=r(7180),t.overwriteProperty=r(374),t.overwriteMethod=r(6078),t.addChainableMethod=r(1949),t.overwriteChainableMethod=r(3863),t.compareByInspect=r(5682),t.getOwnEnumerablePropertySymbols=r(7806),t.getOwnEnumerableProperties=r(6491),t.checkError=r(1360),t.proxify=r(8515),t.addLengthGuard=r(5100),t.isProxyEnabled=r(3993),t.isNaN=r(1915),t.getOperator=r(7602)},154:(e,t,r)=>{r(6708);var n=r(2255),o=r(752);e.exports=function(e,t,r,i){var a={colors:i,depth:void 0===r?2:r,showHidden:t,truncate:o.truncateThreshold?o.truncateThreshold:1/0};return n.inspect(e,a)}},1915:e=>{e.exports=Number.isNaN||function(e){return e!=e}},3993:(e,t,r)=>{var n=r(752);e.exports=function(){return n.useProxy&&"undefined"!=typeof Proxy&&"undefined"!=typeof Reflect}},3917:(e,t,r)=>{var n=r(154),o=r(752);e.exports=function(e){var t=n(e),r=Object.prototype.toString.call(e);if(o.truncateThreshold&&t.length>=o.truncateThreshold){if("[object Function]"===r)return e.name&&""!==e.name?"[Function: "+e.name+"]":"[Function]";if("[object Array]"===r)return"[ Array("+e.length+") ]";if("[object Object]"===r){var i=Object.keys(e);return"{ Object ("+(i.length>2?i.splice(0,2).join(", ")+", ...":i.join(", "))+") }"}return t}return t}},3863:(e,t,r)=>{var n=r(9731),o=r(8316);e.exports=function(e,t,r,i){var a=e.__methods[t],s=a.chainingBehavior;a.chainingBehavior=function(){var e=i(s).call(this);if(void 0!==e)return e;var t=new n.Assertion;return o(this,t),t};var c=a.method;a.method=function(){var e=r(c).apply(this,arguments);if(void 0!==e)return e;var t=new n.Assertion;return o(this,t),t}}},6078:(e,t,r)=>{var n=r(5100),o=r(9731),i=r(3226),a=r(8515),s=r(8316);e.exports=function(e,t,r){var c=e[t],u=function(){throw new Error(t+" is not a function")};c&&"function"==typeof c&&(u=c);var l=function(){i(this,"lockSsfi")||i(this,"ssfi",l);var e=i(this,"lockSsfi");i(this,"lockSsfi",!0);var t=r(u).apply(this,arguments);if(i(this,"lockSsfi",e),void 0!==t)return t;var n=new o.Assertion;return s(this,n),n};n(l,t,!1),e[t]=a(l,t)}},374:(e,t,r)=>{var n=r(9731),o=r(3226),i=r(3993),a=r(8316);e.exports=function(e,t,r){var s=Object.getOwnPropertyDescriptor(e,t),c=function(){};s&&"function"==typeof s.get&&(c=s.get),Object.defineProperty(e,t,{get:function e(){i()||o(this,"lockSsfi")||o(this,"ssfi",e);var t=o(this,"lockSsfi");o(this,"lockSsfi",!0);var s=r(c).call(this);if(o(this,"lockSsfi",t),void 0!==s)return s;var u=new n.Assertion;return a(this,u),u},configurable:!0})}},8515:(e,t,r)=>{var n=r(752),o=r(3226),i=r(6807),a=r(3993),s=["__flags","__methods","_obj","assert"];e.exports=function(e,t){return a()?new Proxy(e,{get:function e(r,a){if("string"==typeof a&&-1===n.proxyExcludedKeys.indexOf(a)&&!Reflect.has(r,a)){if(t)throw Error("Invalid Chai property: "+t+"."+a+'. See docs for proper usage of "'+t+'".');var c=null,u=4;throw i(r).forEach((function(e){if(!Object.prototype.hasOwnProperty(e)&&-1===s.indexOf(e)){var t=function(e,t,r){if(Math.abs(e.length-t.length)>=r)return r;for(var n=[],o=0;o<=e.length;o++)n[o]=Array(t.length+1).fill(0),n[o][0]=o;for(var i=0;i<t.length;i++)n[0][i]=i;for(o=1;o<=e.length;o++){var a=e.charCodeAt(o-1);for(i=1;i<=t.length;i++)Math.abs(o-i)>=r?n[o][i]=r:n[o][i]=Math.min(n[o-1][i]+1,n[o][i-1]+1,n[o-1][i-1]+(a===t.charCodeAt(i-1)?0:1))}return n[e.length][t.length]}(a,e,u);t<u&&(c=e,u=t)}})),null!==c?Error("Invalid Chai property: "+a+'. Did you mean "'+c+'"?'):Error("Invalid Chai property: "+a)}return-1!==s.indexOf(a)||o(r,"lockSsfi")||o(r,"ssfi",e),Reflect.get(r,a)}}):e}},4608:(e,t,r)=>{var n=r(3226);e.exports=function(e,t){var r=n(e,"negate"),o=t[0];return r?!o:o}},8316:e=>{e.exports=function(e,t,r){var n=e.__flags||(e.__flags=Object.create(null));for(var o in t.__flags||(t.__flags=Object.create(null)),r=3!==arguments.length||r,n)(r||"object"!==o&&"ssfi"!==o&&"lockSsfi"!==o&&"message"!=o)&&(t.__flags[o]=n[o])}},1360:(e,t,r)=>{"use strict";var n=r(6708);e.exports={compatibleInstance:function(e,t){return t instanceof Error&&e===t},compatibleConstructor:function(e,t){return t instanceof Error?e.constructor===t.constructor||e instanceof t.constructor:(t.prototype instanceof Error||t===Error)&&(e.constructor===t||e instanceof t)},compatibleMessage:function(e,t){var r="string"==typeof e?e:e.message;return t instanceof RegExp?t.test(r):"string"==typeof t&&-1!==r.indexOf(t)},getMessage:function(e){var t="";return e&&e.message?t=e.message:"string"==typeof e&&(t=e),t},getConstructorName:function(e){var t=e;if(e instanceof Error)t=n(e.constructor);else if("function"==typeof e){if(""===(t=n(e)))t=n(new e)||t}return t}}},4964:(e,t,r)=>{"use strict";var n=r(5855);function o(){this._key="chai/deep-eql__"+Math.random()+Date.now()}o.prototype={get:function(e){return e[this._key]},set:function(e,t){Object.isExtensible(e)&&Object.defineProperty(e,this._key,{value:t,configurable:!0})}};var i="function"==typeof WeakMap?WeakMap:o;function a(e,t,r){if(!r||m(e)||m(t))return null;var n=r.get(e);if(n){var o=n.get(t);if("boolean"==typeof o)return o}return null}function s(e,t,r,n){if(r&&!m(e)&&!m(t)){var o=r.get(e);o?o.set(t,n):((o=new i).set(t,n),r.set(e,o))}}function c(e,t,r){if(r&&r.comparator)return l(e,t,r);var n=u(e,t);return null!==n?n:l(e,t,r)}function u(e,t){return e===t?0!==e||1/e==1/t:e!=e&&t!=t||!m(e)&&!m(t)&&null}function l(e,t,r){(r=r||{}).memoize=!1!==r.memoize&&(r.memoize||new i);var o=r&&r.comparator,l=a(e,t,r.memoize);if(null!==l)return l;var m=a(t,e,r.memoize);if(null!==m)return m;if(o){var w=o(e,t);if(!1===w||!0===w)return s(e,t,r.memoize,w),w;var x=u(e,t);if(null!==x)return x}var O=n(e);if(O!==n(t))return s(e,t,r.memoize,!1),!1;s(e,t,r.memoize,!0);var E=function(e,t,r,n){switch(r){case"String":case"Number":case"Boolean":case"Date":return c(e.valueOf(),t.valueOf());case"Promise":case"Symbol":case"function":case"WeakMap":case"WeakSet":return e===t;case"Error":return g(e,t,["name","message","code"],n);case"Arguments":case"Int8Array":case"Uint8Array":case"Uint8ClampedArray":case"Int16Array":case"Uint16Array":case"Int32Array":case"Uint32Array":case"Float32Array":case"Float64Array":case"Array":return f(e,t,n);case"RegExp":return function(e,t){return e.toString()===t.toString()}(e,t);case"Generator":return function(e,t,r){return f(d(e),d(t),r)}(e,t,n);case"DataView":return f(new Uint8Array(e.buffer),new Uint8Array(t.buffer),n);case"ArrayBuffer":return f(new Uint8Array(e),new Uint8Array(t),n);case"Set":case"Map":return p(e,t,n);case"Temporal.PlainDate":case"Temporal.PlainTime":case"Temporal.PlainDateTime":case"Temporal.Instant":case"Temporal.ZonedDateTime":case"Temporal.PlainYearMonth":case"Temporal.PlainMonthDay":return e.equals(t);case"Temporal.Duration":return e.total("nanoseconds")===t.total("nanoseconds");case"Temporal.TimeZone":case"Temporal.Calendar":return e.toString()===t.toString();default:return function(e,t,r){var n=y(e),o=y(t),i=b(e),a=b(t);if(n=n.concat(i),o=o.concat(a),n.length&&n.length===o.length)return!1!==f(v(n).sort(),v(o).sort())&&g(e,t,n,r);var s=h(e),c=h(t);if(s.length&&s.length===c.length)return s.sort(),c.sort(),f(s,c,r);if(0===n.length&&0===s.length&&0===o.length&&0===c.length)return!0;return!1}(e,t,n)}}(e,t,O,r);return s(e,t,r.memoize,E),E}function p(e,t,r){try{if(e.size!==t.size)return!1;if(0===e.size)return!0}catch(e){return!1}var n=[],o=[];return e.forEach((function(e,t){n.push([e,t])})),t.forEach((function(e,t){o.push([e,t])})),f(n.sort(),o.sort(),r)}function f(e,t,r){var n=e.length;if(n!==t.length)return!1;if(0===n)return!0;for(var o=-1;++o<n;)if(!1===c(e[o],t[o],r))return!1;return!0}function h(e){if(function(e){return"undefined"!=typeof Symbol&&"object"==typeof e&&void 0!==Symbol.iterator&&"function"==typeof e[Symbol.iterator]}(e))try{return d(e[Symbol.iterator]())}catch(e){return[]}return[]}function d(e){for(var t=e.next(),r=[t.value];!1===t.done;)t=e.next(),r.push(t.value);return r}function y(e){var t=[];for(var r in e)t.push(r);return t}function b(e){for(var t=[],r=Object.getOwnPropertySymbols(e),n=0;n<r.length;n+=1){var o=r[n];Object.getOwnPropertyDescriptor(e,o).enumerable&&t.push(o)}return t}function g(e,t,r,n){var o=r.length;if(0===o)return!0;for(var i=0;i<o;i+=1)if(!1===c(e[r[i]],t[r[i]],n))return!1;return!0}function m(e){return null===e||"object"!=typeof e}function v(e){return e.map((function(e){return"symbol"==typeof e?e.toString():e}))}e.exports=c,e.exports.MemoizeMap=i},41:(e,t,r)=>{"use strict";var n=r(655),o=r(8068),i=r(9675),a=r(5795);e.exports=function(e,t,r){if(!e||"object"!=typeof e&&"function"!=typeof e)throw new i("`obj` must be an object or a function`");if("string"!=typeof t&&"symbol"!=typeof t)throw new i("`property` must be a string or a symbol`");if(arguments.length>3&&"boolean"!=typeof arguments[3]&&null!==arguments[3])throw new i("`nonEnumerable`, if provided, must be a boolean or null");if(arguments.length>4&&"boolean"!=typeof arguments[4]&&null!==arguments[4])throw new i("`nonWritable`, if provided, must be a boolean or null");if(arguments.length>5&&"boolean"!=typeof arguments[5]&&null!==arguments[5])throw new i("`nonConfigurable`, if provided, must be a boolean or null");if(arguments.length>6&&"boolean"!=typeof arguments[6])throw new i("`loose`, if provided, must be a boolean");var s=arguments.length>3?arguments[3]:null,c=arguments.length>4?arguments[4]:null,u=arguments.length>5?arguments[5]:null,l=arguments.length>6&&arguments[6],p=!!a&&a(e,t);if(n)n(e,t,{configurable:null===u&&p?p.configurable:!u,enumerable:null===s&&p?p.enumerable:!s,value:r,writable:null===c&&p?p.writable:!c});else{if(!l&&(s||c||u))throw new o("This environment does not support defining a property as non-configurable, non-writable, or non-enumerable.");e[t]=r}}},655:(e,t,r)=>{"use strict";var n=r(453)("%Object.defineProperty%",!0)||!1;if(n)try{n({},"a",{value:1})}catch(e){n=!1}e.exports=n},1237:e=>{"use strict";e.exports=EvalError},9383:e=>{"use strict";e.exports=Error},9290:e=>{"use strict";e.exports=RangeError},9538:e=>{"use strict";e.exports=ReferenceError},8068:e=>{"use strict";e.exports=SyntaxError},9675:e=>{"use strict";e.exports=TypeError},5345:e=>{"use strict";e.exports=URIError},2682:(e,t,r)=>{"use strict";var n=r(9600),o=Object.prototype.toString,i=Object.prototype.hasOwnProperty;e.exports=function(e,t,r){if(!n(t))throw new TypeError("iterator must be a function");var a;arguments.length>=3&&(a=r),"[object Array]"===o.call(e)?function(e,t,r){for(var n=0,o=e.length;n<o;n++)i.call(e,n)&&(null==r?t(e[n],n,e):t.call(r,e[n],n,e))}(e,t,a):"string"==typeof e?function(e,t,r){for(var n=0,o=e.length;n<o;n++)null==r?t(e.charAt(n),n,e):t.call(r,e.charAt(n),n,e)}(e,t,a):function(e,t,r){for(var n in e)i.call(e,n)&&(null==r?t(e[n],n,e):t.call(r,e[n],n,e))}(e,t,a)}},9353:e=>{"use strict";var t=Object.prototype.toString,r=Math.max,n=function(e,t){for(var r=[],n=0;n<e.length;n+=1)r[n]=e[n];for(var o=0;o<t.length;o+=1)r[o+e.length]=t[o];return r};e.exports=function(e){var o=this;if("function"!=typeof o||"[object Function]"!==t.apply(o))throw new TypeError("Function.prototype.bind called on incompatible "+o);for(var i,a=function(e,t){for(var r=[],n=t||0,o=0;n<e.length;n+=1,o+=1)r[o]=e[n];return r}(arguments,1),s=r(0,o.length-a.length),c=[],u=0;u<s;u++)c[u]="$"+u;if(i=Function("binder","return function ("+function(e,t){for(var r="",n=0;n<e.length;n+=1)r+=e[n],n+1<e.length&&(r+=t);return r}(c,",")+"){ return binder.apply(this,arguments); }")((function(){if(this instanceof i){var t=o.apply(this,n(a,arguments));return Object(t)===t?t:this}return o.apply(e,n(a,arguments))})),o.prototype){var l=function(){};l.prototype=o.prototype,i.prototype=new l,l.prototype=null}return i}},6743:(e,t,r)=>{"use strict";var n=r(9353);e.exports=Function.prototype.bind||n},6708:e=>{"use strict";var t=Function.prototype.toString,r=/\s*function(?:\s|\s*\/\*[^(?:*\/)]+\*\/\s*)*([^\s\(\/]+)/;e.exports=function(e){if("function"!=typeof e)return null;var n="";if(void 0===Function.prototype.name&&void 0===e.name){var o=t.call(e);if(o.indexOf("(")>512)return n;var i=o.match(r);i&&(n=i[1])}else n=e.name;return n}},453:(e,t,r)=>{"use strict";var n,o=r(9383),i=r(1237),a=r(9290),s=r(9538),c=r(8068),u=r(9675),l=r(5345),p=Function,f=function(e){try{return p('"use strict"; return ('+e+").constructor;")()}catch(e){}},h=Object.getOwnPropertyDescriptor;if(h)try{h({},"")}catch(e){h=null}var d=function(){throw new u},y=h?function(){try{return d}catch(e){try{return h(arguments,"callee").get}catch(e){return d}}}():d,b=r(4039)(),g=r(24)(),m=Object.getPrototypeOf||(g?function(e){return e.__proto__}:null),v={},w="undefined"!=typeof Uint8Array&&m?m(Uint8Array):n,x={__proto__:null,"%AggregateError%":"undefined"==typeof AggregateError?n:AggregateError,"%Array%":Array,"%ArrayBuffer%":"undefined"==typeof ArrayBuffer?n:ArrayBuffer,"%ArrayIteratorPrototype%":b&&m?m([][Symbol.iterator]()):n,"%AsyncFromSyncIteratorPrototype%":n,"%AsyncFunction%":v,"%AsyncGenerator%":v,"%AsyncGeneratorFunction%":v,"%AsyncIteratorPrototype%":v,"%Atomics%":"undefined"==typeof Atomics?n:Atomics,"%BigInt%":"undefined"==typeof BigInt?n:BigInt,"%BigInt64Array%":"undefined"==typeof BigInt64Array?n:BigInt64Array,"%BigUint64Array%":"undefined"==typeof BigUint64Array?n:BigUint64Array,"%Boolean%":Boolean,"%DataView%":"undefined"==typeof DataView?n:DataView,"%Date%":Date,"%decodeURI%":decodeURI,"%decodeURIComponent%":decodeURIComponent,"%encodeURI%":encodeURI,"%encodeURIComponent%":encodeURIComponent,"%Error%":o,"%eval%":eval,"%EvalError%":i,"%Float32Array%":"undefined"==typeof Float32Array?n:Float32Array,"%Float64Array%":"undefined"==typeof Float64Array?n:Float64Array,"%FinalizationRegistry%":"undefined"==typeof FinalizationRegistry?n:FinalizationRegistry,"%Function%":p,"%GeneratorFunction%":v,"%Int8Array%":"undefined"==typeof Int8Array?n:Int8Array,"%Int16Array%":"undefined"==typeof Int16Array?n:Int16Array,"%Int32Array%":"undefined"==typeof Int32Array?n:Int32Array,"%isFinite%":isFinite,"%isNaN%":isNaN,"%IteratorPrototype%":b&&m?m(m([][Symbol.iterator]())):n,"%JSON%":"object"==typeof JSON?JSON:n,"%Map%":"undefined"==typeof Map?n:Map,"%MapIteratorPrototype%":"undefined"!=typeof Map&&b&&m?m((new Map)[Symbol.iterator]()):n,"%Math%":Math,"%Number%":Number,"%Object%":Object,"%parseFloat%":parseFloat,"%parseInt%":parseInt,"%Promise%":"undefined"==typeof Promise?n:Promise,"%Proxy%":"undefined"==typeof Proxy?n:Proxy,"%RangeError%":a,"%ReferenceError%":s,"%Reflect%":"undefined"==typeof Reflect?n:Reflect,"%RegExp%":RegExp,"%Set%":"undefined"==typeof Set?n:Set,"%SetIteratorPrototype%":"undefined"!=typeof Set&&b&&m?m((new Set)[Symbol.iterator]()):n,"%SharedArrayBuffer%":"undefined"==typeof SharedArrayBuffer?n:SharedArrayBuffer,"%String%":String,"%StringIteratorPrototype%":b&&m?m(""[Symbol.iterator]()):n,"%Symbol%":b?Symbol:n,"%SyntaxError%":c,"%ThrowTypeError%":y,"%TypedArray%":w,"%TypeError%":u,"%Uint8Array%":"undefined"==typeof Uint8Array?n:Uint8Array,"%Uint8ClampedArray%":"undefined"==typeof Uint8ClampedArray?n:Uint8ClampedArray,"%Uint16Array%":"undefined"==typeof Uint16Array?n:Uint16Array,"%Uint32Array%":"undefined"==typeof Uint32Array?n:Uint32Array,"%URIError%":l,"%WeakMap%":"undefined"==typeof WeakMap?n:WeakMap,"%WeakRef%":"undefined"==typeof WeakRef?n:WeakRef,"%WeakSet%":"undefined"==typeof WeakSet?n:WeakSet};if(m)try{null.error}catch(e){var O=m(m(e));x["%Error.prototype%"]=O}var E=function e(t){var r;if("%AsyncFunction%"===t)r=f("async function () {}");else if("%GeneratorFunction%"===t)r=f("function* () {}");else if("%AsyncGeneratorFunction%"===t)r=f("async function* () {}");else if("%AsyncGenerator%"===t){var n=e("%AsyncGeneratorFunction%");n&&(r=n.prototype)}else if("%AsyncIteratorPrototype%"===t){var o=e("%AsyncGenerator%");o&&m&&(r=m(o.prototype))}return x[t]=r,r},A={__proto__:null,"%ArrayBufferPrototype%":["ArrayBuffer","prototype"],"%ArrayPrototype%":["Array","prototype"],"%ArrayProto_entries%":["Array","prototype","entries"],"%ArrayProto_forEach%":["Array","prototype","forEach"],"%ArrayProto_keys%":["Array","prototype","keys"],"%ArrayProto_values%":["Array","prototype","values"],"%AsyncFunctionPrototype%":["AsyncFunction","prototype"],"%AsyncGenerator%":["AsyncGeneratorFunction","prototype"],"%AsyncGeneratorPrototype%":["AsyncGeneratorFunction","prototype","prototype"],"%BooleanPrototype%":["Boolean","prototype"],"%DataViewPrototype%":["DataView","prototype"],"%DatePrototype%":["Date","prototype"],"%ErrorPrototype%":["Error","prototype"],"%EvalErrorPrototype%":["EvalError","prototype"],"%Float32ArrayPrototype%":["Float32Array","prototype"],"%Float64ArrayPrototype%":["Float64Array","prototype"],"%FunctionPrototype%":["Function","prototype"],"%Generator%":["GeneratorFunction","prototype"],"%GeneratorPrototype%":["GeneratorFunction","prototype","prototype"],"%Int8ArrayPrototype%":["Int8Array","prototype"],"%Int16ArrayPrototype%":["Int16Array","prototype"],"%Int32ArrayPrototype%":["Int32Array","prototype"],"%JSONParse%":["JSON","parse"],"%JSONStringify%":["JSON","stringify"],"%MapPrototype%":["Map","prototype"],"%NumberPrototype%":["Number","prototype"],"%ObjectPrototype%":["Object","prototype"],"%ObjProto_toString%":["Object","prototype","toString"],"%ObjProto_valueOf%":["Object","prototype","valueOf"],"%PromisePrototype%":["Promise","prototype"],"%PromiseProto_then%":["Promise","prototype","then"],"%Promise_all%":["Promise","all"],"%Promise_reject%":["Promise","reject"],"%Promise_resolve%":["Promise","resolve"],"%RangeErrorPrototype%":["RangeError","prototype"],"%ReferenceErrorPrototype%":["ReferenceError","prototype"],"%RegExpPrototype%":["RegExp","prototype"],"%SetPrototype%":["Set","prototype"],"%SharedArrayBufferPrototype%":["SharedArrayBuffer","prototype"],"%StringPrototype%":["String","prototype"],"%SymbolPrototype%":["Symbol","prototype"],"%SyntaxErrorPrototype%":["SyntaxError","prototype"],"%TypedArrayPrototype%":["TypedArray","prototype"],"%TypeErrorPrototype%":["TypeError","prototype"],"%Uint8ArrayPrototype%":["Uint8Array","prototype"],"%Uint8ClampedArrayPrototype%":["Uint8ClampedArray","prototype"],"%Uint16ArrayPrototype%":["Uint16Array","prototype"],"%Uint32ArrayPrototype%":["Uint32Array","prototype"],"%URIErrorPrototype%":["URIError","prototype"],"%WeakMapPrototype%":["WeakMap","prototype"],"%WeakSetPrototype%":["WeakSet","prototype"]},S=r(6743),j=r(9957),P=S.call(Function.call,Array.prototype.concat),_=S.call(Function.apply,Array.prototype.splice),k=S.call(Function.call,String.prototype.replace),M=S.call(Function.call,String.prototype.slice),N=S.call(Function.call,RegExp.prototype.exec),C=/[^%.[\]]+|\[(?:(-?\d+(?:\.\d+)?)|(["'])((?:(?!\2)[^\\]|\\.)*?)\2)\]|(?=(?:\.|\[\])(?:\.|\[\]|%$))/g,D=/\\(\\)?/g,I=function(e,t){var r,n=e;if(j(A,n)&&(n="%"+(r=A[n])[0]+"%"),j(x,n)){var o=x[n];if(o===v&&(o=E(n)),void 0===o&&!t)throw new u("intrinsic "+e+" exists, but is not available. Please file an issue!");return{alias:r,name:n,value:o}}throw new c("intrinsic "+e+" does not exist!")};e.exports=function(e,t){if("string"!=typeof e||0===e.length)throw new u("intrinsic name must be a non-empty string");if(arguments.length>1&&"boolean"!=typeof t)throw new u('"allowMissing" argument must be a boolean');if(null===N(/^%?[^%]*%?$/,e))throw new c("`%` may not be present anywhere but at the beginning and end of the intrinsic name");var r=function(e){var t=M(e,0,1),r=M(e,-1);if("%"===t&&"%"!==r)throw new c("invalid intrinsic syntax, expected closing `%`");if("%"===r&&"%"!==t)throw new c("invalid intrinsic syntax, expected opening `%`");var n=[];return k(e,C,(function(e,t,r,o){n[n.length]=r?k(o,D,"$1"):t||e})),n}(e),n=r.length>0?r[0]:"",o=I("%"+n+"%",t),i=o.name,a=o.value,s=!1,l=o.alias;l&&(n=l[0],_(r,P([0,1],l)));for(var p=1,f=!0;p<r.length;p+=1){var d=r[p],y=M(d,0,1),b=M(d,-1);if(('"'===y||"'"===y||"`"===y||'"'===b||"'"===b||"`"===b)&&y!==b)throw new c("property names with quotes must have matching quotes");if("constructor"!==d&&f||(s=!0),j(x,i="%"+(n+="."+d)+"%"))a=x[i];else if(null!=a){if(!(d in a)){if(!t)throw new u("base intrinsic for "+e+" exists, but the property is not available.");return}if(h&&p+1>=r.length){var g=h(a,d);a=(f=!!g)&&"get"in g&&!("originalValue"in g.get)?g.get:a[d]}else f=j(a,d),a=a[d];f&&!s&&(x[i]=a)}}return a}},5795:(e,t,r)=>{"use strict";var n=r(453)("%Object.getOwnPropertyDescriptor%",!0);if(n)try{n([],"length")}catch(e){n=null}e.exports=n},592:(e,t,r)=>{"use strict";var n=r(655),o=function(){return!!n};o.hasArrayLengthDefineBug=function(){if(!n)return null;try{return 1!==n([],"length",{value:1}).length}catch(e){return!0}},e.exports=o},24:e=>{"use strict";var t={__proto__:null,foo:{}},r=Object;e.exports=function(){return{__proto__:t}.foo===t.foo&&!(t instanceof r)}},4039:(e,t,r)=>{"use strict";var n="undefined"!=typeof Symbol&&Symbol,o=r(1333);e.exports=function(){return"function"==typeof n&&("function"==typeof Symbol&&("symbol"==typeof n("foo")&&("symbol"==typeof Symbol("bar")&&o())))}},1333:e=>{"use strict";e.exports=function(){if("function"!=typeof Symbol||"function"!=typeof Object.getOwnPropertySymbols)return!1;if("symbol"==typeof Symbol.iterator)return!0;var e={},t=Symbol("test"),r=Object(t);if("string"==typeof t)return!1;if("[object Symbol]"!==Object.prototype.toString.call(t))return!1;if("[object Symbol]"!==Object.prototype.toString.call(r))return!1;for(t in e[t]=42,e)return!1;if("function"==typeof Object.keys&&0!==Object.keys(e).length)return!1;if("function"==typeof Object.getOwnPropertyNames&&0!==Object.getOwnPropertyNames(e).length)return!1;var n=Object.getOwnPropertySymbols(e);if(1!==n.length||n[0]!==t)return!1;if(!Object.prototype.propertyIsEnumerable.call(e,t))return!1;if("function"==typeof Object.getOwnPropertyDescriptor){var o=Object.getOwnPropertyDescriptor(e,t);if(42!==o.value||!0!==o.enumerable)return!1}return!0}},9092:(e,t,r)=>{"use strict";var n=r(1333);e.exports=function(){return n()&&!!Symbol.toStringTag}},9957:(e,t,r)=>{"use strict";var n=Function.prototype.call,o=Object.prototype.hasOwnProperty,i=r(6743);e.exports=i.call(n,o)},6698:e=>{"function"==typeof Object.create?e.exports=function(e,t){t&&(e.super_=t,e.prototype=Object.create(t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}))}:e.exports=function(e,t){if(t){e.super_=t;var r=function(){};r.prototype=t.prototype,e.prototype=new r,e.prototype.constructor=e}}},7244:(e,t,r)=>{"use strict";var n=r(9092)(),o=r(8075)("Object.prototype.toString"),i=function(e){return!(n&&e&&"object"==typeof e&&Symbol.toStringTag in e)&&"[object Arguments]"===o(e)},a=function(e){return!!i(e)||null!==e&&"object"==typeof e&&"number"==typeof e.length&&e.length>=0&&"[object Array]"!==o(e)&&"[object Function]"===o(e.callee)},s=function(){return i(arguments)}();i.isLegacyArguments=a,e.exports=s?i:a},9600:e=>{"use strict";var t,r,n=Function.prototype.toString,o="object"==typeof Reflect&&null!==Reflect&&Reflect.apply;if("function"==typeof o&&"function"==typeof Object.defineProperty)try{t=Object.defineProperty({},"length",{get:function(){throw r}}),r={},o((function(){throw 42}),null,t)}catch(e){e!==r&&(o=null)}else o=null;var i=/^\s*class\b/,a=function(e){try{var t=n.call(e);return i.test(t)}catch(e){return!1}},s=function(e){try{return!a(e)&&(n.call(e),!0)}catch(e){return!1}},c=Object.prototype.toString,u="function"==typeof Symbol&&!!Symbol.toStringTag,l=!(0 in[,]),p=function(){return!1};if("object"==typeof document){var f=document.all;c.call(f)===c.call(document.all)&&(p=function(e){if((l||!e)&&(void 0===e||"object"==typeof e))try{var t=c.call(e);return("[object HTMLAllCollection]"===t||"[object HTML document.all class]"===t||"[object HTMLCollection]"===t||"[object Object]"===t)&&null==e("")}catch(e){}return!1})}e.exports=o?function(e){if(p(e))return!0;if(!e)return!1;if("function"!=typeof e&&"object"!=typeof e)return!1;try{o(e,null,t)}catch(e){if(e!==r)return!1}return!a(e)&&s(e)}:function(e){if(p(e))return!0;if(!e)return!1;if("function"!=typeof e&&"object"!=typeof e)return!1;if(u)return s(e);if(a(e))return!1;var t=c.call(e);return!("[object Function]"!==t&&"[object GeneratorFunction]"!==t&&!/^\[object HTML/.test(t))&&s(e)}},8184:(e,t,r)=>{"use strict";var n,o=Object.prototype.toString,i=Function.prototype.toString,a=/^\s*(?:function)?\*/,s=r(9092)(),c=Object.getPrototypeOf;e.exports=function(e){if("function"!=typeof e)return!1;if(a.test(i.call(e)))return!0;if(!s)return"[object GeneratorFunction]"===o.call(e);if(!c)return!1;if(void 0===n){var t=function(){if(!s)return!1;try{return Function("return function*() {}")()}catch(e){}}();n=!!t&&c(t)}return c(e)===n}},5680:(e,t,r)=>{"use strict";var n=r(5767);e.exports=function(e){return!!n(e)}},2255:function(e,t,r){!function(e){"use strict";function t(e){return t="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},t(e)}function n(e,t){return o(e)||i(e,t)||a(e,t)||c()}function o(e){if(Array.isArray(e))return e}function i(e,t){if("undefined"!=typeof Symbol&&Symbol.iterator in Object(e)){var r=[],n=!0,o=!1,i=void 0;try{for(var a,s=e[Symbol.iterator]();!(n=(a=s.next()).done)&&(r.push(a.value),!t||r.length!==t);n=!0);}catch(e){o=!0,i=e}finally{try{n||null==s.return||s.return()}finally{if(o)throw i}}return r}}function a(e,t){if(e){if("string"==typeof e)return s(e,t);var r=Object.prototype.toString.call(e).slice(8,-1);return"Object"===r&&e.constructor&&(r=e.constructor.name),"Map"===r||"Set"===r?Array.from(e):"Arguments"===r||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(r)?s(e,t):void 0}}function s(e,t){(null==t||t>e.length)&&(t=e.length);for(var r=0,n=new Array(t);r<t;r++)n[r]=e[r];return n}function c(){throw new TypeError("Invalid attempt to destructure non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}var u={bold:["1","22"],dim:["2","22"],italic:["3","23"],underline:["4","24"],inverse:["7","27"],hidden:["8","28"],strike:["9","29"],black:["30","39"],red:["31","39"],green:["32","39"],yellow:["33","39"],blue:["34","39"],magenta:["35","39"],cyan:["36","39"],white:["37","39"],brightblack:["30;1","39"],brightred:["31;1","39"],brightgreen:["32;1","39"],brightyellow:["33;1","39"],brightblue:["34;1","39"],brightmagenta:["35;1","39"],brightcyan:["36;1","39"],brightwhite:["37;1","39"],grey:["90","39"]},l={special:"cyan",number:"yellow",bigint:"yellow",boolean:"yellow",undefined:"grey",null:"bold",string:"green",symbol:"green",date:"magenta",regexp:"red"},p="…";function f(e,t){var r=u[l[t]]||u[t];return r?"[".concat(r[0],"m").concat(String(e),"[").concat(r[1],"m"):String(e)}function h(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{},t=e.showHidden,r=void 0!==t&&t,n=e.depth,o=void 0===n?2:n,i=e.colors,a=void 0!==i&&i,s=e.customInspect,c=void 0===s||s,u=e.showProxy,l=void 0!==u&&u,p=e.maxArrayLength,h=void 0===p?1/0:p,d=e.breakLength,y=void 0===d?1/0:d,b=e.seen,g=void 0===b?[]:b,m=e.truncate,v=void 0===m?1/0:m,w=e.stylize,x=void 0===w?String:w,O={showHidden:Boolean(r),depth:Number(o),colors:Boolean(a),customInspect:Boolean(c),showProxy:Boolean(l),maxArrayLength:Number(h),breakLength:Number(y),truncate:Number(v),seen:g,stylize:x};return O.colors&&(O.stylize=f),O}function d(e,t){var r=arguments.length>2&&void 0!==arguments[2]?arguments[2]:p;e=String(e);var n=r.length,o=e.length;return n>t&&o>n?r:o>t&&o>n?"".concat(e.slice(0,t-n)).concat(r):e}function y(e,t,r){var n=arguments.length>3&&void 0!==arguments[3]?arguments[3]:", ";r=r||t.inspect;var o=e.length;if(0===o)return"";for(var i=t.truncate,a="",s="",c="",u=0;u<o;u+=1){var l=u+1===e.length,f=u+2===e.length;c="".concat(p,"(").concat(e.length-u,")");var h=e[u];t.truncate=i-a.length-(l?0:n.length);var d=s||r(h,t)+(l?"":n),y=a.length+d.length,b=y+c.length;if(l&&y>i&&a.length+c.length<=i)break;if(!l&&!f&&b>i)break;if(s=l?"":r(e[u+1],t)+(f?"":n),!l&&f&&b>i&&y+s.length>i)break;if(a+=d,!l&&!f&&y+s.length>=i){c="".concat(p,"(").concat(e.length-u-1,")");break}c=""}return"".concat(a).concat(c)}function b(e){return e.match(/^[a-zA-Z_][a-zA-Z_0-9]*$/)?e:JSON.stringify(e).replace(/'/g,"\\'").replace(/\\"/g,'"').replace(/(^"|"$)/g,"'")}function g(e,t){var r=n(e,2),o=r[0],i=r[1];return t.truncate-=2,"string"==typeof o?o=b(o):"number"!=typeof o&&(o="[".concat(t.inspect(o,t),"]")),t.truncate-=o.length,i=t.inspect(i,t),"".concat(o,": ").concat(i)}function m(e,t){var r=Object.keys(e).slice(e.length);if(!e.length&&!r.length)return"[]";t.truncate-=4;var n=y(e,t);t.truncate-=n.length;var o="";return r.length&&(o=y(r.map((function(t){return[t,e[t]]})),t,g)),"[ ".concat(n).concat(o?", ".concat(o):""," ]")}var v=Function.prototype.toString,w=/\s*function(?:\s|\s*\/\*[^(?:*\/)]+\*\/\s*)*([^\s\(\/]+)/,x=512;function O(e){if("function"!=typeof e)return null;var t="";if(void 0===Function.prototype.name&&void 0===e.name){var r=v.call(e);if(r.indexOf("(")>x)return t;var n=r.match(w);n&&(t=n[1])}else t=e.name;return t}var E=O,A=function(e){return"function"==typeof Buffer&&e instanceof Buffer?"Buffer":e[Symbol.toStringTag]?e[Symbol.toStringTag]:E(e.constructor)};function S(e,t){var r=A(e);t.truncate-=r.length+4;var n=Object.keys(e).slice(e.length);if(!e.length&&!n.length)return"".concat(r,"[]");for(var o="",i=0;i<e.length;i++){var a="".concat(t.stylize(d(e[i],t.truncate),"number")).concat(i===e.length-1?"":", ");if(t.truncate-=a.length,e[i]!==e.length&&t.truncate<=3){o+="".concat(p,"(").concat(e.length-e[i]+1,")");break}o+=a}var s="";return n.length&&(s=y(n.map((function(t){return[t,e[t]]})),t,g)),"".concat(r,"[ ").concat(o).concat(s?", ".concat(s):""," ]")}function j(e,t){var r=e.toJSON();if(null===r)return"Invalid Date";var n=r.split("T"),o=n[0];return t.stylize("".concat(o,"T").concat(d(n[1],t.truncate-o.length-1)),"date")}function P(e,t){var r=E(e);return r?t.stylize("[Function ".concat(d(r,t.truncate-11),"]"),"special"):t.stylize("[Function]","special")}function _(e,t){var r=n(e,2),o=r[0],i=r[1];return t.truncate-=4,o=t.inspect(o,t),t.truncate-=o.length,i=t.inspect(i,t),"".concat(o," => ").concat(i)}function k(e){var t=[];return e.forEach((function(e,r){t.push([r,e])})),t}function M(e,t){return e.size-1<=0?"Map{}":(t.truncate-=7,"Map{ ".concat(y(k(e),t,_)," }"))}var N=Number.isNaN||function(e){return e!=e};function C(e,t){return N(e)?t.stylize("NaN","number"):e===1/0?t.stylize("Infinity","number"):e===-1/0?t.stylize("-Infinity","number"):0===e?t.stylize(1/e==1/0?"+0":"-0","number"):t.stylize(d(e,t.truncate),"number")}function D(e,t){var r=d(e.toString(),t.truncate-1);return r!==p&&(r+="n"),t.stylize(r,"bigint")}function I(e,t){var r=e.toString().split("/")[2],n=t.truncate-(2+r.length),o=e.source;return t.stylize("/".concat(d(o,n),"/").concat(r),"regexp")}function T(e){var t=[];return e.forEach((function(e){t.push(e)})),t}function B(e,t){return 0===e.size?"Set{}":(t.truncate-=7,"Set{ ".concat(y(T(e),t)," }"))}var R=new RegExp("['\\u0000-\\u001f\\u007f-\\u009f\\u00ad\\u0600-\\u0604\\u070f\\u17b4\\u17b5\\u200c-\\u200f\\u2028-\\u202f\\u2060-\\u206f\\ufeff\\ufff0-\\uffff]","g"),F={"\b":"\\b","\t":"\\t","\n":"\\n","\f":"\\f","\r":"\\r","'":"\\'","\\":"\\\\"},U=16,L=4;function z(e){return F[e]||"\\u".concat("0000".concat(e.charCodeAt(0).toString(U)).slice(-L))}function q(e,t){return R.test(e)&&(e=e.replace(R,z)),t.stylize("'".concat(d(e,t.truncate-2),"'"),"string")}function $(e){return"description"in Symbol.prototype?e.description?"Symbol(".concat(e.description,")"):"Symbol()":e.toString()}var V=function(){return"Promise{…}"};try{var H=process.binding("util"),W=H.getPromiseDetails,K=H.kPending,J=H.kRejected;Array.isArray(W(Promise.resolve()))&&(V=function(e,t){var r=n(W(e),2),o=r[0],i=r[1];return o===K?"Promise{<pending>}":"Promise".concat(o===J?"!":"","{").concat(t.inspect(i,t),"}")})}catch(e){}var G=V;function X(e,t){var r=Object.getOwnPropertyNames(e),n=Object.getOwnPropertySymbols?Object.getOwnPropertySymbols(e):[];if(0===r.length&&0===n.length)return"{}";if(t.truncate-=4,t.seen=t.seen||[],t.seen.indexOf(e)>=0)return"[Circular]";t.seen.push(e);var o=y(r.map((function(t){return[t,e[t]]})),t,g),i=y(n.map((function(t){return[t,e[t]]})),t,g);t.seen.pop();var a="";return o&&i&&(a=", "),"{ ".concat(o).concat(a).concat(i," }")}var Q=!("undefined"==typeof Symbol||!Symbol.toStringTag)&&Symbol.toStringTag;function Y(e,t){var r="";return Q&&Q in e&&(r=e[Q]),(r=r||E(e.constructor))&&"_class"!==r||(r="<Anonymous Class>"),t.truncate-=r.length,"".concat(r).concat(X(e,t))}function Z(e,t){return 0===e.length?"Arguments[]":(t.truncate-=13,"Arguments[ ".concat(y(e,t)," ]"))}var ee=["stack","line","column","name","message","fileName","lineNumber","columnNumber","number","description"];function te(e,t){var r=Object.getOwnPropertyNames(e).filter((function(e){return-1===ee.indexOf(e)})),n=e.name;t.truncate-=n.length;var o="";"string"==typeof e.message?o=d(e.message,t.truncate):r.unshift("message"),o=o?": ".concat(o):"",t.truncate-=o.length+5;var i=y(r.map((function(t){return[t,e[t]]})),t,g);return"".concat(n).concat(o).concat(i?" { ".concat(i," }"):"")}function re(e,t){var r=n(e,2),o=r[0],i=r[1];return t.truncate-=3,i?"".concat(t.stylize(o,"yellow"),"=").concat(t.stylize('"'.concat(i,'"'),"string")):"".concat(t.stylize(o,"yellow"))}function ne(e,t){return y(e,t,oe,"\n")}function oe(e,t){var r=e.getAttributeNames(),n=e.tagName.toLowerCase(),o=t.stylize("<".concat(n),"special"),i=t.stylize(">","special"),a=t.stylize("</".concat(n,">"),"special");t.truncate-=2*n.length+5;var s="";r.length>0&&(s+=" ",s+=y(r.map((function(t){return[t,e.getAttribute(t)]})),t,re," ")),t.truncate-=s.length;var c=t.truncate,u=ne(e.children,t);return u&&u.length>c&&(u="".concat(p,"(").concat(e.children.length,")")),"".concat(o).concat(s).concat(i).concat(u).concat(a)}var ie="function"==typeof Symbol&&"function"==typeof Symbol.for?Symbol.for("chai/inspect"):"@@chai/inspect",ae=!1;try{var se=r(1786);ae=!!se.inspect&&se.inspect.custom}catch(e){ae=!1}function ce(){this.key="chai/loupe__"+Math.random()+Date.now()}ce.prototype={get:function(e){return e[this.key]},has:function(e){return this.key in e},set:function(e,t){Object.isExtensible(e)&&Object.defineProperty(e,this.key,{value:t,configurable:!0})}};var ue=new("function"==typeof WeakMap?WeakMap:ce),le={},pe={undefined:function(e,t){return t.stylize("undefined","undefined")},null:function(e,t){return t.stylize(null,"null")},boolean:function(e,t){return t.stylize(e,"boolean")},Boolean:function(e,t){return t.stylize(e,"boolean")},number:C,Number:C,bigint:D,BigInt:D,string:q,String:q,function:P,Function:P,symbol:$,Symbol:$,Array:m,Date:j,Map:M,Set:B,RegExp:I,Promise:G,WeakSet:function(e,t){return t.stylize("WeakSet{…}","special")},WeakMap:function(e,t){return t.stylize("WeakMap{…}","special")},Arguments:Z,Int8Array:S,Uint8Array:S,Uint8ClampedArray:S,Int16Array:S,Uint16Array:S,Int32Array:S,Uint32Array:S,Float32Array:S,Float64Array:S,Generator:function(){return""},DataView:function(){return""},ArrayBuffer:function(){return""},Error:te,HTMLCollection:ne,NodeList:ne},fe=function(e,t,r){return ie in e&&"function"==typeof e[ie]?e[ie](t):ae&&ae in e&&"function"==typeof e[ae]?e[ae](t.depth,t):"inspect"in e&&"function"==typeof e.inspect?e.inspect(t.depth,t):"constructor"in e&&ue.has(e.constructor)?ue.get(e.constructor)(e,t):le[r]?le[r](e,t):""},he=Object.prototype.toString;function de(e,r){(r=h(r)).inspect=de;var n=r.customInspect,o=null===e?"null":t(e);if("object"===o&&(o=he.call(e).slice(8,-1)),pe[o])return pe[o](e,r);if(n&&e){var i=fe(e,r,o);if(i)return"string"==typeof i?i:de(i,r)}var a=!!e&&Object.getPrototypeOf(e);return a===Object.prototype||null===a?X(e,r):e&&"function"==typeof HTMLElement&&e instanceof HTMLElement?oe(e,r):"constructor"in e?e.constructor!==Object?Y(e,r):X(e,r):e===Object(e)?X(e,r):r.stylize(String(e),o)}function ye(e,t){return!ue.has(e)&&(ue.set(e,t),!0)}function be(e,t){return!(e in le)&&(le[e]=t,!0)}var ge=ie;e.custom=ge,e.default=de,e.inspect=de,e.registerConstructor=ye,e.registerStringTag=be,Object.defineProperty(e,"__esModule",{value:!0})}(t)},7893:e=>{"use strict";function t(e,t){return null!=e&&t in Object(e)}function r(e){return e.replace(/([^\\])\[/g,"$1.[").match(/(\\\.|[^.]+?)+/g).map((function(e){if("constructor"===e||"__proto__"===e||"prototype"===e)return{};var t=/^\[(\d+)\]$/.exec(e);return t?{i:parseFloat(t[1])}:{p:e.replace(/\\([.[\]])/g,"$1")}}))}function n(e,t,r){var n=e,o=null;r=void 0===r?t.length:r;for(var i=0;i<r;i++){var a=t[i];n&&(n=void 0===a.p?n[a.i]:n[a.p],i===r-1&&(o=n))}return o}function o(e,o){var i=r(o),a=i[i.length-1],s={parent:i.length>1?n(e,i,i.length-1):e,name:a.p||a.i,value:n(e,i)};return s.exists=t(s.parent,s.name),s}e.exports={hasProperty:t,getPathInfo:o,getPathValue:function(e,t){return o(e,t).value},setPathValue:function(e,t,n){return function(e,t,r){for(var n=e,o=r.length,i=null,a=0;a<o;a++){var s=null,c=null;if(i=r[a],a===o-1)n[s=void 0===i.p?i.i:i.p]=t;else if(void 0!==i.p&&n[i.p])n=n[i.p];else if(void 0!==i.i&&n[i.i])n=n[i.i];else{var u=r[a+1];s=void 0===i.p?i.i:i.p,c=void 0===u.p?[]:{},n[s]=c,n=n[s]}}}(e,n,r(t)),e}}},6578:e=>{"use strict";e.exports=["Float32Array","Float64Array","Int8Array","Int16Array","Int32Array","Uint8Array","Uint8ClampedArray","Uint16Array","Uint32Array","BigInt64Array","BigUint64Array"]},6897:(e,t,r)=>{"use strict";var n=r(453),o=r(41),i=r(592)(),a=r(5795),s=r(9675),c=n("%Math.floor%");e.exports=function(e,t){if("function"!=typeof e)throw new s("`fn` is not a function");if("number"!=typeof t||t<0||t>4294967295||c(t)!==t)throw new s("`length` must be a positive 32-bit integer");var r=arguments.length>2&&!!arguments[2],n=!0,u=!0;if("length"in e&&a){var l=a(e,"length");l&&!l.configurable&&(n=!1),l&&!l.writable&&(u=!1)}return(n||u||!r)&&(i?o(e,"length",t,!0,!0):o(e,"length",t)),e}},5855:function(e){e.exports=function(){"use strict";var e="function"==typeof Promise,t=function(e){if("object"==typeof globalThis)return globalThis;Object.defineProperty(e,"typeDetectGlobalObject",{get:function(){return this},configurable:!0});var t=typeDetectGlobalObject;return delete e.typeDetectGlobalObject,t}(Object.prototype),r="undefined"!=typeof Symbol,n="undefined"!=typeof Map,o="undefined"!=typeof Set,i="undefined"!=typeof WeakMap,a="undefined"!=typeof WeakSet,s="undefined"!=typeof DataView,c=r&&void 0!==Symbol.iterator,u=r&&void 0!==Symbol.toStringTag,l=o&&"function"==typeof Set.prototype.entries,p=n&&"function"==typeof Map.prototype.entries,f=l&&Object.getPrototypeOf((new Set).entries()),h=p&&Object.getPrototypeOf((new Map).entries()),d=c&&"function"==typeof Array.prototype[Symbol.iterator],y=d&&Object.getPrototypeOf([][Symbol.iterator]()),b=c&&"function"==typeof String.prototype[Symbol.iterator],g=b&&Object.getPrototypeOf(""[Symbol.iterator]()),m=8,v=-1;function w(r){var c=typeof r;if("object"!==c)return c;if(null===r)return"null";if(r===t)return"global";if(Array.isArray(r)&&(!1===u||!(Symbol.toStringTag in r)))return"Array";if("object"==typeof window&&null!==window){if("object"==typeof window.location&&r===window.location)return"Location";if("object"==typeof window.document&&r===window.document)return"Document";if("object"==typeof window.navigator){if("object"==typeof window.navigator.mimeTypes&&r===window.navigator.mimeTypes)return"MimeTypeArray";if("object"==typeof window.navigator.plugins&&r===window.navigator.plugins)return"PluginArray"}if(("function"==typeof window.HTMLElement||"object"==typeof window.HTMLElement)&&r instanceof window.HTMLElement){if("BLOCKQUOTE"===r.tagName)return"HTMLQuoteElement";if("TD"===r.tagName)return"HTMLTableDataCellElement";if("TH"===r.tagName)return"HTMLTableHeaderCellElement"}}var l=u&&r[Symbol.toStringTag];if("string"==typeof l)return l;var p=Object.getPrototypeOf(r);return p===RegExp.prototype?"RegExp":p===Date.prototype?"Date":e&&p===Promise.prototype?"Promise":o&&p===Set.prototype?"Set":n&&p===Map.prototype?"Map":a&&p===WeakSet.prototype?"WeakSet":i&&p===WeakMap.prototype?"WeakMap":s&&p===DataView.prototype?"DataView":n&&p===h?"Map Iterator":o&&p===f?"Set Iterator":d&&p===y?"Array Iterator":b&&p===g?"String Iterator":null===p?"Object":Object.prototype.toString.call(r).slice(m,v)}return w}()},1135:e=>{e.exports=function(e){return e&&"object"==typeof e&&"function"==typeof e.copy&&"function"==typeof e.fill&&"function"==typeof e.readUInt8}},9032:(e,t,r)=>{"use strict";var n=r(7244),o=r(8184),i=r(5767),a=r(5680);function s(e){return e.call.bind(e)}var c="undefined"!=typeof BigInt,u="undefined"!=typeof Symbol,l=s(Object.prototype.toString),p=s(Number.prototype.valueOf),f=s(String.prototype.valueOf),h=s(Boolean.prototype.valueOf);if(c)var d=s(BigInt.prototype.valueOf);if(u)var y=s(Symbol.prototype.valueOf);function b(e,t){if("object"!=typeof e)return!1;try{return t(e),!0}catch(e){return!1}}function g(e){return"[object Map]"===l(e)}function m(e){return"[object Set]"===l(e)}function v(e){return"[object WeakMap]"===l(e)}function w(e){return"[object WeakSet]"===l(e)}function x(e){return"[object ArrayBuffer]"===l(e)}function O(e){return"undefined"!=typeof ArrayBuffer&&(x.working?x(e):e instanceof ArrayBuffer)}function E(e){return"[object DataView]"===l(e)}function A(e){return"undefined"!=typeof DataView&&(E.working?E(e):e instanceof DataView)}t.isArgumentsObject=n,t.isGeneratorFunction=o,t.isTypedArray=a,t.isPromise=function(e){return"undefined"!=typeof Promise&&e instanceof Promise||null!==e&&"object"==typeof e&&"function"==typeof e.then&&"function"==typeof e.catch},t.isArrayBufferView=function(e){return"undefined"!=typeof ArrayBuffer&&ArrayBuffer.isView?ArrayBuffer.isView(e):a(e)||A(e)},t.isUint8Array=function(e){return"Uint8Array"===i(e)},t.isUint8ClampedArray=function(e){return"Uint8ClampedArray"===i(e)},t.isUint16Array=function(e){return"Uint16Array"===i(e)},t.isUint32Array=function(e){return"Uint32Array"===i(e)},t.isInt8Array=function(e){return"Int8Array"===i(e)},t.isInt16Array=function(e){return"Int16Array"===i(e)},t.isInt32Array=function(e){return"Int32Array"===i(e)},t.isFloat32Array=function(e){return"Float32Array"===i(e)},t.isFloat64Array=function(e){return"Float64Array"===i(e)},t.isBigInt64Array=function(e){return"BigInt64Array"===i(e)},t.isBigUint64Array=function(e){return"BigUint64Array"===i(e)},g.working="undefined"!=typeof Map&&g(new Map),t.isMap=function(e){return"undefined"!=typeof Map&&(g.working?g(e):e instanceof Map)},m.working="undefined"!=typeof Set&&m(new Set),t.isSet=function(e){return"undefined"!=typeof Set&&(m.working?m(e):e instanceof Set)},v.working="undefined"!=typeof WeakMap&&v(new WeakMap),t.isWeakMap=function(e){return"undefined"!=typeof WeakMap&&(v.working?v(e):e instanceof WeakMap)},w.working="undefined"!=typeof WeakSet&&w(new WeakSet),t.isWeakSet=function(e){return w(e)},x.working="undefined"!=typeof ArrayBuffer&&x(new ArrayBuffer),t.isArrayBuffer=O,E.working="undefined"!=typeof ArrayBuffer&&"undefined"!=typeof DataView&&E(new DataView(new ArrayBuffer(1),0,1)),t.isDataView=A;var S="undefined"!=typeof SharedArrayBuffer?SharedArrayBuffer:void 0;function j(e){return"[object SharedArrayBuffer]"===l(e)}function P(e){return void 0!==S&&(void 0===j.working&&(j.working=j(new S)),j.working?j(e):e instanceof S)}function _(e){return b(e,p)}function k(e){return b(e,f)}function M(e){return b(e,h)}function N(e){return c&&b(e,d)}function C(e){return u&&b(e,y)}t.isSharedArrayBuffer=P,t.isAsyncFunction=function(e){return"[object AsyncFunction]"===l(e)},t.isMapIterator=function(e){return"[object Map Iterator]"===l(e)},t.isSetIterator=function(e){return"[object Set Iterator]"===l(e)},t.isGeneratorObject=function(e){return"[object Generator]"===l(e)},t.isWebAssemblyCompiledModule=function(e){return"[object WebAssembly.Module]"===l(e)},t.isNumberObject=_,t.isStringObject=k,t.isBooleanObject=M,t.isBigIntObject=N,t.isSymbolObject=C,t.isBoxedPrimitive=function(e){return _(e)||k(e)||M(e)||N(e)||C(e)},t.isAnyArrayBuffer=function(e){return"undefined"!=typeof Uint8Array&&(O(e)||P(e))},["isProxy","isExternal","isModuleNamespaceObject"].forEach((function(e){Object.defineProperty(t,e,{enumerable:!1,value:function(){throw new Error(e+" is not supported in userland")}})}))},537:(e,t,r)=>{var n=Object.getOwnPropertyDescriptors||function(e){for(var t=Object.keys(e),r={},n=0;n<t.length;n++)r[t[n]]=Object.getOwnPropertyDescriptor(e,t[n]);return r},o=/%[sdj%]/g;t.format=function(e){if(!m(e)){for(var t=[],r=0;r<arguments.length;r++)t.push(c(arguments[r]));return t.join(" ")}r=1;for(var n=arguments,i=n.length,a=String(e).replace(o,(function(e){if("%%"===e)return"%";if(r>=i)return e;switch(e){case"%s":return String(n[r++]);case"%d":return Number(n[r++]);case"%j":try{return JSON.stringify(n[r++])}catch(e){return"[Circular]"}default:return e}})),s=n[r];r<i;s=n[++r])b(s)||!x(s)?a+=" "+s:a+=" "+c(s);return a},t.deprecate=function(e,r){if("undefined"!=typeof process&&!0===process.noDeprecation)return e;if("undefined"==typeof process)return function(){return t.deprecate(e,r).apply(this,arguments)};var n=!1;return function(){if(!n){if(process.throwDeprecation)throw new Error(r);process.traceDeprecation?console.trace(r):console.error(r),n=!0}return e.apply(this,arguments)}};var i={},a=/^$/;if(process.env.NODE_DEBUG){var s=process.env.NODE_DEBUG;s=s.replace(/[|\\{}()[\]^$+?.]/g,"\\$&").replace(/\*/g,".*").replace(/,/g,"$|^").toUpperCase(),a=new RegExp("^"+s+"$","i")}function c(e,r){var n={seen:[],stylize:l};return arguments.length>=3&&(n.depth=arguments[2]),arguments.length>=4&&(n.colors=arguments[3]),y(r)?n.showHidden=r:r&&t._extend(n,r),v(n.showHidden)&&(n.showHidden=!1),v(n.depth)&&(n.depth=2),v(n.colors)&&(n.colors=!1),v(n.customInspect)&&(n.customInspect=!0),n.colors&&(n.stylize=u),p(n,e,n.depth)}function u(e,t){var r=c.styles[t];return r?"["+c.colors[r][0]+"m"+e+"["+c.colors[r][1]+"m":e}function l(e,t){return e}function p(e,r,n){if(e.customInspect&&r&&A(r.inspect)&&r.inspect!==t.inspect&&(!r.constructor||r.constructor.prototype!==r)){var o=r.inspect(n,e);return m(o)||(o=p(e,o,n)),o}var i=function(e,t){if(v(t))return e.stylize("undefined","undefined");if(m(t)){var r="'"+JSON.stringify(t).replace(/^"|"$/g,"").replace(/'/g,"\\'").replace(/\\"/g,'"')+"'";return e.stylize(r,"string")}if(g(t))return e.stylize(""+t,"number");if(y(t))return e.stylize(""+t,"boolean");if(b(t))return e.stylize("null","null")}(e,r);if(i)return i;var a=Object.keys(r),s=function(e){var t={};return e.forEach((function(e,r){t[e]=!0})),t}(a);if(e.showHidden&&(a=Object.getOwnPropertyNames(r)),E(r)&&(a.indexOf("message")>=0||a.indexOf("description")>=0))return f(r);if(0===a.length){if(A(r)){var c=r.name?": "+r.name:"";return e.stylize("[Function"+c+"]","special")}if(w(r))return e.stylize(RegExp.prototype.toString.call(r),"regexp");if(O(r))return e.stylize(Date.prototype.toString.call(r),"date");if(E(r))return f(r)}var u,l="",x=!1,S=["{","}"];(d(r)&&(x=!0,S=["[","]"]),A(r))&&(l=" [Function"+(r.name?": "+r.name:"")+"]");return w(r)&&(l=" "+RegExp.prototype.toString.call(r)),O(r)&&(l=" "+Date.prototype.toUTCString.call(r)),E(r)&&(l=" "+f(r)),0!==a.length||x&&0!=r.length?n<0?w(r)?e.stylize(RegExp.prototype.toString.call(r),"regexp"):e.stylize("[Object]","special"):(e.seen.push(r),u=x?function(e,t,r,n,o){for(var i=[],a=0,s=t.length;a<s;++a)_(t,String(a))?i.push(h(e,t,r,n,String(a),!0)):i.push("");return o.forEach((function(o){o.match(/^\d+$/)||i.push(h(e,t,r,n,o,!0))})),i}(e,r,n,s,a):a.map((function(t){return h(e,r,n,s,t,x)})),e.seen.pop(),function(e,t,r){var n=e.reduce((function(e,t){return t.indexOf("\n")>=0&&0,e+t.replace(/\u001b\[\d\d?m/g,"").length+1}),0);if(n>60)return r[0]+(""===t?"":t+"\n ")+" "+e.join(",\n  ")+" "+r[1];return r[0]+t+" "+e.join(", ")+" "+r[1]}(u,l,S)):S[0]+l+S[1]}function f(e){return"["+Error.prototype.toString.call(e)+"]"}function h(e,t,r,n,o,i){var a,s,c;if((c=Object.getOwnPropertyDescriptor(t,o)||{value:t[o]}).get?s=c.set?e.stylize("[Getter/Setter]","special"):e.stylize("[Getter]","special"):c.set&&(s=e.stylize("[Setter]","special")),_(n,o)||(a="["+o+"]"),s||(e.seen.indexOf(c.value)<0?(s=b(r)?p(e,c.value,null):p(e,c.value,r-1)).indexOf("\n")>-1&&(s=i?s.split("\n").map((function(e){return"  "+e})).join("\n").slice(2):"\n"+s.split("\n").map((function(e){return"   "+e})).join("\n")):s=e.stylize("[Circular]","special")),v(a)){if(i&&o.match(/^\d+$/))return s;(a=JSON.stringify(""+o)).match(/^"([a-zA-Z_][a-zA-Z_0-9]*)"$/)?(a=a.slice(1,-1),a=e.stylize(a,"name")):(a=a.replace(/'/g,"\\'").replace(/\\"/g,'"').replace(/(^"|"$)/g,"'"),a=e.stylize(a,"string"))}return a+": "+s}function d(e){return Array.isArray(e)}function y(e){return"boolean"==typeof e}function b(e){return null===e}function g(e){return"number"==typeof e}function m(e){return"string"==typeof e}function v(e){return void 0===e}function w(e){return x(e)&&"[object RegExp]"===S(e)}function x(e){return"object"==typeof e&&null!==e}function O(e){return x(e)&&"[object Date]"===S(e)}function E(e){return x(e)&&("[object Error]"===S(e)||e instanceof Error)}function A(e){return"function"==typeof e}function S(e){return Object.prototype.toString.call(e)}function j(e){return e<10?"0"+e.toString(10):e.toString(10)}t.debuglog=function(e){if(e=e.toUpperCase(),!i[e])if(a.test(e)){var r=process.pid;i[e]=function(){var n=t.format.apply(t,arguments);console.error("%s %d: %s",e,r,n)}}else i[e]=function(){};return i[e]},t.inspect=c,c.colors={bold:[1,22],italic:[3,23],underline:[4,24],inverse:[7,27],white:[37,39],grey:[90,39],black:[30,39],blue:[34,39],cyan:[36,39],green:[32,39],magenta:[35,39],red:[31,39],yellow:[33,39]},c.styles={special:"cyan",number:"yellow",boolean:"yellow",undefined:"grey",null:"bold",string:"green",date:"magenta",regexp:"red"},t.types=r(9032),t.isArray=d,t.isBoolean=y,t.isNull=b,t.isNullOrUndefined=function(e){return null==e},t.isNumber=g,t.isString=m,t.isSymbol=function(e){return"symbol"==typeof e},t.isUndefined=v,t.isRegExp=w,t.types.isRegExp=w,t.isObject=x,t.isDate=O,t.types.isDate=O,t.isError=E,t.types.isNativeError=E,t.isFunction=A,t.isPrimitive=function(e){return null===e||"boolean"==typeof e||"number"==typeof e||"string"==typeof e||"symbol"==typeof e||void 0===e},t.isBuffer=r(1135);var P=["Jan","Feb","Mar","Apr","May","Jun","Jul","Aug","Sep","Oct","Nov","Dec"];function _(e,t){return Object.prototype.hasOwnProperty.call(e,t)}t.log=function(){var e,r;console.log("%s - %s",(e=new Date,r=[j(e.getHours()),j(e.getMinutes()),j(e.getSeconds())].join(":"),[e.getDate(),P[e.getMonth()],r].join(" ")),t.format.apply(t,arguments))},t.inherits=r(6698),t._extend=function(e,t){if(!t||!x(t))return e;for(var r=Object.keys(t),n=r.length;n--;)e[r[n]]=t[r[n]];return e};var k="undefined"!=typeof Symbol?Symbol("util.promisify.custom"):void 0;function M(e,t){if(!e){var r=new Error("Promise was rejected with a falsy value");r.reason=e,e=r}return t(e)}t.promisify=function(e){if("function"!=typeof e)throw new TypeError('The "original" argument must be of type Function');if(k&&e[k]){var t;if("function"!=typeof(t=e[k]))throw new TypeError('The "util.promisify.custom" argument must be of type Function');return Object.defineProperty(t,k,{value:t,enumerable:!1,writable:!1,configurable:!0}),t}function t(){for(var t,r,n=new Promise((function(e,n){t=e,r=n})),o=[],i=0;i<arguments.length;i++)o.push(arguments[i]);o.push((function(e,n){e?r(e):t(n)}));try{e.apply(this,o)}catch(e){r(e)}return n}return Object.setPrototypeOf(t,Object.getPrototypeOf(e)),k&&Object.defineProperty(t,k,{value:t,enumerable:!1,writable:!1,configurable:!0}),Object.defineProperties(t,n(e))},t.promisify.custom=k,t.callbackify=function(e){if("function"!=typeof e)throw new TypeError('The "original" argument must be of type Function');function t(){for(var t=[],r=0;r<arguments.length;r++)t.push(arguments[r]);var n=t.pop();if("function"!=typeof n)throw new TypeError("The last argument must be of type Function");var o=this,i=function(){return n.apply(o,arguments)};e.apply(this,t).then((function(e){process.nextTick(i.bind(null,null,e))}),(function(e){process.nextTick(M.bind(null,e,i))}))}return Object.setPrototypeOf(t,Object.getPrototypeOf(e)),Object.defineProperties(t,n(e)),t}},5767:(e,t,r)=>{"use strict";var n=r(2682),o=r(9209),i=r(487),a=r(8075),s=r(5795),c=a("Object.prototype.toString"),u=r(9092)(),l="undefined"==typeof globalThis?r.g:globalThis,p=o(),f=a("String.prototype.slice"),h=Object.getPrototypeOf,d=a("Array.prototype.indexOf",!0)||function(e,t){for(var r=0;r<e.length;r+=1)if(e[r]===t)return r;return-1},y={__proto__:null};n(p,u&&s&&h?function(e){var t=new l[e];if(Symbol.toStringTag in t){var r=h(t),n=s(r,Symbol.toStringTag);if(!n){var o=h(r);n=s(o,Symbol.toStringTag)}y["$"+e]=i(n.get)}}:function(e){var t=new l[e],r=t.slice||t.set;r&&(y["$"+e]=i(r))});e.exports=function(e){if(!e||"object"!=typeof e)return!1;if(!u){var t=f(c(e),8,-1);return d(p,t)>-1?t:"Object"===t&&function(e){var t=!1;return n(y,(function(r,n){if(!t)try{r(e),t=f(n,1)}catch(e){}})),t}(e)}return s?function(e){var t=!1;return n(y,(function(r,n){if(!t)try{"$"+r(e)===n&&(t=f(n,1))}catch(e){}})),t}(e):null}},1786:()=>{},9209:(e,t,r)=>{"use strict";var n=r(6578),o="undefined"==typeof globalThis?r.g:globalThis;e.exports=function(){for(var e=[],t=0;t<n.length;t++)"function"==typeof o[n[t]]&&(e[e.length]=n[t]);return e}},274:(e,t,r)=>{"use strict";r.r(t),r.d(t,{JSONPath:()=>y});class n{static get version(){return"1.4.0"}static toString(){return"JavaScript Expression Parser (JSEP) v"+n.version}static addUnaryOp(e){return n.max_unop_len=Math.max(e.length,n.max_unop_len),n.unary_ops[e]=1,n}static addBinaryOp(e,t,r){return n.max_binop_len=Math.max(e.length,n.max_binop_len),n.binary_ops[e]=t,r?n.right_associative.add(e):n.right_associative.delete(e),n}static addIdentifierChar(e){return n.additional_identifier_chars.add(e),n}static addLiteral(e,t){return n.literals[e]=t,n}static removeUnaryOp(e){return delete n.unary_ops[e],e.length===n.max_unop_len&&(n.max_unop_len=n.getMaxKeyLen(n.unary_ops)),n}static removeAllUnaryOps(){return n.unary_ops={},n.max_unop_len=0,n}static removeIdentifierChar(e){return n.additional_identifier_chars.delete(e),n}static removeBinaryOp(e){return delete n.binary_ops[e],e.length===n.max_binop_len&&(n.max_binop_len=n.getMaxKeyLen(n.binary_ops)),n.right_associative.delete(e),n}static removeAllBinaryOps(){return n.binary_ops={},n.max_binop_len=0,n}static removeLiteral(e){return delete n.literals[e],n}static removeAllLiterals(){return n.literals={},n}get char(){return this.expr.charAt(this.index)}get code(){return this.expr.charCodeAt(this.index)}constructor(e){this.expr=e,this.index=0}static parse(e){return new n(e).parse()}static getMaxKeyLen(e){return Math.max(0,...Object.keys(e).map((e=>e.length)))}static isDecimalDigit(e){return e>=48&&e<=57}static binaryPrecedence(e){return n.binary_ops[e]||0}static isIdentifierStart(e){return e>=65&&e<=90||e>=97&&e<=122||e>=128&&!n.binary_ops[String.fromCharCode(e)]||n.additional_identifier_chars.has(String.fromCharCode(e))}static isIdentifierPart(e){return n.isIdentifierStart(e)||n.isDecimalDigit(e)}throwError(e){const t=new Error(e+" at character "+this.index);throw t.index=this.index,t.description=e,t}runHook(e,t){if(n.hooks[e]){const r={context:this,node:t};return n.hooks.run(e,r),r.node}return t}searchHook(e){if(n.hooks[e]){const t={context:this};return n.hooks[e].find((function(e){return e.call(t.context,t),t.node})),t.node}}gobbleSpaces(){let e=this.code;for(;e===n.SPACE_CODE||e===n.TAB_CODE||e===n.LF_CODE||e===n.CR_CODE;)e=this.expr.charCodeAt(++this.index);this.runHook("gobble-spaces")}parse(){this.runHook("before-all");const e=this.gobbleExpressions(),t=1===e.length?e[0]:{type:n.COMPOUND,body:e};return this.runHook("after-all",t)}gobbleExpressions(e){let t,r,o=[];for(;this.index<this.expr.length;)if(t=this.code,t===n.SEMCOL_CODE||t===n.COMMA_CODE)this.index++;else if(r=this.gobbleExpression())o.push(r);else if(this.index<this.expr.length){if(t===e)break;this.throwError('Unexpected "'+this.char+'"')}return o}gobbleExpression(){const e=this.searchHook("gobble-expression")||this.gobbleBinaryExpression();return this.gobbleSpaces(),this.runHook("after-expression",e)}gobbleBinaryOp(){this.gobbleSpaces();let e=this.expr.substr(this.index,n.max_binop_len),t=e.length;for(;t>0;){if(n.binary_ops.hasOwnProperty(e)&&(!n.isIdentifierStart(this.code)||this.index+e.length<this.expr.length&&!n.isIdentifierPart(this.expr.charCodeAt(this.index+e.length))))return this.index+=t,e;e=e.substr(0,--t)}return!1}gobbleBinaryExpression(){let e,t,r,o,i,a,s,c,u;if(a=this.gobbleToken(),!a)return a;if(t=this.gobbleBinaryOp(),!t)return a;for(i={value:t,prec:n.binaryPrecedence(t),right_a:n.right_associative.has(t)},s=this.gobbleToken(),s||this.throwError("Expected expression after "+t),o=[a,i,s];t=this.gobbleBinaryOp();){if(r=n.binaryPrecedence(t),0===r){this.index-=t.length;break}i={value:t,prec:r,right_a:n.right_associative.has(t)},u=t;const c=e=>i.right_a&&e.right_a?r>e.prec:r<=e.prec;for(;o.length>2&&c(o[o.length-2]);)s=o.pop(),t=o.pop().value,a=o.pop(),e={type:n.BINARY_EXP,operator:t,left:a,right:s},o.push(e);e=this.gobbleToken(),e||this.throwError("Expected expression after "+u),o.push(i,e)}for(c=o.length-1,e=o[c];c>1;)e={type:n.BINARY_EXP,operator:o[c-1].value,left:o[c-2],right:e},c-=2;return e}gobbleToken(){let e,t,r,o;if(this.gobbleSpaces(),o=this.searchHook("gobble-token"),o)return this.runHook("after-token",o);if(e=this.code,n.isDecimalDigit(e)||e===n.PERIOD_CODE)return this.gobbleNumericLiteral();if(e===n.SQUOTE_CODE||e===n.DQUOTE_CODE)o=this.gobbleStringLiteral();else if(e===n.OBRACK_CODE)o=this.gobbleArray();else{for(t=this.expr.substr(this.index,n.max_unop_len),r=t.length;r>0;){if(n.unary_ops.hasOwnProperty(t)&&(!n.isIdentifierStart(this.code)||this.index+t.length<this.expr.length&&!n.isIdentifierPart(this.expr.charCodeAt(this.index+t.length)))){this.index+=r;const e=this.gobbleToken();return e||this.throwError("missing unaryOp argument"),this.runHook("after-token",{type:n.UNARY_EXP,operator:t,argument:e,prefix:!0})}t=t.substr(0,--r)}n.isIdentifierStart(e)?(o=this.gobbleIdentifier(),n.literals.hasOwnProperty(o.name)?o={type:n.LITERAL,value:n.literals[o.name],raw:o.name}:o.name===n.this_str&&(o={type:n.THIS_EXP})):e===n.OPAREN_CODE&&(o=this.gobbleGroup())}return o?(o=this.gobbleTokenProperty(o),this.runHook("after-token",o)):this.runHook("after-token",!1)}gobbleTokenProperty(e){this.gobbleSpaces();let t=this.code;for(;t===n.PERIOD_CODE||t===n.OBRACK_CODE||t===n.OPAREN_CODE||t===n.QUMARK_CODE;){let r;if(t===n.QUMARK_CODE){if(this.expr.charCodeAt(this.index+1)!==n.PERIOD_CODE)break;r=!0,this.index+=2,this.gobbleSpaces(),t=this.code}this.index++,t===n.OBRACK_CODE?((e={type:n.MEMBER_EXP,computed:!0,object:e,property:this.gobbleExpression()}).property||this.throwError('Unexpected "'+this.char+'"'),this.gobbleSpaces(),t=this.code,t!==n.CBRACK_CODE&&this.throwError("Unclosed ["),this.index++):t===n.OPAREN_CODE?e={type:n.CALL_EXP,arguments:this.gobbleArguments(n.CPAREN_CODE),callee:e}:(t===n.PERIOD_CODE||r)&&(r&&this.index--,this.gobbleSpaces(),e={type:n.MEMBER_EXP,computed:!1,object:e,property:this.gobbleIdentifier()}),r&&(e.optional=!0),this.gobbleSpaces(),t=this.code}return e}gobbleNumericLiteral(){let e,t,r="";for(;n.isDecimalDigit(this.code);)r+=this.expr.charAt(this.index++);if(this.code===n.PERIOD_CODE)for(r+=this.expr.charAt(this.index++);n.isDecimalDigit(this.code);)r+=this.expr.charAt(this.index++);if(e=this.char,"e"===e||"E"===e){for(r+=this.expr.charAt(this.index++),e=this.char,"+"!==e&&"-"!==e||(r+=this.expr.charAt(this.index++));n.isDecimalDigit(this.code);)r+=this.expr.charAt(this.index++);n.isDecimalDigit(this.expr.charCodeAt(this.index-1))||this.throwError("Expected exponent ("+r+this.char+")")}return t=this.code,n.isIdentifierStart(t)?this.throwError("Variable names cannot start with a number ("+r+this.char+")"):(t===n.PERIOD_CODE||1===r.length&&r.charCodeAt(0)===n.PERIOD_CODE)&&this.throwError("Unexpected period"),{type:n.LITERAL,value:parseFloat(r),raw:r}}gobbleStringLiteral(){let e="";const t=this.index,r=this.expr.charAt(this.index++);let o=!1;for(;this.index<this.expr.length;){let t=this.expr.charAt(this.index++);if(t===r){o=!0;break}if("\\"===t)switch(t=this.expr.charAt(this.index++),t){case"n":e+="\n";break;case"r":e+="\r";break;case"t":e+="\t";break;case"b":e+="\b";break;case"f":e+="\f";break;case"v":e+="\v";break;default:e+=t}else e+=t}return o||this.throwError('Unclosed quote after "'+e+'"'),{type:n.LITERAL,value:e,raw:this.expr.substring(t,this.index)}}gobbleIdentifier(){let e=this.code,t=this.index;for(n.isIdentifierStart(e)?this.index++:this.throwError("Unexpected "+this.char);this.index<this.expr.length&&(e=this.code,n.isIdentifierPart(e));)this.index++;return{type:n.IDENTIFIER,name:this.expr.slice(t,this.index)}}gobbleArguments(e){const t=[];let r=!1,o=0;for(;this.index<this.expr.length;){this.gobbleSpaces();let i=this.code;if(i===e){r=!0,this.index++,e===n.CPAREN_CODE&&o&&o>=t.length&&this.throwError("Unexpected token "+String.fromCharCode(e));break}if(i===n.COMMA_CODE){if(this.index++,o++,o!==t.length)if(e===n.CPAREN_CODE)this.throwError("Unexpected token ,");else if(e===n.CBRACK_CODE)for(let e=t.length;e<o;e++)t.push(null)}else if(t.length!==o&&0!==o)this.throwError("Expected comma");else{const e=this.gobbleExpression();e&&e.type!==n.COMPOUND||this.throwError("Expected comma"),t.push(e)}}return r||this.throwError("Expected "+String.fromCharCode(e)),t}gobbleGroup(){this.index++;let e=this.gobbleExpressions(n.CPAREN_CODE);if(this.code===n.CPAREN_CODE)return this.index++,1===e.length?e[0]:!!e.length&&{type:n.SEQUENCE_EXP,expressions:e};this.throwError("Unclosed (")}gobbleArray(){return this.index++,{type:n.ARRAY_EXP,elements:this.gobbleArguments(n.CBRACK_CODE)}}}const o=new class{add(e,t,r){if("string"!=typeof arguments[0])for(let e in arguments[0])this.add(e,arguments[0][e],arguments[1]);else(Array.isArray(e)?e:[e]).forEach((function(e){this[e]=this[e]||[],t&&this[e][r?"unshift":"push"](t)}),this)}run(e,t){this[e]=this[e]||[],this[e].forEach((function(e){e.call(t&&t.context?t.context:t,t)}))}};Object.assign(n,{hooks:o,plugins:new class{constructor(e){this.jsep=e,this.registered={}}register(){for(var e=arguments.length,t=new Array(e),r=0;r<e;r++)t[r]=arguments[r];t.forEach((e=>{if("object"!=typeof e||!e.name||!e.init)throw new Error("Invalid JSEP plugin format");this.registered[e.name]||(e.init(this.jsep),this.registered[e.name]=e)}))}}(n),COMPOUND:"Compound",SEQUENCE_EXP:"SequenceExpression",IDENTIFIER:"Identifier",MEMBER_EXP:"MemberExpression",LITERAL:"Literal",THIS_EXP:"ThisExpression",CALL_EXP:"CallExpression",UNARY_EXP:"UnaryExpression",BINARY_EXP:"BinaryExpression",ARRAY_EXP:"ArrayExpression",TAB_CODE:9,LF_CODE:10,CR_CODE:13,SPACE_CODE:32,PERIOD_CODE:46,COMMA_CODE:44,SQUOTE_CODE:39,DQUOTE_CODE:34,OPAREN_CODE:40,CPAREN_CODE:41,OBRACK_CODE:91,CBRACK_CODE:93,QUMARK_CODE:63,SEMCOL_CODE:59,COLON_CODE:58,unary_ops:{"-":1,"!":1,"~":1,"+":1},binary_ops:{"||":1,"??":1,"&&":2,"|":3,"^":4,"&":5,"==":6,"!=":6,"===":6,"!==":6,"<":7,">":7,"<=":7,">=":7,"<<":8,">>":8,">>>":8,"+":9,"-":9,"*":10,"/":10,"%":10,"**":11},right_associative:new Set(["**"]),additional_identifier_chars:new Set(["$","_"]),literals:{true:!0,false:!1,null:null},this_str:"this"}),n.max_unop_len=n.getMaxKeyLen(n.unary_ops),n.max_binop_len=n.getMaxKeyLen(n.binary_ops);const i=e=>new n(e).parse(),a=Object.getOwnPropertyNames(class{});Object.getOwnPropertyNames(n).filter((e=>!a.includes(e)&&void 0===i[e])).forEach((e=>{i[e]=n[e]})),i.Jsep=n;var s={name:"ternary",init(e){e.hooks.add("after-expression",(function(t){if(t.node&&this.code===e.QUMARK_CODE){this.index++;const r=t.node,n=this.gobbleExpression();if(n||this.throwError("Expected expression"),this.gobbleSpaces(),this.code===e.COLON_CODE){this.index++;const o=this.gobbleExpression();if(o||this.throwError("Expected expression"),t.node={type:"ConditionalExpression",test:r,consequent:n,alternate:o},r.operator&&e.binary_ops[r.operator]<=.9){let n=r;for(;n.right.operator&&e.binary_ops[n.right.operator]<=.9;)n=n.right;t.node.test=n.right,n.right=t.node,t.node=r}}else this.throwError("Expected :")}}))}};i.plugins.register(s);var c={name:"regex",init(e){e.hooks.add("gobble-token",(function(t){if(47===this.code){const r=++this.index;let n=!1;for(;this.index<this.expr.length;){if(47===this.code&&!n){const n=this.expr.slice(r,this.index);let o,i="";for(;++this.index<this.expr.length;){const e=this.code;if(!(e>=97&&e<=122||e>=65&&e<=90||e>=48&&e<=57))break;i+=this.char}try{o=new RegExp(n,i)}catch(e){this.throwError(e.message)}return t.node={type:e.LITERAL,value:o,raw:this.expr.slice(r-1,this.index)},t.node=this.gobbleTokenProperty(t.node),t.node}this.code===e.OBRACK_CODE?n=!0:n&&this.code===e.CBRACK_CODE&&(n=!1),this.index+=92===this.code?2:1}this.throwError("Unclosed Regex")}}))}};const u={name:"assignment",assignmentOperators:new Set(["=","*=","**=","/=","%=","+=","-=","<<=",">>=",">>>=","&=","^=","|=","||=","&&=","??="]),updateOperators:[43,45],assignmentPrecedence:.9,init(e){const t=[e.IDENTIFIER,e.MEMBER_EXP];function r(e){u.assignmentOperators.has(e.operator)?(e.type="AssignmentExpression",r(e.left),r(e.right)):e.operator||Object.values(e).forEach((e=>{e&&"object"==typeof e&&r(e)}))}u.assignmentOperators.forEach((t=>e.addBinaryOp(t,u.assignmentPrecedence,!0))),e.hooks.add("gobble-token",(function(e){const r=this.code;u.updateOperators.some((e=>e===r&&e===this.expr.charCodeAt(this.index+1)))&&(this.index+=2,e.node={type:"UpdateExpression",operator:43===r?"++":"--",argument:this.gobbleTokenProperty(this.gobbleIdentifier()),prefix:!0},e.node.argument&&t.includes(e.node.argument.type)||this.throwError(`Unexpected ${e.node.operator}`))})),e.hooks.add("after-token",(function(e){if(e.node){const r=this.code;u.updateOperators.some((e=>e===r&&e===this.expr.charCodeAt(this.index+1)))&&(t.includes(e.node.type)||this.throwError(`Unexpected ${e.node.operator}`),this.index+=2,e.node={type:"UpdateExpression",operator:43===r?"++":"--",argument:e.node,prefix:!1})}})),e.hooks.add("after-expression",(function(e){e.node&&r(e.node)}))}};i.plugins.register(c,u),i.addUnaryOp("typeof"),i.addLiteral("null",null),i.addLiteral("undefined",void 0);const l=new Set(["constructor","__proto__","__defineGetter__","__defineSetter__"]),p={evalAst(e,t){switch(e.type){case"BinaryExpression":case"LogicalExpression":return p.evalBinaryExpression(e,t);case"Compound":return p.evalCompound(e,t);case"ConditionalExpression":return p.evalConditionalExpression(e,t);case"Identifier":return p.evalIdentifier(e,t);case"Literal":return p.evalLiteral(e,t);case"MemberExpression":return p.evalMemberExpression(e,t);case"UnaryExpression":return p.evalUnaryExpression(e,t);case"ArrayExpression":return p.evalArrayExpression(e,t);case"CallExpression":return p.evalCallExpression(e,t);case"AssignmentExpression":return p.evalAssignmentExpression(e,t);default:throw SyntaxError("Unexpected expression",e)}},evalBinaryExpression:(e,t)=>({"||":(e,t)=>e||t(),"&&":(e,t)=>e&&t(),"|":(e,t)=>e|t(),"^":(e,t)=>e^t(),"&":(e,t)=>e&t(),"==":(e,t)=>e==t(),"!=":(e,t)=>e!=t(),"===":(e,t)=>e===t(),"!==":(e,t)=>e!==t(),"<":(e,t)=>e<t(),">":(e,t)=>e>t(),"<=":(e,t)=>e<=t(),">=":(e,t)=>e>=t(),"<<":(e,t)=>e<<t(),">>":(e,t)=>e>>t(),">>>":(e,t)=>e>>>t(),"+":(e,t)=>e+t(),"-":(e,t)=>e-t(),"*":(e,t)=>e*t(),"/":(e,t)=>e/t(),"%":(e,t)=>e%t()}[e.operator](p.evalAst(e.left,t),(()=>p.evalAst(e.right,t)))),evalCompound(e,t){let r;for(let n=0;n<e.body.length;n++){"Identifier"===e.body[n].type&&["var","let","const"].includes(e.body[n].name)&&e.body[n+1]&&"AssignmentExpression"===e.body[n+1].type&&(n+=1);const o=e.body[n];r=p.evalAst(o,t)}return r},evalConditionalExpression:(e,t)=>p.evalAst(e.test,t)?p.evalAst(e.consequent,t):p.evalAst(e.alternate,t),evalIdentifier(e,t){if(Object.hasOwn(t,e.name))return t[e.name];throw ReferenceError(`${e.name} is not defined`)},evalLiteral:e=>e.value,evalMemberExpression(e,t){const r=String(e.computed?p.evalAst(e.property):e.property.name),n=p.evalAst(e.object,t);if(null==n)throw TypeError(`Cannot read properties of ${n} (reading '${r}')`);if(!Object.hasOwn(n,r)&&l.has(r))throw TypeError(`Cannot read properties of ${n} (reading '${r}')`);const o=n[r];return"function"==typeof o?o.bind(n):o},evalUnaryExpression:(e,t)=>({"-":e=>-p.evalAst(e,t),"!":e=>!p.evalAst(e,t),"~":e=>~p.evalAst(e,t),"+":e=>+p.evalAst(e,t),typeof:e=>typeof p.evalAst(e,t)}[e.operator](e.argument)),evalArrayExpression:(e,t)=>e.elements.map((e=>p.evalAst(e,t))),evalCallExpression(e,t){const r=e.arguments.map((e=>p.evalAst(e,t)));return p.evalAst(e.callee,t)(...r)},evalAssignmentExpression(e,t){if("Identifier"!==e.left.type)throw SyntaxError("Invalid left-hand side in assignment");const r=e.left.name,n=p.evalAst(e.right,t);return t[r]=n,t[r]}};function f(e,t){return(e=e.slice()).push(t),e}function h(e,t){return(t=t.slice()).unshift(e),t}class d extends Error{constructor(e){super('JSONPath should not be called with "new" (it prevents return of (unwrapped) scalar values)'),this.avoidNew=!0,this.value=e,this.name="NewError"}}function y(e,t,r,n,o){if(!(this instanceof y))try{return new y(e,t,r,n,o)}catch(e){if(!e.avoidNew)throw e;return e.value}"string"==typeof e&&(o=n,n=r,r=t,t=e,e=null);const i=e&&"object"==typeof e;if(e=e||{},this.json=e.json||r,this.path=e.path||t,this.resultType=e.resultType||"value",this.flatten=e.flatten||!1,this.wrap=!Object.hasOwn(e,"wrap")||e.wrap,this.sandbox=e.sandbox||{},this.eval=void 0===e.eval?"safe":e.eval,this.ignoreEvalErrors=void 0!==e.ignoreEvalErrors&&e.ignoreEvalErrors,this.parent=e.parent||null,this.parentProperty=e.parentProperty||null,this.callback=e.callback||n||null,this.otherTypeCallback=e.otherTypeCallback||o||function(){throw new TypeError("You must supply an otherTypeCallback callback option with the @other() operator.")},!1!==e.autostart){const n={path:i?e.path:t};i?"json"in e&&(n.json=e.json):n.json=r;const o=this.evaluate(n);if(!o||"object"!=typeof o)throw new d(o);return o}}y.prototype.evaluate=function(e,t,r,n){let o=this.parent,i=this.parentProperty,{flatten:a,wrap:s}=this;if(this.currResultType=this.resultType,this.currEval=this.eval,this.currSandbox=this.sandbox,r=r||this.callback,this.currOtherTypeCallback=n||this.otherTypeCallback,t=t||this.json,(e=e||this.path)&&"object"==typeof e&&!Array.isArray(e)){if(!e.path&&""!==e.path)throw new TypeError('You must supply a "path" property when providing an object argument to JSONPath.evaluate().');if(!Object.hasOwn(e,"json"))throw new TypeError('You must supply a "json" property when providing an object argument to JSONPath.evaluate().');({json:t}=e),a=Object.hasOwn(e,"flatten")?e.flatten:a,this.currResultType=Object.hasOwn(e,"resultType")?e.resultType:this.currResultType,this.currSandbox=Object.hasOwn(e,"sandbox")?e.sandbox:this.currSandbox,s=Object.hasOwn(e,"wrap")?e.wrap:s,this.currEval=Object.hasOwn(e,"eval")?e.eval:this.currEval,r=Object.hasOwn(e,"callback")?e.callback:r,this.currOtherTypeCallback=Object.hasOwn(e,"otherTypeCallback")?e.otherTypeCallback:this.currOtherTypeCallback,o=Object.hasOwn(e,"parent")?e.parent:o,i=Object.hasOwn(e,"parentProperty")?e.parentProperty:i,e=e.path}if(o=o||null,i=i||null,Array.isArray(e)&&(e=y.toPathString(e)),!e&&""!==e||!t)return;const c=y.toPathArray(e);"$"===c[0]&&c.length>1&&c.shift(),this._hasParentSelector=null;const u=this._trace(c,t,["$"],o,i,r).filter((function(e){return e&&!e.isParentSelector}));return u.length?s||1!==u.length||u[0].hasArrExpr?u.reduce(((e,t)=>{const r=this._getPreferredOutput(t);return a&&Array.isArray(r)?e=e.concat(r):e.push(r),e}),[]):this._getPreferredOutput(u[0]):s?[]:void 0},y.prototype._getPreferredOutput=function(e){const t=this.currResultType;switch(t){case"all":{const t=Array.isArray(e.path)?e.path:y.toPathArray(e.path);return e.pointer=y.toPointer(t),e.path="string"==typeof e.path?e.path:y.toPathString(e.path),e}case"value":case"parent":case"parentProperty":return e[t];case"path":return y.toPathString(e[t]);case"pointer":return y.toPointer(e.path);default:throw new TypeError("Unknown result type")}},y.prototype._handleCallback=function(e,t,r){if(t){const n=this._getPreferredOutput(e);e.path="string"==typeof e.path?e.path:y.toPathString(e.path),t(n,r,e)}},y.prototype._trace=function(e,t,r,n,o,i,a,s){let c;if(!e.length)return c={path:r,value:t,parent:n,parentProperty:o,hasArrExpr:a},this._handleCallback(c,i,"value"),c;const u=e[0],l=e.slice(1),p=[];function d(e){Array.isArray(e)?e.forEach((e=>{p.push(e)})):p.push(e)}if(("string"!=typeof u||s)&&t&&Object.hasOwn(t,u))d(this._trace(l,t[u],f(r,u),t,u,i,a));else if("*"===u)this._walk(t,(e=>{d(this._trace(l,t[e],f(r,e),t,e,i,!0,!0))}));else if(".."===u)d(this._trace(l,t,r,n,o,i,a)),this._walk(t,(n=>{"object"==typeof t[n]&&d(this._trace(e.slice(),t[n],f(r,n),t,n,i,!0))}));else{if("^"===u)return this._hasParentSelector=!0,{path:r.slice(0,-1),expr:l,isParentSelector:!0};if("~"===u)return c={path:f(r,u),value:o,parent:n,parentProperty:null},this._handleCallback(c,i,"property"),c;if("$"===u)d(this._trace(l,t,r,null,null,i,a));else if(/^(-?\d*):(-?\d*):?(\d*)$/u.test(u))d(this._slice(u,l,t,r,n,o,i));else if(0===u.indexOf("?(")){if(!1===this.currEval)throw new Error("Eval [?(expr)] prevented in JSONPath expression.");const e=u.replace(/^\?\((.*?)\)$/u,"$1"),a=/@.?([^?]*)[['](\??\(.*?\))(?!.\)\])[\]']/gu.exec(e);a?this._walk(t,(e=>{const s=[a[2]],c=a[1]?t[e][a[1]]:t[e];this._trace(s,c,r,n,o,i,!0).length>0&&d(this._trace(l,t[e],f(r,e),t,e,i,!0))})):this._walk(t,(a=>{this._eval(e,t[a],a,r,n,o)&&d(this._trace(l,t[a],f(r,a),t,a,i,!0))}))}else if("("===u[0]){if(!1===this.currEval)throw new Error("Eval [(expr)] prevented in JSONPath expression.");d(this._trace(h(this._eval(u,t,r.at(-1),r.slice(0,-1),n,o),l),t,r,n,o,i,a))}else if("@"===u[0]){let e=!1;const a=u.slice(1,-2);switch(a){case"scalar":t&&["object","function"].includes(typeof t)||(e=!0);break;case"boolean":case"string":case"undefined":case"function":typeof t===a&&(e=!0);break;case"integer":!Number.isFinite(t)||t%1||(e=!0);break;case"number":Number.isFinite(t)&&(e=!0);break;case"nonFinite":"number"!=typeof t||Number.isFinite(t)||(e=!0);break;case"object":t&&typeof t===a&&(e=!0);break;case"array":Array.isArray(t)&&(e=!0);break;case"other":e=this.currOtherTypeCallback(t,r,n,o);break;case"null":null===t&&(e=!0);break;default:throw new TypeError("Unknown value type "+a)}if(e)return c={path:r,value:t,parent:n,parentProperty:o},this._handleCallback(c,i,"value"),c}else if("`"===u[0]&&t&&Object.hasOwn(t,u.slice(1))){const e=u.slice(1);d(this._trace(l,t[e],f(r,e),t,e,i,a,!0))}else if(u.includes(",")){const e=u.split(",");for(const a of e)d(this._trace(h(a,l),t,r,n,o,i,!0))}else!s&&t&&Object.hasOwn(t,u)&&d(this._trace(l,t[u],f(r,u),t,u,i,a,!0))}if(this._hasParentSelector)for(let e=0;e<p.length;e++){const r=p[e];if(r&&r.isParentSelector){const s=this._trace(r.expr,t,r.path,n,o,i,a);if(Array.isArray(s)){p[e]=s[0];const t=s.length;for(let r=1;r<t;r++)e++,p.splice(e,0,s[r])}else p[e]=s}}return p},y.prototype._walk=function(e,t){if(Array.isArray(e)){const r=e.length;for(let e=0;e<r;e++)t(e)}else e&&"object"==typeof e&&Object.keys(e).forEach((e=>{t(e)}))},y.prototype._slice=function(e,t,r,n,o,i,a){if(!Array.isArray(r))return;const s=r.length,c=e.split(":"),u=c[2]&&Number.parseInt(c[2])||1;let l=c[0]&&Number.parseInt(c[0])||0,p=c[1]&&Number.parseInt(c[1])||s;l=l<0?Math.max(0,l+s):Math.min(s,l),p=p<0?Math.max(0,p+s):Math.min(s,p);const f=[];for(let e=l;e<p;e+=u){this._trace(h(e,t),r,n,o,i,a,!0).forEach((e=>{f.push(e)}))}return f},y.prototype._eval=function(e,t,r,n,o,i){this.currSandbox._$_parentProperty=i,this.currSandbox._$_parent=o,this.currSandbox._$_property=r,this.currSandbox._$_root=this.json,this.currSandbox._$_v=t;const a=e.includes("@path");a&&(this.currSandbox._$_path=y.toPathString(n.concat([r])));const s=this.currEval+"Script:"+e;if(!y.cache[s]){let t=e.replaceAll("@parentProperty","_$_parentProperty").replaceAll("@parent","_$_parent").replaceAll("@property","_$_property").replaceAll("@root","_$_root").replaceAll(/@([.\s)[])/gu,"_$_v$1");if(a&&(t=t.replaceAll("@path","_$_path")),"safe"===this.currEval||!0===this.currEval||void 0===this.currEval)y.cache[s]=new this.safeVm.Script(t);else if("native"===this.currEval)y.cache[s]=new this.vm.Script(t);else if("function"==typeof this.currEval&&this.currEval.prototype&&Object.hasOwn(this.currEval.prototype,"runInNewContext")){const e=this.currEval;y.cache[s]=new e(t)}else{if("function"!=typeof this.currEval)throw new TypeError(`Unknown "eval" property "${this.currEval}"`);y.cache[s]={runInNewContext:e=>this.currEval(t,e)}}}try{return y.cache[s].runInNewContext(this.currSandbox)}catch(t){if(this.ignoreEvalErrors)return!1;throw new Error("jsonPath: "+t.message+": "+e)}},y.cache={},y.toPathString=function(e){const t=e,r=t.length;let n="$";for(let e=1;e<r;e++)/^(~|\^|@.*?\(\))$/u.test(t[e])||(n+=/^[0-9*]+$/u.test(t[e])?"["+t[e]+"]":"['"+t[e]+"']");return n},y.toPointer=function(e){const t=e,r=t.length;let n="";for(let e=1;e<r;e++)/^(~|\^|@.*?\(\))$/u.test(t[e])||(n+="/"+t[e].toString().replaceAll("~","~0").replaceAll("/","~1"));return n},y.toPathArray=function(e){const{cache:t}=y;if(t[e])return t[e].concat();const r=[],n=e.replaceAll(/@(?:null|boolean|number|string|integer|undefined|nonFinite|scalar|array|object|function|other)\(\)/gu,";$&;").replaceAll(/[['](\??\(.*?\))[\]'](?!.\])/gu,(function(e,t){return"[#"+(r.push(t)-1)+"]"})).replaceAll(/\[['"]([^'\]]*)['"]\]/gu,(function(e,t){return"['"+t.replaceAll(".","%@%").replaceAll("~","%%@@%%")+"']"})).replaceAll("~",";~;").replaceAll(/['"]?\.['"]?(?![^[]*\])|\[['"]?/gu,";").replaceAll("%@%",".").replaceAll("%%@@%%","~").replaceAll(/(?:;)?(\^+)(?:;)?/gu,(function(e,t){return";"+t.split("").join(";")+";"})).replaceAll(/;;;|;;/gu,";..;").replaceAll(/;$|'?\]|'$/gu,"").split(";").map((function(e){const t=e.match(/#(\d+)/u);return t&&t[1]?r[t[1]]:e}));return t[e]=n,t[e].concat()},y.prototype.safeVm={Script:class{constructor(e){this.code=e,this.ast=i(this.code)}runInNewContext(e){const t=Object.assign(Object.create(null),e);return p.evalAst(this.ast,t)}}};y.prototype.vm={Script:class{constructor(e){this.code=e}runInNewContext(e){let t=this.code;const r=Object.keys(e),n=[];!function(e,t,r){const n=e.length;for(let o=0;o<n;o++)r(e[o])&&t.push(e.splice(o--,1)[0])}(r,n,(t=>"function"==typeof e[t]));const o=r.map((t=>e[t]));t=n.reduce(((t,r)=>{let n=e[r].toString();return/function/u.test(n)||(n="function "+n),"var "+r+"="+n+";"+t}),"")+t,/(['"])use strict\1/u.test(t)||r.includes("arguments")||(t="var arguments = undefined;"+t),t=t.replace(/;\s*$/u,"");const i=t.lastIndexOf(";"),a=-1!==i?t.slice(0,i+1)+" return "+t.slice(i+1):" return "+t;return new Function(...r,a)(...o)}}}}},t={};function r(n){var o=t[n];if(void 0!==o)return o.exports;var i=t[n]={exports:{}};return e[n].call(i.exports,i,i.exports,r),i.exports}r.d=(e,t)=>{for(var n in t)r.o(t,n)&&!r.o(e,n)&&Object.defineProperty(e,n,{enumerable:!0,get:t[n]})},r.g=function(){if("object"==typeof globalThis)return globalThis;try{return this||new Function("return this")()}catch(e){if("object"==typeof window)return window}}(),r.o=(e,t)=>Object.prototype.hasOwnProperty.call(e,t),r.r=e=>{"undefined"!=typeof Symbol&&Symbol.toStringTag&&Object.defineProperty(e,Symbol.toStringTag,{value:"Module"}),Object.defineProperty(e,"__esModule",{value:!0})};r(5237)})();
\ No newline at end of file
+(()=>{var e={5237:(e,t,r)=>{process={env:{}};const n=r(8324),o=r(537).format,i=r(274);let a=0;function s(e){if("object"==typeof e)return i.JSONPath({...e,json:this});if("string"==typeof e)return i.JSONPath({path:e,json:this});throw new Error("Argument for jp must be either a JSON path (string) or named parameters")}request={},response={},variables={},scenario={},data={},$={},outputVars={},assert=n.assert,expect=n.expect,should=n.should,jsonpath=i.JSONPath,Object.prototype.jp=s,Array.prototype.jp=s,String.prototype.jp=s,Number.prototype.jp=s,console={log:(e,...t)=>l.appendLog("log",e,...t),info:(e,...t)=>l.appendLog("info",e,...t),warn:(e,...t)=>l.appendLog("warn",e,...t),error:(e,...t)=>l.appendLog("error",e,...t),trace:(e,...t)=>l.appendLog("trace",e,...t),debug:(e,...t)=>l.appendLog("debug",e,...t)},BodyType={JSON:"JSON",XML:"XML",Text:"Text",Form:"Form",Binary:"Binary",Multipart:"Multipart"};class c{constructor(e){this.type="Scenario",this.name=e,this.tag=void 0,this.success=!0,this.children=[],this.testCount=0,this.testFailCount=0}}class u{constructor(e){this.type="Behavior",this.name=e,this.tag=void 0,this.success=!0,this.testCount=0,this.testFailCount=0}succeed(){this.success=!0,this.testCount=1}fail(e){this.success=!1,this.testCount=1,this.testFailCount=1,this.error=e}}function h(e){return null!==e&&("object"==typeof e||"function"==typeof e)&&"function"==typeof e.then}let l=new class{constructor(){this.results=[],this.logs=[],this.currentResult=null,this.activeBehavior=null,this.asyncBehavior=null,this.synchronous=!1,this.pending=null,this.unsettled=new Set,this.error=void 0,this.completed=void 0,this.inScenario=!1,this.inBehavior=!1}target(){return this.activeBehavior??(this.synchronous?null:this.asyncBehavior)??this.currentResult}appendLog(e,t,...r){const n=function(e,t=null){if(0===e&&t)return t;const r=Math.floor(e/6e4);e-=6e4*r;const n=Math.floor(e/1e3);return e-=1e3*n,`${r.toString().padStart(2,"0")}:${n.toString().padStart(2,"0")}${.1.toString()[1]}${e.toString().padEnd(3,"0")}`}(Date.now()-a);const d=this.target();let i;d?(d.logs||(d.logs=[]),i=d.logs):(this.logs||(this.logs=[]),i=this.logs),i.push(`${n} [${e}] ${o(t,...r)}`)}push(e){null==this.currentResult?this.results.push(e):(e.parent=this.currentResult,e.parent.children?e.parent.children.push(e):e.parent.children=[e]),this.currentResult=e}pop(){const e=this.currentResult;e&&(this.currentResult=e.parent??null)}enterScenario(e){const t=new c(e);return this.push(t),t}exitScenario(){this.pop()}enterBehavior(e){if("Scenario"!==this.currentResult?.type)throw new Error('"it" must be called from within a "describe" block');const t=new u(e);return this.push(t),t}exitBehavior(){this.pop()}schedule(e){if(this.pending)this.pending=this.pending.then(e);else{const t=e();h(t)&&(this.pending=t)}}track(e){const t=Promise.resolve(e).then((()=>{}),(e=>{void 0===this.error&&(this.error=e)}));this.pending=this.pending?Promise.all([this.pending,t]):t}runBehavior(e,t){const r=this.currentResult;let n;this.currentResult=e,this.activeBehavior=e,e.started=!0;try{n=t()}catch(t){return void this.settle(e,t)}finally{this.currentResult=r,this.activeBehavior=null}if(h(n))return this.asyncBehavior=e,Promise.resolve(n).then((()=>this.settle(e)),(t=>this.settle(e,t??new Error("Promise rejected"))));this.settle(e)}settle(e,t){this.unsettled.delete(e)&&(this.asyncBehavior===e&&(this.asyncBehavior=null),delete e.started,void 0===t?e.succeed():e.fail(t?.message??`${t}`))}finalize(e,t){for(const r of e)r.children&&this.finalize(r.children,r),t&&(t.tag&&(r.tag=t.tag+(r.tag?"."+r.tag:"")),t.success=r.success&&t.success,t.testCount+=r.testCount,t.testFailCount+=r.testFailCount),delete r.parent}complete(){if(void 0===this.completed){if(void 0!==this.error)throw this.error;for(const e of[...this.unsettled])this.settle(e,new Error(e.started?"Test did not complete, promise is still pending":"Test did not run, waiting on a pending promise"));this.finalize(this.results),this.completed=JSON.stringify({results:this.results,output:outputVars,logs:this.logs,clearCookies:clearCookieJar})}return this.completed}};describe=(e,t)=>{let r;l.enterScenario(e);try{r=t()}finally{l.exitScenario()}h(r)&&l.track(r)},it=(e,t)=>{const r=l.enterBehavior(e);l.exitBehavior(),l.unsettled.add(r),l.schedule((()=>l.runBehavior(r,t)))},tag=e=>{l.target().tag=function(e){let t;switch(typeof e){case"boolean":case"undefined":case"array":case"function":throw new Error('Invalid parameter for "tag"');default:if(null!==e){const r=`${e}`;r.length>0&&(t=r)}}if(t){const e=t.matchAll(/\{\{(.*?)\}\}/g);for(const[r,n]of e){const e=$[n];if(!e)throw new Error(`"${n}" is not available for use in a tag`);t=t.replaceAll(r,$[n]?`${e}`:"")}}return t}(e)},output=(e,t)=>{switch(typeof t){case"function":throw new Error("Functions cannot be output");case"symbol":throw new Error("Symbols cannot be output");case"undefined":delete outputVars[e];break;default:outputVars[e]=t}},runTestSuite=(e,t,r,n,o,i,s)=>{l=new l.constructor,request=e,response=t,scenario=r??{},data=n??{},outputVars=o??{},$={...outputVars,...scenario,...data},variables=$,a=i,l.synchronous=!0;try{s()}finally{l.synchronous=!1}return l.pending?l.pending.then((()=>l.complete())):l.complete()},completeTestSuite=()=>l.complete(),cookieJar=[],clearCookieJar=!1,cookies={all:()=>[...cookieJar],get:e=>cookieJar.find((t=>t.name===e))?.value,clear:()=>{cookieJar=[],clearCookieJar=!0}},loadCookieJar=e=>{cookieJar=e??[],clearCookieJar=!1},latency={},loadLatency=e=>{latency=e??{}},evaluateCondition=(e,t,r,n,o,i)=>(l=new l.constructor,request=e,response=t,scenario=r??{},data=n??{},outputVars=o??{},$={...outputVars,...scenario,...data},variables=$,JSON.stringify(!!i())),jsonPathMatches=(e,t)=>{const r=jsonpath({path:e,json:response?.body?.data??{},wrap:!0});return void 0===t?r.length>0:r.some((e=>JSON.stringify(e)===JSON.stringify(t)))},e.exports=runTestSuite},9296:e=>{function t(){var e=[].slice.call(arguments);function t(t,r){Object.keys(r).forEach((function(n){~e.indexOf(n)||(t[n]=r[n])}))}return function(){for(var e=[].slice.call(arguments),r=0,n={};r<e.length;r++)t(n,e[r]);return n}}function r(e,n,o){var i=t("name","message","stack","constructor","toJSON")(n||{});for(var a in this.message=e||"Unspecified AssertionError",this.showDiff=!1,i)this[a]=i[a];if(o=o||r,Error.captureStackTrace)Error.captureStackTrace(this,o);else try{throw new Error}catch(e){this.stack=e.stack}}e.exports=r,r.prototype=Object.create(Error.prototype),r.prototype.name="AssertionError",r.prototype.constructor=r,r.prototype.toJSON=function(e){var r=t("constructor","toJSON","stack")({name:this.name},this);return!1!==e&&this.stack&&(r.stack=this.stack),r}},8075:(e,t,r)=>{"use strict";var n=r(453),o=r(487),i=o(n("String.prototype.indexOf"));e.exports=function(e,t){var r=n(e,!!t);return"function"==typeof r&&i(e,".prototype.")>-1?o(r):r}},487:(e,t,r)=>{"use strict";var n=r(6743),o=r(453),i=r(6897),a=r(9675),s=o("%Function.prototype.apply%"),c=o("%Function.prototype.call%"),u=o("%Reflect.apply%",!0)||n.call(c,s),l=r(655),p=o("%Math.max%");e.exports=function(e){if("function"!=typeof e)throw new a("a function is required");var t=u(n,c,arguments);return i(t,1+p(0,e.length-(arguments.length-1)),!0)};var f=function(){return u(n,s,arguments)};l?l(e.exports,"apply",{value:f}):e.exports.apply=f},8324:(e,t,r)=>{e.exports=r(9731)},9731:(e,t,r)=>{var n=[];t.version="4.3.8",t.AssertionError=r(9296);var o=r(1118);t.use=function(e){return~n.indexOf(e)||(e(t,o),n.push(e)),t},t.util=o;var i=r(752);t.config=i;var a=r(3896);t.use(a);var s=r(9289);t.use(s);var c=r(6667);t.use(c);var u=r(705);t.use(u);var l=r(4740);t.use(l)},3896:(e,t,r)=>{var n=r(752);e.exports=function(e,t){var r=e.AssertionError,o=t.flag;function i(e,r,a,s){return o(this,"ssfi",a||i),o(this,"lockSsfi",s),o(this,"object",e),o(this,"message",r),o(this,"eql",n.deepEqual||t.eql),t.proxify(this)}e.Assertion=i,Object.defineProperty(i,"includeStack",{get:function(){return console.warn("Assertion.includeStack is deprecated, use chai.config.includeStack instead."),n.includeStack},set:function(e){console.warn("Assertion.includeStack is deprecated, use chai.config.includeStack instead."),n.includeStack=e}}),Object.defineProperty(i,"showDiff",{get:function(){return console.warn("Assertion.showDiff is deprecated, use chai.config.showDiff instead."),n.showDiff},set:function(e){console.warn("Assertion.showDiff is deprecated, use chai.config.showDiff instead."),n.showDiff=e}}),i.addProperty=function(e,r){t.addProperty(this.prototype,e,r)},i.addMethod=function(e,r){t.addMethod(this.prototype,e,r)},i.addChainableMethod=function(e,r,n){t.addChainableMethod(this.prototype,e,r,n)},i.overwriteProperty=function(e,r){t.overwriteProperty(this.prototype,e,r)},i.overwriteMethod=function(e,r){t.overwriteMethod(this.prototype,e,r)},i.overwriteChainableMethod=function(e,r,n){t.overwriteChainableMethod(this.prototype,e,r,n)},i.prototype.assert=function(e,i,a,s,c,u){var l=t.test(this,arguments);if(!1!==u&&(u=!0),void 0===s&&void 0===c&&(u=!1),!0!==n.showDiff&&(u=!1),!l){i=t.getMessage(this,arguments);var p={actual:t.getActual(this,arguments),expected:s,showDiff:u},f=t.getOperator(this,arguments);throw f&&(p.operator=f),new r(i,p,n.includeStack?this.assert:o(this,"ssfi"))}},Object.defineProperty(i.prototype,"_obj",{get:function(){return o(this,"object")},set:function(e){o(this,"object",e)}})}},752:e=>{e.exports={includeStack:!1,showDiff:!0,truncateThreshold:40,useProxy:!0,proxyExcludedKeys:["then","catch","inspect","toJSON"],deepEqual:null}},9289:e=>{e.exports=function(e,t){var r=e.Assertion,n=e.AssertionError,o=t.flag;function i(e,r){r&&o(this,"message",r),e=e.toLowerCase();var n=o(this,"object"),i=~["a","e","i","o","u"].indexOf(e.charAt(0))?"an ":"a ";this.assert(e===t.type(n).toLowerCase(),"expected #{this} to be "+i+e,"expected #{this} not to be "+i+e)}function a(e,r){return t.isNaN(e)&&t.isNaN(r)||e===r}function s(){o(this,"contains",!0)}function c(e,i){i&&o(this,"message",i);var s=o(this,"object"),c=t.type(s).toLowerCase(),u=o(this,"message"),l=o(this,"negate"),p=o(this,"ssfi"),f=o(this,"deep"),h=f?"deep ":"",d=f?o(this,"eql"):a;u=u?u+": ":"";var y=!1;switch(c){case"string":y=-1!==s.indexOf(e);break;case"weakset":if(f)throw new n(u+"unable to use .deep.include with WeakSet",void 0,p);y=s.has(e);break;case"map":s.forEach((function(t){y=y||d(t,e)}));break;case"set":f?s.forEach((function(t){y=y||d(t,e)})):y=s.has(e);break;case"array":y=f?s.some((function(t){return d(t,e)})):-1!==s.indexOf(e);break;default:if(e!==Object(e))throw new n(u+"the given combination of arguments ("+c+" and "+t.type(e).toLowerCase()+") is invalid for this assertion. You can use an array, a map, an object, a set, a string, or a weakset instead of a "+t.type(e).toLowerCase(),void 0,p);var b=Object.keys(e),g=null,m=0;if(b.forEach((function(i){var a=new r(s);if(t.transferFlags(this,a,!0),o(a,"lockSsfi",!0),l&&1!==b.length)try{a.property(i,e[i])}catch(e){if(!t.checkError.compatibleConstructor(e,n))throw e;null===g&&(g=e),m++}else a.property(i,e[i])}),this),l&&b.length>1&&m===b.length)throw g;return}this.assert(y,"expected #{this} to "+h+"include "+t.inspect(e),"expected #{this} to not "+h+"include "+t.inspect(e))}function u(){var e=o(this,"object");this.assert(null!=e,"expected #{this} to exist","expected #{this} to not exist")}function l(){var e=o(this,"object"),r=t.type(e);this.assert("Arguments"===r,"expected #{this} to be arguments but got "+r,"expected #{this} to not be arguments")}function p(e,t){t&&o(this,"message",t);var r=o(this,"object");if(o(this,"deep")){var n=o(this,"lockSsfi");o(this,"lockSsfi",!0),this.eql(e),o(this,"lockSsfi",n)}else this.assert(e===r,"expected #{this} to equal #{exp}","expected #{this} to not equal #{exp}",e,this._obj,!0)}function f(e,t){t&&o(this,"message",t);var r=o(this,"eql");this.assert(r(e,o(this,"object")),"expected #{this} to deeply equal #{exp}","expected #{this} to not deeply equal #{exp}",e,this._obj,!0)}function h(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to above must be a number";else a=l+"the argument to above must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y>e,"expected #{this} to have a "+b+" above #{exp} but got #{act}","expected #{this} to not have a "+b+" above #{exp}",e,y)}else this.assert(s>e,"expected #{this} to be above #{exp}","expected #{this} to be at most #{exp}",e)}function d(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to least must be a number";else a=l+"the argument to least must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y>=e,"expected #{this} to have a "+b+" at least #{exp} but got #{act}","expected #{this} to have a "+b+" below #{exp}",e,y)}else this.assert(s>=e,"expected #{this} to be at least #{exp}","expected #{this} to be below #{exp}",e)}function y(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to below must be a number";else a=l+"the argument to below must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y<e,"expected #{this} to have a "+b+" below #{exp} but got #{act}","expected #{this} to not have a "+b+" below #{exp}",e,y)}else this.assert(s<e,"expected #{this} to be below #{exp}","expected #{this} to be at least #{exp}",e)}function b(e,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"doLength"),u=o(this,"message"),l=u?u+": ":"",p=o(this,"ssfi"),f=t.type(s).toLowerCase(),h=t.type(e).toLowerCase(),d=!0;if(c&&"map"!==f&&"set"!==f&&new r(s,u,p,!0).to.have.property("length"),c||"date"!==f||"date"===h)if("number"===h||!c&&"number"!==f)if(c||"date"===f||"number"===f)d=!1;else{a=l+"expected "+("string"===f?"'"+s+"'":s)+" to be a number or a date"}else a=l+"the argument to most must be a number";else a=l+"the argument to most must be a date";if(d)throw new n(a,void 0,p);if(c){var y,b="length";"map"===f||"set"===f?(b="size",y=s.size):y=s.length,this.assert(y<=e,"expected #{this} to have a "+b+" at most #{exp} but got #{act}","expected #{this} to have a "+b+" above #{exp}",e,y)}else this.assert(s<=e,"expected #{this} to be at most #{exp}","expected #{this} to be above #{exp}",e)}function g(e,r){r&&o(this,"message",r);var i=o(this,"object"),a=o(this,"ssfi"),s=o(this,"message");try{var c=i instanceof e}catch(r){if(r instanceof TypeError)throw new n((s=s?s+": ":"")+"The instanceof assertion needs a constructor but "+t.type(e)+" was given.",void 0,a);throw r}var u=t.getName(e);null===u&&(u="an unnamed constructor"),this.assert(c,"expected #{this} to be an instance of "+u,"expected #{this} to not be an instance of "+u)}function m(e,r,i){i&&o(this,"message",i);var a=o(this,"nested"),s=o(this,"own"),c=o(this,"message"),u=o(this,"object"),l=o(this,"ssfi"),p=typeof e;if(c=c?c+": ":"",a){if("string"!==p)throw new n(c+"the argument to property must be a string when using nested syntax",void 0,l)}else if("string"!==p&&"number"!==p&&"symbol"!==p)throw new n(c+"the argument to property must be a string, number, or symbol",void 0,l);if(a&&s)throw new n(c+'The "nested" and "own" flags cannot be combined.',void 0,l);if(null==u)throw new n(c+"Target cannot be null or undefined.",void 0,l);var f,h=o(this,"deep"),d=o(this,"negate"),y=a?t.getPathInfo(u,e):null,b=a?y.value:u[e],g=h?o(this,"eql"):(e,t)=>e===t,m="";h&&(m+="deep "),s&&(m+="own "),a&&(m+="nested "),m+="property ",f=s?Object.prototype.hasOwnProperty.call(u,e):a?y.exists:t.hasProperty(u,e),d&&1!==arguments.length||this.assert(f,"expected #{this} to have "+m+t.inspect(e),"expected #{this} to not have "+m+t.inspect(e)),arguments.length>1&&this.assert(f&&g(r,b),"expected #{this} to have "+m+t.inspect(e)+" of #{exp}, but got #{act}","expected #{this} to not have "+m+t.inspect(e)+" of #{act}",r,b),o(this,"object",b)}function v(e,t,r){o(this,"own",!0),m.apply(this,arguments)}function w(e,r,n){"string"==typeof r&&(n=r,r=null),n&&o(this,"message",n);var i=o(this,"object"),a=Object.getOwnPropertyDescriptor(Object(i),e),s=o(this,"eql");a&&r?this.assert(s(r,a),"expected the own property descriptor for "+t.inspect(e)+" on #{this} to match "+t.inspect(r)+", got "+t.inspect(a),"expected the own property descriptor for "+t.inspect(e)+" on #{this} to not match "+t.inspect(r),r,a,!0):this.assert(a,"expected #{this} to have an own property descriptor for "+t.inspect(e),"expected #{this} to not have an own property descriptor for "+t.inspect(e)),o(this,"object",a)}function x(){o(this,"doLength",!0)}function O(e,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=t.type(a).toLowerCase(),c=o(this,"message"),u=o(this,"ssfi"),l="length";switch(s){case"map":case"set":l="size",i=a.size;break;default:new r(a,c,u,!0).to.have.property("length"),i=a.length}this.assert(i==e,"expected #{this} to have a "+l+" of #{exp} but got #{act}","expected #{this} to not have a "+l+" of #{act}",e,i)}function E(e,t){t&&o(this,"message",t);var r=o(this,"object");this.assert(e.exec(r),"expected #{this} to match "+e,"expected #{this} not to match "+e)}function A(e){var r,i,a=o(this,"object"),s=t.type(a),c=t.type(e),u=o(this,"ssfi"),l=o(this,"deep"),p="",f=!0,h=o(this,"message"),d=(h=h?h+": ":"")+"when testing keys against an object or an array you must give a single Array|Object|String argument or multiple String arguments";if("Map"===s||"Set"===s)p=l?"deeply ":"",i=[],a.forEach((function(e,t){i.push(t)})),"Array"!==c&&(e=Array.prototype.slice.call(arguments));else{switch(i=t.getOwnEnumerableProperties(a),c){case"Array":if(arguments.length>1)throw new n(d,void 0,u);break;case"Object":if(arguments.length>1)throw new n(d,void 0,u);e=Object.keys(e);break;default:e=Array.prototype.slice.call(arguments)}e=e.map((function(e){return"symbol"==typeof e?e:String(e)}))}if(!e.length)throw new n(h+"keys required",void 0,u);var y=e.length,b=o(this,"any"),g=o(this,"all"),m=e,v=l?o(this,"eql"):(e,t)=>e===t;if(b||g||(g=!0),b&&(f=m.some((function(e){return i.some((function(t){return v(e,t)}))}))),g&&(f=m.every((function(e){return i.some((function(t){return v(e,t)}))})),o(this,"contains")||(f=f&&e.length==i.length)),y>1){var w=(e=e.map((function(e){return t.inspect(e)}))).pop();g&&(r=e.join(", ")+", and "+w),b&&(r=e.join(", ")+", or "+w)}else r=t.inspect(e[0]);r=(y>1?"keys ":"key ")+r,r=(o(this,"contains")?"contain ":"have ")+r,this.assert(f,"expected #{this} to "+p+r,"expected #{this} to not "+p+r,m.slice(0).sort(t.compareByInspect),i.sort(t.compareByInspect),!0)}function S(e,n,i){i&&o(this,"message",i);var a,s=o(this,"object"),c=o(this,"ssfi"),u=o(this,"message"),l=o(this,"negate")||!1;new r(s,u,c,!0).is.a("function"),(e instanceof RegExp||"string"==typeof e)&&(n=e,e=null);try{s()}catch(e){a=e}var p=void 0===e&&void 0===n,f=Boolean(e&&n),h=!1,d=!1;if(p||!p&&!l){var y="an error";e instanceof Error?y="#{exp}":e&&(y=t.checkError.getConstructorName(e)),this.assert(a,"expected #{this} to throw "+y,"expected #{this} to not throw an error but #{act} was thrown",e&&e.toString(),a instanceof Error?a.toString():"string"==typeof a?a:a&&t.checkError.getConstructorName(a))}if(e&&a){if(e instanceof Error)t.checkError.compatibleInstance(a,e)===l&&(f&&l?h=!0:this.assert(l,"expected #{this} to throw #{exp} but #{act} was thrown","expected #{this} to not throw #{exp}"+(a&&!l?" but #{act} was thrown":""),e.toString(),a.toString()));t.checkError.compatibleConstructor(a,e)===l&&(f&&l?h=!0:this.assert(l,"expected #{this} to throw #{exp} but #{act} was thrown","expected #{this} to not throw #{exp}"+(a?" but #{act} was thrown":""),e instanceof Error?e.toString():e&&t.checkError.getConstructorName(e),a instanceof Error?a.toString():a&&t.checkError.getConstructorName(a)))}if(a&&null!=n){var b="including";n instanceof RegExp&&(b="matching"),t.checkError.compatibleMessage(a,n)===l&&(f&&l?d=!0:this.assert(l,"expected #{this} to throw error "+b+" #{exp} but got #{act}","expected #{this} to throw error not "+b+" #{exp}",n,t.checkError.getMessage(a)))}h&&d&&this.assert(l,"expected #{this} to throw #{exp} but #{act} was thrown","expected #{this} to not throw #{exp}"+(a?" but #{act} was thrown":""),e instanceof Error?e.toString():e&&t.checkError.getConstructorName(e),a instanceof Error?a.toString():a&&t.checkError.getConstructorName(a)),o(this,"object",a)}function j(e,r){r&&o(this,"message",r);var n=o(this,"object"),i=o(this,"itself"),a="function"!=typeof n||i?n[e]:n.prototype[e];this.assert("function"==typeof a,"expected #{this} to respond to "+t.inspect(e),"expected #{this} to not respond to "+t.inspect(e))}function P(e,r){r&&o(this,"message",r);var n=e(o(this,"object"));this.assert(n,"expected #{this} to satisfy "+t.objDisplay(e),"expected #{this} to not satisfy"+t.objDisplay(e),!o(this,"negate"),n)}function _(e,t,i){i&&o(this,"message",i);var a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");if(new r(a,s,c,!0).is.a("number"),"number"!=typeof e||"number"!=typeof t)throw new n((s=s?s+": ":"")+"the arguments to closeTo or approximately must be numbers"+(void 0===t?", and a delta is required":""),void 0,c);this.assert(Math.abs(a-e)<=t,"expected #{this} to be close to "+e+" +/- "+t,"expected #{this} not to be close to "+e+" +/- "+t)}function k(e,t,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");new r(a,s,c,!0).is.a("function"),t?(new r(e,s,c,!0).to.have.property(t),i=e[t]):(new r(e,s,c,!0).is.a("function"),i=e()),a();var u=null==t?e():e[t],l=null==t?i:"."+t;o(this,"deltaMsgObj",l),o(this,"initialDeltaValue",i),o(this,"finalDeltaValue",u),o(this,"deltaBehavior","change"),o(this,"realDelta",u!==i),this.assert(i!==u,"expected "+l+" to change","expected "+l+" to not change")}function M(e,t,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");new r(a,s,c,!0).is.a("function"),t?(new r(e,s,c,!0).to.have.property(t),i=e[t]):(new r(e,s,c,!0).is.a("function"),i=e()),new r(i,s,c,!0).is.a("number"),a();var u=null==t?e():e[t],l=null==t?i:"."+t;o(this,"deltaMsgObj",l),o(this,"initialDeltaValue",i),o(this,"finalDeltaValue",u),o(this,"deltaBehavior","increase"),o(this,"realDelta",u-i),this.assert(u-i>0,"expected "+l+" to increase","expected "+l+" to not increase")}function N(e,t,n){n&&o(this,"message",n);var i,a=o(this,"object"),s=o(this,"message"),c=o(this,"ssfi");new r(a,s,c,!0).is.a("function"),t?(new r(e,s,c,!0).to.have.property(t),i=e[t]):(new r(e,s,c,!0).is.a("function"),i=e()),new r(i,s,c,!0).is.a("number"),a();var u=null==t?e():e[t],l=null==t?i:"."+t;o(this,"deltaMsgObj",l),o(this,"initialDeltaValue",i),o(this,"finalDeltaValue",u),o(this,"deltaBehavior","decrease"),o(this,"realDelta",i-u),this.assert(u-i<0,"expected "+l+" to decrease","expected "+l+" to not decrease")}["to","be","been","is","and","has","have","with","that","which","at","of","same","but","does","still","also"].forEach((function(e){r.addProperty(e)})),r.addProperty("not",(function(){o(this,"negate",!0)})),r.addProperty("deep",(function(){o(this,"deep",!0)})),r.addProperty("nested",(function(){o(this,"nested",!0)})),r.addProperty("own",(function(){o(this,"own",!0)})),r.addProperty("ordered",(function(){o(this,"ordered",!0)})),r.addProperty("any",(function(){o(this,"any",!0),o(this,"all",!1)})),r.addProperty("all",(function(){o(this,"all",!0),o(this,"any",!1)})),r.addChainableMethod("an",i),r.addChainableMethod("a",i),r.addChainableMethod("include",c,s),r.addChainableMethod("contain",c,s),r.addChainableMethod("contains",c,s),r.addChainableMethod("includes",c,s),r.addProperty("ok",(function(){this.assert(o(this,"object"),"expected #{this} to be truthy","expected #{this} to be falsy")})),r.addProperty("true",(function(){this.assert(!0===o(this,"object"),"expected #{this} to be true","expected #{this} to be false",!o(this,"negate"))})),r.addProperty("false",(function(){this.assert(!1===o(this,"object"),"expected #{this} to be false","expected #{this} to be true",!!o(this,"negate"))})),r.addProperty("null",(function(){this.assert(null===o(this,"object"),"expected #{this} to be null","expected #{this} not to be null")})),r.addProperty("undefined",(function(){this.assert(void 0===o(this,"object"),"expected #{this} to be undefined","expected #{this} not to be undefined")})),r.addProperty("NaN",(function(){this.assert(t.isNaN(o(this,"object")),"expected #{this} to be NaN","expected #{this} not to be NaN")})),r.addProperty("exist",u),r.addProperty("exists",u),r.addProperty("empty",(function(){var e,r=o(this,"object"),i=o(this,"ssfi"),a=o(this,"message");switch(a=a?a+": ":"",t.type(r).toLowerCase()){case"array":case"string":e=r.length;break;case"map":case"set":e=r.size;break;case"weakmap":case"weakset":throw new n(a+".empty was passed a weak collection",void 0,i);case"function":var s=a+".empty was passed a function "+t.getName(r);throw new n(s.trim(),void 0,i);default:if(r!==Object(r))throw new n(a+".empty was passed non-string primitive "+t.inspect(r),void 0,i);e=Object.keys(r).length}this.assert(0===e,"expected #{this} to be empty","expected #{this} not to be empty")})),r.addProperty("arguments",l),r.addProperty("Arguments",l),r.addMethod("equal",p),r.addMethod("equals",p),r.addMethod("eq",p),r.addMethod("eql",f),r.addMethod("eqls",f),r.addMethod("above",h),r.addMethod("gt",h),r.addMethod("greaterThan",h),r.addMethod("least",d),r.addMethod("gte",d),r.addMethod("greaterThanOrEqual",d),r.addMethod("below",y),r.addMethod("lt",y),r.addMethod("lessThan",y),r.addMethod("most",b),r.addMethod("lte",b),r.addMethod("lessThanOrEqual",b),r.addMethod("within",(function(e,i,a){a&&o(this,"message",a);var s,c=o(this,"object"),u=o(this,"doLength"),l=o(this,"message"),p=l?l+": ":"",f=o(this,"ssfi"),h=t.type(c).toLowerCase(),d=t.type(e).toLowerCase(),y=t.type(i).toLowerCase(),b=!0,g="date"===d&&"date"===y?e.toISOString()+".."+i.toISOString():e+".."+i;if(u&&"map"!==h&&"set"!==h&&new r(c,l,f,!0).to.have.property("length"),u||"date"!==h||"date"===d&&"date"===y)if("number"===d&&"number"===y||!u&&"number"!==h)if(u||"date"===h||"number"===h)b=!1;else{s=p+"expected "+("string"===h?"'"+c+"'":c)+" to be a number or a date"}else s=p+"the arguments to within must be numbers";else s=p+"the arguments to within must be dates";if(b)throw new n(s,void 0,f);if(u){var m,v="length";"map"===h||"set"===h?(v="size",m=c.size):m=c.length,this.assert(m>=e&&m<=i,"expected #{this} to have a "+v+" within "+g,"expected #{this} to not have a "+v+" within "+g)}else this.assert(c>=e&&c<=i,"expected #{this} to be within "+g,"expected #{this} to not be within "+g)})),r.addMethod("instanceof",g),r.addMethod("instanceOf",g),r.addMethod("property",m),r.addMethod("ownProperty",v),r.addMethod("haveOwnProperty",v),r.addMethod("ownPropertyDescriptor",w),r.addMethod("haveOwnPropertyDescriptor",w),r.addChainableMethod("length",O,x),r.addChainableMethod("lengthOf",O,x),r.addMethod("match",E),r.addMethod("matches",E),r.addMethod("string",(function(e,n){n&&o(this,"message",n);var i=o(this,"object"),a=o(this,"message"),s=o(this,"ssfi");new r(i,a,s,!0).is.a("string"),this.assert(~i.indexOf(e),"expected #{this} to contain "+t.inspect(e),"expected #{this} to not contain "+t.inspect(e))})),r.addMethod("keys",A),r.addMethod("key",A),r.addMethod("throw",S),r.addMethod("throws",S),r.addMethod("Throw",S),r.addMethod("respondTo",j),r.addMethod("respondsTo",j),r.addProperty("itself",(function(){o(this,"itself",!0)})),r.addMethod("satisfy",P),r.addMethod("satisfies",P),r.addMethod("closeTo",_),r.addMethod("approximately",_),r.addMethod("members",(function(e,t){t&&o(this,"message",t);var n=o(this,"object"),i=o(this,"message"),a=o(this,"ssfi");new r(n,i,a,!0).to.be.an("array"),new r(e,i,a,!0).to.be.an("array");var s,c,u,l=o(this,"contains"),p=o(this,"ordered");l?(c="expected #{this} to be "+(s=p?"an ordered superset":"a superset")+" of #{exp}",u="expected #{this} to not be "+s+" of #{exp}"):(c="expected #{this} to have the same "+(s=p?"ordered members":"members")+" as #{exp}",u="expected #{this} to not have the same "+s+" as #{exp}");var f=o(this,"deep")?o(this,"eql"):void 0;this.assert(function(e,t,r,n,o){if(!n){if(e.length!==t.length)return!1;t=t.slice()}return e.every((function(e,i){if(o)return r?r(e,t[i]):e===t[i];if(!r){var a=t.indexOf(e);return-1!==a&&(n||t.splice(a,1),!0)}return t.some((function(o,i){return!!r(e,o)&&(n||t.splice(i,1),!0)}))}))}(e,n,f,l,p),c,u,e,n,!0)})),r.addMethod("oneOf",(function(e,t){t&&o(this,"message",t);var n=o(this,"object"),i=o(this,"message"),a=o(this,"ssfi"),s=o(this,"contains"),c=o(this,"deep"),u=o(this,"eql");new r(e,i,a,!0).to.be.an("array"),s?this.assert(e.some((function(e){return n.indexOf(e)>-1})),"expected #{this} to contain one of #{exp}","expected #{this} to not contain one of #{exp}",e,n):c?this.assert(e.some((function(e){return u(n,e)})),"expected #{this} to deeply equal one of #{exp}","expected #{this} to deeply equal one of #{exp}",e,n):this.assert(e.indexOf(n)>-1,"expected #{this} to be one of #{exp}","expected #{this} to not be one of #{exp}",e,n)})),r.addMethod("change",k),r.addMethod("changes",k),r.addMethod("increase",M),r.addMethod("increases",M),r.addMethod("decrease",N),r.addMethod("decreases",N),r.addMethod("by",(function(e,t){t&&o(this,"message",t);var r,n=o(this,"deltaMsgObj"),i=o(this,"initialDeltaValue"),a=o(this,"finalDeltaValue"),s=o(this,"deltaBehavior"),c=o(this,"realDelta");r="change"===s?Math.abs(a-i)===Math.abs(e):c===Math.abs(e),this.assert(r,"expected "+n+" to "+s+" by "+e,"expected "+n+" to not "+s+" by "+e)})),r.addProperty("extensible",(function(){var e=o(this,"object"),t=e===Object(e)&&Object.isExtensible(e);this.assert(t,"expected #{this} to be extensible","expected #{this} to not be extensible")})),r.addProperty("sealed",(function(){var e=o(this,"object"),t=e!==Object(e)||Object.isSealed(e);this.assert(t,"expected #{this} to be sealed","expected #{this} to not be sealed")})),r.addProperty("frozen",(function(){var e=o(this,"object"),t=e!==Object(e)||Object.isFrozen(e);this.assert(t,"expected #{this} to be frozen","expected #{this} to not be frozen")})),r.addProperty("finite",(function(e){var t=o(this,"object");this.assert("number"==typeof t&&isFinite(t),"expected #{this} to be a finite number","expected #{this} to not be a finite number")}))}},4740:e=>{e.exports=function(e,t){var r=e.Assertion,n=t.flag,o=e.assert=function(t,n){new r(null,null,e.assert,!0).assert(t,n,"[ negation message unavailable ]")};o.fail=function(t,r,n,i){throw arguments.length<2&&(n=t,t=void 0),n=n||"assert.fail()",new e.AssertionError(n,{actual:t,expected:r,operator:i},o.fail)},o.isOk=function(e,t){new r(e,t,o.isOk,!0).is.ok},o.isNotOk=function(e,t){new r(e,t,o.isNotOk,!0).is.not.ok},o.equal=function(e,t,i){var a=new r(e,i,o.equal,!0);a.assert(t==n(a,"object"),"expected #{this} to equal #{exp}","expected #{this} to not equal #{act}",t,e,!0)},o.notEqual=function(e,t,i){var a=new r(e,i,o.notEqual,!0);a.assert(t!=n(a,"object"),"expected #{this} to not equal #{exp}","expected #{this} to equal #{act}",t,e,!0)},o.strictEqual=function(e,t,n){new r(e,n,o.strictEqual,!0).to.equal(t)},o.notStrictEqual=function(e,t,n){new r(e,n,o.notStrictEqual,!0).to.not.equal(t)},o.deepEqual=o.deepStrictEqual=function(e,t,n){new r(e,n,o.deepEqual,!0).to.eql(t)},o.notDeepEqual=function(e,t,n){new r(e,n,o.notDeepEqual,!0).to.not.eql(t)},o.isAbove=function(e,t,n){new r(e,n,o.isAbove,!0).to.be.above(t)},o.isAtLeast=function(e,t,n){new r(e,n,o.isAtLeast,!0).to.be.least(t)},o.isBelow=function(e,t,n){new r(e,n,o.isBelow,!0).to.be.below(t)},o.isAtMost=function(e,t,n){new r(e,n,o.isAtMost,!0).to.be.most(t)},o.isTrue=function(e,t){new r(e,t,o.isTrue,!0).is.true},o.isNotTrue=function(e,t){new r(e,t,o.isNotTrue,!0).to.not.equal(!0)},o.isFalse=function(e,t){new r(e,t,o.isFalse,!0).is.false},o.isNotFalse=function(e,t){new r(e,t,o.isNotFalse,!0).to.not.equal(!1)},o.isNull=function(e,t){new r(e,t,o.isNull,!0).to.equal(null)},o.isNotNull=function(e,t){new r(e,t,o.isNotNull,!0).to.not.equal(null)},o.isNaN=function(e,t){new r(e,t,o.isNaN,!0).to.be.NaN},o.isNotNaN=function(e,t){new r(e,t,o.isNotNaN,!0).not.to.be.NaN},o.exists=function(e,t){new r(e,t,o.exists,!0).to.exist},o.notExists=function(e,t){new r(e,t,o.notExists,!0).to.not.exist},o.isUndefined=function(e,t){new r(e,t,o.isUndefined,!0).to.equal(void 0)},o.isDefined=function(e,t){new r(e,t,o.isDefined,!0).to.not.equal(void 0)},o.isFunction=function(e,t){new r(e,t,o.isFunction,!0).to.be.a("function")},o.isNotFunction=function(e,t){new r(e,t,o.isNotFunction,!0).to.not.be.a("function")},o.isObject=function(e,t){new r(e,t,o.isObject,!0).to.be.a("object")},o.isNotObject=function(e,t){new r(e,t,o.isNotObject,!0).to.not.be.a("object")},o.isArray=function(e,t){new r(e,t,o.isArray,!0).to.be.an("array")},o.isNotArray=function(e,t){new r(e,t,o.isNotArray,!0).to.not.be.an("array")},o.isString=function(e,t){new r(e,t,o.isString,!0).to.be.a("string")},o.isNotString=function(e,t){new r(e,t,o.isNotString,!0).to.not.be.a("string")},o.isNumber=function(e,t){new r(e,t,o.isNumber,!0).to.be.a("number")},o.isNotNumber=function(e,t){new r(e,t,o.isNotNumber,!0).to.not.be.a("number")},o.isFinite=function(e,t){new r(e,t,o.isFinite,!0).to.be.finite},o.isBoolean=function(e,t){new r(e,t,o.isBoolean,!0).to.be.a("boolean")},o.isNotBoolean=function(e,t){new r(e,t,o.isNotBoolean,!0).to.not.be.a("boolean")},o.typeOf=function(e,t,n){new r(e,n,o.typeOf,!0).to.be.a(t)},o.notTypeOf=function(e,t,n){new r(e,n,o.notTypeOf,!0).to.not.be.a(t)},o.instanceOf=function(e,t,n){new r(e,n,o.instanceOf,!0).to.be.instanceOf(t)},o.notInstanceOf=function(e,t,n){new r(e,n,o.notInstanceOf,!0).to.not.be.instanceOf(t)},o.include=function(e,t,n){new r(e,n,o.include,!0).include(t)},o.notInclude=function(e,t,n){new r(e,n,o.notInclude,!0).not.include(t)},o.deepInclude=function(e,t,n){new r(e,n,o.deepInclude,!0).deep.include(t)},o.notDeepInclude=function(e,t,n){new r(e,n,o.notDeepInclude,!0).not.deep.include(t)},o.nestedInclude=function(e,t,n){new r(e,n,o.nestedInclude,!0).nested.include(t)},o.notNestedInclude=function(e,t,n){new r(e,n,o.notNestedInclude,!0).not.nested.include(t)},o.deepNestedInclude=function(e,t,n){new r(e,n,o.deepNestedInclude,!0).deep.nested.include(t)},o.notDeepNestedInclude=function(e,t,n){new r(e,n,o.notDeepNestedInclude,!0).not.deep.nested.include(t)},o.ownInclude=function(e,t,n){new r(e,n,o.ownInclude,!0).own.include(t)},o.notOwnInclude=function(e,t,n){new r(e,n,o.notOwnInclude,!0).not.own.include(t)},o.deepOwnInclude=function(e,t,n){new r(e,n,o.deepOwnInclude,!0).deep.own.include(t)},o.notDeepOwnInclude=function(e,t,n){new r(e,n,o.notDeepOwnInclude,!0).not.deep.own.include(t)},o.match=function(e,t,n){new r(e,n,o.match,!0).to.match(t)},o.notMatch=function(e,t,n){new r(e,n,o.notMatch,!0).to.not.match(t)},o.property=function(e,t,n){new r(e,n,o.property,!0).to.have.property(t)},o.notProperty=function(e,t,n){new r(e,n,o.notProperty,!0).to.not.have.property(t)},o.propertyVal=function(e,t,n,i){new r(e,i,o.propertyVal,!0).to.have.property(t,n)},o.notPropertyVal=function(e,t,n,i){new r(e,i,o.notPropertyVal,!0).to.not.have.property(t,n)},o.deepPropertyVal=function(e,t,n,i){new r(e,i,o.deepPropertyVal,!0).to.have.deep.property(t,n)},o.notDeepPropertyVal=function(e,t,n,i){new r(e,i,o.notDeepPropertyVal,!0).to.not.have.deep.property(t,n)},o.ownProperty=function(e,t,n){new r(e,n,o.ownProperty,!0).to.have.own.property(t)},o.notOwnProperty=function(e,t,n){new r(e,n,o.notOwnProperty,!0).to.not.have.own.property(t)},o.ownPropertyVal=function(e,t,n,i){new r(e,i,o.ownPropertyVal,!0).to.have.own.property(t,n)},o.notOwnPropertyVal=function(e,t,n,i){new r(e,i,o.notOwnPropertyVal,!0).to.not.have.own.property(t,n)},o.deepOwnPropertyVal=function(e,t,n,i){new r(e,i,o.deepOwnPropertyVal,!0).to.have.deep.own.property(t,n)},o.notDeepOwnPropertyVal=function(e,t,n,i){new r(e,i,o.notDeepOwnPropertyVal,!0).to.not.have.deep.own.property(t,n)},o.nestedProperty=function(e,t,n){new r(e,n,o.nestedProperty,!0).to.have.nested.property(t)},o.notNestedProperty=function(e,t,n){new r(e,n,o.notNestedProperty,!0).to.not.have.nested.property(t)},o.nestedPropertyVal=function(e,t,n,i){new r(e,i,o.nestedPropertyVal,!0).to.have.nested.property(t,n)},o.notNestedPropertyVal=function(e,t,n,i){new r(e,i,o.notNestedPropertyVal,!0).to.not.have.nested.property(t,n)},o.deepNestedPropertyVal=function(e,t,n,i){new r(e,i,o.deepNestedPropertyVal,!0).to.have.deep.nested.property(t,n)},o.notDeepNestedPropertyVal=function(e,t,n,i){new r(e,i,o.notDeepNestedPropertyVal,!0).to.not.have.deep.nested.property(t,n)},o.lengthOf=function(e,t,n){new r(e,n,o.lengthOf,!0).to.have.lengthOf(t)},o.hasAnyKeys=function(e,t,n){new r(e,n,o.hasAnyKeys,!0).to.have.any.keys(t)},o.hasAllKeys=function(e,t,n){new r(e,n,o.hasAllKeys,!0).to.have.all.keys(t)},o.containsAllKeys=function(e,t,n){new r(e,n,o.containsAllKeys,!0).to.contain.all.keys(t)},o.doesNotHaveAnyKeys=function(e,t,n){new r(e,n,o.doesNotHaveAnyKeys,!0).to.not.have.any.keys(t)},o.doesNotHaveAllKeys=function(e,t,n){new r(e,n,o.doesNotHaveAllKeys,!0).to.not.have.all.keys(t)},o.hasAnyDeepKeys=function(e,t,n){new r(e,n,o.hasAnyDeepKeys,!0).to.have.any.deep.keys(t)},o.hasAllDeepKeys=function(e,t,n){new r(e,n,o.hasAllDeepKeys,!0).to.have.all.deep.keys(t)},o.containsAllDeepKeys=function(e,t,n){new r(e,n,o.containsAllDeepKeys,!0).to.contain.all.deep.keys(t)},o.doesNotHaveAnyDeepKeys=function(e,t,n){new r(e,n,o.doesNotHaveAnyDeepKeys,!0).to.not.have.any.deep.keys(t)},o.doesNotHaveAllDeepKeys=function(e,t,n){new r(e,n,o.doesNotHaveAllDeepKeys,!0).to.not.have.all.deep.keys(t)},o.throws=function(e,t,i,a){("string"==typeof t||t instanceof RegExp)&&(i=t,t=null);var s=new r(e,a,o.throws,!0).to.throw(t,i);return n(s,"object")},o.doesNotThrow=function(e,t,n,i){("string"==typeof t||t instanceof RegExp)&&(n=t,t=null),new r(e,i,o.doesNotThrow,!0).to.not.throw(t,n)},o.operator=function(i,a,s,c){var u;switch(a){case"==":u=i==s;break;case"===":u=i===s;break;case">":u=i>s;break;case">=":u=i>=s;break;case"<":u=i<s;break;case"<=":u=i<=s;break;case"!=":u=i!=s;break;case"!==":u=i!==s;break;default:throw c=c?c+": ":c,new e.AssertionError(c+'Invalid operator "'+a+'"',void 0,o.operator)}var l=new r(u,c,o.operator,!0);l.assert(!0===n(l,"object"),"expected "+t.inspect(i)+" to be "+a+" "+t.inspect(s),"expected "+t.inspect(i)+" to not be "+a+" "+t.inspect(s))},o.closeTo=function(e,t,n,i){new r(e,i,o.closeTo,!0).to.be.closeTo(t,n)},o.approximately=function(e,t,n,i){new r(e,i,o.approximately,!0).to.be.approximately(t,n)},o.sameMembers=function(e,t,n){new r(e,n,o.sameMembers,!0).to.have.same.members(t)},o.notSameMembers=function(e,t,n){new r(e,n,o.notSameMembers,!0).to.not.have.same.members(t)},o.sameDeepMembers=function(e,t,n){new r(e,n,o.sameDeepMembers,!0).to.have.same.deep.members(t)},o.notSameDeepMembers=function(e,t,n){new r(e,n,o.notSameDeepMembers,!0).to.not.have.same.deep.members(t)},o.sameOrderedMembers=function(e,t,n){new r(e,n,o.sameOrderedMembers,!0).to.have.same.ordered.members(t)},o.notSameOrderedMembers=function(e,t,n){new r(e,n,o.notSameOrderedMembers,!0).to.not.have.same.ordered.members(t)},o.sameDeepOrderedMembers=function(e,t,n){new r(e,n,o.sameDeepOrderedMembers,!0).to.have.same.deep.ordered.members(t)},o.notSameDeepOrderedMembers=function(e,t,n){new r(e,n,o.notSameDeepOrderedMembers,!0).to.not.have.same.deep.ordered.members(t)},o.includeMembers=function(e,t,n){new r(e,n,o.includeMembers,!0).to.include.members(t)},o.notIncludeMembers=function(e,t,n){new r(e,n,o.notIncludeMembers,!0).to.not.include.members(t)},o.includeDeepMembers=function(e,t,n){new r(e,n,o.includeDeepMembers,!0).to.include.deep.members(t)},o.notIncludeDeepMembers=function(e,t,n){new r(e,n,o.notIncludeDeepMembers,!0).to.not.include.deep.members(t)},o.includeOrderedMembers=function(e,t,n){new r(e,n,o.includeOrderedMembers,!0).to.include.ordered.members(t)},o.notIncludeOrderedMembers=function(e,t,n){new r(e,n,o.notIncludeOrderedMembers,!0).to.not.include.ordered.members(t)},o.includeDeepOrderedMembers=function(e,t,n){new r(e,n,o.includeDeepOrderedMembers,!0).to.include.deep.ordered.members(t)},o.notIncludeDeepOrderedMembers=function(e,t,n){new r(e,n,o.notIncludeDeepOrderedMembers,!0).to.not.include.deep.ordered.members(t)},o.oneOf=function(e,t,n){new r(e,n,o.oneOf,!0).to.be.oneOf(t)},o.changes=function(e,t,n,i){3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.changes,!0).to.change(t,n)},o.changesBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.changesBy,!0).to.change(t,n).by(i)},o.doesNotChange=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.doesNotChange,!0).to.not.change(t,n)},o.changesButNotBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.changesButNotBy,!0).to.change(t,n).but.not.by(i)},o.increases=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.increases,!0).to.increase(t,n)},o.increasesBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.increasesBy,!0).to.increase(t,n).by(i)},o.doesNotIncrease=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.doesNotIncrease,!0).to.not.increase(t,n)},o.increasesButNotBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.increasesButNotBy,!0).to.increase(t,n).but.not.by(i)},o.decreases=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.decreases,!0).to.decrease(t,n)},o.decreasesBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.decreasesBy,!0).to.decrease(t,n).by(i)},o.doesNotDecrease=function(e,t,n,i){return 3===arguments.length&&"function"==typeof t&&(i=n,n=null),new r(e,i,o.doesNotDecrease,!0).to.not.decrease(t,n)},o.doesNotDecreaseBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);return new r(e,a,o.doesNotDecreaseBy,!0).to.not.decrease(t,n).by(i)},o.decreasesButNotBy=function(e,t,n,i,a){if(4===arguments.length&&"function"==typeof t){var s=i;i=n,a=s}else 3===arguments.length&&(i=n,n=null);new r(e,a,o.decreasesButNotBy,!0).to.decrease(t,n).but.not.by(i)},o.ifError=function(e){if(e)throw e},o.isExtensible=function(e,t){new r(e,t,o.isExtensible,!0).to.be.extensible},o.isNotExtensible=function(e,t){new r(e,t,o.isNotExtensible,!0).to.not.be.extensible},o.isSealed=function(e,t){new r(e,t,o.isSealed,!0).to.be.sealed},o.isNotSealed=function(e,t){new r(e,t,o.isNotSealed,!0).to.not.be.sealed},o.isFrozen=function(e,t){new r(e,t,o.isFrozen,!0).to.be.frozen},o.isNotFrozen=function(e,t){new r(e,t,o.isNotFrozen,!0).to.not.be.frozen},o.isEmpty=function(e,t){new r(e,t,o.isEmpty,!0).to.be.empty},o.isNotEmpty=function(e,t){new r(e,t,o.isNotEmpty,!0).to.not.be.empty},function e(t,r){return o[r]=o[t],e}("isOk","ok")("isNotOk","notOk")("throws","throw")("throws","Throw")("isExtensible","extensible")("isNotExtensible","notExtensible")("isSealed","sealed")("isNotSealed","notSealed")("isFrozen","frozen")("isNotFrozen","notFrozen")("isEmpty","empty")("isNotEmpty","notEmpty")}},6667:e=>{e.exports=function(e,t){e.expect=function(t,r){return new e.Assertion(t,r)},e.expect.fail=function(t,r,n,o){throw arguments.length<2&&(n=t,t=void 0),n=n||"expect.fail()",new e.AssertionError(n,{actual:t,expected:r,operator:o},e.expect.fail)}}},705:e=>{e.exports=function(e,t){var r=e.Assertion;function n(){Object.defineProperty(Object.prototype,"should",{set:function(e){Object.defineProperty(this,"should",{value:e,enumerable:!0,configurable:!0,writable:!0})},get:function e(){return this instanceof String||this instanceof Number||this instanceof Boolean||"function"==typeof Symbol&&this instanceof Symbol||"function"==typeof BigInt&&this instanceof BigInt?new r(this.valueOf(),null,e):new r(this,null,e)},configurable:!0});var t={fail:function(r,n,o,i){throw arguments.length<2&&(o=r,r=void 0),o=o||"should.fail()",new e.AssertionError(o,{actual:r,expected:n,operator:i},t.fail)},equal:function(e,t,n){new r(e,n).to.equal(t)},Throw:function(e,t,n,o){new r(e,o).to.Throw(t,n)},exist:function(e,t){new r(e,t).to.exist},not:{}};return t.not.equal=function(e,t,n){new r(e,n).to.not.equal(t)},t.not.Throw=function(e,t,n,o){new r(e,o).to.not.Throw(t,n)},t.not.exist=function(e,t){new r(e,t).to.not.exist},t.throw=t.Throw,t.not.throw=t.not.Throw,t}e.should=n,e.Should=n}},1949:(e,t,r)=>{var n=r(5100),o=r(9731),i=r(3226),a=r(8515),s=r(8316),c="function"==typeof Object.setPrototypeOf,u=function(){},l=Object.getOwnPropertyNames(u).filter((function(e){var t=Object.getOwnPropertyDescriptor(u,e);return"object"!=typeof t||!t.configurable})),p=Function.prototype.call,f=Function.prototype.apply;e.exports=function(e,t,r,u){"function"!=typeof u&&(u=function(){});var h={method:r,chainingBehavior:u};e.__methods||(e.__methods={}),e.__methods[t]=h,Object.defineProperty(e,t,{get:function(){h.chainingBehavior.call(this);var r=function(){i(this,"lockSsfi")||i(this,"ssfi",r);var e=h.method.apply(this,arguments);if(void 0!==e)return e;var t=new o.Assertion;return s(this,t),t};if(n(r,t,!0),c){var u=Object.create(this);u.call=p,u.apply=f,Object.setPrototypeOf(r,u)}else{Object.getOwnPropertyNames(e).forEach((function(t){if(-1===l.indexOf(t)){var n=Object.getOwnPropertyDescriptor(e,t);Object.defineProperty(r,t,n)}}))}return s(this,r),a(r)},configurable:!0})}},5100:e=>{var t=Object.getOwnPropertyDescriptor((function(){}),"length");e.exports=function(e,r,n){return t.configurable?(Object.defineProperty(e,"length",{get:function(){if(n)throw Error("Invalid Chai property: "+r+'.length. Due to a compatibility issue, "length" cannot directly follow "'+r+'". Use "'+r+'.lengthOf" instead.');throw Error("Invalid Chai property: "+r+'.length. See docs for proper usage of "'+r+'".')}}),e):e}},7180:(e,t,r)=>{var n=r(5100),o=r(9731),i=r(3226),a=r(8515),s=r(8316);e.exports=function(e,t,r){var c=function(){i(this,"lockSsfi")||i(this,"ssfi",c);var e=r.apply(this,arguments);if(void 0!==e)return e;var t=new o.Assertion;return s(this,t),t};n(c,t,!1),e[t]=a(c,t)}},7444:(e,t,r)=>{var n=r(9731),o=r(3226),i=r(3993),a=r(8316);e.exports=function(e,t,r){r=void 0===r?function(){}:r,Object.defineProperty(e,t,{get:function e(){i()||o(this,"lockSsfi")||o(this,"ssfi",e);var t=r.call(this);if(void 0!==t)return t;var s=new n.Assertion;return a(this,s),s},configurable:!0})}},5682:(e,t,r)=>{var n=r(154);e.exports=function(e,t){return n(e)<n(t)?-1:1}},5964:(e,t,r)=>{var n=r(9296),o=r(3226),i=r(5855);e.exports=function(e,t){var r=o(e,"message"),a=o(e,"ssfi");r=r?r+": ":"",e=o(e,"object"),(t=t.map((function(e){return e.toLowerCase()}))).sort();var s=t.map((function(e,r){var n=~["a","e","i","o","u"].indexOf(e.charAt(0))?"an":"a";return(t.length>1&&r===t.length-1?"or ":"")+n+" "+e})).join(", "),c=i(e).toLowerCase();if(!t.some((function(e){return c===e})))throw new n(r+"object tested must be "+s+", but "+c+" given",void 0,a)}},3226:e=>{e.exports=function(e,t,r){var n=e.__flags||(e.__flags=Object.create(null));if(3!==arguments.length)return n[t];n[t]=r}},8808:e=>{e.exports=function(e,t){return t.length>4?t[4]:e._obj}},7807:(e,t,r)=>{var n=r(3226),o=r(8808),i=r(3917);e.exports=function(e,t){var r=n(e,"negate"),a=n(e,"object"),s=t[3],c=o(e,t),u=r?t[2]:t[1],l=n(e,"message");return"function"==typeof u&&(u=u()),u=(u=u||"").replace(/#\{this\}/g,(function(){return i(a)})).replace(/#\{act\}/g,(function(){return i(c)})).replace(/#\{exp\}/g,(function(){return i(s)})),l?l+": "+u:u}},7602:(e,t,r)=>{var n=r(5855),o=r(3226);e.exports=function(e,t){var r=o(e,"operator"),i=o(e,"negate"),a=t[3],s=i?t[2]:t[1];if(r)return r;if("function"==typeof s&&(s=s()),(s=s||"")&&!/\shave\s/.test(s)){var c=function(e){var t=n(e);return-1!==["Array","Object","function"].indexOf(t)}(a);return/\snot\s/.test(s)?c?"notDeepStrictEqual":"notStrictEqual":c?"deepStrictEqual":"strictEqual"}}},6491:(e,t,r)=>{var n=r(7806);e.exports=function(e){return Object.keys(e).concat(n(e))}},7806:e=>{e.exports=function(e){return"function"!=typeof Object.getOwnPropertySymbols?[]:Object.getOwnPropertySymbols(e).filter((function(t){return Object.getOwnPropertyDescriptor(e,t).enumerable}))}},6807:e=>{e.exports=function(e){var t=Object.getOwnPropertyNames(e);function r(e){-1===t.indexOf(e)&&t.push(e)}for(var n=Object.getPrototypeOf(e);null!==n;)Object.getOwnPropertyNames(n).forEach(r),n=Object.getPrototypeOf(n);return t}},1118:(e,t,r)=>{var n=r(7893);t.test=r(4608),t.type=r(5855),t.expectTypes=r(5964),t.getMessage=r(7807),t.getActual=r(8808),t.inspect=r(154),t.objDisplay=r(3917),t.flag=r(3226),t.transferFlags=r(8316),t.eql=r(4964),t.getPathInfo=n.getPathInfo,t.hasProperty=n.hasProperty,t.getName=r(6708),t.addProperty=r(7444),t.addMethod=r(7180),t.overwriteProperty=r(374),t.overwriteMethod=r(6078),t.addChainableMethod=r(1949),t.overwriteChainableMethod=r(3863),t.compareByInspect=r(5682),t.getOwnEnumerablePropertySymbols=r(7806),t.getOwnEnumerableProperties=r(6491),t.checkError=r(1360),t.proxify=r(8515),t.addLengthGuard=r(5100),t.isProxyEnabled=r(3993),t.isNaN=r(1915),t.getOperator=r(7602)},154:(e,t,r)=>{r(6708);var n=r(2255),o=r(752);e.exports=function(e,t,r,i){var a={colors:i,depth:void 0===r?2:r,showHidden:t,truncate:o.truncateThreshold?o.truncateThreshold:1/0};return n.inspect(e,a)}},1915:e=>{e.exports=Number.isNaN||function(e){return e!=e}},3993:(e,t,r)=>{var n=r(752);e.exports=function(){return n.useProxy&&"undefined"!=typeof Proxy&&"undefined"!=typeof Reflect}},3917:(e,t,r)=>{var n=r(154),o=r(752);e.exports=function(e){var t=n(e),r=Object.prototype.toString.call(e);if(o.truncateThreshold&&t.length>=o.truncateThreshold){if("[object Function]"===r)return e.name&&""!==e.name?"[Function: "+e.name+"]":"[Function]";if("[object Array]"===r)return"[ Array("+e.length+") ]";if("[object Object]"===r){var i=Object.keys(e);return"{ Object ("+(i.length>2?i.splice(0,2).join(", ")+", ...":i.join(", "))+") }"}return t}return t}},3863:(e,t,r)=>{var n=r(9731),o=r(8316);e.exports=function(e,t,r,i){var a=e.__methods[t],s=a.chainingBehavior;a.chainingBehavior=function(){var e=i(s).call(this);if(void 0!==e)return e;var t=new n.Assertion;return o(this,t),t};var c=a.method;a.method=function(){var e=r(c).apply(this,arguments);if(void 0!==e)return e;var t=new n.Assertion;return o(this,t),t}}},6078:(e,t,r)=>{var n=r(5100),o=r(9731),i=r(3226),a=r(8515),s=r(8316);e.exports=function(e,t,r){var c=e[t],u=function(){throw new Error(t+" is not a function")};c&&"function"==typeof c&&(u=c);var l=function(){i(this,"lockSsfi")||i(this,"ssfi",l);var e=i(this,"lockSsfi");i(this,"lockSsfi",!0);var t=r(u).apply(this,arguments);if(i(this,"lockSsfi",e),void 0!==t)return t;var n=new o.Assertion;return s(this,n),n};n(l,t,!1),e[t]=a(l,t)}},374:(e,t,r)=>{var n=r(9731),o=r(3226),i=r(3993),a=r(8316);e.exports=function(e,t,r){var s=Object.getOwnPropertyDescriptor(e,t),c=function(){};s&&"function"==typeof s.get&&(c=s.get),Object.defineProperty(e,t,{get:function e(){i()||o(this,"lockSsfi")||o(this,"ssfi",e);var t=o(this,"lockSsfi");o(this,"lockSsfi",!0);var s=r(c).call(this);if(o(this,"lockSsfi",t),void 0!==s)return s;var u=new n.Assertion;return a(this,u),u},configurable:!0})}},8515:(e,t,r)=>{var n=r(752),o=r(3226),i=r(6807),a=r(3993),s=["__flags","__methods","_obj","assert"];e.exports=function(e,t){return a()?new Proxy(e,{get:function e(r,a){if("string"==typeof a&&-1===n.proxyExcludedKeys.indexOf(a)&&!Reflect.has(r,a)){if(t)throw Error("Invalid Chai property: "+t+"."+a+'. See docs for proper usage of "'+t+'".');var c=null,u=4;throw i(r).forEach((function(e){if(!Object.prototype.hasOwnProperty(e)&&-1===s.indexOf(e)){var t=function(e,t,r){if(Math.abs(e.length-t.length)>=r)return r;for(var n=[],o=0;o<=e.length;o++)n[o]=Array(t.length+1).fill(0),n[o][0]=o;for(var i=0;i<t.length;i++)n[0][i]=i;for(o=1;o<=e.length;o++){var a=e.charCodeAt(o-1);for(i=1;i<=t.length;i++)Math.abs(o-i)>=r?n[o][i]=r:n[o][i]=Math.min(n[o-1][i]+1,n[o][i-1]+1,n[o-1][i-1]+(a===t.charCodeAt(i-1)?0:1))}return n[e.length][t.length]}(a,e,u);t<u&&(c=e,u=t)}})),null!==c?Error("Invalid Chai property: "+a+'. Did you mean "'+c+'"?'):Error("Invalid Chai property: "+a)}return-1!==s.indexOf(a)||o(r,"lockSsfi")||o(r,"ssfi",e),Reflect.get(r,a)}}):e}},4608:(e,t,r)=>{var n=r(3226);e.exports=function(e,t){var r=n(e,"negate"),o=t[0];return r?!o:o}},8316:e=>{e.exports=function(e,t,r){var n=e.__flags||(e.__flags=Object.create(null));for(var o in t.__flags||(t.__flags=Object.create(null)),r=3!==arguments.length||r,n)(r||"object"!==o&&"ssfi"!==o&&"lockSsfi"!==o&&"message"!=o)&&(t.__flags[o]=n[o])}},1360:(e,t,r)=>{"use strict";var n=r(6708);e.exports={compatibleInstance:function(e,t){return t instanceof Error&&e===t},compatibleConstructor:function(e,t){return t instanceof Error?e.constructor===t.constructor||e instanceof t.constructor:(t.prototype instanceof Error||t===Error)&&(e.constructor===t||e instanceof t)},compatibleMessage:function(e,t){var r="string"==typeof e?e:e.message;return t instanceof RegExp?t.test(r):"string"==typeof t&&-1!==r.indexOf(t)},getMessage:function(e){var t="";return e&&e.message?t=e.message:"string"==typeof e&&(t=e),t},getConstructorName:function(e){var t=e;if(e instanceof Error)t=n(e.constructor);else if("function"==typeof e){if(""===(t=n(e)))t=n(new e)||t}return t}}},4964:(e,t,r)=>{"use strict";var n=r(5855);function o(){this._key="chai/deep-eql__"+Math.random()+Date.now()}o.prototype={get:function(e){return e[this._key]},set:function(e,t){Object.isExtensible(e)&&Object.defineProperty(e,this._key,{value:t,configurable:!0})}};var i="function"==typeof WeakMap?WeakMap:o;function a(e,t,r){if(!r||m(e)||m(t))return null;var n=r.get(e);if(n){var o=n.get(t);if("boolean"==typeof o)return o}return null}function s(e,t,r,n){if(r&&!m(e)&&!m(t)){var o=r.get(e);o?o.set(t,n):((o=new i).set(t,n),r.set(e,o))}}function c(e,t,r){if(r&&r.comparator)return l(e,t,r);var n=u(e,t);return null!==n?n:l(e,t,r)}function u(e,t){return e===t?0!==e||1/e==1/t:e!=e&&t!=t||!m(e)&&!m(t)&&null}function l(e,t,r){(r=r||{}).memoize=!1!==r.memoize&&(r.memoize||new i);var o=r&&r.comparator,l=a(e,t,r.memoize);if(null!==l)return l;var m=a(t,e,r.memoize);if(null!==m)return m;if(o){var w=o(e,t);if(!1===w||!0===w)return s(e,t,r.memoize,w),w;var x=u(e,t);if(null!==x)return x}var O=n(e);if(O!==n(t))return s(e,t,r.memoize,!1),!1;s(e,t,r.memoize,!0);var E=function(e,t,r,n){switch(r){case"String":case"Number":case"Boolean":case"Date":return c(e.valueOf(),t.valueOf());case"Promise":case"Symbol":case"function":case"WeakMap":case"WeakSet":return e===t;case"Error":return g(e,t,["name","message","code"],n);case"Arguments":case"Int8Array":case"Uint8Array":case"Uint8ClampedArray":case"Int16Array":case"Uint16Array":case"Int32Array":case"Uint32Array":case"Float32Array":case"Float64Array":case"Array":return f(e,t,n);case"RegExp":return function(e,t){return e.toString()===t.toString()}(e,t);case"Generator":return function(e,t,r){return f(d(e),d(t),r)}(e,t,n);case"DataView":return f(new Uint8Array(e.buffer),new Uint8Array(t.buffer),n);case"ArrayBuffer":return f(new Uint8Array(e),new Uint8Array(t),n);case"Set":case"Map":return p(e,t,n);case"Temporal.PlainDate":case"Temporal.PlainTime":case"Temporal.PlainDateTime":case"Temporal.Instant":case"Temporal.ZonedDateTime":case"Temporal.PlainYearMonth":case"Temporal.PlainMonthDay":return e.equals(t);case"Temporal.Duration":return e.total("nanoseconds")===t.total("nanoseconds");case"Temporal.TimeZone":case"Temporal.Calendar":return e.toString()===t.toString();default:return function(e,t,r){var n=y(e),o=y(t),i=b(e),a=b(t);if(n=n.concat(i),o=o.concat(a),n.length&&n.length===o.length)return!1!==f(v(n).sort(),v(o).sort())&&g(e,t,n,r);var s=h(e),c=h(t);if(s.length&&s.length===c.length)return s.sort(),c.sort(),f(s,c,r);if(0===n.length&&0===s.length&&0===o.length&&0===c.length)return!0;return!1}(e,t,n)}}(e,t,O,r);return s(e,t,r.memoize,E),E}function p(e,t,r){try{if(e.size!==t.size)return!1;if(0===e.size)return!0}catch(e){return!1}var n=[],o=[];return e.forEach((function(e,t){n.push([e,t])})),t.forEach((function(e,t){o.push([e,t])})),f(n.sort(),o.sort(),r)}function f(e,t,r){var n=e.length;if(n!==t.length)return!1;if(0===n)return!0;for(var o=-1;++o<n;)if(!1===c(e[o],t[o],r))return!1;return!0}function h(e){if(function(e){return"undefined"!=typeof Symbol&&"object"==typeof e&&void 0!==Symbol.iterator&&"function"==typeof e[Symbol.iterator]}(e))try{return d(e[Symbol.iterator]())}catch(e){return[]}return[]}function d(e){for(var t=e.next(),r=[t.value];!1===t.done;)t=e.next(),r.push(t.value);return r}function y(e){var t=[];for(var r in e)t.push(r);return t}function b(e){for(var t=[],r=Object.getOwnPropertySymbols(e),n=0;n<r.length;n+=1){var o=r[n];Object.getOwnPropertyDescriptor(e,o).enumerable&&t.push(o)}return t}function g(e,t,r,n){var o=r.length;if(0===o)return!0;for(var i=0;i<o;i+=1)if(!1===c(e[r[i]],t[r[i]],n))return!1;return!0}function m(e){return null===e||"object"!=typeof e}function v(e){return e.map((function(e){return"symbol"==typeof e?e.toString():e}))}e.exports=c,e.exports.MemoizeMap=i},41:(e,t,r)=>{"use strict";var n=r(655),o=r(8068),i=r(9675),a=r(5795);e.exports=function(e,t,r){if(!e||"object"!=typeof e&&"function"!=typeof e)throw new i("`obj` must be an object or a function`");if("string"!=typeof t&&"symbol"!=typeof t)throw new i("`property` must be a string or a symbol`");if(arguments.length>3&&"boolean"!=typeof arguments[3]&&null!==arguments[3])throw new i("`nonEnumerable`, if provided, must be a boolean or null");if(arguments.length>4&&"boolean"!=typeof arguments[4]&&null!==arguments[4])throw new i("`nonWritable`, if provided, must be a boolean or null");if(arguments.length>5&&"boolean"!=typeof arguments[5]&&null!==arguments[5])throw new i("`nonConfigurable`, if provided, must be a boolean or null");if(arguments.length>6&&"boolean"!=typeof arguments[6])throw new i("`loose`, if provided, must be a boolean");var s=arguments.length>3?arguments[3]:null,c=arguments.length>4?arguments[4]:null,u=arguments.length>5?arguments[5]:null,l=arguments.length>6&&arguments[6],p=!!a&&a(e,t);if(n)n(e,t,{configurable:null===u&&p?p.configurable:!u,enumerable:null===s&&p?p.enumerable:!s,value:r,writable:null===c&&p?p.writable:!c});else{if(!l&&(s||c||u))throw new o("This environment does not support defining a property as non-configurable, non-writable, or non-enumerable.");e[t]=r}}},655:(e,t,r)=>{"use strict";var n=r(453)("%Object.defineProperty%",!0)||!1;if(n)try{n({},"a",{value:1})}catch(e){n=!1}e.exports=n},1237:e=>{"use strict";e.exports=EvalError},9383:e=>{"use strict";e.exports=Error},9290:e=>{"use strict";e.exports=RangeError},9538:e=>{"use strict";e.exports=ReferenceError},8068:e=>{"use strict";e.exports=SyntaxError},9675:e=>{"use strict";e.exports=TypeError},5345:e=>{"use strict";e.exports=URIError},2682:(e,t,r)=>{"use strict";var n=r(9600),o=Object.prototype.toString,i=Object.prototype.hasOwnProperty;e.exports=function(e,t,r){if(!n(t))throw new TypeError("iterator must be a function");var a;arguments.length>=3&&(a=r),"[object Array]"===o.call(e)?function(e,t,r){for(var n=0,o=e.length;n<o;n++)i.call(e,n)&&(null==r?t(e[n],n,e):t.call(r,e[n],n,e))}(e,t,a):"string"==typeof e?function(e,t,r){for(var n=0,o=e.length;n<o;n++)null==r?t(e.charAt(n),n,e):t.call(r,e.charAt(n),n,e)}(e,t,a):function(e,t,r){for(var n in e)i.call(e,n)&&(null==r?t(e[n],n,e):t.call(r,e[n],n,e))}(e,t,a)}},9353:e=>{"use strict";var t=Object.prototype.toString,r=Math.max,n=function(e,t){for(var r=[],n=0;n<e.length;n+=1)r[n]=e[n];for(var o=0;o<t.length;o+=1)r[o+e.length]=t[o];return r};e.exports=function(e){var o=this;if("function"!=typeof o||"[object Function]"!==t.apply(o))throw new TypeError("Function.prototype.bind called on incompatible "+o);for(var i,a=function(e,t){for(var r=[],n=t||0,o=0;n<e.length;n+=1,o+=1)r[o]=e[n];return r}(arguments,1),s=r(0,o.length-a.length),c=[],u=0;u<s;u++)c[u]="$"+u;if(i=Function("binder","return function ("+function(e,t){for(var r="",n=0;n<e.length;n+=1)r+=e[n],n+1<e.length&&(r+=t);return r}(c,",")+"){ return binder.apply(this,arguments); }")((function(){if(this instanceof i){var t=o.apply(this,n(a,arguments));return Object(t)===t?t:this}return o.apply(e,n(a,arguments))})),o.prototype){var l=function(){};l.prototype=o.prototype,i.prototype=new l,l.prototype=null}return i}},6743:(e,t,r)=>{"use strict";var n=r(9353);e.exports=Function.prototype.bind||n},6708:e=>{"use strict";var t=Function.prototype.toString,r=/\s*function(?:\s|\s*\/\*[^(?:*\/)]+\*\/\s*)*([^\s\(\/]+)/;e.exports=function(e){if("function"!=typeof e)return null;var n="";if(void 0===Function.prototype.name&&void 0===e.name){var o=t.call(e);if(o.indexOf("(")>512)return n;var i=o.match(r);i&&(n=i[1])}else n=e.name;return n}},453:(e,t,r)=>{"use strict";var n,o=r(9383),i=r(1237),a=r(9290),s=r(9538),c=r(8068),u=r(9675),l=r(5345),p=Function,f=function(e){try{return p('"use strict"; return ('+e+").constructor;")()}catch(e){}},h=Object.getOwnPropertyDescriptor;if(h)try{h({},"")}catch(e){h=null}var d=function(){throw new u},y=h?function(){try{return d}catch(e){try{return h(arguments,"callee").get}catch(e){return d}}}():d,b=r(4039)(),g=r(24)(),m=Object.getPrototypeOf||(g?function(e){return e.__proto__}:null),v={},w="undefined"!=typeof Uint8Array&&m?m(Uint8Array):n,x={__proto__:null,"%AggregateError%":"undefined"==typeof AggregateError?n:AggregateError,"%Array%":Array,"%ArrayBuffer%":"undefined"==typeof ArrayBuffer?n:ArrayBuffer,"%ArrayIteratorPrototype%":b&&m?m([][Symbol.iterator]()):n,"%AsyncFromSyncIteratorPrototype%":n,"%AsyncFunction%":v,"%AsyncGenerator%":v,"%AsyncGeneratorFunction%":v,"%AsyncIteratorPrototype%":v,"%Atomics%":"undefined"==typeof Atomics?n:Atomics,"%BigInt%":"undefined"==typeof BigInt?n:BigInt,"%BigInt64Array%":"undefined"==typeof BigInt64Array?n:BigInt64Array,"%BigUint64Array%":"undefined"==typeof BigUint64Array?n:BigUint64Array,"%Boolean%":Boolean,"%DataView%":"undefined"==typeof DataView?n:DataView,"%Date%":Date,"%decodeURI%":decodeURI,"%decodeURIComponent%":decodeURIComponent,"%encodeURI%":encodeURI,"%encodeURIComponent%":encodeURIComponent,"%Error%":o,"%eval%":eval,"%EvalError%":i,"%Float32Array%":"undefined"==typeof Float32Array?n:Float32Array,"%Float64Array%":"undefined"==typeof Float64Array?n:Float64Array,"%FinalizationRegistry%":"undefined"==typeof FinalizationRegistry?n:FinalizationRegistry,"%Function%":p,"%GeneratorFunction%":v,"%Int8Array%":"undefined"==typeof Int8Array?n:Int8Array,"%Int16Array%":"undefined"==typeof Int16Array?n:Int16Array,"%Int32Array%":"undefined"==typeof Int32Array?n:Int32Array,"%isFinite%":isFinite,"%isNaN%":isNaN,"%IteratorPrototype%":b&&m?m(m([][Symbol.iterator]())):n,"%JSON%":"object"==typeof JSON?JSON:n,"%Map%":"undefined"==typeof Map?n:Map,"%MapIteratorPrototype%":"undefined"!=typeof Map&&b&&m?m((new Map)[Symbol.iterator]()):n,"%Math%":Math,"%Number%":Number,"%Object%":Object,"%parseFloat%":parseFloat,"%parseInt%":parseInt,"%Promise%":"undefined"==typeof Promise?n:Promise,"%Proxy%":"undefined"==typeof Proxy?n:Proxy,"%RangeError%":a,"%ReferenceError%":s,"%Reflect%":"undefined"==typeof Reflect?n:Reflect,"%RegExp%":RegExp,"%Set%":"undefined"==typeof Set?n:Set,"%SetIteratorPrototype%":"undefined"!=typeof Set&&b&&m?m((new Set)[Symbol.iterator]()):n,"%SharedArrayBuffer%":"undefined"==typeof SharedArrayBuffer?n:SharedArrayBuffer,"%String%":String,"%StringIteratorPrototype%":b&&m?m(""[Symbol.iterator]()):n,"%Symbol%":b?Symbol:n,"%SyntaxError%":c,"%ThrowTypeError%":y,"%TypedArray%":w,"%TypeError%":u,"%Uint8Array%":"undefined"==typeof Uint8Array?n:Uint8Array,"%Uint8ClampedArray%":"undefined"==typeof Uint8ClampedArray?n:Uint8ClampedArray,"%Uint16Array%":"undefined"==typeof Uint16Array?n:Uint16Array,"%Uint32Array%":"undefined"==typeof Uint32Array?n:Uint32Array,"%URIError%":l,"%WeakMap%":"undefined"==typeof WeakMap?n:WeakMap,"%WeakRef%":"undefined"==typeof WeakRef?n:WeakRef,"%WeakSet%":"undefined"==typeof WeakSet?n:WeakSet};if(m)try{null.error}catch(e){var O=m(m(e));x["%Error.prototype%"]=O}var E=function e(t){var r;if("%AsyncFunction%"===t)r=f("async function () {}");else if("%GeneratorFunction%"===t)r=f("function* () {}");else if("%AsyncGeneratorFunction%"===t)r=f("async function* () {}");else if("%AsyncGenerator%"===t){var n=e("%AsyncGeneratorFunction%");n&&(r=n.prototype)}else if("%AsyncIteratorPrototype%"===t){var o=e("%AsyncGenerator%");o&&m&&(r=m(o.prototype))}return x[t]=r,r},A={__proto__:null,"%ArrayBufferPrototype%":["ArrayBuffer","prototype"],"%ArrayPrototype%":["Array","prototype"],"%ArrayProto_entries%":["Array","prototype","entries"],"%ArrayProto_forEach%":["Array","prototype","forEach"],"%ArrayProto_keys%":["Array","prototype","keys"],"%ArrayProto_values%":["Array","prototype","values"],"%AsyncFunctionPrototype%":["AsyncFunction","prototype"],"%AsyncGenerator%":["AsyncGeneratorFunction","prototype"],"%AsyncGeneratorPrototype%":["AsyncGeneratorFunction","prototype","prototype"],"%BooleanPrototype%":["Boolean","prototype"],"%DataViewPrototype%":["DataView","prototype"],"%DatePrototype%":["Date","prototype"],"%ErrorPrototype%":["Error","prototype"],"%EvalErrorPrototype%":["EvalError","prototype"],"%Float32ArrayPrototype%":["Float32Array","prototype"],"%Float64ArrayPrototype%":["Float64Array","prototype"],"%FunctionPrototype%":["Function","prototype"],"%Generator%":["GeneratorFunction","prototype"],"%GeneratorPrototype%":["GeneratorFunction","prototype","prototype"],"%Int8ArrayPrototype%":["Int8Array","prototype"],"%Int16ArrayPrototype%":["Int16Array","prototype"],"%Int32ArrayPrototype%":["Int32Array","prototype"],"%JSONParse%":["JSON","parse"],"%JSONStringify%":["JSON","stringify"],"%MapPrototype%":["Map","prototype"],"%NumberPrototype%":["Number","prototype"],"%ObjectPrototype%":["Object","prototype"],"%ObjProto_toString%":["Object","prototype","toString"],"%ObjProto_valueOf%":["Object","prototype","valueOf"],"%PromisePrototype%":["Promise","prototype"],"%PromiseProto_then%":["Promise","prototype","then"],"%Promise_all%":["Promise","all"],"%Promise_reject%":["Promise","reject"],"%Promise_resolve%":["Promise","resolve"],"%RangeErrorPrototype%":["RangeError","prototype"],"%ReferenceErrorPrototype%":["ReferenceError","prototype"],"%RegExpPrototype%":["RegExp","prototype"],"%SetPrototype%":["Set","prototype"],"%SharedArrayBufferPrototype%":["SharedArrayBuffer","prototype"],"%StringPrototype%":["String","prototype"],"%SymbolPrototype%":["Symbol","prototype"],"%SyntaxErrorPrototype%":["SyntaxError","prototype"],"%TypedArrayPrototype%":["TypedArray","prototype"],"%TypeErrorPrototype%":["TypeError","prototype"],"%Uint8ArrayPrototype%":["Uint8Array","prototype"],"%Uint8ClampedArrayPrototype%":["Uint8ClampedArray","prototype"],"%Uint16ArrayPrototype%":["Uint16Array","prototype"],"%Uint32ArrayPrototype%":["Uint32Array","prototype"],"%URIErrorPrototype%":["URIError","prototype"],"%WeakMapPrototype%":["WeakMap","prototype"],"%WeakSetPrototype%":["WeakSet","prototype"]},S=r(6743),j=r(9957),P=S.call(Function.call,Array.prototype.concat),_=S.call(Function.apply,Array.prototype.splice),k=S.call(Function.call,String.prototype.replace),M=S.call(Function.call,String.prototype.slice),N=S.call(Function.call,RegExp.prototype.exec),C=/[^%.[\]]+|\[(?:(-?\d+(?:\.\d+)?)|(["'])((?:(?!\2)[^\\]|\\.)*?)\2)\]|(?=(?:\.|\[\])(?:\.|\[\]|%$))/g,D=/\\(\\)?/g,I=function(e,t){var r,n=e;if(j(A,n)&&(n="%"+(r=A[n])[0]+"%"),j(x,n)){var o=x[n];if(o===v&&(o=E(n)),void 0===o&&!t)throw new u("intrinsic "+e+" exists, but is not available. Please file an issue!");return{alias:r,name:n,value:o}}throw new c("intrinsic "+e+" does not exist!")};e.exports=function(e,t){if("string"!=typeof e||0===e.length)throw new u("intrinsic name must be a non-empty string");if(arguments.length>1&&"boolean"!=typeof t)throw new u('"allowMissing" argument must be a boolean');if(null===N(/^%?[^%]*%?$/,e))throw new c("`%` may not be present anywhere but at the beginning and end of the intrinsic name");var r=function(e){var t=M(e,0,1),r=M(e,-1);if("%"===t&&"%"!==r)throw new c("invalid intrinsic syntax, expected closing `%`");if("%"===r&&"%"!==t)throw new c("invalid intrinsic syntax, expected opening `%`");var n=[];return k(e,C,(function(e,t,r,o){n[n.length]=r?k(o,D,"$1"):t||e})),n}(e),n=r.length>0?r[0]:"",o=I("%"+n+"%",t),i=o.name,a=o.value,s=!1,l=o.alias;l&&(n=l[0],_(r,P([0,1],l)));for(var p=1,f=!0;p<r.length;p+=1){var d=r[p],y=M(d,0,1),b=M(d,-1);if(('"'===y||"'"===y||"`"===y||'"'===b||"'"===b||"`"===b)&&y!==b)throw new c("property names with quotes must have matching quotes");if("constructor"!==d&&f||(s=!0),j(x,i="%"+(n+="."+d)+"%"))a=x[i];else if(null!=a){if(!(d in a)){if(!t)throw new u("base intrinsic for "+e+" exists, but the property is not available.");return}if(h&&p+1>=r.length){var g=h(a,d);a=(f=!!g)&&"get"in g&&!("originalValue"in g.get)?g.get:a[d]}else f=j(a,d),a=a[d];f&&!s&&(x[i]=a)}}return a}},5795:(e,t,r)=>{"use strict";var n=r(453)("%Object.getOwnPropertyDescriptor%",!0);if(n)try{n([],"length")}catch(e){n=null}e.exports=n},592:(e,t,r)=>{"use strict";var n=r(655),o=function(){return!!n};o.hasArrayLengthDefineBug=function(){if(!n)return null;try{return 1!==n([],"length",{value:1}).length}catch(e){return!0}},e.exports=o},24:e=>{"use strict";var t={__proto__:null,foo:{}},r=Object;e.exports=function(){return{__proto__:t}.foo===t.foo&&!(t instanceof r)}},4039:(e,t,r)=>{"use strict";var n="undefined"!=typeof Symbol&&Symbol,o=r(1333);e.exports=function(){return"function"==typeof n&&("function"==typeof Symbol&&("symbol"==typeof n("foo")&&("symbol"==typeof Symbol("bar")&&o())))}},1333:e=>{"use strict";e.exports=function(){if("function"!=typeof Symbol||"function"!=typeof Object.getOwnPropertySymbols)return!1;if("symbol"==typeof Symbol.iterator)return!0;var e={},t=Symbol("test"),r=Object(t);if("string"==typeof t)return!1;if("[object Symbol]"!==Object.prototype.toString.call(t))return!1;if("[object Symbol]"!==Object.prototype.toString.call(r))return!1;for(t in e[t]=42,e)return!1;if("function"==typeof Object.keys&&0!==Object.keys(e).length)return!1;if("function"==typeof Object.getOwnPropertyNames&&0!==Object.getOwnPropertyNames(e).length)return!1;var n=Object.getOwnPropertySymbols(e);if(1!==n.length||n[0]!==t)return!1;if(!Object.prototype.propertyIsEnumerable.call(e,t))return!1;if("function"==typeof Object.getOwnPropertyDescriptor){var o=Object.getOwnPropertyDescriptor(e,t);if(42!==o.value||!0!==o.enumerable)return!1}return!0}},9092:(e,t,r)=>{"use strict";var n=r(1333);e.exports=function(){return n()&&!!Symbol.toStringTag}},9957:(e,t,r)=>{"use strict";var n=Function.prototype.call,o=Object.prototype.hasOwnProperty,i=r(6743);e.exports=i.call(n,o)},6698:e=>{"function"==typeof Object.create?e.exports=function(e,t){t&&(e.super_=t,e.prototype=Object.create(t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}))}:e.exports=function(e,t){if(t){e.super_=t;var r=function(){};r.prototype=t.prototype,e.prototype=new r,e.prototype.constructor=e}}},7244:(e,t,r)=>{"use strict";var n=r(9092)(),o=r(8075)("Object.prototype.toString"),i=function(e){return!(n&&e&&"object"==typeof e&&Symbol.toStringTag in e)&&"[object Arguments]"===o(e)},a=function(e){return!!i(e)||null!==e&&"object"==typeof e&&"number"==typeof e.length&&e.length>=0&&"[object Array]"!==o(e)&&"[object Function]"===o(e.callee)},s=function(){return i(arguments)}();i.isLegacyArguments=a,e.exports=s?i:a},9600:e=>{"use strict";var t,r,n=Function.prototype.toString,o="object"==typeof Reflect&&null!==Reflect&&Reflect.apply;if("function"==typeof o&&"function"==typeof Object.defineProperty)try{t=Object.defineProperty({},"length",{get:function(){throw r}}),r={},o((function(){throw 42}),null,t)}catch(e){e!==r&&(o=null)}else o=null;var i=/^\s*class\b/,a=function(e){try{var t=n.call(e);return i.test(t)}catch(e){return!1}},s=function(e){try{return!a(e)&&(n.call(e),!0)}catch(e){return!1}},c=Object.prototype.toString,u="function"==typeof Symbol&&!!Symbol.toStringTag,l=!(0 in[,]),p=function(){return!1};if("object"==typeof document){var f=document.all;c.call(f)===c.call(document.all)&&(p=function(e){if((l||!e)&&(void 0===e||"object"==typeof e))try{var t=c.call(e);return("[object HTMLAllCollection]"===t||"[object HTML document.all class]"===t||"[object HTMLCollection]"===t||"[object Object]"===t)&&null==e("")}catch(e){}return!1})}e.exports=o?function(e){if(p(e))return!0;if(!e)return!1;if("function"!=typeof e&&"object"!=typeof e)return!1;try{o(e,null,t)}catch(e){if(e!==r)return!1}return!a(e)&&s(e)}:function(e){if(p(e))return!0;if(!e)return!1;if("function"!=typeof e&&"object"!=typeof e)return!1;if(u)return s(e);if(a(e))return!1;var t=c.call(e);return!("[object Function]"!==t&&"[object GeneratorFunction]"!==t&&!/^\[object HTML/.test(t))&&s(e)}},8184:(e,t,r)=>{"use strict";var n,o=Object.prototype.toString,i=Function.prototype.toString,a=/^\s*(?:function)?\*/,s=r(9092)(),c=Object.getPrototypeOf;e.exports=function(e){if("function"!=typeof e)return!1;if(a.test(i.call(e)))return!0;if(!s)return"[object GeneratorFunction]"===o.call(e);if(!c)return!1;if(void 0===n){var t=function(){if(!s)return!1;try{return Function("return function*() {}")()}catch(e){}}();n=!!t&&c(t)}return c(e)===n}},5680:(e,t,r)=>{"use strict";var n=r(5767);e.exports=function(e){return!!n(e)}},2255:function(e,t,r){!function(e){"use strict";function t(e){return t="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},t(e)}function n(e,t){return o(e)||i(e,t)||a(e,t)||c()}function o(e){if(Array.isArray(e))return e}function i(e,t){if("undefined"!=typeof Symbol&&Symbol.iterator in Object(e)){var r=[],n=!0,o=!1,i=void 0;try{for(var a,s=e[Symbol.iterator]();!(n=(a=s.next()).done)&&(r.push(a.value),!t||r.length!==t);n=!0);}catch(e){o=!0,i=e}finally{try{n||null==s.return||s.return()}finally{if(o)throw i}}return r}}function a(e,t){if(e){if("string"==typeof e)return s(e,t);var r=Object.prototype.toString.call(e).slice(8,-1);return"Object"===r&&e.constructor&&(r=e.constructor.name),"Map"===r||"Set"===r?Array.from(e):"Arguments"===r||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(r)?s(e,t):void 0}}function s(e,t){(null==t||t>e.length)&&(t=e.length);for(var r=0,n=new Array(t);r<t;r++)n[r]=e[r];return n}function c(){throw new TypeError("Invalid attempt to destructure non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}var u={bold:["1","22"],dim:["2","22"],italic:["3","23"],underline:["4","24"],inverse:["7","27"],hidden:["8","28"],strike:["9","29"],black:["30","39"],red:["31","39"],green:["32","39"],yellow:["33","39"],blue:["34","39"],magenta:["35","39"],cyan:["36","39"],white:["37","39"],brightblack:["30;1","39"],brightred:["31;1","39"],brightgreen:["32;1","39"],brightyellow:["33;1","39"],brightblue:["34;1","39"],brightmagenta:["35;1","39"],brightcyan:["36;1","39"],brightwhite:["37;1","39"],grey:["90","39"]},l={special:"cyan",number:"yellow",bigint:"yellow",boolean:"yellow",undefined:"grey",null:"bold",string:"green",symbol:"green",date:"magenta",regexp:"red"},p="…";function f(e,t){var r=u[l[t]]||u[t];return r?"[".concat(r[0],"m").concat(String(e),"[").concat(r[1],"m"):String(e)}function h(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{},t=e.showHidden,r=void 0!==t&&t,n=e.depth,o=void 0===n?2:n,i=e.colors,a=void 0!==i&&i,s=e.customInspect,c=void 0===s||s,u=e.showProxy,l=void 0!==u&&u,p=e.maxArrayLength,h=void 0===p?1/0:p,d=e.breakLength,y=void 0===d?1/0:d,b=e.seen,g=void 0===b?[]:b,m=e.truncate,v=void 0===m?1/0:m,w=e.stylize,x=void 0===w?String:w,O={showHidden:Boolean(r),depth:Number(o),colors:Boolean(a),customInspect:Boolean(c),showProxy:Boolean(l),maxArrayLength:Number(h),breakLength:Number(y),truncate:Number(v),seen:g,stylize:x};return O.colors&&(O.stylize=f),O}function d(e,t){var r=arguments.length>2&&void 0!==arguments[2]?arguments[2]:p;e=String(e);var n=r.length,o=e.length;return n>t&&o>n?r:o>t&&o>n?"".concat(e.slice(0,t-n)).concat(r):e}function y(e,t,r){var n=arguments.length>3&&void 0!==arguments[3]?arguments[3]:", ";r=r||t.inspect;var o=e.length;if(0===o)return"";for(var i=t.truncate,a="",s="",c="",u=0;u<o;u+=1){var l=u+1===e.length,f=u+2===e.length;c="".concat(p,"(").concat(e.length-u,")");var h=e[u];t.truncate=i-a.length-(l?0:n.length);var d=s||r(h,t)+(l?"":n),y=a.length+d.length,b=y+c.length;if(l&&y>i&&a.length+c.length<=i)break;if(!l&&!f&&b>i)break;if(s=l?"":r(e[u+1],t)+(f?"":n),!l&&f&&b>i&&y+s.length>i)break;if(a+=d,!l&&!f&&y+s.length>=i){c="".concat(p,"(").concat(e.length-u-1,")");break}c=""}return"".concat(a).concat(c)}function b(e){return e.match(/^[a-zA-Z_][a-zA-Z_0-9]*$/)?e:JSON.stringify(e).replace(/'/g,"\\'").replace(/\\"/g,'"').replace(/(^"|"$)/g,"'")}function g(e,t){var r=n(e,2),o=r[0],i=r[1];return t.truncate-=2,"string"==typeof o?o=b(o):"number"!=typeof o&&(o="[".concat(t.inspect(o,t),"]")),t.truncate-=o.length,i=t.inspect(i,t),"".concat(o,": ").concat(i)}function m(e,t){var r=Object.keys(e).slice(e.length);if(!e.length&&!r.length)return"[]";t.truncate-=4;var n=y(e,t);t.truncate-=n.length;var o="";return r.length&&(o=y(r.map((function(t){return[t,e[t]]})),t,g)),"[ ".concat(n).concat(o?", ".concat(o):""," ]")}var v=Function.prototype.toString,w=/\s*function(?:\s|\s*\/\*[^(?:*\/)]+\*\/\s*)*([^\s\(\/]+)/,x=512;function O(e){if("function"!=typeof e)return null;var t="";if(void 0===Function.prototype.name&&void 0===e.name){var r=v.call(e);if(r.indexOf("(")>x)return t;var n=r.match(w);n&&(t=n[1])}else t=e.name;return t}var E=O,A=function(e){return"function"==typeof Buffer&&e instanceof Buffer?"Buffer":e[Symbol.toStringTag]?e[Symbol.toStringTag]:E(e.constructor)};function S(e,t){var r=A(e);t.truncate-=r.length+4;var n=Object.keys(e).slice(e.length);if(!e.length&&!n.length)return"".concat(r,"[]");for(var o="",i=0;i<e.length;i++){var a="".concat(t.stylize(d(e[i],t.truncate),"number")).concat(i===e.length-1?"":", ");if(t.truncate-=a.length,e[i]!==e.length&&t.truncate<=3){o+="".concat(p,"(").concat(e.length-e[i]+1,")");break}o+=a}var s="";return n.length&&(s=y(n.map((function(t){return[t,e[t]]})),t,g)),"".concat(r,"[ ").concat(o).concat(s?", ".concat(s):""," ]")}function j(e,t){var r=e.toJSON();if(null===r)return"Invalid Date";var n=r.split("T"),o=n[0];return t.stylize("".concat(o,"T").concat(d(n[1],t.truncate-o.length-1)),"date")}function P(e,t){var r=E(e);return r?t.stylize("[Function ".concat(d(r,t.truncate-11),"]"),"special"):t.stylize("[Function]","special")}function _(e,t){var r=n(e,2),o=r[0],i=r[1];return t.truncate-=4,o=t.inspect(o,t),t.truncate-=o.length,i=t.inspect(i,t),"".concat(o," => ").concat(i)}function k(e){var t=[];return e.forEach((function(e,r){t.push([r,e])})),t}function M(e,t){return e.size-1<=0?"Map{}":(t.truncate-=7,"Map{ ".concat(y(k(e),t,_)," }"))}var N=Number.isNaN||function(e){return e!=e};function C(e,t){return N(e)?t.stylize("NaN","number"):e===1/0?t.stylize("Infinity","number"):e===-1/0?t.stylize("-Infinity","number"):0===e?t.stylize(1/e==1/0?"+0":"-0","number"):t.stylize(d(e,t.truncate),"number")}function D(e,t){var r=d(e.toString(),t.truncate-1);return r!==p&&(r+="n"),t.stylize(r,"bigint")}function I(e,t){var r=e.toString().split("/")[2],n=t.truncate-(2+r.length),o=e.source;return t.stylize("/".concat(d(o,n),"/").concat(r),"regexp")}function T(e){var t=[];return e.forEach((function(e){t.push(e)})),t}function B(e,t){return 0===e.size?"Set{}":(t.truncate-=7,"Set{ ".concat(y(T(e),t)," }"))}var R=new RegExp("['\\u0000-\\u001f\\u007f-\\u009f\\u00ad\\u0600-\\u0604\\u070f\\u17b4\\u17b5\\u200c-\\u200f\\u2028-\\u202f\\u2060-\\u206f\\ufeff\\ufff0-\\uffff]","g"),F={"\b":"\\b","\t":"\\t","\n":"\\n","\f":"\\f","\r":"\\r","'":"\\'","\\":"\\\\"},U=16,L=4;function z(e){return F[e]||"\\u".concat("0000".concat(e.charCodeAt(0).toString(U)).slice(-L))}function q(e,t){return R.test(e)&&(e=e.replace(R,z)),t.stylize("'".concat(d(e,t.truncate-2),"'"),"string")}function $(e){return"description"in Symbol.prototype?e.description?"Symbol(".concat(e.description,")"):"Symbol()":e.toString()}var V=function(){return"Promise{…}"};try{var H=process.binding("util"),W=H.getPromiseDetails,K=H.kPending,J=H.kRejected;Array.isArray(W(Promise.resolve()))&&(V=function(e,t){var r=n(W(e),2),o=r[0],i=r[1];return o===K?"Promise{<pending>}":"Promise".concat(o===J?"!":"","{").concat(t.inspect(i,t),"}")})}catch(e){}var G=V;function X(e,t){var r=Object.getOwnPropertyNames(e),n=Object.getOwnPropertySymbols?Object.getOwnPropertySymbols(e):[];if(0===r.length&&0===n.length)return"{}";if(t.truncate-=4,t.seen=t.seen||[],t.seen.indexOf(e)>=0)return"[Circular]";t.seen.push(e);var o=y(r.map((function(t){return[t,e[t]]})),t,g),i=y(n.map((function(t){return[t,e[t]]})),t,g);t.seen.pop();var a="";return o&&i&&(a=", "),"{ ".concat(o).concat(a).concat(i," }")}var Q=!("undefined"==typeof Symbol||!Symbol.toStringTag)&&Symbol.toStringTag;function Y(e,t){var r="";return Q&&Q in e&&(r=e[Q]),(r=r||E(e.constructor))&&"_class"!==r||(r="<Anonymous Class>"),t.truncate-=r.length,"".concat(r).concat(X(e,t))}function Z(e,t){return 0===e.length?"Arguments[]":(t.truncate-=13,"Arguments[ ".concat(y(e,t)," ]"))}var ee=["stack","line","column","name","message","fileName","lineNumber","columnNumber","number","description"];function te(e,t){var r=Object.getOwnPropertyNames(e).filter((function(e){return-1===ee.indexOf(e)})),n=e.name;t.truncate-=n.length;var o="";"string"==typeof e.message?o=d(e.message,t.truncate):r.unshift("message"),o=o?": ".concat(o):"",t.truncate-=o.length+5;var i=y(r.map((function(t){return[t,e[t]]})),t,g);return"".concat(n).concat(o).concat(i?" { ".concat(i," }"):"")}function re(e,t){var r=n(e,2),o=r[0],i=r[1];return t.truncate-=3,i?"".concat(t.stylize(o,"yellow"),"=").concat(t.stylize('"'.concat(i,'"'),"string")):"".concat(t.stylize(o,"yellow"))}function ne(e,t){return y(e,t,oe,"\n")}function oe(e,t){var r=e.getAttributeNames(),n=e.tagName.toLowerCase(),o=t.stylize("<".concat(n),"special"),i=t.stylize(">","special"),a=t.stylize("</".concat(n,">"),"special");t.truncate-=2*n.length+5;var s="";r.length>0&&(s+=" ",s+=y(r.map((function(t){return[t,e.getAttribute(t)]})),t,re," ")),t.truncate-=s.length;var c=t.truncate,u=ne(e.children,t);return u&&u.length>c&&(u="".concat(p,"(").concat(e.children.length,")")),"".concat(o).concat(s).concat(i).concat(u).concat(a)}var ie="function"==typeof Symbol&&"function"==typeof Symbol.for?Symbol.for("chai/inspect"):"@@chai/inspect",ae=!1;try{var se=r(1786);ae=!!se.inspect&&se.inspect.custom}catch(e){ae=!1}function ce(){this.key="chai/loupe__"+Math.random()+Date.now()}ce.prototype={get:function(e){return e[this.key]},has:function(e){return this.key in e},set:function(e,t){Object.isExtensible(e)&&Object.defineProperty(e,this.key,{value:t,configurable:!0})}};var ue=new("function"==typeof WeakMap?WeakMap:ce),le={},pe={undefined:function(e,t){return t.stylize("undefined","undefined")},null:function(e,t){return t.stylize(null,"null")},boolean:function(e,t){return t.stylize(e,"boolean")},Boolean:function(e,t){return t.stylize(e,"boolean")},number:C,Number:C,bigint:D,BigInt:D,string:q,String:q,function:P,Function:P,symbol:$,Symbol:$,Array:m,Date:j,Map:M,Set:B,RegExp:I,Promise:G,WeakSet:function(e,t){return t.stylize("WeakSet{…}","special")},WeakMap:function(e,t){return t.stylize("WeakMap{…}","special")},Arguments:Z,Int8Array:S,Uint8Array:S,Uint8ClampedArray:S,Int16Array:S,Uint16Array:S,Int32Array:S,Uint32Array:S,Float32Array:S,Float64Array:S,Generator:function(){return""},DataView:function(){return""},ArrayBuffer:function(){return""},Error:te,HTMLCollection:ne,NodeList:ne},fe=function(e,t,r){return ie in e&&"function"==typeof e[ie]?e[ie](t):ae&&ae in e&&"function"==typeof e[ae]?e[ae](t.depth,t):"inspect"in e&&"function"==typeof e.inspect?e.inspect(t.depth,t):"constructor"in e&&ue.has(e.constructor)?ue.get(e.constructor)(e,t):le[r]?le[r](e,t):""},he=Object.prototype.toString;function de(e,r){(r=h(r)).inspect=de;var n=r.customInspect,o=null===e?"null":t(e);if("object"===o&&(o=he.call(e).slice(8,-1)),pe[o])return pe[o](e,r);if(n&&e){var i=fe(e,r,o);if(i)return"string"==typeof i?i:de(i,r)}var a=!!e&&Object.getPrototypeOf(e);return a===Object.prototype||null===a?X(e,r):e&&"function"==typeof HTMLElement&&e instanceof HTMLElement?oe(e,r):"constructor"in e?e.constructor!==Object?Y(e,r):X(e,r):e===Object(e)?X(e,r):r.stylize(String(e),o)}function ye(e,t){return!ue.has(e)&&(ue.set(e,t),!0)}function be(e,t){return!(e in le)&&(le[e]=t,!0)}var ge=ie;e.custom=ge,e.default=de,e.inspect=de,e.registerConstructor=ye,e.registerStringTag=be,Object.defineProperty(e,"__esModule",{value:!0})}(t)},7893:e=>{"use strict";function t(e,t){return null!=e&&t in Object(e)}function r(e){return e.replace(/([^\\])\[/g,"$1.[").match(/(\\\.|[^.]+?)+/g).map((function(e){if("constructor"===e||"__proto__"===e||"prototype"===e)return{};var t=/^\[(\d+)\]$/.exec(e);return t?{i:parseFloat(t[1])}:{p:e.replace(/\\([.[\]])/g,"$1")}}))}function n(e,t,r){var n=e,o=null;r=void 0===r?t.length:r;for(var i=0;i<r;i++){var a=t[i];n&&(n=void 0===a.p?n[a.i]:n[a.p],i===r-1&&(o=n))}return o}function o(e,o){var i=r(o),a=i[i.length-1],s={parent:i.length>1?n(e,i,i.length-1):e,name:a.p||a.i,value:n(e,i)};return s.exists=t(s.parent,s.name),s}e.exports={hasProperty:t,getPathInfo:o,getPathValue:function(e,t){return o(e,t).value},setPathValue:function(e,t,n){return function(e,t,r){for(var n=e,o=r.length,i=null,a=0;a<o;a++){var s=null,c=null;if(i=r[a],a===o-1)n[s=void 0===i.p?i.i:i.p]=t;else if(void 0!==i.p&&n[i.p])n=n[i.p];else if(void 0!==i.i&&n[i.i])n=n[i.i];else{var u=r[a+1];s=void 0===i.p?i.i:i.p,c=void 0===u.p?[]:{},n[s]=c,n=n[s]}}}(e,n,r(t)),e}}},6578:e=>{"use strict";e.exports=["Float32Array","Float64Array","Int8Array","Int16Array","Int32Array","Uint8Array","Uint8ClampedArray","Uint16Array","Uint32Array","BigInt64Array","BigUint64Array"]},6897:(e,t,r)=>{"use strict";var n=r(453),o=r(41),i=r(592)(),a=r(5795),s=r(9675),c=n("%Math.floor%");e.exports=function(e,t){if("function"!=typeof e)throw new s("`fn` is not a function");if("number"!=typeof t||t<0||t>4294967295||c(t)!==t)throw new s("`length` must be a positive 32-bit integer");var r=arguments.length>2&&!!arguments[2],n=!0,u=!0;if("length"in e&&a){var l=a(e,"length");l&&!l.configurable&&(n=!1),l&&!l.writable&&(u=!1)}return(n||u||!r)&&(i?o(e,"length",t,!0,!0):o(e,"length",t)),e}},5855:function(e){e.exports=function(){"use strict";var e="function"==typeof Promise,t=function(e){if("object"==typeof globalThis)return globalThis;Object.defineProperty(e,"typeDetectGlobalObject",{get:function(){return this},configurable:!0});var t=typeDetectGlobalObject;return delete e.typeDetectGlobalObject,t}(Object.prototype),r="undefined"!=typeof Symbol,n="undefined"!=typeof Map,o="undefined"!=typeof Set,i="undefined"!=typeof WeakMap,a="undefined"!=typeof WeakSet,s="undefined"!=typeof DataView,c=r&&void 0!==Symbol.iterator,u=r&&void 0!==Symbol.toStringTag,l=o&&"function"==typeof Set.prototype.entries,p=n&&"function"==typeof Map.prototype.entries,f=l&&Object.getPrototypeOf((new Set).entries()),h=p&&Object.getPrototypeOf((new Map).entries()),d=c&&"function"==typeof Array.prototype[Symbol.iterator],y=d&&Object.getPrototypeOf([][Symbol.iterator]()),b=c&&"function"==typeof String.prototype[Symbol.iterator],g=b&&Object.getPrototypeOf(""[Symbol.iterator]()),m=8,v=-1;function w(r){var c=typeof r;if("object"!==c)return c;if(null===r)return"null";if(r===t)return"global";if(Array.isArray(r)&&(!1===u||!(Symbol.toStringTag in r)))return"Array";if("object"==typeof window&&null!==window){if("object"==typeof window.location&&r===window.location)return"Location";if("object"==typeof window.document&&r===window.document)return"Document";if("object"==typeof window.navigator){if("object"==typeof window.navigator.mimeTypes&&r===window.navigator.mimeTypes)return"MimeTypeArray";if("object"==typeof window.navigator.plugins&&r===window.navigator.plugins)return"PluginArray"}if(("function"==typeof window.HTMLElement||"object"==typeof window.HTMLElement)&&r instanceof window.HTMLElement){if("BLOCKQUOTE"===r.tagName)return"HTMLQuoteElement";if("TD"===r.tagName)return"HTMLTableDataCellElement";if("TH"===r.tagName)return"HTMLTableHeaderCellElement"}}var l=u&&r[Symbol.toStringTag];if("string"==typeof l)return l;var p=Object.getPrototypeOf(r);return p===RegExp.prototype?"RegExp":p===Date.prototype?"Date":e&&p===Promise.prototype?"Promise":o&&p===Set.prototype?"Set":n&&p===Map.prototype?"Map":a&&p===WeakSet.prototype?"WeakSet":i&&p===WeakMap.prototype?"WeakMap":s&&p===DataView.prototype?"DataView":n&&p===h?"Map Iterator":o&&p===f?"Set Iterator":d&&p===y?"Array Iterator":b&&p===g?"String Iterator":null===p?"Object":Object.prototype.toString.call(r).slice(m,v)}return w}()},1135:e=>{e.exports=function(e){return e&&"object"==typeof e&&"function"==typeof e.copy&&"function"==typeof e.fill&&"function"==typeof e.readUInt8}},9032:(e,t,r)=>{"use strict";var n=r(7244),o=r(8184),i=r(5767),a=r(5680);function s(e){return e.call.bind(e)}var c="undefined"!=typeof BigInt,u="undefined"!=typeof Symbol,l=s(Object.prototype.toString),p=s(Number.prototype.valueOf),f=s(String.prototype.valueOf),h=s(Boolean.prototype.valueOf);if(c)var d=s(BigInt.prototype.valueOf);if(u)var y=s(Symbol.prototype.valueOf);function b(e,t){if("object"!=typeof e)return!1;try{return t(e),!0}catch(e){return!1}}function g(e){return"[object Map]"===l(e)}function m(e){return"[object Set]"===l(e)}function v(e){return"[object WeakMap]"===l(e)}function w(e){return"[object WeakSet]"===l(e)}function x(e){return"[object ArrayBuffer]"===l(e)}function O(e){return"undefined"!=typeof ArrayBuffer&&(x.working?x(e):e instanceof ArrayBuffer)}function E(e){return"[object DataView]"===l(e)}function A(e){return"undefined"!=typeof DataView&&(E.working?E(e):e instanceof DataView)}t.isArgumentsObject=n,t.isGeneratorFunction=o,t.isTypedArray=a,t.isPromise=function(e){return"undefined"!=typeof Promise&&e instanceof Promise||null!==e&&"object"==typeof e&&"function"==typeof e.then&&"function"==typeof e.catch},t.isArrayBufferView=function(e){return"undefined"!=typeof ArrayBuffer&&ArrayBuffer.isView?ArrayBuffer.isView(e):a(e)||A(e)},t.isUint8Array=function(e){return"Uint8Array"===i(e)},t.isUint8ClampedArray=function(e){return"Uint8ClampedArray"===i(e)},t.isUint16Array=function(e){return"Uint16Array"===i(e)},t.isUint32Array=function(e){return"Uint32Array"===i(e)},t.isInt8Array=function(e){return"Int8Array"===i(e)},t.isInt16Array=function(e){return"Int16Array"===i(e)},t.isInt32Array=function(e){return"Int32Array"===i(e)},t.isFloat32Array=function(e){return"Float32Array"===i(e)},t.isFloat64Array=function(e){return"Float64Array"===i(e)},t.isBigInt64Array=function(e){return"BigInt64Array"===i(e)},t.isBigUint64Array=function(e){return"BigUint64Array"===i(e)},g.working="undefined"!=typeof Map&&g(new Map),t.isMap=function(e){return"undefined"!=typeof Map&&(g.working?g(e):e instanceof Map)},m.working="undefined"!=typeof Set&&m(new Set),t.isSet=function(e){return"undefined"!=typeof Set&&(m.working?m(e):e instanceof Set)},v.working="undefined"!=typeof WeakMap&&v(new WeakMap),t.isWeakMap=function(e){return"undefined"!=typeof WeakMap&&(v.working?v(e):e instanceof WeakMap)},w.working="undefined"!=typeof WeakSet&&w(new WeakSet),t.isWeakSet=function(e){return w(e)},x.working="undefined"!=typeof ArrayBuffer&&x(new ArrayBuffer),t.isArrayBuffer=O,E.working="undefined"!=typeof ArrayBuffer&&"undefined"!=typeof DataView&&E(new DataView(new ArrayBuffer(1),0,1)),t.isDataView=A;var S="undefined"!=typeof SharedArrayBuffer?SharedArrayBuffer:void 0;function j(e){return"[object SharedArrayBuffer]"===l(e)}function P(e){return void 0!==S&&(void 0===j.working&&(j.working=j(new S)),j.working?j(e):e instanceof S)}function _(e){return b(e,p)}function k(e){return b(e,f)}function M(e){return b(e,h)}function N(e){return c&&b(e,d)}function C(e){return u&&b(e,y)}t.isSharedArrayBuffer=P,t.isAsyncFunction=function(e){return"[object AsyncFunction]"===l(e)},t.isMapIterator=function(e){return"[object Map Iterator]"===l(e)},t.isSetIterator=function(e){return"[object Set Iterator]"===l(e)},t.isGeneratorObject=function(e){return"[object Generator]"===l(e)},t.isWebAssemblyCompiledModule=function(e){return"[object WebAssembly.Module]"===l(e)},t.isNumberObject=_,t.isStringObject=k,t.isBooleanObject=M,t.isBigIntObject=N,t.isSymbolObject=C,t.isBoxedPrimitive=function(e){return _(e)||k(e)||M(e)||N(e)||C(e)},t.isAnyArrayBuffer=function(e){return"undefined"!=typeof Uint8Array&&(O(e)||P(e))},["isProxy","isExternal","isModuleNamespaceObject"].forEach((function(e){Object.defineProperty(t,e,{enumerable:!1,value:function(){throw new Error(e+" is not supported in userland")}})}))},537:(e,t,r)=>{var n=Object.getOwnPropertyDescriptors||function(e){for(var t=Object.keys(e),r={},n=0;n<t.length;n++)r[t[n]]=Object.getOwnPropertyDescriptor(e,t[n]);return r},o=/%[sdj%]/g;t.format=function(e){if(!m(e)){for(var t=[],r=0;r<arguments.length;r++)t.push(c(arguments[r]));return t.join(" ")}r=1;for(var n=arguments,i=n.length,a=String(e).replace(o,(function(e){if("%%"===e)return"%";if(r>=i)return e;switch(e){case"%s":return String(n[r++]);case"%d":return Number(n[r++]);case"%j":try{return JSON.stringify(n[r++])}catch(e){return"[Circular]"}default:return e}})),s=n[r];r<i;s=n[++r])b(s)||!x(s)?a+=" "+s:a+=" "+c(s);return a},t.deprecate=function(e,r){if("undefined"!=typeof process&&!0===process.noDeprecation)return e;if("undefined"==typeof process)return function(){return t.deprecate(e,r).apply(this,arguments)};var n=!1;return function(){if(!n){if(process.throwDeprecation)throw new Error(r);process.traceDeprecation?console.trace(r):console.error(r),n=!0}return e.apply(this,arguments)}};var i={},a=/^$/;if(process.env.NODE_DEBUG){var s=process.env.NODE_DEBUG;s=s.replace(/[|\\{}()[\]^$+?.]/g,"\\$&").replace(/\*/g,".*").replace(/,/g,"$|^").toUpperCase(),a=new RegExp("^"+s+"$","i")}function c(e,r){var n={seen:[],stylize:l};return arguments.length>=3&&(n.depth=arguments[2]),arguments.length>=4&&(n.colors=arguments[3]),y(r)?n.showHidden=r:r&&t._extend(n,r),v(n.showHidden)&&(n.showHidden=!1),v(n.depth)&&(n.depth=2),v(n.colors)&&(n.colors=!1),v(n.customInspect)&&(n.customInspect=!0),n.colors&&(n.stylize=u),p(n,e,n.depth)}function u(e,t){var r=c.styles[t];return r?"["+c.colors[r][0]+"m"+e+"["+c.colors[r][1]+"m":e}function l(e,t){return e}function p(e,r,n){if(e.customInspect&&r&&A(r.inspect)&&r.inspect!==t.inspect&&(!r.constructor||r.constructor.prototype!==r)){var o=r.inspect(n,e);return m(o)||(o=p(e,o,n)),o}var i=function(e,t){if(v(t))return e.stylize("undefined","undefined");if(m(t)){var r="'"+JSON.stringify(t).replace(/^"|"$/g,"").replace(/'/g,"\\'").replace(/\\"/g,'"')+"'";return e.stylize(r,"string")}if(g(t))return e.stylize(""+t,"number");if(y(t))return e.stylize(""+t,"boolean");if(b(t))return e.stylize("null","null")}(e,r);if(i)return i;var a=Object.keys(r),s=function(e){var t={};return e.forEach((function(e,r){t[e]=!0})),t}(a);if(e.showHidden&&(a=Object.getOwnPropertyNames(r)),E(r)&&(a.indexOf("message")>=0||a.indexOf("description")>=0))return f(r);if(0===a.length){if(A(r)){var c=r.name?": "+r.name:"";return e.stylize("[Function"+c+"]","special")}if(w(r))return e.stylize(RegExp.prototype.toString.call(r),"regexp");if(O(r))return e.stylize(Date.prototype.toString.call(r),"date");if(E(r))return f(r)}var u,l="",x=!1,S=["{","}"];(d(r)&&(x=!0,S=["[","]"]),A(r))&&(l=" [Function"+(r.name?": "+r.name:"")+"]");return w(r)&&(l=" "+RegExp.prototype.toString.call(r)),O(r)&&(l=" "+Date.prototype.toUTCString.call(r)),E(r)&&(l=" "+f(r)),0!==a.length||x&&0!=r.length?n<0?w(r)?e.stylize(RegExp.prototype.toString.call(r),"regexp"):e.stylize("[Object]","special"):(e.seen.push(r),u=x?function(e,t,r,n,o){for(var i=[],a=0,s=t.length;a<s;++a)_(t,String(a))?i.push(h(e,t,r,n,String(a),!0)):i.push("");return o.forEach((function(o){o.match(/^\d+$/)||i.push(h(e,t,r,n,o,!0))})),i}(e,r,n,s,a):a.map((function(t){return h(e,r,n,s,t,x)})),e.seen.pop(),function(e,t,r){var n=e.reduce((function(e,t){return t.indexOf("\n")>=0&&0,e+t.replace(/\u001b\[\d\d?m/g,"").length+1}),0);if(n>60)return r[0]+(""===t?"":t+"\n ")+" "+e.join(",\n  ")+" "+r[1];return r[0]+t+" "+e.join(", ")+" "+r[1]}(u,l,S)):S[0]+l+S[1]}function f(e){return"["+Error.prototype.toString.call(e)+"]"}function h(e,t,r,n,o,i){var a,s,c;if((c=Object.getOwnPropertyDescriptor(t,o)||{value:t[o]}).get?s=c.set?e.stylize("[Getter/Setter]","special"):e.stylize("[Getter]","special"):c.set&&(s=e.stylize("[Setter]","special")),_(n,o)||(a="["+o+"]"),s||(e.seen.indexOf(c.value)<0?(s=b(r)?p(e,c.value,null):p(e,c.value,r-1)).indexOf("\n")>-1&&(s=i?s.split("\n").map((function(e){return"  "+e})).join("\n").slice(2):"\n"+s.split("\n").map((function(e){return"   "+e})).join("\n")):s=e.stylize("[Circular]","special")),v(a)){if(i&&o.match(/^\d+$/))return s;(a=JSON.stringify(""+o)).match(/^"([a-zA-Z_][a-zA-Z_0-9]*)"$/)?(a=a.slice(1,-1),a=e.stylize(a,"name")):(a=a.replace(/'/g,"\\'").replace(/\\"/g,'"').replace(/(^"|"$)/g,"'"),a=e.stylize(a,"string"))}return a+": "+s}function d(e){return Array.isArray(e)}function y(e){return"boolean"==typeof e}function b(e){return null===e}function g(e){return"number"==typeof e}function m(e){return"string"==typeof e}function v(e){return void 0===e}function w(e){return x(e)&&"[object RegExp]"===S(e)}function x(e){return"object"==typeof e&&null!==e}function O(e){return x(e)&&"[object Date]"===S(e)}function E(e){return x(e)&&("[object Error]"===S(e)||e instanceof Error)}function A(e){return"function"==typeof e}function S(e){return Object.prototype.toString.call(e)}function j(e){return e<10?"0"+e.toString(10):e.toString(10)}t.debuglog=function(e){if(e=e.toUpperCase(),!i[e])if(a.test(e)){var r=process.pid;i[e]=function(){var n=t.format.apply(t,arguments);console.error("%s %d: %s",e,r,n)}}else i[e]=function(){};return i[e]},t.inspect=c,c.colors={bold:[1,22],italic:[3,23],underline:[4,24],inverse:[7,27],white:[37,39],grey:[90,39],black:[30,39],blue:[34,39],cyan:[36,39],green:[32,39],magenta:[35,39],red:[31,39],yellow:[33,39]},c.styles={special:"cyan",number:"yellow",boolean:"yellow",undefined:"grey",null:"bold",string:"green",date:"magenta",regexp:"red"},t.types=r(9032),t.isArray=d,t.isBoolean=y,t.isNull=b,t.isNullOrUndefined=function(e){return null==e},t.isNumber=g,t.isString=m,t.isSymbol=function(e){return"symbol"==typeof e},t.isUndefined=v,t.isRegExp=w,t.types.isRegExp=w,t.isObject=x,t.isDate=O,t.types.isDate=O,t.isError=E,t.types.isNativeError=E,t.isFunction=A,t.isPrimitive=function(e){return null===e||"boolean"==typeof e||"number"==typeof e||"string"==typeof e||"symbol"==typeof e||void 0===e},t.isBuffer=r(1135);var P=["Jan","Feb","Mar","Apr","May","Jun","Jul","Aug","Sep","Oct","Nov","Dec"];function _(e,t){return Object.prototype.hasOwnProperty.call(e,t)}t.log=function(){var e,r;console.log("%s - %s",(e=new Date,r=[j(e.getHours()),j(e.getMinutes()),j(e.getSeconds())].join(":"),[e.getDate(),P[e.getMonth()],r].join(" ")),t.format.apply(t,arguments))},t.inherits=r(6698),t._extend=function(e,t){if(!t||!x(t))return e;for(var r=Object.keys(t),n=r.length;n--;)e[r[n]]=t[r[n]];return e};var k="undefined"!=typeof Symbol?Symbol("util.promisify.custom"):void 0;function M(e,t){if(!e){var r=new Error("Promise was rejected with a falsy value");r.reason=e,e=r}return t(e)}t.promisify=function(e){if("function"!=typeof e)throw new TypeError('The "original" argument must be of type Function');if(k&&e[k]){var t;if("function"!=typeof(t=e[k]))throw new TypeError('The "util.promisify.custom" argument must be of type Function');return Object.defineProperty(t,k,{value:t,enumerable:!1,writable:!1,configurable:!0}),t}function t(){for(var t,r,n=new Promise((function(e,n){t=e,r=n})),o=[],i=0;i<arguments.length;i++)o.push(arguments[i]);o.push((function(e,n){e?r(e):t(n)}));try{e.apply(this,o)}catch(e){r(e)}return n}return Object.setPrototypeOf(t,Object.getPrototypeOf(e)),k&&Object.defineProperty(t,k,{value:t,enumerable:!1,writable:!1,configurable:!0}),Object.defineProperties(t,n(e))},t.promisify.custom=k,t.callbackify=function(e){if("function"!=typeof e)throw new TypeError('The "original" argument must be of type Function');function t(){for(var t=[],r=0;r<arguments.length;r++)t.push(arguments[r]);var n=t.pop();if("function"!=typeof n)throw new TypeError("The last argument must be of type Function");var o=this,i=function(){return n.apply(o,arguments)};e.apply(this,t).then((function(e){process.nextTick(i.bind(null,null,e))}),(function(e){process.nextTick(M.bind(null,e,i))}))}return Object.setPrototypeOf(t,Object.getPrototypeOf(e)),Object.defineProperties(t,n(e)),t}},5767:(e,t,r)=>{"use strict";var n=r(2682),o=r(9209),i=r(487),a=r(8075),s=r(5795),c=a("Object.prototype.toString"),u=r(9092)(),l="undefined"==typeof globalThis?r.g:globalThis,p=o(),f=a("String.prototype.slice"),h=Object.getPrototypeOf,d=a("Array.prototype.indexOf",!0)||function(e,t){for(var r=0;r<e.length;r+=1)if(e[r]===t)return r;return-1},y={__proto__:null};n(p,u&&s&&h?function(e){var t=new l[e];if(Symbol.toStringTag in t){var r=h(t),n=s(r,Symbol.toStringTag);if(!n){var o=h(r);n=s(o,Symbol.toStringTag)}y["$"+e]=i(n.get)}}:function(e){var t=new l[e],r=t.slice||t.set;r&&(y["$"+e]=i(r))});e.exports=function(e){if(!e||"object"!=typeof e)return!1;if(!u){var t=f(c(e),8,-1);return d(p,t)>-1?t:"Object"===t&&function(e){var t=!1;return n(y,(function(r,n){if(!t)try{r(e),t=f(n,1)}catch(e){}})),t}(e)}return s?function(e){var t=!1;return n(y,(function(r,n){if(!t)try{"$"+r(e)===n&&(t=f(n,1))}catch(e){}})),t}(e):null}},1786:()=>{},9209:(e,t,r)=>{"use strict";var n=r(6578),o="undefined"==typeof globalThis?r.g:globalThis;e.exports=function(){for(var e=[],t=0;t<n.length;t++)"function"==typeof o[n[t]]&&(e[e.length]=n[t]);return e}},274:(e,t,r)=>{"use strict";r.r(t),r.d(t,{JSONPath:()=>y});class n{static get version(){return"1.4.0"}static toString(){return"JavaScript Expression Parser (JSEP) v"+n.version}static addUnaryOp(e){return n.max_unop_len=Math.max(e.length,n.max_unop_len),n.unary_ops[e]=1,n}static addBinaryOp(e,t,r){return n.max_binop_len=Math.max(e.length,n.max_binop_len),n.binary_ops[e]=t,r?n.right_associative.add(e):n.right_associative.delete(e),n}static addIdentifierChar(e){return n.additional_identifier_chars.add(e),n}static addLiteral(e,t){return n.literals[e]=t,n}static removeUnaryOp(e){return delete n.unary_ops[e],e.length===n.max_unop_len&&(n.max_unop_len=n.getMaxKeyLen(n.unary_ops)),n}static removeAllUnaryOps(){return n.unary_ops={},n.max_unop_len=0,n}static removeIdentifierChar(e){return n.additional_identifier_chars.delete(e),n}static removeBinaryOp(e){return delete n.binary_ops[e],e.length===n.max_binop_len&&(n.max_binop_len=n.getMaxKeyLen(n.binary_ops)),n.right_associative.delete(e),n}static removeAllBinaryOps(){return n.binary_ops={},n.max_binop_len=0,n}static removeLiteral(e){return delete n.literals[e],n}static removeAllLiterals(){return n.literals={},n}get char(){return this.expr.charAt(this.index)}get code(){return this.expr.charCodeAt(this.index)}constructor(e){this.expr=e,this.index=0}static parse(e){return new n(e).parse()}static getMaxKeyLen(e){return Math.max(0,...Object.keys(e).map((e=>e.length)))}static isDecimalDigit(e){return e>=48&&e<=57}static binaryPrecedence(e){return n.binary_ops[e]||0}static isIdentifierStart(e){return e>=65&&e<=90||e>=97&&e<=122||e>=128&&!n.binary_ops[String.fromCharCode(e)]||n.additional_identifier_chars.has(String.fromCharCode(e))}static isIdentifierPart(e){return n.isIdentifierStart(e)||n.isDecimalDigit(e)}throwError(e){const t=new Error(e+" at character "+this.index);throw t.index=this.index,t.description=e,t}runHook(e,t){if(n.hooks[e]){const r={context:this,node:t};return n.hooks.run(e,r),r.node}return t}searchHook(e){if(n.hooks[e]){const t={context:this};return n.hooks[e].find((function(e){return e.call(t.context,t),t.node})),t.node}}gobbleSpaces(){let e=this.code;for(;e===n.SPACE_CODE||e===n.TAB_CODE||e===n.LF_CODE||e===n.CR_CODE;)e=this.expr.charCodeAt(++this.index);this.runHook("gobble-spaces")}parse(){this.runHook("before-all");const e=this.gobbleExpressions(),t=1===e.length?e[0]:{type:n.COMPOUND,body:e};return this.runHook("after-all",t)}gobbleExpressions(e){let t,r,o=[];for(;this.index<this.expr.length;)if(t=this.code,t===n.SEMCOL_CODE||t===n.COMMA_CODE)this.index++;else if(r=this.gobbleExpression())o.push(r);else if(this.index<this.expr.length){if(t===e)break;this.throwError('Unexpected "'+this.char+'"')}return o}gobbleExpression(){const e=this.searchHook("gobble-expression")||this.gobbleBinaryExpression();return this.gobbleSpaces(),this.runHook("after-expression",e)}gobbleBinaryOp(){this.gobbleSpaces();let e=this.expr.substr(this.index,n.max_binop_len),t=e.length;for(;t>0;){if(n.binary_ops.hasOwnProperty(e)&&(!n.isIdentifierStart(this.code)||this.index+e.length<this.expr.length&&!n.isIdentifierPart(this.expr.charCodeAt(this.index+e.length))))return this.index+=t,e;e=e.substr(0,--t)}return!1}gobbleBinaryExpression(){let e,t,r,o,i,a,s,c,u;if(a=this.gobbleToken(),!a)return a;if(t=this.gobbleBinaryOp(),!t)return a;for(i={value:t,prec:n.binaryPrecedence(t),right_a:n.right_associative.has(t)},s=this.gobbleToken(),s||this.throwError("Expected expression after "+t),o=[a,i,s];t=this.gobbleBinaryOp();){if(r=n.binaryPrecedence(t),0===r){this.index-=t.length;break}i={value:t,prec:r,right_a:n.right_associative.has(t)},u=t;const c=e=>i.right_a&&e.right_a?r>e.prec:r<=e.prec;for(;o.length>2&&c(o[o.length-2]);)s=o.pop(),t=o.pop().value,a=o.pop(),e={type:n.BINARY_EXP,operator:t,left:a,right:s},o.push(e);e=this.gobbleToken(),e||this.throwError("Expected expression after "+u),o.push(i,e)}for(c=o.length-1,e=o[c];c>1;)e={type:n.BINARY_EXP,operator:o[c-1].value,left:o[c-2],right:e},c-=2;return e}gobbleToken(){let e,t,r,o;if(this.gobbleSpaces(),o=this.searchHook("gobble-token"),o)return this.runHook("after-token",o);if(e=this.code,n.isDecimalDigit(e)||e===n.PERIOD_CODE)return this.gobbleNumericLiteral();if(e===n.SQUOTE_CODE||e===n.DQUOTE_CODE)o=this.gobbleStringLiteral();else if(e===n.OBRACK_CODE)o=this.gobbleArray();else{for(t=this.expr.substr(this.index,n.max_unop_len),r=t.length;r>0;){if(n.unary_ops.hasOwnProperty(t)&&(!n.isIdentifierStart(this.code)||this.index+t.length<this.expr.length&&!n.isIdentifierPart(this.expr.charCodeAt(this.index+t.length)))){this.index+=r;const e=this.gobbleToken();return e||this.throwError("missing unaryOp argument"),this.runHook("after-token",{type:n.UNARY_EXP,operator:t,argument:e,prefix:!0})}t=t.substr(0,--r)}n.isIdentifierStart(e)?(o=this.gobbleIdentifier(),n.literals.hasOwnProperty(o.name)?o={type:n.LITERAL,value:n.literals[o.name],raw:o.name}:o.name===n.this_str&&(o={type:n.THIS_EXP})):e===n.OPAREN_CODE&&(o=this.gobbleGroup())}return o?(o=this.gobbleTokenProperty(o),this.runHook("after-token",o)):this.runHook("after-token",!1)}gobbleTokenProperty(e){this.gobbleSpaces();let t=this.code;for(;t===n.PERIOD_CODE||t===n.OBRACK_CODE||t===n.OPAREN_CODE||t===n.QUMARK_CODE;){let r;if(t===n.QUMARK_CODE){if(this.expr.charCodeAt(this.index+1)!==n.PERIOD_CODE)break;r=!0,this.index+=2,this.gobbleSpaces(),t=this.code}this.index++,t===n.OBRACK_CODE?((e={type:n.MEMBER_EXP,computed:!0,object:e,property:this.gobbleExpression()}).property||this.throwError('Unexpected "'+this.char+'"'),this.gobbleSpaces(),t=this.code,t!==n.CBRACK_CODE&&this.throwError("Unclosed ["),this.index++):t===n.OPAREN_CODE?e={type:n.CALL_EXP,arguments:this.gobbleArguments(n.CPAREN_CODE),callee:e}:(t===n.PERIOD_CODE||r)&&(r&&this.index--,this.gobbleSpaces(),e={type:n.MEMBER_EXP,computed:!1,object:e,property:this.gobbleIdentifier()}),r&&(e.optional=!0),this.gobbleSpaces(),t=this.code}return e}gobbleNumericLiteral(){let e,t,r="";for(;n.isDecimalDigit(this.code);)r+=this.expr.charAt(this.index++);if(this.code===n.PERIOD_CODE)for(r+=this.expr.charAt(this.index++);n.isDecimalDigit(this.code);)r+=this.expr.charAt(this.index++);if(e=this.char,"e"===e||"E"===e){for(r+=this.expr.charAt(this.index++),e=this.char,"+"!==e&&"-"!==e||(r+=this.expr.charAt(this.index++));n.isDecimalDigit(this.code);)r+=this.expr.charAt(this.index++);n.isDecimalDigit(this.expr.charCodeAt(this.index-1))||this.throwError("Expected exponent ("+r+this.char+")")}return t=this.code,n.isIdentifierStart(t)?this.throwError("Variable names cannot start with a number ("+r+this.char+")"):(t===n.PERIOD_CODE||1===r.length&&r.charCodeAt(0)===n.PERIOD_CODE)&&this.throwError("Unexpected period"),{type:n.LITERAL,value:parseFloat(r),raw:r}}gobbleStringLiteral(){let e="";const t=this.index,r=this.expr.charAt(this.index++);let o=!1;for(;this.index<this.expr.length;){let t=this.expr.charAt(this.index++);if(t===r){o=!0;break}if("\\"===t)switch(t=this.expr.charAt(this.index++),t){case"n":e+="\n";break;case"r":e+="\r";break;case"t":e+="\t";break;case"b":e+="\b";break;case"f":e+="\f";break;case"v":e+="\v";break;default:e+=t}else e+=t}return o||this.throwError('Unclosed quote after "'+e+'"'),{type:n.LITERAL,value:e,raw:this.expr.substring(t,this.index)}}gobbleIdentifier(){let e=this.code,t=this.index;for(n.isIdentifierStart(e)?this.index++:this.throwError("Unexpected "+this.char);this.index<this.expr.length&&(e=this.code,n.isIdentifierPart(e));)this.index++;return{type:n.IDENTIFIER,name:this.expr.slice(t,this.index)}}gobbleArguments(e){const t=[];let r=!1,o=0;for(;this.index<this.expr.length;){this.gobbleSpaces();let i=this.code;if(i===e){r=!0,this.index++,e===n.CPAREN_CODE&&o&&o>=t.length&&this.throwError("Unexpected token "+String.fromCharCode(e));break}if(i===n.COMMA_CODE){if(this.index++,o++,o!==t.length)if(e===n.CPAREN_CODE)this.throwError("Unexpected token ,");else if(e===n.CBRACK_CODE)for(let e=t.length;e<o;e++)t.push(null)}else if(t.length!==o&&0!==o)this.throwError("Expected comma");else{const e=this.gobbleExpression();e&&e.type!==n.COMPOUND||this.throwError("Expected comma"),t.push(e)}}return r||this.throwError("Expected "+String.fromCharCode(e)),t}gobbleGroup(){this.index++;let e=this.gobbleExpressions(n.CPAREN_CODE);if(this.code===n.CPAREN_CODE)return this.index++,1===e.length?e[0]:!!e.length&&{type:n.SEQUENCE_EXP,expressions:e};this.throwError("Unclosed (")}gobbleArray(){return this.index++,{type:n.ARRAY_EXP,elements:this.gobbleArguments(n.CBRACK_CODE)}}}const o=new class{add(e,t,r){if("string"!=typeof arguments[0])for(let e in arguments[0])this.add(e,arguments[0][e],arguments[1]);else(Array.isArray(e)?e:[e]).forEach((function(e){this[e]=this[e]||[],t&&this[e][r?"unshift":"push"](t)}),this)}run(e,t){this[e]=this[e]||[],this[e].forEach((function(e){e.call(t&&t.context?t.context:t,t)}))}};Object.assign(n,{hooks:o,plugins:new class{constructor(e){this.jsep=e,this.registered={}}register(){for(var e=arguments.length,t=new Array(e),r=0;r<e;r++)t[r]=arguments[r];t.forEach((e=>{if("object"!=typeof e||!e.name||!e.init)throw new Error("Invalid JSEP plugin format");this.registered[e.name]||(e.init(this.jsep),this.registered[e.name]=e)}))}}(n),COMPOUND:"Compound",SEQUENCE_EXP:"SequenceExpression",IDENTIFIER:"Identifier",MEMBER_EXP:"MemberExpression",LITERAL:"Literal",THIS_EXP:"ThisExpression",CALL_EXP:"CallExpression",UNARY_EXP:"UnaryExpression",BINARY_EXP:"BinaryExpression",ARRAY_EXP:"ArrayExpression",TAB_CODE:9,LF_CODE:10,CR_CODE:13,SPACE_CODE:32,PERIOD_CODE:46,COMMA_CODE:44,SQUOTE_CODE:39,DQUOTE_CODE:34,OPAREN_CODE:40,CPAREN_CODE:41,OBRACK_CODE:91,CBRACK_CODE:93,QUMARK_CODE:63,SEMCOL_CODE:59,COLON_CODE:58,unary_ops:{"-":1,"!":1,"~":1,"+":1},binary_ops:{"||":1,"??":1,"&&":2,"|":3,"^":4,"&":5,"==":6,"!=":6,"===":6,"!==":6,"<":7,">":7,"<=":7,">=":7,"<<":8,">>":8,">>>":8,"+":9,"-":9,"*":10,"/":10,"%":10,"**":11},right_associative:new Set(["**"]),additional_identifier_chars:new Set(["$","_"]),literals:{true:!0,false:!1,null:null},this_str:"this"}),n.max_unop_len=n.getMaxKeyLen(n.unary_ops),n.max_binop_len=n.getMaxKeyLen(n.binary_ops);const i=e=>new n(e).parse(),a=Object.getOwnPropertyNames(class{});Object.getOwnPropertyNames(n).filter((e=>!a.includes(e)&&void 0===i[e])).forEach((e=>{i[e]=n[e]})),i.Jsep=n;var s={name:"ternary",init(e){e.hooks.add("after-expression",(function(t){if(t.node&&this.code===e.QUMARK_CODE){this.index++;const r=t.node,n=this.gobbleExpression();if(n||this.throwError("Expected expression"),this.gobbleSpaces(),this.code===e.COLON_CODE){this.index++;const o=this.gobbleExpression();if(o||this.throwError("Expected expression"),t.node={type:"ConditionalExpression",test:r,consequent:n,alternate:o},r.operator&&e.binary_ops[r.operator]<=.9){let n=r;for(;n.right.operator&&e.binary_ops[n.right.operator]<=.9;)n=n.right;t.node.test=n.right,n.right=t.node,t.node=r}}else this.throwError("Expected :")}}))}};i.plugins.register(s);var c={name:"regex",init(e){e.hooks.add("gobble-token",(function(t){if(47===this.code){const r=++this.index;let n=!1;for(;this.index<this.expr.length;){if(47===this.code&&!n){const n=this.expr.slice(r,this.index);let o,i="";for(;++this.index<this.expr.length;){const e=this.code;if(!(e>=97&&e<=122||e>=65&&e<=90||e>=48&&e<=57))break;i+=this.char}try{o=new RegExp(n,i)}catch(e){this.throwError(e.message)}return t.node={type:e.LITERAL,value:o,raw:this.expr.slice(r-1,this.index)},t.node=this.gobbleTokenProperty(t.node),t.node}this.code===e.OBRACK_CODE?n=!0:n&&this.code===e.CBRACK_CODE&&(n=!1),this.index+=92===this.code?2:1}this.throwError("Unclosed Regex")}}))}};const u={name:"assignment",assignmentOperators:new Set(["=","*=","**=","/=","%=","+=","-=","<<=",">>=",">>>=","&=","^=","|=","||=","&&=","??="]),updateOperators:[43,45],assignmentPrecedence:.9,init(e){const t=[e.IDENTIFIER,e.MEMBER_EXP];function r(e){u.assignmentOperators.has(e.operator)?(e.type="AssignmentExpression",r(e.left),r(e.right)):e.operator||Object.values(e).forEach((e=>{e&&"object"==typeof e&&r(e)}))}u.assignmentOperators.forEach((t=>e.addBinaryOp(t,u.assignmentPrecedence,!0))),e.hooks.add("gobble-token",(function(e){const r=this.code;u.updateOperators.some((e=>e===r&&e===this.expr.charCodeAt(this.index+1)))&&(this.index+=2,e.node={type:"UpdateExpression",operator:43===r?"++":"--",argument:this.gobbleTokenProperty(this.gobbleIdentifier()),prefix:!0},e.node.argument&&t.includes(e.node.argument.type)||this.throwError(`Unexpected ${e.node.operator}`))})),e.hooks.add("after-token",(function(e){if(e.node){const r=this.code;u.updateOperators.some((e=>e===r&&e===this.expr.charCodeAt(this.index+1)))&&(t.includes(e.node.type)||this.throwError(`Unexpected ${e.node.operator}`),this.index+=2,e.node={type:"UpdateExpression",operator:43===r?"++":"--",argument:e.node,prefix:!1})}})),e.hooks.add("after-expression",(function(e){e.node&&r(e.node)}))}};i.plugins.register(c,u),i.addUnaryOp("typeof"),i.addLiteral("null",null),i.addLiteral("undefined",void 0);const l=new Set(["constructor","__proto__","__defineGetter__","__defineSetter__"]),p={evalAst(e,t){switch(e.type){case"BinaryExpression":case"LogicalExpression":return p.evalBinaryExpression(e,t);case"Compound":return p.evalCompound(e,t);case"ConditionalExpression":return p.evalConditionalExpression(e,t);case"Identifier":return p.evalIdentifier(e,t);case"Literal":return p.evalLiteral(e,t);case"MemberExpression":return p.evalMemberExpression(e,t);case"UnaryExpression":return p.evalUnaryExpression(e,t);case"ArrayExpression":return p.evalArrayExpression(e,t);case"CallExpression":return p.evalCallExpression(e,t);case"AssignmentExpression":return p.evalAssignmentExpression(e,t);default:throw SyntaxError("Unexpected expression",e)}},evalBinaryExpression:(e,t)=>({"||":(e,t)=>e||t(),"&&":(e,t)=>e&&t(),"|":(e,t)=>e|t(),"^":(e,t)=>e^t(),"&":(e,t)=>e&t(),"==":(e,t)=>e==t(),"!=":(e,t)=>e!=t(),"===":(e,t)=>e===t(),"!==":(e,t)=>e!==t(),"<":(e,t)=>e<t(),">":(e,t)=>e>t(),"<=":(e,t)=>e<=t(),">=":(e,t)=>e>=t(),"<<":(e,t)=>e<<t(),">>":(e,t)=>e>>t(),">>>":(e,t)=>e>>>t(),"+":(e,t)=>e+t(),"-":(e,t)=>e-t(),"*":(e,t)=>e*t(),"/":(e,t)=>e/t(),"%":(e,t)=>e%t()}[e.operator](p.evalAst(e.left,t),(()=>p.evalAst(e.right,t)))),evalCompound(e,t){let r;for(let n=0;n<e.body.length;n++){"Identifier"===e.body[n].type&&["var","let","const"].includes(e.body[n].name)&&e.body[n+1]&&"AssignmentExpression"===e.body[n+1].type&&(n+=1);const o=e.body[n];r=p.evalAst(o,t)}return r},evalConditionalExpression:(e,t)=>p.evalAst(e.test,t)?p.evalAst(e.consequent,t):p.evalAst(e.alternate,t),evalIdentifier(e,t){if(Object.hasOwn(t,e.name))return t[e.name];throw ReferenceError(`${e.name} is not defined`)},evalLiteral:e=>e.value,evalMemberExpression(e,t){const r=String(e.computed?p.evalAst(e.property):e.property.name),n=p.evalAst(e.object,t);if(null==n)throw TypeError(`Cannot read properties of ${n} (reading '${r}')`);if(!Object.hasOwn(n,r)&&l.has(r))throw TypeError(`Cannot read properties of ${n} (reading '${r}')`);const o=n[r];return"function"==typeof o?o.bind(n):o},evalUnaryExpression:(e,t)=>({"-":e=>-p.evalAst(e,t),"!":e=>!p.evalAst(e,t),"~":e=>~p.evalAst(e,t),"+":e=>+p.evalAst(e,t),typeof:e=>typeof p.evalAst(e,t)}[e.operator](e.argument)),evalArrayExpression:(e,t)=>e.elements.map((e=>p.evalAst(e,t))),evalCallExpression(e,t){const r=e.arguments.map((e=>p.evalAst(e,t)));return p.evalAst(e.callee,t)(...r)},evalAssignmentExpression(e,t){if("Identifier"!==e.left.type)throw SyntaxError("Invalid left-hand side in assignment");const r=e.left.name,n=p.evalAst(e.right,t);return t[r]=n,t[r]}};function f(e,t){return(e=e.slice()).push(t),e}function h(e,t){return(t=t.slice()).unshift(e),t}class d extends Error{constructor(e){super('JSONPath should not be called with "new" (it prevents return of (unwrapped) scalar values)'),this.avoidNew=!0,this.value=e,this.name="NewError"}}function y(e,t,r,n,o){if(!(this instanceof y))try{return new y(e,t,r,n,o)}catch(e){if(!e.avoidNew)throw e;return e.value}"string"==typeof e&&(o=n,n=r,r=t,t=e,e=null);const i=e&&"object"==typeof e;if(e=e||{},this.json=e.json||r,this.path=e.path||t,this.resultType=e.resultType||"value",this.flatten=e.flatten||!1,this.wrap=!Object.hasOwn(e,"wrap")||e.wrap,this.sandbox=e.sandbox||{},this.eval=void 0===e.eval?"safe":e.eval,this.ignoreEvalErrors=void 0!==e.ignoreEvalErrors&&e.ignoreEvalErrors,this.parent=e.parent||null,this.parentProperty=e.parentProperty||null,this.callback=e.callback||n||null,this.otherTypeCallback=e.otherTypeCallback||o||function(){throw new TypeError("You must supply an otherTypeCallback callback option with the @other() operator.")},!1!==e.autostart){const n={path:i?e.path:t};i?"json"in e&&(n.json=e.json):n.json=r;const o=this.evaluate(n);if(!o||"object"!=typeof o)throw new d(o);return o}}y.prototype.evaluate=function(e,t,r,n){let o=this.parent,i=this.parentProperty,{flatten:a,wrap:s}=this;if(this.currResultType=this.resultType,this.currEval=this.eval,this.currSandbox=this.sandbox,r=r||this.callback,this.currOtherTypeCallback=n||this.otherTypeCallback,t=t||this.json,(e=e||this.path)&&"object"==typeof e&&!Array.isArray(e)){if(!e.path&&""!==e.path)throw new TypeError('You must supply a "path" property when providing an object argument to JSONPath.evaluate().');if(!Object.hasOwn(e,"json"))throw new TypeError('You must supply a "json" property when providing an object argument to JSONPath.evaluate().');({json:t}=e),a=Object.hasOwn(e,"flatten")?e.flatten:a,this.currResultType=Object.hasOwn(e,"resultType")?e.resultType:this.currResultType,this.currSandbox=Object.hasOwn(e,"sandbox")?e.sandbox:this.currSandbox,s=Object.hasOwn(e,"wrap")?e.wrap:s,this.currEval=Object.hasOwn(e,"eval")?e.eval:this.currEval,r=Object.hasOwn(e,"callback")?e.callback:r,this.currOtherTypeCallback=Object.hasOwn(e,"otherTypeCallback")?e.otherTypeCallback:this.currOtherTypeCallback,o=Object.hasOwn(e,"parent")?e.parent:o,i=Object.hasOwn(e,"parentProperty")?e.parentProperty:i,e=e.path}if(o=o||null,i=i||null,Array.isArray(e)&&(e=y.toPathString(e)),!e&&""!==e||!t)return;const c=y.toPathArray(e);"$"===c[0]&&c.length>1&&c.shift(),this._hasParentSelector=null;const u=this._trace(c,t,["$"],o,i,r).filter((function(e){return e&&!e.isParentSelector}));return u.length?s||1!==u.length||u[0].hasArrExpr?u.reduce(((e,t)=>{const r=this._getPreferredOutput(t);return a&&Array.isArray(r)?e=e.concat(r):e.push(r),e}),[]):this._getPreferredOutput(u[0]):s?[]:void 0},y.prototype._getPreferredOutput=function(e){const t=this.currResultType;switch(t){case"all":{const t=Array.isArray(e.path)?e.path:y.toPathArray(e.path);return e.pointer=y.toPointer(t),e.path="string"==typeof e.path?e.path:y.toPathString(e.path),e}case"value":case"parent":case"parentProperty":return e[t];case"path":return y.toPathString(e[t]);case"pointer":return y.toPointer(e.path);default:throw new TypeError("Unknown result type")}},y.prototype._handleCallback=function(e,t,r){if(t){const n=this._getPreferredOutput(e);e.path="string"==typeof e.path?e.path:y.toPathString(e.path),t(n,r,e)}},y.prototype._trace=function(e,t,r,n,o,i,a,s){let c;if(!e.length)return c={path:r,value:t,parent:n,parentProperty:o,hasArrExpr:a},this._handleCallback(c,i,"value"),c;const u=e[0],l=e.slice(1),p=[];function d(e){Array.isArray(e)?e.forEach((e=>{p.push(e)})):p.push(e)}if(("string"!=typeof u||s)&&t&&Object.hasOwn(t,u))d(this._trace(l,t[u],f(r,u),t,u,i,a));else if("*"===u)this._walk(t,(e=>{d(this._trace(l,t[e],f(r,e),t,e,i,!0,!0))}));else if(".."===u)d(this._trace(l,t,r,n,o,i,a)),this._walk(t,(n=>{"object"==typeof t[n]&&d(this._trace(e.slice(),t[n],f(r,n),t,n,i,!0))}));else{if("^"===u)return this._hasParentSelector=!0,{path:r.slice(0,-1),expr:l,isParentSelector:!0};if("~"===u)return c={path:f(r,u),value:o,parent:n,parentProperty:null},this._handleCallback(c,i,"property"),c;if("$"===u)d(this._trace(l,t,r,null,null,i,a));else if(/^(-?\d*):(-?\d*):?(\d*)$/u.test(u))d(this._slice(u,l,t,r,n,o,i));else if(0===u.indexOf("?(")){if(!1===this.currEval)throw new Error("Eval [?(expr)] prevented in JSONPath expression.");const e=u.replace(/^\?\((.*?)\)$/u,"$1"),a=/@.?([^?]*)[['](\??\(.*?\))(?!.\)\])[\]']/gu.exec(e);a?this._walk(t,(e=>{const s=[a[2]],c=a[1]?t[e][a[1]]:t[e];this._trace(s,c,r,n,o,i,!0).length>0&&d(this._trace(l,t[e],f(r,e),t,e,i,!0))})):this._walk(t,(a=>{this._eval(e,t[a],a,r,n,o)&&d(this._trace(l,t[a],f(r,a),t,a,i,!0))}))}else if("("===u[0]){if(!1===this.currEval)throw new Error("Eval [(expr)] prevented in JSONPath expression.");d(this._trace(h(this._eval(u,t,r.at(-1),r.slice(0,-1),n,o),l),t,r,n,o,i,a))}else if("@"===u[0]){let e=!1;const a=u.slice(1,-2);switch(a){case"scalar":t&&["object","function"].includes(typeof t)||(e=!0);break;case"boolean":case"string":case"undefined":case"function":typeof t===a&&(e=!0);break;case"integer":!Number.isFinite(t)||t%1||(e=!0);break;case"number":Number.isFinite(t)&&(e=!0);break;case"nonFinite":"number"!=typeof t||Number.isFinite(t)||(e=!0);break;case"object":t&&typeof t===a&&(e=!0);break;case"array":Array.isArray(t)&&(e=!0);break;case"other":e=this.currOtherTypeCallback(t,r,n,o);break;case"null":null===t&&(e=!0);break;default:throw new TypeError("Unknown value type "+a)}if(e)return c={path:r,value:t,parent:n,parentProperty:o},this._handleCallback(c,i,"value"),c}else if("`"===u[0]&&t&&Object.hasOwn(t,u.slice(1))){const e=u.slice(1);d(this._trace(l,t[e],f(r,e),t,e,i,a,!0))}else if(u.includes(",")){const e=u.split(",");for(const a of e)d(this._trace(h(a,l),t,r,n,o,i,!0))}else!s&&t&&Object.hasOwn(t,u)&&d(this._trace(l,t[u],f(r,u),t,u,i,a,!0))}if(this._hasParentSelector)for(let e=0;e<p.length;e++){const r=p[e];if(r&&r.isParentSelector){const s=this._trace(r.expr,t,r.path,n,o,i,a);if(Array.isArray(s)){p[e]=s[0];const t=s.length;for(let r=1;r<t;r++)e++,p.splice(e,0,s[r])}else p[e]=s}}return p},y.prototype._walk=function(e,t){if(Array.isArray(e)){const r=e.length;for(let e=0;e<r;e++)t(e)}else e&&"object"==typeof e&&Object.keys(e).forEach((e=>{t(e)}))},y.prototype._slice=function(e,t,r,n,o,i,a){if(!Array.isArray(r))return;const s=r.length,c=e.split(":"),u=c[2]&&Number.parseInt(c[2])||1;let l=c[0]&&Number.parseInt(c[0])||0,p=c[1]&&Number.parseInt(c[1])||s;l=l<0?Math.max(0,l+s):Math.min(s,l),p=p<0?Math.max(0,p+s):Math.min(s,p);const f=[];for(let e=l;e<p;e+=u){this._trace(h(e,t),r,n,o,i,a,!0).forEach((e=>{f.push(e)}))}return f},y.prototype._eval=function(e,t,r,n,o,i){this.currSandbox._$_parentProperty=i,this.currSandbox._$_parent=o,this.currSandbox._$_property=r,this.currSandbox._$_root=this.json,this.currSandbox._$_v=t;const a=e.includes("@path");a&&(this.currSandbox._$_path=y.toPathString(n.concat([r])));const s=this.currEval+"Script:"+e;if(!y.cache[s]){let t=e.replaceAll("@parentProperty","_$_parentProperty").replaceAll("@parent","_$_parent").replaceAll("@property","_$_property").replaceAll("@root","_$_root").replaceAll(/@([.\s)[])/gu,"_$_v$1");if(a&&(t=t.replaceAll("@path","_$_path")),"safe"===this.currEval||!0===this.currEval||void 0===this.currEval)y.cache[s]=new this.safeVm.Script(t);else if("native"===this.currEval)y.cache[s]=new this.vm.Script(t);else if("function"==typeof this.currEval&&this.currEval.prototype&&Object.hasOwn(this.currEval.prototype,"runInNewContext")){const e=this.currEval;y.cache[s]=new e(t)}else{if("function"!=typeof this.currEval)throw new TypeError(`Unknown "eval" property "${this.currEval}"`);y.cache[s]={runInNewContext:e=>this.currEval(t,e)}}}try{return y.cache[s].runInNewContext(this.currSandbox)}catch(t){if(this.ignoreEvalErrors)return!1;throw new Error("jsonPath: "+t.message+": "+e)}},y.cache={},y.toPathString=function(e){const t=e,r=t.length;let n="$";for(let e=1;e<r;e++)/^(~|\^|@.*?\(\))$/u.test(t[e])||(n+=/^[0-9*]+$/u.test(t[e])?"["+t[e]+"]":"['"+t[e]+"']");return n},y.toPointer=function(e){const t=e,r=t.length;let n="";for(let e=1;e<r;e++)/^(~|\^|@.*?\(\))$/u.test(t[e])||(n+="/"+t[e].toString().replaceAll("~","~0").replaceAll("/","~1"));return n},y.toPathArray=function(e){const{cache:t}=y;if(t[e])return t[e].concat();const r=[],n=e.replaceAll(/@(?:null|boolean|number|string|integer|undefined|nonFinite|scalar|array|object|function|other)\(\)/gu,";$&;").replaceAll(/[['](\??\(.*?\))[\]'](?!.\])/gu,(function(e,t){return"[#"+(r.push(t)-1)+"]"})).replaceAll(/\[['"]([^'\]]*)['"]\]/gu,(function(e,t){return"['"+t.replaceAll(".","%@%").replaceAll("~","%%@@%%")+"']"})).replaceAll("~",";~;").replaceAll(/['"]?\.['"]?(?![^[]*\])|\[['"]?/gu,";").replaceAll("%@%",".").replaceAll("%%@@%%","~").replaceAll(/(?:;)?(\^+)(?:;)?/gu,(function(e,t){return";"+t.split("").join(";")+";"})).replaceAll(/;;;|;;/gu,";..;").replaceAll(/;$|'?\]|'$/gu,"").split(";").map((function(e){const t=e.match(/#(\d+)/u);return t&&t[1]?r[t[1]]:e}));return t[e]=n,t[e].concat()},y.prototype.safeVm={Script:class{constructor(e){this.code=e,this.ast=i(this.code)}runInNewContext(e){const t=Object.assign(Object.create(null),e);return p.evalAst(this.ast,t)}}};y.prototype.vm={Script:class{constructor(e){this.code=e}runInNewContext(e){let t=this.code;const r=Object.keys(e),n=[];!function(e,t,r){const n=e.length;for(let o=0;o<n;o++)r(e[o])&&t.push(e.splice(o--,1)[0])}(r,n,(t=>"function"==typeof e[t]));const o=r.map((t=>e[t]));t=n.reduce(((t,r)=>{let n=e[r].toString();return/function/u.test(n)||(n="function "+n),"var "+r+"="+n+";"+t}),"")+t,/(['"])use strict\1/u.test(t)||r.includes("arguments")||(t="var arguments = undefined;"+t),t=t.replace(/;\s*$/u,"");const i=t.lastIndexOf(";"),a=-1!==i?t.slice(0,i+1)+" return "+t.slice(i+1):" return "+t;return new Function(...r,a)(...o)}}}}},t={};function r(n){var o=t[n];if(void 0!==o)return o.exports;var i=t[n]={exports:{}};return e[n].call(i.exports,i,i.exports,r),i.exports}r.d=(e,t)=>{for(var n in t)r.o(t,n)&&!r.o(e,n)&&Object.defineProperty(e,n,{enumerable:!0,get:t[n]})},r.g=function(){if("object"==typeof globalThis)return globalThis;try{return this||new Function("return this")()}catch(e){if("object"==typeof window)return window}}(),r.o=(e,t)=>Object.prototype.hasOwnProperty.call(e,t),r.r=e=>{"undefined"!=typeof Symbol&&Symbol.toStringTag&&Object.defineProperty(e,Symbol.toStringTag,{value:"Module"}),Object.defineProperty(e,"__esModule",{value:!0})};r(5237)})();
\ No newline at end of file
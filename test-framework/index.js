@@ -32,6 +32,8 @@ scenario = {};
 data = {};
 $ = {};
 outputVars = {};
+cookieJar = [];
+clearCookieJar = false;
 
 assert = chai.assert;
 expect = chai.expect;
@@ -71,7 +73,8 @@ BodyType = {
     XML: 'XML',
     Text: 'Text',
     Form: 'Form',
-    Binary: 'Binary'
+    Binary: 'Binary',
+    Multipart: 'Multipart'
 }
 
 function generateTag(arg) {
@@ -147,18 +150,36 @@ class Context {
         this.results = []
         this.logs = []
         this.currentResult = null
+        this.activeBehavior = null
+        this.asyncBehavior = null
+        this.synchronous = false
+        this.pending = null
+        this.unsettled = new Set()
+        this.error = undefined
+        this.completed = undefined
         this.inScenario = false
         this.inBehavior = false
     }
 
+    /**
+     * Returns the result that logging and tagging should be applied to;
+     * once the synchronous pass completes, only one asynchronous behavior runs at a time
+     */
+    target() {
+        return this.activeBehavior
+            ?? (this.synchronous ? null : this.asyncBehavior)
+            ?? this.currentResult
+    }
+
     appendLog(type, message, ...optionalParams) {
         const timestamp = fmtMinSec(Date.now() - testOffset)
+        const target = this.target()
         let appendToLogs
-        if (this.currentResult) {
-            if (! this.currentResult.logs) {
-                this.currentResult.logs = []
+        if (target) {
+            if (! target.logs) {
+                target.logs = []
             }
-            appendToLogs = this.currentResult.logs
+            appendToLogs = target.logs
         } else {
             if (! this.logs) {
                 this.logs = []
@@ -188,18 +209,7 @@ class Context {
         if (!current) {
             return
         }
-        if (current.parent) {
-            if (current.parent.tag) {
-                current.tag = current.parent.tag + (current.tag ? '.' + current.tag : '')
-            }
-            current.parent.success = current.success && current.parent.success
-            current.parent.testCount += current.testCount
-            current.parent.testFailCount += current.testFailCount
-            this.currentResult = current.parent
-            current.parent = undefined
-        } else {
-            this.currentResult = null
-        }
+        this.currentResult = current.parent ?? null
     }
 
     enterScenario(name) {
@@ -224,33 +234,152 @@ class Context {
     exitBehavior() {
         this.pop()
     }
+
+    /**
+     * Run the function immediately unless asynchronous work is pending,
+     * in which case run it once that work completes
+     */
+    schedule(fn) {
+        if (this.pending) {
+            this.pending = this.pending.then(fn)
+        } else {
+            const result = fn()
+            if (isThenable(result)) {
+                this.pending = result
+            }
+        }
+    }
+
+    /**
+     * Wait for the promise before completing the test suite, recording
+     * the first rejection as the test suite's error
+     */
+    track(promise) {
+        const tracked = Promise.resolve(promise).then(
+            () => { },
+            (e) => {
+                if (this.error === undefined) {
+                    this.error = e
+                }
+            })
+        this.pending = this.pending ? Promise.all([this.pending, tracked]) : tracked
+    }
+
+    runBehavior(behavior, run) {
+        const previous = this.currentResult
+        this.currentResult = behavior
+        this.activeBehavior = behavior
+        behavior.started = true
+        let result
+        try {
+            result = run()
+        } catch (e) {
+            this.settle(behavior, e)
+            return
+        } finally {
+            this.currentResult = previous
+            this.activeBehavior = null
+        }
+
+        if (isThenable(result)) {
+            this.asyncBehavior = behavior
+            return Promise.resolve(result).then(
+                () => this.settle(behavior),
+                (e) => this.settle(behavior, e ?? new Error('Promise rejected')))
+        }
+        this.settle(behavior)
+    }
+
+    settle(behavior, error) {
+        if (! this.unsettled.delete(behavior)) {
+            return
+        }
+        if (this.asyncBehavior === behavior) {
+            this.asyncBehavior = null
+        }
+        delete behavior.started
+        if (error === undefined) {
+            behavior.succeed()
+        } else {
+            behavior.fail(error?.message ?? `${error}`)
+        }
+    }
+
+    /**
+     * Roll up tags, success and counts from children to their parents
+     */
+    finalize(items, parent) {
+        for (const item of items) {
+            if (item.children) {
+                this.finalize(item.children, item)
+            }
+            if (parent) {
+                if (parent.tag) {
+                    item.tag = parent.tag + (item.tag ? '.' + item.tag : '')
+                }
+                parent.success = item.success && parent.success
+                parent.testCount += item.testCount
+                parent.testFailCount += item.testFailCount
+            }
+            delete item.parent
+        }
+    }
+
+    /**
+     * Complete the test suite, failing any tests whose promises have not settled
+     */
+    complete() {
+        if (this.completed === undefined) {
+            if (this.error !== undefined) {
+                throw this.error
+            }
+            for (const behavior of [...this.unsettled]) {
+                this.settle(behavior, new Error(behavior.started
+                    ? 'Test did not complete, promise is still pending'
+                    : 'Test did not run, waiting on a pending promise'))
+            }
+            this.finalize(this.results)
+            this.completed = JSON.stringify({
+                results: this.results,
+                output: outputVars,
+                logs: this.logs,
+                clearCookies: clearCookieJar
+            })
+        }
+        return this.completed
+    }
+}
+
+function isThenable(value) {
+    return value !== null
+        && (typeof value === 'object' || typeof value === 'function')
+        && typeof value.then === 'function'
 }
 
 let context = new Context()
 
 describe = (name, run) => {
     context.enterScenario(name)
+    let result
     try {
-        run()
+        result = run()
     } finally {
         context.exitScenario()
     }
+    if (isThenable(result)) {
+        context.track(result)
+    }
 }
 
 it = (name, run) => {
     const behavior = context.enterBehavior(name)
-    try {
-        run();
-        behavior.succeed()
-    } catch (e) {
-        behavior.fail(e.message)
-    } finally {
-        context.exitBehavior()
-    }
+    context.exitBehavior()
+    context.unsettled.add(behavior)
+    context.schedule(() => context.runBehavior(behavior, run))
 }
 
 tag = (arg) => {
-    context.currentResult.tag = generateTag(arg)
+    context.target().tag = generateTag(arg)
 }
 
 output = (name, value) => {
@@ -268,7 +397,41 @@ output = (name, value) => {
     }
 }
 
+cookies = {
+    /**
+     * Return all cookies in the run or group's cookie jar
+     */
+    all: () => [...cookieJar],
+    /**
+     * Return the value of the named cookie in the jar, if present
+     */
+    get: (name) => cookieJar.find(c => c.name === name)?.value,
+    /**
+     * Remove all cookies from the jar
+     */
+    clear: () => {
+        cookieJar = []
+        clearCookieJar = true
+    }
+}
+
+loadCookieJar = (cookies1) => {
+    cookieJar = cookies1 ?? []
+    clearCookieJar = false
+}
+
+/**
+ * Latency statistics of requests with multiple runs or rows completed earlier in the run,
+ * by request ID (and key, if set)
+ */
+latency = {}
+
+loadLatency = (latency1) => {
+    latency = latency1 ?? {}
+}
+
 runTestSuite = (request1, response1, variables1, data1, output1, testOffset1, testSuite) => {
+    context = new Context()
     request = request1
     response = response1
     scenario = variables1 ?? {}
@@ -279,13 +442,48 @@ runTestSuite = (request1, response1, variables1, data1, output1, testOffset1, te
     variables = $ // retain variables for some level of backward compatibility
 
     testOffset = testOffset1
-    testSuite()
+    context.synchronous = true
+    try {
+        testSuite()
+    } finally {
+        context.synchronous = false
+    }
 
-    return JSON.stringify({
-        results: context.results,
-        output: outputVars,
-        logs: context.logs
-    })
+    // If tests are asynchronous, return a promise that resolves once they complete
+    if (context.pending) {
+        return context.pending.then(() => context.complete())
+    }
+    return context.complete()
 };
 
+completeTestSuite = () => context.complete()
+
+/**
+ * Evaluate a condition against the request and response, returning "true" if the condition is truthy
+ */
+evaluateCondition = (request1, response1, variables1, data1, output1, condition) => {
+    context = new Context()
+    request = request1
+    response = response1
+    scenario = variables1 ?? {}
+    data = data1 ?? {}
+    outputVars = output1 ?? {}
+
+    $ = { ...outputVars, ...scenario, ...data }
+    variables = $
+
+    return JSON.stringify(!!condition())
+}
+
+/**
+ * Returns true if the JSON path matches a value in the response body, or
+ * if the expected value is specified, matches that value
+ */
+jsonPathMatches = (path, expected) => {
+    const results = jsonpath({ path, json: response?.body?.data ?? {}, wrap: true })
+    return expected === undefined
+        ? results.length > 0
+        : results.some(r => JSON.stringify(r) === JSON.stringify(expected))
+}
+
 module.exports = runTestSuite
\ No newline at end of file
@@ -17,3 +17,75 @@ test('processes tag substitution', () => {
     expect(testOk.success).to.equal(true)
 })
 
+
+test('awaits asynchronous tests', async () => {
+    let response = await runTestSuite({}, {}, {}, {}, {}, 0, () => {
+        describe('test', () => {
+            it('should resolve', async () => {
+                const value = await Promise.resolve(100)
+                expect(value).to.equal(100)
+            })
+            it('should reject', async () => {
+                await Promise.resolve()
+                expect(1).to.equal(2)
+            })
+        })
+    })
+    results = JSON.parse(response).results
+    testBehavior = results[0]
+    expect(testBehavior.testCount).to.equal(2)
+    expect(testBehavior.testFailCount).to.equal(1)
+    expect(testBehavior.children[0].success).to.equal(true)
+    expect(testBehavior.children[1].error).to.equal('expected 1 to equal 2')
+})
+
+test('fails tests with pending promises', () => {
+    runTestSuite({}, {}, {}, {}, {}, 0, () => {
+        describe('test', () => {
+            it('should never resolve', () => new Promise(() => { }))
+        })
+    })
+    results = JSON.parse(completeTestSuite()).results
+    testPending = results[0].children[0]
+    expect(testPending.success).to.equal(false)
+    expect(testPending.error).to.equal('Test did not complete, promise is still pending')
+})
+
+test('reads and clears the cookie jar', () => {
+    loadCookieJar([{ name: 'session', value: 'abc', domain: 'localhost', path: '/' }])
+    let response = runTestSuite({}, {}, {}, {}, {}, 0, () => {
+        describe('test', () => {
+            it('should read cookies', () => {
+                expect(cookies.get('session')).to.equal('abc')
+                expect(cookies.all().length).to.equal(1)
+                cookies.clear()
+                expect(cookies.get('session')).to.be.undefined
+            })
+        })
+    })
+    result = JSON.parse(response)
+    expect(result.results[0].children[0].success).to.equal(true)
+    expect(result.clearCookies).to.equal(true)
+})
+
+test('evaluates conditions against the response', () => {
+    const response = { status: 200, body: { type: 'JSON', text: '', data: { job: { state: 'done' } } } }
+    expect(evaluateCondition({}, response, {}, {}, {}, () => response.status === 200)).to.equal('true')
+    expect(evaluateCondition({}, response, {}, {}, {}, () => jsonPathMatches('$.job.state', 'done'))).to.equal('true')
+    expect(evaluateCondition({}, response, {}, {}, {}, () => jsonPathMatches('$.job.state', 'pending'))).to.equal('false')
+    expect(evaluateCondition({}, response, {}, {}, {}, () => jsonPathMatches('$.job.id'))).to.equal('false')
+})
+
+test('exposes latency statistics', () => {
+    loadLatency({ 'get-orders': { count: 10, min: 5, max: 90, mean: 20, stdDev: 4, p50: 15, p90: 40, p95: 60, p99: 90 } })
+    let response = runTestSuite({}, {}, {}, {}, {}, 0, () => {
+        describe('test', () => {
+            it('should be fast enough', () => {
+                expect(latency['get-orders'].p95).to.be.below(100)
+            })
+        })
+    })
+    expect(JSON.parse(response).results[0].children[0].success).to.equal(true)
+    loadLatency(null)
+    expect(latency).to.deep.equal({})
+})
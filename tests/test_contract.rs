@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use apicize_lib::{
+    ApicizeBody, ApicizeHttpResponse, ApicizeTestBehavior, OpenApiContract, parse_openapi,
+    validate_openapi_schema,
+};
+use serde_json::{Value, json};
+
+const OPENAPI_DOCUMENT: &str = r##"{
+    "openapi": "3.0.3",
+    "info": { "title": "Orders", "version": "1" },
+    "paths": {
+        "/orders/{id}": {
+            "get": {
+                "operationId": "getOrder",
+                "responses": {
+                    "200": {
+                        "description": "Order",
+                        "headers": {
+                            "X-Rate-Limit": {
+                                "required": true,
+                                "schema": { "type": "integer", "minimum": 0 }
+                            }
+                        },
+                        "content": {
+                            "application/json": {
+                                "schema": { "$ref": "#/components/schemas/Order" }
+                            }
+                        }
+                    },
+                    "4XX": { "description": "Client error" }
+                }
+            },
+            "delete": {
+                "responses": { "204": { "description": "Deleted" } }
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "Order": {
+                "type": "object",
+                "required": ["id", "status"],
+                "additionalProperties": false,
+                "properties": {
+                    "id": { "type": "integer" },
+                    "status": { "type": "string", "enum": ["open", "closed"] },
+                    "note": { "type": "string", "nullable": true, "maxLength": 5 },
+                    "lines": {
+                        "type": "array",
+                        "minItems": 1,
+                        "items": {
+                            "type": "object",
+                            "properties": { "quantity": { "type": "integer", "minimum": 1 } }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}"##;
+
+/// Helper to build a JSON response with the specified status, headers and body
+fn make_response(
+    status: u16,
+    headers: &[(&str, &str)],
+    body: Option<Value>,
+) -> ApicizeHttpResponse {
+    ApicizeHttpResponse {
+        duration: 0,
+        status,
+        status_text: String::default(),
+        headers: Some(
+            headers
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<String, String>>(),
+        ),
+        body: body.map(|data| ApicizeBody::JSON {
+            text: data.to_string(),
+            data,
+        }),
+        cookies: None,
+        connection: None,
+        timings: None,
+        oauth2_token: None,
+    }
+}
+
+fn validate(operation: &str, response: &ApicizeHttpResponse) -> Vec<ApicizeTestBehavior> {
+    OpenApiContract::new(parse_openapi(OPENAPI_DOCUMENT).unwrap()).validate(operation, response)
+}
+
+// =============================================================================
+// Response contract validation
+// =============================================================================
+
+#[test]
+fn test_contract_valid_response() {
+    let response = make_response(
+        200,
+        &[
+            ("content-type", "application/json; charset=utf-8"),
+            ("x-rate-limit", "10"),
+        ],
+        Some(json!({ "id": 1, "status": "open", "note": null, "lines": [{ "quantity": 2 }] })),
+    );
+    let behaviors = validate("getOrder", &response);
+    assert_eq!(
+        behaviors
+            .iter()
+            .map(|b| b.name.as_str())
+            .collect::<Vec<&str>>(),
+        vec![
+            "contract response status is documented",
+            "contract response headers match",
+            "contract response body matches schema",
+        ]
+    );
+    assert!(behaviors.iter().all(|b| b.success));
+}
+
+#[test]
+fn test_contract_operation_by_method_and_path() {
+    let behaviors = validate("DELETE /orders/{id}", &make_response(204, &[], None));
+    assert_eq!(behaviors.len(), 1);
+    assert!(behaviors[0].success);
+}
+
+#[test]
+fn test_contract_unknown_operation() {
+    let behaviors = validate("missing", &make_response(200, &[], None));
+    assert_eq!(behaviors.len(), 1);
+    assert!(!behaviors[0].success);
+    assert_eq!(behaviors[0].name, "contract operation is documented");
+}
+
+#[test]
+fn test_contract_undocumented_status() {
+    let behaviors = validate("getOrder", &make_response(500, &[], None));
+    assert_eq!(behaviors.len(), 1);
+    assert!(!behaviors[0].success);
+    assert_eq!(
+        behaviors[0].error.as_deref(),
+        Some("Status 500 is not documented")
+    );
+}
+
+#[test]
+fn test_contract_status_range() {
+    let behaviors = validate("getOrder", &make_response(404, &[], None));
+    assert_eq!(behaviors.len(), 1);
+    assert!(behaviors[0].success);
+}
+
+#[test]
+fn test_contract_header_violations() {
+    let behaviors = validate(
+        "getOrder",
+        &make_response(
+            200,
+            &[("content-type", "application/json")],
+            Some(json!({ "id": 1, "status": "open" })),
+        ),
+    );
+    assert!(!behaviors[1].success);
+    assert_eq!(
+        behaviors[1].error.as_deref(),
+        Some("Required header X-Rate-Limit is missing")
+    );
+
+    let behaviors = validate(
+        "getOrder",
+        &make_response(
+            200,
+            &[("content-type", "application/json"), ("X-Rate-Limit", "-1")],
+            Some(json!({ "id": 1, "status": "open" })),
+        ),
+    );
+    assert_eq!(
+        behaviors[1].error.as_deref(),
+        Some("header X-Rate-Limit: expected at least 0")
+    );
+}
+
+#[test]
+fn test_contract_body_violations() {
+    let behaviors = validate(
+        "getOrder",
+        &make_response(
+            200,
+            &[("content-type", "application/json"), ("x-rate-limit", "1")],
+            Some(
+                json!({ "id": 1.5, "status": "pending", "note": "too long", "lines": [{ "quantity": 0 }], "extra": true }),
+            ),
+        ),
+    );
+    let body = &behaviors[2];
+    assert!(!body.success);
+    assert_eq!(body.tag.as_deref(), Some("getOrder"));
+    assert_eq!(
+        body.error.as_deref(),
+        Some(
+            "$.id: expected integer, found number; \
+            $.status: \"pending\" is not one of the allowed values; \
+            $.note: expected at most 5 characters; \
+            $.lines[0].quantity: expected at least 1; \
+            $: unexpected property \"extra\""
+        )
+    );
+}
+
+#[test]
+fn test_contract_body_not_json() {
+    let mut response = make_response(200, &[("x-rate-limit", "1")], None);
+    response.body = Some(ApicizeBody::Text {
+        text: "oops".to_string(),
+    });
+    let behaviors = validate("getOrder", &response);
+    assert_eq!(
+        behaviors[2].error.as_deref(),
+        Some("Response body is not JSON")
+    );
+}
+
+// =============================================================================
+// Schema validation
+// =============================================================================
+
+#[test]
+fn test_schema_composition() {
+    let document = json!({});
+    let schema = json!({
+        "oneOf": [
+            { "type": "string" },
+            { "type": "integer", "minimum": 10 }
+        ]
+    });
+    assert!(validate_openapi_schema(&document, &schema, &json!("text")).is_empty());
+    assert!(validate_openapi_schema(&document, &schema, &json!(12)).is_empty());
+    assert_eq!(
+        validate_openapi_schema(&document, &schema, &json!(5)),
+        vec!["$: matches 0 schemas, expected exactly one".to_string()]
+    );
+
+    let schema = json!({
+        "allOf": [
+            { "type": "object", "required": ["a"] },
+            { "type": "object", "required": ["b"] }
+        ]
+    });
+    assert_eq!(
+        validate_openapi_schema(&document, &schema, &json!({ "a": 1 })),
+        vec!["$: missing required property \"b\"".to_string()]
+    );
+}
+
+#[test]
+fn test_schema_openapi_31_types() {
+    let document = json!({});
+    let schema = json!({ "type": ["string", "null"], "pattern": "^[a-z]+$" });
+    assert!(validate_openapi_schema(&document, &schema, &Value::Null).is_empty());
+    assert!(validate_openapi_schema(&document, &schema, &json!("abc")).is_empty());
+    assert_eq!(
+        validate_openapi_schema(&document, &schema, &json!("ABC")),
+        vec!["$: does not match pattern ^[a-z]+$".to_string()]
+    );
+    assert_eq!(
+        validate_openapi_schema(&document, &json!({ "exclusiveMinimum": 0 }), &json!(0)),
+        vec!["$: expected greater than 0".to_string()]
+    );
+}
@@ -0,0 +1,113 @@
+use apicize_lib::{ApicizeCookie, CookieJar};
+use reqwest::Url;
+
+/// Helper to parse a URL
+fn url(url: &str) -> Url {
+    Url::parse(url).unwrap()
+}
+
+// =============================================================================
+// Set-Cookie parsing tests
+// =============================================================================
+
+#[test]
+fn test_parse_cookie_defaults_to_request_host_and_path() {
+    let cookie =
+        ApicizeCookie::parse(&url("https://api.example.com/v1/login"), "session=abc").unwrap();
+    assert_eq!(cookie.name, "session");
+    assert_eq!(cookie.value, "abc");
+    assert_eq!(cookie.domain, "api.example.com");
+    assert_eq!(cookie.path, "/v1");
+    assert!(cookie.host_only);
+    assert!(cookie.expires.is_none());
+}
+
+#[test]
+fn test_parse_cookie_attributes() {
+    let cookie = ApicizeCookie::parse(
+        &url("https://api.example.com/login"),
+        "id=42; Domain=.example.com; Path=/; Expires=Wed, 21 Oct 2065 07:28:00 GMT; Secure; HttpOnly",
+    )
+    .unwrap();
+    assert_eq!(cookie.domain, "example.com");
+    assert_eq!(cookie.path, "/");
+    assert!(!cookie.host_only);
+    assert!(cookie.secure);
+    assert!(cookie.http_only);
+    assert_eq!(cookie.expires, Some(3_023_335_680_000));
+}
+
+#[test]
+fn test_parse_cookie_rejects_foreign_domain() {
+    assert!(
+        ApicizeCookie::parse(&url("https://api.example.com/"), "id=1; Domain=other.com").is_none()
+    );
+    assert!(ApicizeCookie::parse(&url("https://api.example.com/"), "no-value").is_none());
+}
+
+#[test]
+fn test_parse_cookie_max_age_zero_is_expired() {
+    let cookie = ApicizeCookie::parse(
+        &url("https://api.example.com/"),
+        "id=1; Max-Age=0; Expires=Wed, 21 Oct 2065 07:28:00 GMT",
+    )
+    .unwrap();
+    assert!(cookie.is_expired());
+}
+
+// =============================================================================
+// Cookie jar tests
+// =============================================================================
+
+#[test]
+fn test_jar_matches_domain_path_and_scheme() {
+    let jar = CookieJar::default();
+    let origin = url("https://api.example.com/v1/login");
+    jar.store(&[
+        ApicizeCookie::parse(&origin, "host=1; Path=/").unwrap(),
+        ApicizeCookie::parse(&origin, "shared=2; Domain=example.com; Path=/").unwrap(),
+        ApicizeCookie::parse(&origin, "scoped=3; Path=/v1").unwrap(),
+        ApicizeCookie::parse(&origin, "secure=4; Path=/; Secure").unwrap(),
+    ]);
+
+    let names = |u: &str| {
+        jar.get_cookies(&url(u))
+            .into_iter()
+            .map(|c| c.name)
+            .collect::<Vec<String>>()
+    };
+
+    assert_eq!(
+        names("https://api.example.com/v1/orders"),
+        vec!["scoped", "host", "shared", "secure"]
+    );
+    assert_eq!(
+        names("http://api.example.com/v1"),
+        vec!["scoped", "host", "shared"]
+    );
+    assert_eq!(
+        names("https://api.example.com/v10"),
+        vec!["host", "shared", "secure"]
+    );
+    assert_eq!(names("https://www.example.com/"), vec!["shared"]);
+    assert!(names("https://example.org/").is_empty());
+}
+
+#[test]
+fn test_jar_replaces_and_removes_cookies() {
+    let jar = CookieJar::default();
+    let origin = url("http://localhost/");
+    jar.store(&[ApicizeCookie::parse(&origin, "session=1").unwrap()]);
+    jar.store(&[ApicizeCookie::parse(&origin, "session=2").unwrap()]);
+
+    let cookies = jar.list();
+    assert_eq!(cookies.len(), 1);
+    assert_eq!(cookies[0].value, "2");
+
+    jar.store(&[ApicizeCookie::parse(&origin, "session=; Max-Age=0").unwrap()]);
+    assert!(jar.list().is_empty());
+
+    jar.store(&[ApicizeCookie::parse(&origin, "session=3").unwrap()]);
+    jar.clear();
+    assert!(jar.list().is_empty());
+}
@@ -0,0 +1,117 @@
+use apicize_lib::{
+    IndexedEntities, Request, RequestEntry, RequestGroup, SiblingDependencies, Validated,
+    ValidationState, WorkbookDefaultParameters, Workspace, workspace::ParameterLockStatus,
+};
+
+fn make_request(id: &str, key: Option<&str>, depends_on: &[&str]) -> RequestEntry {
+    RequestEntry::Request(Request {
+        id: id.to_string(),
+        name: id.to_string(),
+        url: "http://localhost".to_string(),
+        key: key.map(|k| k.to_string()),
+        depends_on: if depends_on.is_empty() {
+            None
+        } else {
+            Some(depends_on.iter().map(|d| d.to_string()).collect())
+        },
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_no_dependencies() {
+    let a = make_request("a", None, &[]);
+    let b = make_request("b", None, &[]);
+    assert!(SiblingDependencies::new(&[&a, &b]).is_none());
+}
+
+#[test]
+fn test_dependencies_resolve_by_key_or_id() {
+    let user = make_request("user-id", Some("user"), &[]);
+    let product = make_request("product-id", None, &[]);
+    let order = make_request("order-id", None, &["user", "product-id", "user"]);
+
+    let dependencies = SiblingDependencies::new(&[&user, &product, &order]).unwrap();
+    assert_eq!(dependencies.dependencies, vec![vec![], vec![], vec![0, 1]]);
+    assert!(dependencies.unknown.is_empty());
+    assert!(dependencies.find_cycle().is_empty());
+}
+
+#[test]
+fn test_unknown_dependencies() {
+    let a = make_request("a", None, &["missing"]);
+    let dependencies = SiblingDependencies::new(&[&a]).unwrap();
+    assert_eq!(dependencies.unknown, vec![(0, "missing".to_string())]);
+}
+
+#[test]
+fn test_cycles_detected() {
+    let a = make_request("a", None, &["c"]);
+    let b = make_request("b", None, &["a"]);
+    let c = make_request("c", None, &["b"]);
+    let d = make_request("d", None, &[]);
+    let e = make_request("e", None, &["e"]);
+
+    let dependencies = SiblingDependencies::new(&[&a, &b, &c, &d, &e]).unwrap();
+    assert_eq!(dependencies.find_cycle(), vec![0, 1, 2, 4]);
+}
+
+#[test]
+fn test_workspace_validation_flags_cycles_and_unknown_dependencies() {
+    let group = RequestEntry::Group(RequestGroup {
+        id: "group".to_string(),
+        name: "Group".to_string(),
+        children: Some(vec![
+            make_request("a", None, &["b"]),
+            make_request("b", None, &["a"]),
+            make_request("c", None, &["missing"]),
+            make_request("d", None, &[]),
+        ]),
+        ..Default::default()
+    });
+    let mut workspace = Workspace {
+        private_lock_status: ParameterLockStatus::UnlockedNoPassword,
+        vault_lock_status: ParameterLockStatus::UnlockedNoPassword,
+        private_password: None,
+        vault_password: None,
+        requests: IndexedEntities::<RequestEntry>::new(&[group]),
+        scenarios: IndexedEntities::default(),
+        authorizations: IndexedEntities::default(),
+        certificates: IndexedEntities::default(),
+        proxies: IndexedEntities::default(),
+        data: IndexedEntities::default(),
+        defaults: WorkbookDefaultParameters::default(),
+        openapi: None,
+        private_encryption: None,
+        vault_encryption: None,
+    };
+
+    workspace.validate_dependencies();
+
+    let error = |id: &str| {
+        workspace.requests.entities[id]
+            .get_validation_errors()
+            .as_ref()
+            .and_then(|errors| errors.get("dependsOn").cloned())
+    };
+    assert_eq!(error("a"), Some("Dependencies form a cycle".to_string()));
+    assert_eq!(error("b"), Some("Dependencies form a cycle".to_string()));
+    assert_eq!(
+        error("c"),
+        Some("\"missing\" is not a sibling key or ID".to_string())
+    );
+    assert_eq!(error("d"), None);
+    assert!(
+        workspace.requests.entities["a"]
+            .get_validation_state()
+            .contains(ValidationState::ERROR)
+    );
+
+    // Resolving the cycle clears the error
+    if let Some(RequestEntry::Request(b)) = workspace.requests.entities.get_mut("b") {
+        b.depends_on = None;
+    }
+    workspace.validate_dependencies();
+    assert_eq!(error("a"), None);
+    assert_eq!(error("b"), None);
+}
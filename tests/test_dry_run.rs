@@ -0,0 +1,313 @@
+use std::sync::Arc;
+
+use apicize_lib::{
+    ApicizeBody, ApicizeRunner, Authorization, DataSet, DataSourceType, ExecutionConcurrency,
+    IndexedEntities, NameValuePair, PersistedIndex, Request, RequestBody, RequestEntry,
+    RequestGroup, Scenario, ScenarioPlain, Selection, TestRunnerContext, TestRunnerContextInit,
+    Variable, WorkbookDefaultParameters, Workspace, authorization::AuthorizationPlain,
+    workspace::ParameterLockStatus,
+};
+use serde_json::json;
+use serial_test::serial;
+
+fn build_workspace(entries: Vec<RequestEntry>) -> Workspace {
+    Workspace {
+        private_lock_status: ParameterLockStatus::UnlockedNoPassword,
+        vault_lock_status: ParameterLockStatus::UnlockedNoPassword,
+        private_password: None,
+        vault_password: None,
+        requests: IndexedEntities::<RequestEntry>::new(&entries),
+        scenarios: IndexedEntities::default(),
+        authorizations: IndexedEntities::default(),
+        certificates: IndexedEntities::default(),
+        proxies: IndexedEntities::default(),
+        data: IndexedEntities::default(),
+        defaults: WorkbookDefaultParameters::default(),
+        openapi: None,
+        private_encryption: None,
+        vault_encryption: None,
+    }
+}
+
+fn build_context(workspace: Workspace) -> Arc<TestRunnerContext> {
+    Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace,
+        executing_request_or_group_id: "test-run",
+        ..Default::default()
+    }))
+}
+
+fn selection(id: &str, name: &str) -> Selection {
+    Selection {
+        id: id.to_string(),
+        name: name.to_string(),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_dry_run_renders_rows_without_dispatching() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let request = Request {
+        id: "req-1".to_string(),
+        name: "Create {{type}}".to_string(),
+        url: format!("{}/api/users/{{{{type}}}}", server.url()),
+        method: Some("POST".to_string()),
+        headers: Some(vec![NameValuePair {
+            name: "X-Region".to_string(),
+            value: "{{region}}".to_string(),
+            disabled: None,
+        }]),
+        query_string_params: Some(vec![NameValuePair {
+            name: "q".to_string(),
+            value: "{{type}}".to_string(),
+            disabled: None,
+        }]),
+        body: Some(RequestBody::JSON {
+            data: r#"{"type": "{{type}}", "id": {{id}}, "token": "{{token}}"}"#.to_string(),
+        }),
+        selected_scenario: selection("scenario-1", "Production"),
+        selected_authorization: selection("auth-1", "API Key"),
+        selected_data: selection("data-1", "Users"),
+        ..Default::default()
+    };
+
+    let mut ws = build_workspace(vec![RequestEntry::Request(request)]);
+    ws.scenarios = <IndexedEntities<Scenario> as PersistedIndex<Scenario>>::new(
+        Some(vec![Scenario::Plain(Box::new(ScenarioPlain {
+            id: "scenario-1".to_string(),
+            name: "Production".to_string(),
+            variables: Some(vec![Variable {
+                name: "region".to_string(),
+                source_type: Default::default(),
+                value: "us-east".to_string(),
+                disabled: None,
+            }]),
+            ..Default::default()
+        }))]),
+        None,
+        None,
+    );
+    ws.authorizations = <IndexedEntities<Authorization> as PersistedIndex<Authorization>>::new(
+        Some(vec![Authorization::Plain(Box::new(
+            AuthorizationPlain::ApiKey {
+                id: "auth-1".to_string(),
+                name: "API Key".to_string(),
+                header: "x-api-key".to_string(),
+                value: "{{key}}".to_string(),
+                validation_state: Default::default(),
+                validation_warnings: None,
+                validation_errors: None,
+            },
+        ))]),
+        None,
+        None,
+    );
+    ws.data = IndexedEntities::<DataSet>::new(Some(vec![DataSet {
+        id: "data-1".to_string(),
+        name: "Users".to_string(),
+        source_type: DataSourceType::JSON,
+        source: r#"[{"type": "admin", "id": 1}, {"type": "user", "id": 2}]"#.to_string(),
+        source_error: None,
+        validation_state: Default::default(),
+        validation_warnings: None,
+        validation_errors: None,
+    }]));
+    let ctx = build_context(ws);
+
+    let dry_runs = ctx
+        .dry_run(vec!["req-1".to_string()])
+        .await
+        .remove(0)
+        .unwrap();
+    assert_eq!(dry_runs.len(), 2);
+
+    let first = &dry_runs[0];
+    assert_eq!(first.name, "Create admin");
+    assert_eq!(first.row_number, Some(1));
+    assert!(first.error.is_none());
+    assert_eq!(first.scenario.as_ref().unwrap().name, "Production");
+    assert_eq!(first.authorization.as_ref().unwrap().name, "API Key");
+    assert_eq!(first.data.as_ref().unwrap().name, "Users");
+    assert!(first.certificate.is_none());
+    assert!(first.proxy.is_none());
+    assert_eq!(first.unresolved, vec!["{{key}}", "{{token}}"]);
+
+    let sent = first.request.as_ref().unwrap();
+    assert_eq!(sent.method, "POST");
+    assert_eq!(
+        sent.url,
+        format!("{}/api/users/admin?q=admin", server.url())
+    );
+    assert_eq!(sent.headers.get("x-region").unwrap(), "us-east");
+    assert_eq!(sent.headers.get("x-api-key").unwrap(), "{{key}}");
+    let Some(ApicizeBody::JSON { data, .. }) = &sent.body else {
+        panic!("Expected JSON body");
+    };
+    assert_eq!(
+        data,
+        &json!({"type": "admin", "id": 1, "token": "{{token}}"})
+    );
+
+    let second = &dry_runs[1];
+    assert_eq!(second.row_number, Some(2));
+    assert_eq!(
+        second.request.as_ref().unwrap().url,
+        format!("{}/api/users/user?q=user", server.url())
+    );
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_dry_run_guesses_scheme_without_connecting() {
+    let group = RequestGroup {
+        id: "group-1".to_string(),
+        name: "Group".to_string(),
+        execution: ExecutionConcurrency::Sequential,
+        children: Some(vec![
+            RequestEntry::Request(Request {
+                id: "req-https".to_string(),
+                name: "Secure".to_string(),
+                url: "localhost:9443".to_string(),
+                ..Default::default()
+            }),
+            RequestEntry::Request(Request {
+                id: "req-http".to_string(),
+                name: "Plain".to_string(),
+                url: "localhost:1".to_string(),
+                ..Default::default()
+            }),
+            RequestEntry::Request(Request {
+                id: "req-disabled".to_string(),
+                name: "Disabled".to_string(),
+                url: "localhost:1/disabled".to_string(),
+                disabled: true,
+                ..Default::default()
+            }),
+        ]),
+        ..Default::default()
+    };
+    let ctx = build_context(build_workspace(vec![RequestEntry::Group(group)]));
+
+    let dry_runs = ctx
+        .dry_run(vec!["group-1".to_string()])
+        .await
+        .remove(0)
+        .unwrap();
+    assert_eq!(dry_runs.len(), 2);
+    assert_eq!(dry_runs[0].id, "req-https");
+    assert_eq!(
+        dry_runs[0].request.as_ref().unwrap().url,
+        "https://localhost:9443/"
+    );
+    assert_eq!(dry_runs[1].id, "req-http");
+    assert_eq!(
+        dry_runs[1].request.as_ref().unwrap().url,
+        "http://localhost:1/"
+    );
+    assert!(dry_runs.iter().all(|d| d.row_number.is_none()));
+
+    // Schemes are only assumed when they cannot be determined from the port
+    assert!(dry_runs[0].warnings.is_empty());
+    assert_eq!(
+        dry_runs[1].warnings,
+        vec!["Scheme of localhost:1 is not known without checking port 443, assuming http"]
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn test_dry_run_applies_setup_and_conditions() {
+    let group = RequestGroup {
+        id: "group-1".to_string(),
+        name: "Group".to_string(),
+        execution: ExecutionConcurrency::Sequential,
+        setup: Some("output('featureFlag', 'off'); output('region', 'eu')".to_string()),
+        children: Some(vec![
+            RequestEntry::Request(Request {
+                id: "req-on".to_string(),
+                name: "On".to_string(),
+                url: "http://localhost:1/{{region}}/on".to_string(),
+                condition: Some("$.featureFlag === 'on'".to_string()),
+                selected_data: selection("data-1", "Users"),
+                ..Default::default()
+            }),
+            RequestEntry::Request(Request {
+                id: "req-off".to_string(),
+                name: "Off".to_string(),
+                url: "http://localhost:1/{{region}}/{{type}}".to_string(),
+                condition: Some("$.featureFlag === 'off' && $.type !== 'user'".to_string()),
+                selected_data: selection("data-1", "Users"),
+                ..Default::default()
+            }),
+        ]),
+        ..Default::default()
+    };
+    let mut ws = build_workspace(vec![RequestEntry::Group(group)]);
+    ws.data = IndexedEntities::<DataSet>::new(Some(vec![DataSet {
+        id: "data-1".to_string(),
+        name: "Users".to_string(),
+        source_type: DataSourceType::JSON,
+        source: r#"[{"type": "admin"}, {"type": "user"}, {"type": "guest"}]"#.to_string(),
+        source_error: None,
+        validation_state: Default::default(),
+        validation_warnings: None,
+        validation_errors: None,
+    }]));
+    let ctx = build_context(ws);
+
+    let dry_runs = ctx
+        .dry_run(vec!["group-1".to_string()])
+        .await
+        .remove(0)
+        .unwrap();
+    assert!(dry_runs.iter().all(|d| d.id == "req-off"));
+    assert_eq!(
+        dry_runs
+            .iter()
+            .map(|d| d.request.as_ref().unwrap().url.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["http://localhost:1/eu/admin", "http://localhost:1/eu/guest"]
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn test_dry_run_records_render_errors() {
+    let ctx = build_context(build_workspace(vec![RequestEntry::Request(Request {
+        id: "req-1".to_string(),
+        name: "Missing URL".to_string(),
+        url: String::new(),
+        headers: Some(vec![NameValuePair {
+            name: "Authorization".to_string(),
+            value: "Bearer {{token}}".to_string(),
+            disabled: None,
+        }]),
+        ..Default::default()
+    })]));
+
+    let dry_runs = ctx
+        .dry_run(vec!["req-1".to_string()])
+        .await
+        .remove(0)
+        .unwrap();
+    assert_eq!(dry_runs.len(), 1);
+    assert!(dry_runs[0].request.is_none());
+    assert!(dry_runs[0].error.is_some());
+    assert_eq!(dry_runs[0].unresolved, vec!["{{token}}"]);
+
+    assert!(
+        ctx.dry_run(vec!["missing".to_string()])
+            .await
+            .remove(0)
+            .is_err()
+    );
+}
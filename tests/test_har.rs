@@ -0,0 +1,223 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use apicize_lib::{ExecutionResultDetailRequest, generate_har};
+use serde_json::{Value, json};
+
+/// Helper to build request execution details from JSON
+fn make_detail(value: Value) -> ExecutionResultDetailRequest {
+    serde_json::from_value(value).unwrap()
+}
+
+// =============================================================================
+// HAR export
+// =============================================================================
+
+#[test]
+fn test_har_export_entries() {
+    let dispatched = make_detail(json!({
+        "execCtr": 1,
+        "requestId": "req-1",
+        "name": "Create Order",
+        "executedAt": 1500,
+        "duration": 250,
+        "testContext": {
+            "request": {
+                "url": "https://example.com/orders?limit=10&debug",
+                "method": "POST",
+                "headers": { "content-type": "application/json", "accept": "*/*" },
+                "body": { "type": "JSON", "text": "{\"id\":1}", "data": { "id": 1 } }
+            },
+            "response": {
+                "duration": 200,
+                "status": 201,
+                "statusText": "Created",
+                "headers": { "content-type": "application/json", "location": "/orders/1" },
+                "body": { "type": "Binary", "data": "AAE" },
+                "connection": {
+                    "remoteAddress": "93.184.215.14:443",
+                    "localAddress": "10.0.0.5:52114",
+                    "reused": true
+                },
+                "oauth2Token": null
+            }
+        },
+        "outputVariables": null,
+        "success": "SUCCESS",
+        "requestSuccessCount": 1,
+        "requestFailureCount": 0,
+        "requestErrorCount": 0,
+        "testPassCount": 0,
+        "testFailCount": 0
+    }));
+    let not_dispatched = make_detail(json!({
+        "execCtr": 2,
+        "requestId": "req-2",
+        "name": "Invalid",
+        "executedAt": 1750,
+        "duration": 0,
+        "testContext": {},
+        "outputVariables": null,
+        "success": "ERROR",
+        "requestSuccessCount": 0,
+        "requestFailureCount": 0,
+        "requestErrorCount": 1,
+        "testPassCount": 0,
+        "testFailCount": 0
+    }));
+
+    let started_at = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+    let har = generate_har([&dispatched, &not_dispatched], started_at).unwrap();
+    let har = serde_json::from_str::<Value>(&har).unwrap();
+
+    assert_eq!(har["log"]["version"], "1.2");
+    assert_eq!(har["log"]["creator"]["name"], "Apicize");
+    let entries = har["log"]["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+
+    let entry = &entries[0];
+    assert_eq!(entry["startedDateTime"], "2023-11-14T22:13:21.500Z");
+    assert_eq!(entry["time"], 250);
+    assert_eq!(entry["timings"]["wait"], 250);
+    assert_eq!(entry["comment"], "Create Order");
+    assert_eq!(entry["serverIPAddress"], "93.184.215.14");
+    assert_eq!(entry["connection"], "52114");
+
+    let request = &entry["request"];
+    assert_eq!(request["method"], "POST");
+    assert_eq!(request["url"], "https://example.com/orders?limit=10&debug");
+    assert_eq!(
+        request["queryString"],
+        json!([{ "name": "limit", "value": "10" }, { "name": "debug", "value": "" }])
+    );
+    assert_eq!(
+        request["headers"],
+        json!([
+            { "name": "accept", "value": "*/*" },
+            { "name": "content-type", "value": "application/json" }
+        ])
+    );
+    assert_eq!(
+        request["postData"],
+        json!({ "mimeType": "application/json", "text": "{\"id\":1}" })
+    );
+    assert_eq!(request["bodySize"], 8);
+
+    let response = &entry["response"];
+    assert_eq!(response["status"], 201);
+    assert_eq!(response["statusText"], "Created");
+    assert_eq!(response["redirectURL"], "/orders/1");
+    assert_eq!(
+        response["content"],
+        json!({ "size": 2, "mimeType": "application/json", "text": "AAE=", "encoding": "base64" })
+    );
+}
+
+#[test]
+fn test_har_export_timings() {
+    let detail = make_detail(json!({
+        "execCtr": 1,
+        "requestId": "req-1",
+        "name": "Get Order",
+        "executedAt": 0,
+        "duration": 95,
+        "testContext": {
+            "request": {
+                "url": "https://example.com/orders/1",
+                "method": "GET",
+                "headers": {}
+            },
+            "response": {
+                "duration": 90,
+                "status": 200,
+                "statusText": "OK",
+                "timings": { "dns": 5, "connect": 20, "ttfb": 60, "download": 5 },
+                "oauth2Token": null
+            }
+        },
+        "outputVariables": null,
+        "success": "SUCCESS",
+        "requestSuccessCount": 1,
+        "requestFailureCount": 0,
+        "requestErrorCount": 0,
+        "testPassCount": 0,
+        "testFailCount": 0
+    }));
+
+    let har = generate_har([&detail], UNIX_EPOCH).unwrap();
+    let har = serde_json::from_str::<Value>(&har).unwrap();
+    assert_eq!(
+        har["log"]["entries"][0]["timings"],
+        json!({ "blocked": -1, "dns": 5, "connect": 20, "send": 0, "wait": 60, "receive": 5, "ssl": -1 })
+    );
+}
+
+#[test]
+fn test_har_export_cookies() {
+    let detail = make_detail(json!({
+        "execCtr": 1,
+        "requestId": "req-1",
+        "name": "Login",
+        "executedAt": 0,
+        "duration": 50,
+        "testContext": {
+            "request": {
+                "url": "https://example.com/login",
+                "method": "POST",
+                "headers": {},
+                "cookies": [
+                    { "name": "tracking", "value": "abc", "domain": "example.com", "path": "/" }
+                ]
+            },
+            "response": {
+                "duration": 45,
+                "status": 200,
+                "statusText": "OK",
+                "cookies": [{
+                    "name": "session",
+                    "value": "xyz",
+                    "domain": "example.com",
+                    "path": "/api",
+                    "expires": 1_700_000_000_000u64,
+                    "hostOnly": true,
+                    "secure": true,
+                    "httpOnly": true
+                }],
+                "oauth2Token": null
+            }
+        },
+        "outputVariables": null,
+        "success": "SUCCESS",
+        "requestSuccessCount": 1,
+        "requestFailureCount": 0,
+        "requestErrorCount": 0,
+        "testPassCount": 0,
+        "testFailCount": 0
+    }));
+
+    let har = generate_har([&detail], UNIX_EPOCH).unwrap();
+    let har = serde_json::from_str::<Value>(&har).unwrap();
+    let entry = &har["log"]["entries"][0];
+    assert_eq!(
+        entry["request"]["cookies"],
+        json!([{
+            "name": "tracking",
+            "value": "abc",
+            "path": "/",
+            "domain": "example.com",
+            "httpOnly": false,
+            "secure": false
+        }])
+    );
+    assert_eq!(
+        entry["response"]["cookies"],
+        json!([{
+            "name": "session",
+            "value": "xyz",
+            "path": "/api",
+            "domain": "example.com",
+            "expires": "2023-11-14T22:13:20.000Z",
+            "httpOnly": true,
+            "secure": true
+        }])
+    );
+}
@@ -0,0 +1,48 @@
+use std::cell::Cell;
+use std::time::Duration;
+
+use apicize_lib::{ApicizeError, HttpClientCache, HttpClientKey};
+use reqwest::Client;
+
+/// Helper to build a client key with the specified redirect limit
+fn make_key(number_of_redirects: usize) -> HttpClientKey {
+    HttpClientKey {
+        certificate_id: String::default(),
+        proxy_id: String::default(),
+        number_of_redirects,
+        accept_invalid_certs: false,
+        timeout: Some(Duration::from_secs(30)),
+        keep_alive: false,
+    }
+}
+
+#[test]
+fn test_client_reused_for_matching_key() {
+    let cache = HttpClientCache::default();
+    let builds = Cell::new(0);
+    let build = || {
+        builds.set(builds.get() + 1);
+        Ok(Client::new())
+    };
+
+    let first = cache.get_or_build(make_key(10), build).unwrap();
+    let second = cache.get_or_build(make_key(10), build).unwrap();
+    assert!(std::sync::Arc::ptr_eq(&first, &second));
+    assert_eq!(builds.get(), 1);
+
+    cache.get_or_build(make_key(0), build).unwrap();
+    assert_eq!(builds.get(), 2);
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn test_client_build_error_not_cached() {
+    let cache = HttpClientCache::default();
+    let result = cache.get_or_build(make_key(10), || {
+        Err(ApicizeError::Error {
+            description: "bad certificate".to_string(),
+        })
+    });
+    assert!(result.is_err());
+    assert!(cache.is_empty());
+}
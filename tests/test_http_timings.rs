@@ -0,0 +1,110 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use apicize_lib::{TimedConnectLayer, TimedResolver, TimingRecorder};
+use hyper_util::client::legacy::connect::HttpInfo;
+use reqwest::Client;
+
+/// Helper to build a client which records DNS resolution and connection times
+fn make_client() -> Client {
+    Client::builder()
+        .dns_resolver(Arc::new(TimedResolver))
+        .connector_layer(TimedConnectLayer)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_timings_without_connection() {
+    let recorder = TimingRecorder::default();
+    let timings = recorder.to_timings(Duration::from_millis(120), Duration::from_millis(30), false);
+    assert_eq!(timings.dns, None);
+    assert_eq!(timings.connect, None);
+    assert_eq!(timings.ttfb, 120);
+    assert_eq!(timings.download, 30);
+}
+
+#[tokio::test]
+async fn test_timings_recorded_for_new_connection() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/timed")
+        .with_status(200)
+        .with_body("ok")
+        .create_async()
+        .await;
+
+    let client = make_client();
+    let url = format!("http://localhost:{}/timed", server.socket_address().port());
+
+    let recorder = Arc::new(TimingRecorder::default());
+    let response = recorder.scope(client.get(&url).send()).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let dns = recorder.dns().expect("DNS resolution should be recorded");
+    let connect = recorder.connect().expect("connection should be recorded");
+    assert!(connect >= dns);
+
+    let timings = recorder.to_timings(connect + Duration::from_millis(5), Duration::ZERO, false);
+    assert_eq!(timings.dns, Some(dns.as_millis()));
+    assert_eq!(timings.connect, Some(connect.as_millis() - dns.as_millis()));
+    assert_eq!(timings.ttfb, 5);
+
+    // Connection phases are not reported for reused connections
+    let timings = recorder.to_timings(connect, Duration::ZERO, true);
+    assert_eq!(timings.dns, None);
+    assert_eq!(timings.connect, None);
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_ip_address_not_resolved() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/address")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let client = make_client();
+    let recorder = Arc::new(TimingRecorder::default());
+    recorder
+        .scope(client.get(format!("{}/address", server.url())).send())
+        .await
+        .unwrap();
+    assert_eq!(recorder.dns(), None);
+    assert!(recorder.connect().is_some());
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_opened_connection_recorded() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/opened")
+        .with_status(204)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = make_client();
+    let url = format!("{}/opened", server.url());
+
+    let recorder = Arc::new(TimingRecorder::default());
+    let response = recorder.scope(client.get(&url).send()).await.unwrap();
+    let local_addr = response
+        .extensions()
+        .get::<HttpInfo>()
+        .unwrap()
+        .local_addr();
+    response.bytes().await.unwrap();
+    assert_eq!(recorder.opened_connection(), Some(local_addr));
+
+    // The second request reuses the connection, so does not open one
+    let recorder = Arc::new(TimingRecorder::default());
+    recorder.scope(client.get(&url).send()).await.unwrap();
+    assert_eq!(recorder.opened_connection(), None);
+
+    mock.assert_async().await;
+}
@@ -0,0 +1,785 @@
+use apicize_lib::{
+    Authorization, MultipartPart, NameValuePair, Scenario, Selection, StoredRequestBody,
+    StoredRequestEntry, VariableSourceType, authorization::AuthorizationPlain, import_har,
+    import_openapi, import_postman_collection,
+};
+
+/// Helper to return the stored request at the given path of child indexes
+fn get_request(entries: &[StoredRequestEntry], path: &[usize]) -> apicize_lib::StoredRequest {
+    let mut entries = entries;
+    for (i, index) in path.iter().enumerate() {
+        match &entries[*index] {
+            StoredRequestEntry::Request(request) if i == path.len() - 1 => {
+                return *request.clone();
+            }
+            StoredRequestEntry::Group(group) => {
+                entries = group.children.as_deref().unwrap();
+            }
+            _ => panic!("Unexpected entry at {path:?}"),
+        }
+    }
+    panic!("No request at {path:?}")
+}
+
+// =============================================================================
+// Postman collection import
+// =============================================================================
+
+const POSTMAN_COLLECTION: &str = r#"{
+    "info": {
+        "name": "Orders API",
+        "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+    },
+    "auth": {
+        "type": "basic",
+        "basic": [
+            { "key": "username", "value": "user" },
+            { "key": "password", "value": "secret" }
+        ]
+    },
+    "variable": [
+        { "key": "baseUrl", "value": "https://api.example.com" },
+        { "key": "limit", "value": "10" }
+    ],
+    "item": [
+        {
+            "name": "Orders",
+            "item": [
+                {
+                    "name": "Get Order",
+                    "event": [
+                        {
+                            "listen": "test",
+                            "script": {
+                                "exec": [
+                                    "var data = pm.response.json();",
+                                    "pm.test(\"Status code is 200\", function () {",
+                                    "    pm.response.to.have.status(200);",
+                                    "});",
+                                    "pm.test('Has id', () => {",
+                                    "    pm.expect(data.id).to.eql(\"{ 1 }\");",
+                                    "});"
+                                ]
+                            }
+                        }
+                    ],
+                    "request": {
+                        "method": "get",
+                        "header": [
+                            { "key": "Accept", "value": "application/json" },
+                            { "key": "X-Debug", "value": "1", "disabled": true }
+                        ],
+                        "url": {
+                            "raw": "{{baseUrl}}/orders/:orderId?limit={{limit}}",
+                            "host": ["{{baseUrl}}"],
+                            "path": ["orders", ":orderId"],
+                            "query": [
+                                { "key": "limit", "value": "{{limit}}" },
+                                { "key": "debug", "value": "true", "disabled": true }
+                            ],
+                            "variable": [ { "key": "orderId" } ]
+                        }
+                    }
+                },
+                {
+                    "name": "Create Order",
+                    "auth": {
+                        "type": "bearer",
+                        "bearer": [ { "key": "token", "value": "{{token}}" } ]
+                    },
+                    "request": {
+                        "method": "POST",
+                        "url": "{{baseUrl}}/orders",
+                        "body": {
+                            "mode": "raw",
+                            "raw": "{\"item\": \"widget\"}",
+                            "options": { "raw": { "language": "json" } }
+                        }
+                    }
+                }
+            ]
+        },
+        {
+            "name": "Upload",
+            "request": {
+                "method": "POST",
+                "auth": { "type": "noauth" },
+                "url": "{{baseUrl}}/upload",
+                "body": {
+                    "mode": "formdata",
+                    "formdata": [
+                        { "key": "description", "value": "Invoice", "type": "text" },
+                        { "key": "file", "type": "file", "src": "/home/user/invoice.pdf" }
+                    ]
+                }
+            }
+        }
+    ]
+}"#;
+
+#[test]
+fn test_postman_folders_and_requests() {
+    let result = import_postman_collection(POSTMAN_COLLECTION, &[]).unwrap();
+    let requests = &result.workbook.requests;
+    assert_eq!(requests.len(), 2);
+
+    let StoredRequestEntry::Group(group) = &requests[0] else {
+        panic!("Expected group");
+    };
+    assert_eq!(group.name, "Orders");
+    assert_eq!(group.children.as_ref().unwrap().len(), 2);
+
+    let get_order = get_request(requests, &[0, 0]);
+    assert_eq!(get_order.name, "Get Order");
+    assert_eq!(get_order.method.as_deref(), Some("GET"));
+    assert_eq!(get_order.url, "{{baseUrl}}/orders/{{orderId}}");
+    assert!(
+        get_order.query_string_params
+            == Some(vec![
+                NameValuePair {
+                    name: "limit".to_string(),
+                    value: "{{limit}}".to_string(),
+                    disabled: None,
+                },
+                NameValuePair {
+                    name: "debug".to_string(),
+                    value: "true".to_string(),
+                    disabled: Some(true),
+                },
+            ])
+    );
+    let headers = get_order.headers.unwrap();
+    assert_eq!(headers.len(), 2);
+    assert_eq!(headers[1].disabled, Some(true));
+
+    let create_order = get_request(requests, &[0, 1]);
+    assert_eq!(create_order.method.as_deref(), Some("POST"));
+    let Some(StoredRequestBody::JSON(json)) = create_order.body else {
+        panic!("Expected JSON body");
+    };
+    assert_eq!(json.formatted.as_deref(), Some("{\"item\": \"widget\"}"));
+}
+
+#[test]
+fn test_postman_formdata_body() {
+    let result = import_postman_collection(POSTMAN_COLLECTION, &[]).unwrap();
+    let upload = get_request(&result.workbook.requests, &[1]);
+    let Some(StoredRequestBody::Multipart { data }) = upload.body else {
+        panic!("Expected multipart body");
+    };
+    assert_eq!(data.len(), 2);
+    assert!(matches!(&data[0], MultipartPart::Text { name, value, .. }
+        if name == "description" && value == "Invoice"));
+    assert!(
+        matches!(&data[1], MultipartPart::File { name, file_name, path, .. }
+        if name == "file"
+            && file_name.as_deref() == Some("invoice.pdf")
+            && path.as_deref() == Some("/home/user/invoice.pdf"))
+    );
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.path == "Upload" && w.element == "body.formdata")
+    );
+}
+
+#[test]
+fn test_postman_authorizations() {
+    let result = import_postman_collection(POSTMAN_COLLECTION, &[]).unwrap();
+    let authorizations = result.workbook.authorizations.as_ref().unwrap();
+    assert_eq!(authorizations.len(), 2);
+
+    let Authorization::Plain(basic) = &authorizations[0] else {
+        panic!("Expected plain authorization");
+    };
+    let AuthorizationPlain::Basic {
+        id,
+        username,
+        password,
+        ..
+    } = basic.as_ref()
+    else {
+        panic!("Expected basic authorization");
+    };
+    assert_eq!(username, "user");
+    assert_eq!(password, "secret");
+    assert_eq!(
+        &result
+            .workbook
+            .defaults
+            .as_ref()
+            .unwrap()
+            .selected_authorization
+            .id,
+        id
+    );
+
+    let Authorization::Plain(bearer) = &authorizations[1] else {
+        panic!("Expected plain authorization");
+    };
+    let AuthorizationPlain::ApiKey {
+        id, header, value, ..
+    } = bearer.as_ref()
+    else {
+        panic!("Expected API key authorization");
+    };
+    assert_eq!(header, "Authorization");
+    assert_eq!(value, "Bearer {{token}}");
+
+    let requests = &result.workbook.requests;
+    assert_eq!(
+        &get_request(requests, &[0, 1]).selected_authorization.id,
+        id
+    );
+    assert!(get_request(requests, &[0, 0]).selected_authorization == Selection::default());
+    assert!(get_request(requests, &[1]).selected_authorization == Selection::new_none());
+}
+
+#[test]
+fn test_postman_test_script_stubs() {
+    let result = import_postman_collection(POSTMAN_COLLECTION, &[]).unwrap();
+    let test = get_request(&result.workbook.requests, &[0, 0])
+        .test
+        .unwrap();
+    assert!(test.starts_with("describe('Get Order', () => {"));
+    assert!(test.contains("    // var data = pm.response.json();\n"));
+    assert!(test.contains(
+        "    it('Status code is 200', () => {\n        // pm.response.to.have.status(200);\n    })\n"
+    ));
+    assert!(test.contains(
+        "    it('Has id', () => {\n        // pm.expect(data.id).to.eql(\"{ 1 }\");\n    })\n"
+    ));
+    assert!(!test.contains("pm.test"));
+}
+
+#[test]
+fn test_postman_collection_variables_as_scenario() {
+    let result = import_postman_collection(POSTMAN_COLLECTION, &[]).unwrap();
+    let scenarios = result.workbook.scenarios.as_ref().unwrap();
+    assert_eq!(scenarios.len(), 1);
+    let Scenario::Plain(scenario) = &scenarios[0] else {
+        panic!("Expected plain scenario");
+    };
+    assert_eq!(scenario.name, "Orders API");
+    assert_eq!(scenario.variables.as_ref().unwrap().len(), 2);
+    assert_eq!(
+        result
+            .workbook
+            .defaults
+            .as_ref()
+            .unwrap()
+            .selected_scenario
+            .id,
+        scenario.id
+    );
+}
+
+#[test]
+fn test_postman_environments_as_scenarios() {
+    let environment = r#"{
+        "name": "Staging",
+        "values": [
+            { "key": "baseUrl", "value": "https://staging.example.com", "enabled": true },
+            { "key": "token", "value": "abc", "enabled": false },
+            { "key": "filter", "value": { "status": "open" } }
+        ]
+    }"#;
+    let result = import_postman_collection(POSTMAN_COLLECTION, &[environment]).unwrap();
+    let scenarios = result.workbook.scenarios.as_ref().unwrap();
+    assert_eq!(scenarios.len(), 1);
+    let Scenario::Plain(scenario) = &scenarios[0] else {
+        panic!("Expected plain scenario");
+    };
+    assert_eq!(scenario.name, "Staging");
+
+    let variables = scenario.variables.as_ref().unwrap();
+    assert_eq!(variables.len(), 4);
+    assert_eq!(variables[0].name, "baseUrl");
+    assert_eq!(variables[0].value, "https://staging.example.com");
+    assert_eq!(variables[1].name, "limit");
+    assert_eq!(variables[2].disabled, Some(true));
+    assert!(matches!(variables[3].source_type, VariableSourceType::JSON));
+    assert_eq!(variables[3].value, "{\"status\":\"open\"}");
+}
+
+#[test]
+fn test_postman_unsupported_elements_return_warnings() {
+    let collection = r#"{
+        "info": { "name": "Unsupported" },
+        "event": [
+            { "listen": "prerequest", "script": { "exec": "pm.variables.set('a', 1);" } }
+        ],
+        "item": [
+            {
+                "name": "Digest",
+                "auth": { "type": "digest", "digest": [] },
+                "event": [
+                    { "listen": "test", "script": { "exec": "console.log('no tests');" } }
+                ],
+                "request": {
+                    "method": "PUT",
+                    "url": "https://example.com",
+                    "body": { "mode": "file", "file": { "src": "data.bin" } }
+                }
+            }
+        ]
+    }"#;
+    let result = import_postman_collection(collection, &[]).unwrap();
+    let warnings = result
+        .warnings
+        .iter()
+        .map(|w| (w.path.as_str(), w.element.as_str()))
+        .collect::<Vec<(&str, &str)>>();
+    assert_eq!(
+        warnings,
+        vec![
+            ("Unsupported", "event.prerequest"),
+            ("Digest", "auth.digest"),
+            ("Digest", "event.test"),
+            ("Digest", "body.file"),
+        ]
+    );
+
+    let request = get_request(&result.workbook.requests, &[0]);
+    assert!(request.body.is_none());
+    assert!(
+        request
+            .test
+            .unwrap()
+            .contains("    // console.log('no tests');\n")
+    );
+}
+
+#[test]
+fn test_postman_invalid_collection() {
+    assert!(import_postman_collection("{ \"item\": [] }", &[]).is_err());
+}
+
+// =============================================================================
+// OpenAPI import
+// =============================================================================
+
+const OPENAPI_DOCUMENT: &str = r##"
+openapi: 3.0.3
+info:
+  title: Pet Store
+  version: 1.0.0
+servers:
+  - url: https://{environment}.example.com/v1/
+    variables:
+      environment:
+        default: api
+tags:
+  - name: pets
+security:
+  - bearerAuth: []
+paths:
+  /pets:
+    get:
+      tags: [pets]
+      operationId: listPets
+      summary: List pets
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+            example: 25
+        - name: status
+          in: query
+          required: true
+          schema:
+            type: string
+            enum: [available, sold]
+      responses:
+        "200":
+          description: Pets
+    post:
+      tags: [pets]
+      operationId: createPet
+      security:
+        - apiKey: []
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/Pet"
+      responses:
+        "201":
+          description: Created
+        "400":
+          description: Invalid
+  /pets/{petId}:
+    parameters:
+      - name: petId
+        in: path
+        required: true
+        schema:
+          type: string
+          format: uuid
+    delete:
+      operationId: deletePet
+      security: []
+      parameters:
+        - name: session
+          in: cookie
+          schema:
+            type: string
+      responses:
+        2XX:
+          description: Deleted
+components:
+  securitySchemes:
+    bearerAuth:
+      type: http
+      scheme: bearer
+    apiKey:
+      type: apiKey
+      in: header
+      name: X-API-Key
+    openId:
+      type: openIdConnect
+      openIdConnectUrl: https://example.com/.well-known/openid-configuration
+  schemas:
+    Pet:
+      type: object
+      required: [name]
+      properties:
+        name:
+          type: string
+          example: Rex
+        tags:
+          type: array
+          items:
+            type: string
+        owner:
+          allOf:
+            - $ref: "#/components/schemas/Owner"
+    Owner:
+      type: object
+      properties:
+        id:
+          type: integer
+        email:
+          type: string
+          format: email
+"##;
+
+#[test]
+fn test_openapi_groups_and_requests() {
+    let result = import_openapi(OPENAPI_DOCUMENT).unwrap();
+    let requests = &result.workbook.requests;
+    assert_eq!(requests.len(), 2);
+
+    let StoredRequestEntry::Group(group) = &requests[0] else {
+        panic!("Expected group");
+    };
+    assert_eq!(group.name, "pets");
+    assert_eq!(group.children.as_ref().unwrap().len(), 2);
+
+    let list_pets = get_request(requests, &[0, 0]);
+    assert_eq!(list_pets.name, "List pets");
+    assert_eq!(list_pets.key.as_deref(), Some("listPets"));
+    assert_eq!(list_pets.operation.as_deref(), Some("listPets"));
+    assert_eq!(list_pets.method.as_deref(), Some("GET"));
+    assert_eq!(list_pets.url, "{{baseUrl}}/pets");
+    assert!(
+        list_pets.query_string_params
+            == Some(vec![
+                NameValuePair {
+                    name: "limit".to_string(),
+                    value: "{{limit}}".to_string(),
+                    disabled: Some(true),
+                },
+                NameValuePair {
+                    name: "status".to_string(),
+                    value: "{{status}}".to_string(),
+                    disabled: None,
+                },
+            ])
+    );
+
+    let delete_pet = get_request(requests, &[1]);
+    assert_eq!(delete_pet.name, "deletePet");
+    assert_eq!(delete_pet.method.as_deref(), Some("DELETE"));
+    assert_eq!(delete_pet.url, "{{baseUrl}}/pets/{{petId}}");
+}
+
+#[test]
+fn test_openapi_json_body_from_schema_examples() {
+    let result = import_openapi(OPENAPI_DOCUMENT).unwrap();
+    let create_pet = get_request(&result.workbook.requests, &[0, 1]);
+    let Some(StoredRequestBody::JSON(json)) = create_pet.body else {
+        panic!("Expected JSON body");
+    };
+    assert_eq!(
+        json.data,
+        Some(serde_json::json!({
+            "name": "Rex",
+            "tags": ["string"],
+            "owner": { "id": 0, "email": "user@example.com" }
+        }))
+    );
+}
+
+#[test]
+fn test_openapi_success_status_tests() {
+    let result = import_openapi(OPENAPI_DOCUMENT).unwrap();
+    let requests = &result.workbook.requests;
+    assert!(
+        get_request(requests, &[0, 1])
+            .test
+            .unwrap()
+            .contains("expect(response.status).to.equal(201)")
+    );
+    assert!(
+        get_request(requests, &[1])
+            .test
+            .unwrap()
+            .contains("expect(response.status).to.be.within(200, 299)")
+    );
+}
+
+#[test]
+fn test_openapi_security_schemes() {
+    let result = import_openapi(OPENAPI_DOCUMENT).unwrap();
+    let authorizations = result.workbook.authorizations.as_ref().unwrap();
+    assert_eq!(authorizations.len(), 2);
+
+    let Authorization::Plain(bearer) = &authorizations[0] else {
+        panic!("Expected plain authorization");
+    };
+    let AuthorizationPlain::ApiKey {
+        id: bearer_id,
+        header,
+        ..
+    } = bearer.as_ref()
+    else {
+        panic!("Expected API key authorization");
+    };
+    assert_eq!(header, "Authorization");
+
+    let Authorization::Plain(api_key) = &authorizations[1] else {
+        panic!("Expected plain authorization");
+    };
+    let AuthorizationPlain::ApiKey {
+        id: api_key_id,
+        header,
+        ..
+    } = api_key.as_ref()
+    else {
+        panic!("Expected API key authorization");
+    };
+    assert_eq!(header, "X-API-Key");
+
+    let requests = &result.workbook.requests;
+    assert_eq!(
+        &result
+            .workbook
+            .defaults
+            .as_ref()
+            .unwrap()
+            .selected_authorization
+            .id,
+        bearer_id
+    );
+    assert!(get_request(requests, &[0, 0]).selected_authorization == Selection::default());
+    assert_eq!(
+        &get_request(requests, &[0, 1]).selected_authorization.id,
+        api_key_id
+    );
+    assert!(get_request(requests, &[1]).selected_authorization == Selection::new_none());
+}
+
+#[test]
+fn test_openapi_scenario_variables() {
+    let result = import_openapi(OPENAPI_DOCUMENT).unwrap();
+    let scenarios = result.workbook.scenarios.as_ref().unwrap();
+    let Scenario::Plain(scenario) = &scenarios[0] else {
+        panic!("Expected plain scenario");
+    };
+    assert_eq!(scenario.name, "Pet Store");
+    let variables = scenario
+        .variables
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|v| (v.name.as_str(), v.value.as_str()))
+        .collect::<Vec<(&str, &str)>>();
+    assert_eq!(
+        variables,
+        vec![
+            ("baseUrl", "https://api.example.com/v1"),
+            ("limit", "25"),
+            ("status", "available"),
+            ("petId", "00000000-0000-0000-0000-000000000000"),
+        ]
+    );
+}
+
+#[test]
+fn test_openapi_unsupported_elements_return_warnings() {
+    let result = import_openapi(OPENAPI_DOCUMENT).unwrap();
+    let warnings = result
+        .warnings
+        .iter()
+        .map(|w| (w.path.as_str(), w.element.as_str()))
+        .collect::<Vec<(&str, &str)>>();
+    assert_eq!(
+        warnings,
+        vec![
+            ("securitySchemes / openId", "type"),
+            ("DELETE /pets/{petId}", "parameters.session"),
+        ]
+    );
+}
+
+#[test]
+fn test_openapi_json_document() {
+    let document = r#"{
+        "openapi": "3.1.0",
+        "info": { "title": "Minimal", "version": "1" },
+        "servers": [ { "url": "http://localhost:8080" } ],
+        "paths": { "/health": { "get": { "responses": { "204": { "description": "OK" } } } } }
+    }"#;
+    let result = import_openapi(document).unwrap();
+    let health = get_request(&result.workbook.requests, &[0]);
+    assert_eq!(health.name, "GET /health");
+    assert_eq!(health.url, "{{baseUrl}}/health");
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_openapi_rejects_swagger_2() {
+    let document = r#"{ "swagger": "2.0", "info": { "title": "Old" }, "paths": {} }"#;
+    assert!(import_openapi(document).is_err());
+}
+
+// =============================================================================
+// HAR import
+// =============================================================================
+
+const HAR_LOG: &str = r#"{
+    "log": {
+        "version": "1.2",
+        "creator": { "name": "Browser", "version": "1" },
+        "entries": [
+            {
+                "request": {
+                    "method": "get",
+                    "url": "https://example.com/api/orders?status=open&limit=5#top",
+                    "httpVersion": "HTTP/2",
+                    "headers": [
+                        { "name": ":authority", "value": "example.com" },
+                        { "name": "Accept", "value": "application/json" },
+                        { "name": "Content-Length", "value": "0" }
+                    ],
+                    "queryString": [
+                        { "name": "status", "value": "open" },
+                        { "name": "limit", "value": "5" }
+                    ]
+                },
+                "response": { "status": 200 }
+            },
+            {
+                "request": {
+                    "method": "POST",
+                    "url": "https://example.com/api/login",
+                    "headers": [],
+                    "postData": {
+                        "mimeType": "application/x-www-form-urlencoded; charset=UTF-8",
+                        "text": "user=a&password=b"
+                    }
+                },
+                "response": { "status": 302 }
+            },
+            {
+                "request": {
+                    "method": "POST",
+                    "url": "https://example.com/api/upload",
+                    "postData": {
+                        "mimeType": "multipart/form-data; boundary=x",
+                        "params": [
+                            { "name": "title", "value": "Report" },
+                            { "name": "file", "fileName": "report.pdf", "contentType": "application/pdf" }
+                        ]
+                    }
+                },
+                "response": { "status": 0 }
+            },
+            {
+                "request": {
+                    "method": "PUT",
+                    "url": "https://example.com/api/orders/1",
+                    "postData": { "mimeType": "application/json", "text": "{\"status\":\"closed\"}" }
+                }
+            }
+        ]
+    }
+}"#;
+
+#[test]
+fn test_har_entries_as_requests() {
+    let result = import_har(HAR_LOG).unwrap();
+    let requests = &result.workbook.requests;
+    assert_eq!(requests.len(), 4);
+
+    let orders = get_request(requests, &[0]);
+    assert_eq!(orders.name, "GET /api/orders");
+    assert_eq!(orders.method.as_deref(), Some("GET"));
+    assert_eq!(orders.url, "https://example.com/api/orders");
+    let query = orders.query_string_params.unwrap();
+    assert_eq!(query.len(), 2);
+    assert_eq!(
+        (query[0].name.as_str(), query[0].value.as_str()),
+        ("status", "open")
+    );
+    let headers = orders.headers.unwrap();
+    assert_eq!(headers.len(), 1);
+    assert_eq!(headers[0].name, "Accept");
+    assert!(
+        orders
+            .test
+            .unwrap()
+            .contains("expect(response.status).to.equal(200)")
+    );
+}
+
+#[test]
+fn test_har_request_bodies() {
+    let result = import_har(HAR_LOG).unwrap();
+    let requests = &result.workbook.requests;
+
+    let Some(StoredRequestBody::Form { data }) = get_request(requests, &[1]).body else {
+        panic!("Expected form body");
+    };
+    assert_eq!(data.len(), 2);
+    assert_eq!(
+        (data[1].name.as_str(), data[1].value.as_str()),
+        ("password", "b")
+    );
+
+    let upload = get_request(requests, &[2]);
+    assert!(upload.test.is_none());
+    let Some(StoredRequestBody::Multipart { data }) = upload.body else {
+        panic!("Expected multipart body");
+    };
+    assert!(matches!(&data[0], MultipartPart::Text { name, value, .. }
+        if name == "title" && value == "Report"));
+    assert!(
+        matches!(&data[1], MultipartPart::File { name, file_name, content_type, .. }
+        if name == "file"
+            && file_name.as_deref() == Some("report.pdf")
+            && content_type.as_deref() == Some("application/pdf"))
+    );
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].path, "POST /api/upload");
+
+    let update = get_request(requests, &[3]);
+    assert!(update.test.is_none());
+    let Some(StoredRequestBody::JSON(json)) = update.body else {
+        panic!("Expected JSON body");
+    };
+    assert_eq!(json.data, Some(serde_json::json!({ "status": "closed" })));
+}
@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use apicize_lib::{
+    ApicizeLatencyStatistics, LatencyHistogram, LoadProfile, LoadResultBuilder, Tallies,
+};
+
+/// Helper to build a load profile
+fn make_profile(rate: Option<f64>, ramp_up: u64, duration: u64, ramp_down: u64) -> LoadProfile {
+    LoadProfile {
+        virtual_users: 4,
+        rate,
+        ramp_up,
+        duration,
+        ramp_down,
+    }
+}
+
+/// Helper to build tallies for an iteration
+fn make_tallies(success: bool) -> Tallies {
+    Tallies {
+        success,
+        request_success_count: if success { 1 } else { 0 },
+        request_failure_count: if success { 0 } else { 1 },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_load_profile_defaults() {
+    let profile = serde_json::from_str::<LoadProfile>(r#"{"duration": 5000}"#).unwrap();
+    assert_eq!(profile.virtual_users, 1);
+    assert_eq!(profile.get_rate(), None);
+    assert_eq!(profile.total_duration(), Duration::from_millis(5000));
+    assert_eq!(profile.scheduled_at(0), None);
+}
+
+#[test]
+fn test_steady_rate_schedule() {
+    let profile = make_profile(Some(10.0), 0, 1000, 0);
+    assert_eq!(profile.scheduled_at(0), Some(Duration::ZERO));
+    assert_eq!(profile.scheduled_at(5), Some(Duration::from_millis(500)));
+    assert_eq!(profile.scheduled_at(9), Some(Duration::from_millis(900)));
+    assert_eq!(profile.scheduled_at(10), None);
+}
+
+#[test]
+fn test_ramped_rate_schedule() {
+    // 5 iterations ramping up over 1s, 10 steady over 1s and 5 ramping down over 1s
+    let profile = make_profile(Some(10.0), 1000, 1000, 1000);
+    let schedule = (0..)
+        .map_while(|iteration| profile.scheduled_at(iteration))
+        .collect::<Vec<Duration>>();
+    assert_eq!(schedule.len(), 20);
+    assert!(schedule.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    // Iterations are sparse while ramping up and down
+    assert!(schedule[1] - schedule[0] > Duration::from_millis(400));
+    assert_eq!(schedule[5], Duration::from_secs(1));
+    assert_eq!(schedule[15], Duration::from_secs(2));
+    assert!(schedule[19] - schedule[18] > Duration::from_millis(150));
+    assert!(schedule[19] < profile.total_duration());
+}
+
+#[test]
+fn test_virtual_user_windows() {
+    let profile = make_profile(None, 1000, 2000, 400);
+    assert_eq!(
+        profile.virtual_user_window(0),
+        (Duration::ZERO, Duration::from_millis(3400))
+    );
+    assert_eq!(
+        profile.virtual_user_window(3),
+        (Duration::from_millis(750), Duration::from_millis(3100))
+    );
+
+    let profile = make_profile(None, 0, 2000, 0);
+    assert!(
+        (0..4)
+            .all(|vu| profile.virtual_user_window(vu) == (Duration::ZERO, Duration::from_secs(2)))
+    );
+}
+
+#[test]
+fn test_latency_statistics() {
+    assert_eq!(ApicizeLatencyStatistics::from_durations(&[]), None);
+
+    let durations = (1..=100).rev().collect::<Vec<u128>>();
+    let stats = ApicizeLatencyStatistics::from_durations(&durations).unwrap();
+    assert_eq!(stats.count, 100);
+    assert_eq!(stats.min, 1);
+    assert_eq!(stats.max, 100);
+    assert_eq!(stats.mean, 50.5);
+    assert_eq!(stats.p50, 50);
+    assert_eq!(stats.p90, 90);
+    assert_eq!(stats.p95, 95);
+    assert_eq!(stats.p99, 99);
+
+    let stats = ApicizeLatencyStatistics::from_durations(&[10, 20]).unwrap();
+    assert_eq!(stats.std_dev, 5.0);
+    assert_eq!(stats.p50, 10);
+    assert_eq!(stats.p99, 20);
+}
+
+#[test]
+fn test_latency_histogram() {
+    assert_eq!(LatencyHistogram::default().statistics(), None);
+
+    // Durations below 256ms are counted exactly
+    let mut histogram = LatencyHistogram::default();
+    (1..=100).rev().for_each(|d| histogram.record(d));
+    assert_eq!(
+        histogram.statistics(),
+        ApicizeLatencyStatistics::from_durations(&(1..=100).collect::<Vec<u128>>())
+    );
+
+    // Larger durations are bucketed, percentiles are within 1% while the rest are exact
+    let durations = (1..=10_000).map(|d| d * 7).collect::<Vec<u128>>();
+    let mut histogram = LatencyHistogram::default();
+    durations.iter().for_each(|d| histogram.record(*d));
+    let stats = histogram.statistics().unwrap();
+    let expected = ApicizeLatencyStatistics::from_durations(&durations).unwrap();
+    assert_eq!(histogram.count(), 10_000);
+    assert_eq!(stats.min, 7);
+    assert_eq!(stats.max, 70_000);
+    assert!((stats.mean - expected.mean).abs() < 1e-6);
+    assert!((stats.std_dev - expected.std_dev).abs() < 1e-6);
+    for (actual, expected) in [
+        (stats.p50, expected.p50),
+        (stats.p90, expected.p90),
+        (stats.p95, expected.p95),
+        (stats.p99, expected.p99),
+    ] {
+        assert!(actual <= expected && expected - actual <= expected / 100);
+    }
+}
+
+#[test]
+fn test_load_result_aggregation() {
+    let mut builder = LoadResultBuilder::default();
+    builder.record(100, &make_tallies(true), None);
+    builder.record(300, &make_tallies(true), None);
+    builder.record(200, &make_tallies(false), Some("timeout".to_string()));
+    builder.record(400, &make_tallies(false), Some("timeout".to_string()));
+
+    let result = builder.build(2, Duration::from_secs(2));
+    assert_eq!(result.virtual_users, 2);
+    assert_eq!(result.iterations, 4);
+    assert_eq!(result.duration, 2000);
+    assert_eq!(result.throughput, 2.0);
+    assert_eq!(result.error_rate, 0.5);
+    assert_eq!(result.errors.get("timeout"), Some(&2));
+    assert_eq!(result.latency.unwrap().p50, 200);
+    assert!(!result.success);
+    assert_eq!(result.request_success_count, 2);
+    assert_eq!(result.request_failure_count, 2);
+}
@@ -0,0 +1,36 @@
+use apicize_lib::{PollCondition, PollUntil};
+use serde_json::json;
+
+#[test]
+fn test_poll_until_defaults() {
+    let poll = serde_json::from_str::<PollUntil>(
+        r#"{"condition": {"type": "Script", "script": "response.status === 200"}}"#,
+    )
+    .unwrap();
+    assert_eq!(poll.interval, 1000);
+    assert_eq!(poll.max_wait, 60_000);
+    assert_eq!(poll.condition.to_script(), "response.status === 200");
+}
+
+#[test]
+fn test_json_path_condition_script() {
+    let condition = serde_json::from_value::<PollCondition>(json!({
+        "type": "JSONPath",
+        "path": "$.job.status",
+        "value": "complete"
+    }))
+    .unwrap();
+    assert_eq!(
+        condition.to_script(),
+        r#"jsonPathMatches("$.job.status", "complete")"#
+    );
+
+    let condition = PollCondition::JsonPath {
+        path: "$.items[0]".to_string(),
+        value: None,
+    };
+    assert_eq!(
+        condition.to_script(),
+        r#"jsonPathMatches("$.items[0]", undefined)"#
+    );
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use apicize_lib::{ApicizeError, RetryBackoff, RetryPolicy};
+
+/// Helper to build a retry policy without jitter
+fn make_policy(backoff: RetryBackoff) -> RetryPolicy {
+    RetryPolicy {
+        backoff,
+        delay: 100,
+        max_delay: 1000,
+        jitter: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_retry_policy_defaults() {
+    let policy = serde_json::from_str::<RetryPolicy>("{}").unwrap();
+    assert_eq!(policy, RetryPolicy::default());
+    assert_eq!(policy.max_attempts, 3);
+    assert!(policy.is_retryable_status(503));
+    assert!(!policy.is_retryable_status(500));
+}
+
+#[test]
+fn test_fixed_backoff() {
+    let policy = make_policy(RetryBackoff::Fixed);
+    assert_eq!(policy.get_delay(1, None), Duration::from_millis(100));
+    assert_eq!(policy.get_delay(4, None), Duration::from_millis(100));
+}
+
+#[test]
+fn test_exponential_backoff_capped() {
+    let policy = make_policy(RetryBackoff::Exponential);
+    assert_eq!(policy.get_delay(1, None), Duration::from_millis(100));
+    assert_eq!(policy.get_delay(2, None), Duration::from_millis(200));
+    assert_eq!(policy.get_delay(3, None), Duration::from_millis(400));
+    assert_eq!(policy.get_delay(5, None), Duration::from_millis(1000));
+    assert_eq!(policy.get_delay(100, None), Duration::from_millis(1000));
+}
+
+#[test]
+fn test_jitter_within_range() {
+    let policy = RetryPolicy {
+        jitter: true,
+        ..make_policy(RetryBackoff::Fixed)
+    };
+    for _ in 0..50 {
+        let delay = policy.get_delay(1, None);
+        assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn test_retry_after_honored() {
+    let policy = make_policy(RetryBackoff::Fixed);
+
+    let headers = HashMap::from([("retry-after".to_string(), "0".to_string())]);
+    assert_eq!(policy.get_delay(1, Some(&headers)), Duration::ZERO);
+
+    // Delays are capped at the maximum delay
+    let headers = HashMap::from([("Retry-After".to_string(), "120".to_string())]);
+    assert_eq!(
+        policy.get_delay(1, Some(&headers)),
+        Duration::from_millis(1000)
+    );
+
+    let past = httpdate::fmt_http_date(SystemTime::now() - Duration::from_secs(60));
+    let headers = HashMap::from([("retry-after".to_string(), past)]);
+    assert_eq!(policy.get_delay(1, Some(&headers)), Duration::ZERO);
+
+    let ignored = RetryPolicy {
+        honor_retry_after: false,
+        ..make_policy(RetryBackoff::Fixed)
+    };
+    assert_eq!(
+        ignored.get_delay(1, Some(&headers)),
+        Duration::from_millis(100)
+    );
+}
+
+#[test]
+fn test_retryable_errors() {
+    let policy = RetryPolicy::default();
+    let sent = ApicizeError::Http {
+        context: None,
+        description: "connection reset".to_string(),
+        url: Some("http://localhost/".to_string()),
+    };
+    let unsent = ApicizeError::Http {
+        context: None,
+        description: "Missing URL".to_string(),
+        url: None,
+    };
+    let timeout = ApicizeError::Timeout { url: None };
+    assert!(policy.is_retryable_error(&sent));
+    assert!(!policy.is_retryable_error(&unsent));
+    assert!(policy.is_retryable_error(&timeout));
+    assert!(!policy.is_retryable_error(&ApicizeError::Cancelled));
+
+    let policy = RetryPolicy {
+        retry_on_error: false,
+        retry_on_timeout: false,
+        ..Default::default()
+    };
+    assert!(!policy.is_retryable_error(&sent));
+    assert!(!policy.is_retryable_error(&timeout));
+}
@@ -0,0 +1,114 @@
+use apicize_lib::{IndexedEntities, Request, RequestEntry, RequestGroup, RunFilter};
+
+fn make_request(id: &str, key: Option<&str>, tags: &[&str]) -> RequestEntry {
+    RequestEntry::Request(Request {
+        id: id.to_string(),
+        name: id.to_string(),
+        url: "http://localhost".to_string(),
+        key: key.map(|k| k.to_string()),
+        tags: if tags.is_empty() {
+            None
+        } else {
+            Some(tags.iter().map(|t| t.to_string()).collect())
+        },
+        ..Default::default()
+    })
+}
+
+fn make_group(
+    id: &str,
+    key: Option<&str>,
+    tags: &[&str],
+    children: Vec<RequestEntry>,
+) -> RequestEntry {
+    RequestEntry::Group(RequestGroup {
+        id: id.to_string(),
+        name: id.to_string(),
+        key: key.map(|k| k.to_string()),
+        tags: if tags.is_empty() {
+            None
+        } else {
+            Some(tags.iter().map(|t| t.to_string()).collect())
+        },
+        children: Some(children),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_tag_expressions() {
+    let filter = RunFilter::parse("smoke && !slow").unwrap();
+    assert!(filter.matches(&["smoke"], &[]));
+    assert!(!filter.matches(&["smoke", "slow"], &[]));
+    assert!(!filter.matches(&["slow"], &[]));
+
+    let filter = RunFilter::parse("(smoke || regression) && !tag:slow").unwrap();
+    assert!(filter.matches(&["regression"], &[]));
+    assert!(!filter.matches(&["regression", "slow"], &[]));
+    assert!(!filter.matches(&[], &[]));
+}
+
+#[test]
+fn test_key_globs() {
+    let filter = RunFilter::parse("orders.*").unwrap();
+    assert!(filter.matches(&[], &["orders.create"]));
+    assert!(filter.matches(&[], &["orders", "orders.list"]));
+    assert!(!filter.matches(&[], &["users.create"]));
+
+    let filter = RunFilter::parse("key:login").unwrap();
+    assert!(filter.matches(&[], &["login"]));
+    assert!(!filter.matches(&["login"], &["logout"]));
+
+    let filter = RunFilter::parse("user?").unwrap();
+    assert!(filter.matches(&[], &["users"]));
+    assert!(!filter.matches(&[], &["user"]));
+}
+
+#[test]
+fn test_invalid_filters() {
+    for filter in ["", "smoke &&", "(smoke", "smoke)", "!"] {
+        assert!(
+            RunFilter::parse(filter).is_err(),
+            "{filter} should be invalid"
+        );
+    }
+}
+
+#[test]
+fn test_select_includes_ancestor_groups() {
+    let requests = IndexedEntities::<RequestEntry>::new(&[
+        make_group(
+            "orders",
+            Some("orders"),
+            &["smoke"],
+            vec![
+                make_request("orders-create", Some("create"), &[]),
+                make_request("orders-list", Some("list"), &["slow"]),
+            ],
+        ),
+        make_group(
+            "users",
+            Some("users"),
+            &[],
+            vec![make_request("users-create", Some("create"), &[])],
+        ),
+    ]);
+
+    // Tags are inherited from ancestor groups
+    let selected = RunFilter::parse("smoke && !slow")
+        .unwrap()
+        .select(&requests);
+    assert!(selected.contains("orders"));
+    assert!(selected.contains("orders-create"));
+    assert!(!selected.contains("orders-list"));
+    assert!(!selected.contains("users"));
+    assert!(!selected.contains("users-create"));
+
+    // Key globs match the keys of the request or its ancestor groups
+    let selected = RunFilter::parse("users").unwrap().select(&requests);
+    assert!(selected.is_empty());
+    let selected = RunFilter::parse("key:users").unwrap().select(&requests);
+    assert!(selected.contains("users"));
+    assert!(selected.contains("users-create"));
+    assert!(!selected.contains("orders"));
+}
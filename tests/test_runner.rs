@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use apicize_lib::{
-    ApicizeError, ApicizeResult, ApicizeRunner, ExecutionConcurrency, Identifiable,
-    IndexedEntities, Request, RequestEntry, RequestGroup, TestRunnerContext, TestRunnerContextInit,
-    WorkbookDefaultParameters, Workspace, workspace::ParameterLockStatus,
+    ApicizeError, ApicizeGroupScript, ApicizeRequestResultContent, ApicizeResult, ApicizeRunner,
+    ExecutionConcurrency, Identifiable, IndexedEntities, MultipartPart, NameValuePair, Request,
+    RequestBody, RequestEntry, RequestGroup, ScriptLimits, TestRunnerContext,
+    TestRunnerContextInit, WorkbookDefaultParameters, Workspace, workspace::ParameterLockStatus,
 };
 use serial_test::serial;
 use tokio_util::sync::CancellationToken;
@@ -22,6 +23,7 @@ fn build_workspace(entries: Vec<RequestEntry>) -> Workspace {
         proxies: IndexedEntities::default(),
         data: IndexedEntities::default(),
         defaults: WorkbookDefaultParameters::default(),
+        openapi: None,
         private_encryption: None,
         vault_encryption: None,
     }
@@ -73,10 +75,7 @@ fn build_context(
         workspace,
         cancellation,
         executing_request_or_group_id: "test-run",
-        single_run_no_timeout: false,
-        allowed_data_path: &None,
-        enable_trace: false,
-        execution_counter_callback: None,
+        ..Default::default()
     }))
 }
 
@@ -404,6 +403,142 @@ async fn test_cancellation_stops_execution() {
     }
 }
 
+// =============================================================================
+// Script limit tests
+// =============================================================================
+
+fn build_context_with_limits(
+    workspace: Workspace,
+    cancellation: Option<CancellationToken>,
+    script_limits: ScriptLimits,
+) -> Arc<TestRunnerContext> {
+    Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace,
+        cancellation,
+        executing_request_or_group_id: "test-run",
+        script_limits,
+        ..Default::default()
+    }))
+}
+
+fn get_execution_error(result: ApicizeResult) -> Option<ApicizeError> {
+    match result {
+        ApicizeResult::Request(req_result) => match req_result.content {
+            ApicizeRequestResultContent::Execution { execution } => execution.error,
+            _ => panic!("Expected Execution content"),
+        },
+        _ => panic!("Expected Request result"),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_script_exceeding_timeout_is_terminated() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let req = make_request_with_test(
+        "req-1",
+        "Endless Test",
+        &format!("{}/api/test", server.url()),
+        "while (true) {}",
+    );
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context_with_limits(
+        ws,
+        None,
+        ScriptLimits {
+            timeout: 250,
+            ..Default::default()
+        },
+    );
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    match get_execution_error(result) {
+        Some(ApicizeError::ScriptLimit { description }) => {
+            assert!(description.contains("250 ms"));
+        }
+        _ => panic!("Expected ScriptLimit error"),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_script_exceeding_heap_limit_is_terminated() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let req = make_request_with_test(
+        "req-1",
+        "Greedy Test",
+        &format!("{}/api/test", server.url()),
+        "const hoard = []; while (true) { hoard.push(new Array(100000).fill('x')) }",
+    );
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context_with_limits(
+        ws,
+        None,
+        ScriptLimits {
+            timeout: 0,
+            max_heap_size: 64,
+        },
+    );
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    match get_execution_error(result) {
+        Some(ApicizeError::ScriptLimit { description }) => {
+            assert!(description.contains("64 MB"));
+        }
+        _ => panic!("Expected ScriptLimit error"),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_cancellation_terminates_running_script() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let cancel = CancellationToken::new();
+    let req = make_request_with_test(
+        "req-1",
+        "Endless Test",
+        &format!("{}/api/test", server.url()),
+        "while (true) {}",
+    );
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context_with_limits(
+        ws,
+        Some(cancel.clone()),
+        ScriptLimits {
+            timeout: 0,
+            ..Default::default()
+        },
+    );
+
+    // Scripts run synchronously, so cancel from a separate thread
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(250));
+        cancel.cancel();
+    });
+
+    let results = ctx.run(vec!["req-1".to_string()]).await;
+    canceller.join().unwrap();
+    assert!(matches!(results[0], Err(ApicizeError::Cancelled)));
+}
+
 // =============================================================================
 // Disabled request/group tests
 // =============================================================================
@@ -1421,3 +1556,1944 @@ async fn test_script_can_access_json_response_body() {
     }
     mock.assert_async().await;
 }
+
+// =============================================================================
+// Multipart request body tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_multipart_body_sends_text_and_file_parts() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/upload")
+        .match_header(
+            "content-type",
+            mockito::Matcher::Regex("^multipart/form-data; boundary=".to_string()),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex(r#"name="title"\r\n\r\nQuarterly"#.to_string()),
+            mockito::Matcher::Regex(
+                r#"name="report"; filename="report.txt"\r\ncontent-type: text/plain\r\n\r\nfile contents"#
+                    .to_string(),
+            ),
+        ]))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let req = Request {
+        method: Some("POST".to_string()),
+        body: Some(RequestBody::Multipart {
+            data: vec![
+                MultipartPart::Text {
+                    name: "title".to_string(),
+                    value: "Quarterly".to_string(),
+                    content_type: None,
+                    disabled: None,
+                },
+                MultipartPart::Text {
+                    name: "ignored".to_string(),
+                    value: "nope".to_string(),
+                    content_type: None,
+                    disabled: Some(true),
+                },
+                MultipartPart::File {
+                    name: "report".to_string(),
+                    file_name: Some("report.txt".to_string()),
+                    content_type: Some("text/plain".to_string()),
+                    data: Some(b"file contents".to_vec()),
+                    path: None,
+                    disabled: None,
+                },
+            ],
+        }),
+        ..make_request_with_test(
+            "req-1",
+            "Multipart Request",
+            &format!("{}/api/upload", server.url()),
+            r#"
+            describe('request', () => {
+                it('records multipart parts', () => {
+                    expect(request.body.type).to.equal(BodyType.Multipart)
+                    expect(request.body.parts.length).to.equal(2)
+                    expect(request.body.parts[0].text).to.equal('Quarterly')
+                    expect(request.body.parts[1].fileName).to.equal('report.txt')
+                })
+            })
+            "#,
+        )
+    };
+
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let results = ctx.run(vec!["req-1".to_string()]).await;
+    match results.into_iter().next().unwrap().unwrap() {
+        ApicizeResult::Request(req_result) => {
+            assert!(req_result.success);
+            assert_eq!(req_result.test_pass_count, 1);
+        }
+        _ => panic!("Expected Request result"),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_multipart_file_part_loaded_from_data_path() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/upload")
+        .match_body(mockito::Matcher::Regex(
+            r#"name="upload"; filename="data.csv"\r\n\r\na,b\n1,2"#.to_string(),
+        ))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let data_dir = dir.path().join("data");
+    std::fs::create_dir(&data_dir).unwrap();
+    std::fs::write(data_dir.join("data.csv"), "a,b\n1,2").unwrap();
+    std::fs::write(dir.path().join("outside.csv"), "secret").unwrap();
+    let allowed_data_path = Some(data_dir);
+
+    let make_upload = |id: &str, path: &str| Request {
+        method: Some("POST".to_string()),
+        body: Some(RequestBody::Multipart {
+            data: vec![MultipartPart::File {
+                name: "upload".to_string(),
+                file_name: Some("data.csv".to_string()),
+                content_type: None,
+                data: None,
+                path: Some(path.to_string()),
+                disabled: None,
+            }],
+        }),
+        ..make_request(id, id, &format!("{}/api/upload", server.url()))
+    };
+
+    let ws = build_workspace(vec![
+        RequestEntry::Request(make_upload("req-1", "data.csv")),
+        RequestEntry::Request(make_upload("req-2", "../outside.csv")),
+    ]);
+    let ctx = Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace: ws,
+        executing_request_or_group_id: "test-run",
+        allowed_data_path: &allowed_data_path,
+        ..Default::default()
+    }));
+
+    let results = ctx
+        .run(vec!["req-1".to_string(), "req-2".to_string()])
+        .await;
+    let mut results = results.into_iter();
+    match results.next().unwrap().unwrap() {
+        ApicizeResult::Request(req_result) => assert!(req_result.success),
+        _ => panic!("Expected Request result"),
+    }
+    match results.next().unwrap().unwrap() {
+        ApicizeResult::Request(req_result) => assert_eq!(req_result.request_error_count, 1),
+        _ => panic!("Expected Request result"),
+    }
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Asynchronous test script tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_async_test_bodies_are_awaited() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let req = make_request_with_test(
+        "req-1",
+        "Async Test",
+        &format!("{}/api/test", server.url()),
+        r#"
+        const getStatus = () => Promise.resolve(response.status)
+        describe('response', () => {
+            it('resolves status', async () => {
+                expect(await getStatus()).to.equal(200)
+            })
+            it('rejects mismatched status', async () => {
+                expect(await getStatus()).to.equal(404)
+            })
+        })
+        "#,
+    );
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let results = ctx.run(vec!["req-1".to_string()]).await;
+    match results.into_iter().next().unwrap().unwrap() {
+        ApicizeResult::Request(req_result) => {
+            assert!(!req_result.success);
+            assert_eq!(req_result.test_pass_count, 1);
+            assert_eq!(req_result.test_fail_count, 1);
+        }
+        _ => panic!("Expected Request result"),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_pending_promise_reported_as_failure() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let req = make_request_with_test(
+        "req-1",
+        "Pending Test",
+        &format!("{}/api/test", server.url()),
+        r#"
+        describe('response', () => {
+            it('never settles', () => new Promise(() => {}))
+        })
+        "#,
+    );
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    match result {
+        ApicizeResult::Request(req_result) => match req_result.content {
+            ApicizeRequestResultContent::Execution { execution } => {
+                let tests = execution.tests.unwrap();
+                assert_eq!(tests.len(), 1);
+                assert!(!tests[0].success);
+                assert_eq!(
+                    tests[0].error.as_deref(),
+                    Some("Test did not complete, promise is still pending")
+                );
+            }
+            _ => panic!("Expected Execution content"),
+        },
+        _ => panic!("Expected Request result"),
+    }
+}
+
+// =============================================================================
+// OpenAPI contract validation tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_response_validated_against_openapi_contract() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/pets/1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id": "1", "name": "Rex"}"#)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("openapi.yaml"),
+        r#"
+openapi: 3.0.3
+info:
+  title: Pets
+  version: "1"
+paths:
+  /api/pets/{id}:
+    get:
+      operationId: getPet
+      responses:
+        "200":
+          description: Pet
+          content:
+            application/json:
+              schema:
+                type: object
+                required: [id, name]
+                properties:
+                  id:
+                    type: integer
+                  name:
+                    type: string
+"#,
+    )
+    .unwrap();
+    let allowed_data_path = Some(dir.path().to_path_buf());
+
+    let req = Request {
+        operation: Some("getPet".to_string()),
+        ..make_request("req-1", "Get Pet", &format!("{}/api/pets/1", server.url()))
+    };
+    let mut ws = build_workspace(vec![RequestEntry::Request(req)]);
+    ws.openapi = Some("openapi.yaml".to_string());
+    let ctx = Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace: ws,
+        executing_request_or_group_id: "test-run",
+        allowed_data_path: &allowed_data_path,
+        ..Default::default()
+    }));
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    match result {
+        ApicizeResult::Request(req_result) => match req_result.content {
+            ApicizeRequestResultContent::Execution { execution } => {
+                assert!(!execution.success);
+                assert_eq!(execution.test_pass_count, 1);
+                assert_eq!(execution.test_fail_count, 1);
+                let tests = execution.tests.unwrap();
+                assert_eq!(tests[0].name, "contract response status is documented");
+                assert!(tests[0].success);
+                assert_eq!(tests[1].name, "contract response body matches schema");
+                assert_eq!(tests[1].tag.as_deref(), Some("getPet"));
+                assert_eq!(
+                    tests[1].error.as_deref(),
+                    Some("$.id: expected integer, found string")
+                );
+            }
+            _ => panic!("Expected Execution content"),
+        },
+        _ => panic!("Expected Request result"),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_operation_without_openapi_document_not_validated() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/pets/1")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let req = Request {
+        operation: Some("getPet".to_string()),
+        ..make_request("req-1", "Get Pet", &format!("{}/api/pets/1", server.url()))
+    };
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(req_result) = result else {
+        panic!("Expected Request result");
+    };
+    let ApicizeRequestResultContent::Execution { execution } = req_result.content else {
+        panic!("Expected Execution content");
+    };
+    assert!(execution.success);
+    assert!(execution.tests.is_none());
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Cookie jar tests
+// =============================================================================
+
+/// Helper to return the execution of a group's child request
+fn get_child_execution(result: &ApicizeResult, index: usize) -> apicize_lib::ApicizeExecution {
+    let ApicizeResult::Group(group_result) = result else {
+        panic!("Expected Group result");
+    };
+    let apicize_lib::ApicizeGroupResultContent::Results { results } = &group_result.content else {
+        panic!("Expected Results content");
+    };
+    let ApicizeResult::Request(request_result) = &results[index] else {
+        panic!("Expected Request result");
+    };
+    match &request_result.content {
+        ApicizeRequestResultContent::Execution { execution } => (**execution).clone(),
+        _ => panic!("Expected Execution content"),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_group_cookie_jar_sends_received_cookies() {
+    let mut server = mockito::Server::new_async().await;
+    let login_mock = server
+        .mock("POST", "/api/login")
+        .with_status(200)
+        .with_header("set-cookie", "session=abc123; Path=/api; HttpOnly")
+        .create_async()
+        .await;
+    let profile_mock = server
+        .mock("GET", "/api/profile")
+        .match_header("cookie", "session=abc123")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let login = Request {
+        method: Some("POST".to_string()),
+        ..make_request("req-1", "Login", &format!("{}/api/login", server.url()))
+    };
+    let profile = make_request_with_test(
+        "req-2",
+        "Profile",
+        &format!("{}/api/profile", server.url()),
+        "describe('cookies', () => { it('has session', () => { expect(cookies.get('session')).to.equal('abc123') }) })",
+    );
+    let group = RequestGroup {
+        cookie_jar: true,
+        ..make_group(
+            "grp-1",
+            "Session",
+            vec![RequestEntry::Request(login), RequestEntry::Request(profile)],
+            ExecutionConcurrency::Sequential,
+        )
+    };
+
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+
+    let login_execution = get_child_execution(&result, 0);
+    let received = login_execution
+        .test_context
+        .response
+        .unwrap()
+        .cookies
+        .unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].name, "session");
+    assert_eq!(received[0].path, "/api");
+    assert!(received[0].http_only);
+
+    let profile_execution = get_child_execution(&result, 1);
+    assert!(profile_execution.success);
+    assert_eq!(profile_execution.test_pass_count, 1);
+    let sent = profile_execution
+        .test_context
+        .request
+        .unwrap()
+        .cookies
+        .unwrap();
+    assert_eq!(sent[0].value, "abc123");
+
+    login_mock.assert_async().await;
+    profile_mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_cookie_jar_follows_redirects() {
+    let mut server = mockito::Server::new_async().await;
+    let theme_mock = server
+        .mock("GET", "/theme")
+        .with_status(200)
+        .with_header("set-cookie", "theme=dark; Path=/")
+        .create_async()
+        .await;
+    let login_mock = server
+        .mock("POST", "/api/login")
+        .match_header("cookie", "theme=dark")
+        .with_status(303)
+        .with_header("set-cookie", "session=abc123; Path=/api")
+        .with_header("location", "/api/home")
+        .create_async()
+        .await;
+    let home_mock = server
+        .mock("GET", "/api/home")
+        .match_header("cookie", "session=abc123; theme=dark")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let theme = make_request("req-1", "Theme", &format!("{}/theme", server.url()));
+    let login = Request {
+        method: Some("POST".to_string()),
+        ..make_request("req-2", "Login", &format!("{}/api/login", server.url()))
+    };
+    let group = RequestGroup {
+        cookie_jar: true,
+        ..make_group(
+            "grp-1",
+            "Session",
+            vec![RequestEntry::Request(theme), RequestEntry::Request(login)],
+            ExecutionConcurrency::Sequential,
+        )
+    };
+
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let login_execution = get_child_execution(&result, 1);
+    assert!(login_execution.success);
+    assert_eq!(login_execution.test_context.response.unwrap().status, 200);
+
+    theme_mock.assert_async().await;
+    login_mock.assert_async().await;
+    home_mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_cookies_not_sent_without_cookie_jar() {
+    let mut server = mockito::Server::new_async().await;
+    let _login_mock = server
+        .mock("GET", "/api/login")
+        .with_status(200)
+        .with_header("set-cookie", "session=abc123")
+        .create_async()
+        .await;
+    let profile_mock = server
+        .mock("GET", "/api/profile")
+        .match_header("cookie", mockito::Matcher::Missing)
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let login = make_request("req-1", "Login", &format!("{}/api/login", server.url()));
+    let profile = make_request("req-2", "Profile", &format!("{}/api/profile", server.url()));
+    let group = make_group(
+        "grp-1",
+        "Session",
+        vec![RequestEntry::Request(login), RequestEntry::Request(profile)],
+        ExecutionConcurrency::Sequential,
+    );
+
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let profile_execution = get_child_execution(&result, 1);
+    assert!(
+        profile_execution
+            .test_context
+            .request
+            .unwrap()
+            .cookies
+            .is_none()
+    );
+    profile_mock.assert_async().await;
+}
+
+// =============================================================================
+// HTTP client reuse tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_connection_reused_across_requests() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let req1 = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    let req2 = make_request("req-2", "Request 2", &format!("{}/api/test", server.url()));
+    let group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(req1), RequestEntry::Request(req2)],
+        ExecutionConcurrency::Sequential,
+    );
+
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let first = get_child_execution(&result, 0)
+        .test_context
+        .response
+        .unwrap()
+        .connection
+        .unwrap();
+    let second = get_child_execution(&result, 1)
+        .test_context
+        .response
+        .unwrap()
+        .connection
+        .unwrap();
+    assert!(!first.reused);
+    assert!(second.reused);
+    assert_eq!(first.local_address, second.local_address);
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Timing tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_response_timings_available_to_tests() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .with_body("ok")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let test = r#"
+        describe('timings', () => {
+            it('should record time to first byte', () => {
+                expect(response.timings.ttfb).to.be.a('number')
+                expect(response.timings.download).to.be.a('number')
+            })
+        })
+        "#;
+    let req1 = make_request_with_test(
+        "req-1",
+        "Request 1",
+        &format!("{}/api/test", server.url()),
+        test,
+    );
+    let req2 = make_request_with_test(
+        "req-2",
+        "Request 2",
+        &format!("{}/api/test", server.url()),
+        test,
+    );
+    let group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(req1), RequestEntry::Request(req2)],
+        ExecutionConcurrency::Sequential,
+    );
+
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let first = get_child_execution(&result, 0);
+    let second = get_child_execution(&result, 1);
+    assert_eq!(first.test_pass_count, 1);
+    assert_eq!(second.test_pass_count, 1);
+
+    // The server is addressed by IP, so there is no DNS resolution, and
+    // the second request reuses the first request's connection
+    let first = first.test_context.response.unwrap().timings.unwrap();
+    let second = second.test_context.response.unwrap().timings.unwrap();
+    assert_eq!(first.dns, None);
+    assert!(first.connect.is_some());
+    assert_eq!(second.connect, None);
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Retry tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_retry_on_retryable_status() {
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/api/test")
+        .with_status(503)
+        .with_header("retry-after", "0")
+        .expect(2)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut req = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    req.retry = Some(apicize_lib::RetryPolicy {
+        max_attempts: 3,
+        ..Default::default()
+    });
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(request_result) = result else {
+        panic!("Expected Request result");
+    };
+    let ApicizeRequestResultContent::Execution { execution } = request_result.content else {
+        panic!("Expected Execution content");
+    };
+    assert!(execution.success);
+    assert_eq!(execution.test_context.response.unwrap().status, 200);
+
+    let attempts = execution.attempts.unwrap();
+    assert_eq!(attempts.len(), 3);
+    assert_eq!(attempts[0].status, Some(503));
+    assert_eq!(attempts[0].delay, Some(0));
+    assert!(attempts[0].response.is_some());
+    assert_eq!(attempts[2].status, Some(200));
+    assert_eq!(attempts[2].delay, None);
+    assert!(attempts[2].response.is_none());
+
+    unavailable.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_retry_policy_inherited_from_group() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let req = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(req)],
+        ExecutionConcurrency::Sequential,
+    );
+    group.retry = Some(apicize_lib::RetryPolicy {
+        max_attempts: 2,
+        delay: 0,
+        ..Default::default()
+    });
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let execution = get_child_execution(&result, 0);
+    let attempts = execution.attempts.unwrap();
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[1].status, Some(503));
+    assert_eq!(execution.test_context.response.unwrap().status, 503);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_disabled_retry_policy_overrides_group() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut req = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    req.retry = Some(apicize_lib::RetryPolicy {
+        disabled: true,
+        ..Default::default()
+    });
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(req)],
+        ExecutionConcurrency::Sequential,
+    );
+    group.retry = Some(apicize_lib::RetryPolicy {
+        max_attempts: 2,
+        delay: 0,
+        ..Default::default()
+    });
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let execution = get_child_execution(&result, 0);
+    assert!(execution.attempts.is_none());
+    assert_eq!(execution.test_context.response.unwrap().status, 503);
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Poll tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_poll_until_condition_met() {
+    let mut server = mockito::Server::new_async().await;
+    let pending = server
+        .mock("GET", "/api/job")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"state":"pending"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let done = server
+        .mock("GET", "/api/job")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"state":"done"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut req = make_request("req-1", "Request 1", &format!("{}/api/job", server.url()));
+    req.poll_until = Some(Box::new(apicize_lib::PollUntil {
+        condition: apicize_lib::PollCondition::JsonPath {
+            path: "$.state".to_string(),
+            value: Some(serde_json::json!("done")),
+        },
+        interval: 0,
+        max_wait: 10_000,
+    }));
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(request_result) = result else {
+        panic!("Expected Request result");
+    };
+    let ApicizeRequestResultContent::Execution { execution } = request_result.content else {
+        panic!("Expected Execution content");
+    };
+    assert!(execution.success);
+    assert!(execution.error.is_none());
+
+    let poll = execution.poll.unwrap();
+    assert_eq!(poll.dispatches, 3);
+    assert!(poll.condition_met);
+    assert_eq!(poll.statuses, vec![Some(200), Some(200), Some(200)]);
+
+    pending.assert_async().await;
+    done.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_poll_until_max_wait_exceeded() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/job")
+        .with_status(202)
+        .create_async()
+        .await;
+
+    let mut req = make_request("req-1", "Request 1", &format!("{}/api/job", server.url()));
+    req.poll_until = Some(Box::new(apicize_lib::PollUntil {
+        condition: apicize_lib::PollCondition::Script {
+            script: "response.status === 200".to_string(),
+        },
+        interval: 50,
+        max_wait: 100,
+    }));
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(request_result) = result else {
+        panic!("Expected Request result");
+    };
+    let ApicizeRequestResultContent::Execution { execution } = request_result.content else {
+        panic!("Expected Execution content");
+    };
+    assert!(!execution.success);
+    assert!(execution.error.is_some());
+
+    let poll = execution.poll.unwrap();
+    assert!(!poll.condition_met);
+    assert!(poll.dispatches >= 2);
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Concurrency limit tests
+// =============================================================================
+
+/// Helper to start a server which responds after a delay, returning its URL and
+/// the highest number of requests it was handling at once
+async fn start_counting_server() -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/api/test", listener.local_addr().unwrap());
+    let active = Arc::new(AtomicUsize::new(0));
+    let max_active = Arc::new(AtomicUsize::new(0));
+    let observed = max_active.clone();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let active = active.clone();
+            let max_active = max_active.clone();
+            tokio::spawn(async move {
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer).await;
+                let count = active.fetch_add(1, Ordering::SeqCst) + 1;
+                max_active.fetch_max(count, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+                let _ = stream
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                    .await;
+            });
+        }
+    });
+
+    (url, observed)
+}
+
+#[tokio::test]
+#[serial]
+async fn test_request_max_concurrency_limits_runs() {
+    let (url, max_active) = start_counting_server().await;
+
+    let mut req = make_request("req-1", "Request 1", &url);
+    req.runs = 6;
+    req.multi_run_execution = ExecutionConcurrency::Concurrent;
+    req.max_concurrency = Some(2);
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(request_result) = result else {
+        panic!("Expected Request result");
+    };
+    assert_eq!(request_result.request_success_count, 6);
+    assert!(max_active.load(std::sync::atomic::Ordering::SeqCst) <= 2);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_group_max_concurrency_limits_children() {
+    let (url, max_active) = start_counting_server().await;
+
+    let children = (1..=4)
+        .map(|i| {
+            RequestEntry::Request(make_request(
+                &format!("req-{i}"),
+                &format!("Request {i}"),
+                &url,
+            ))
+        })
+        .collect();
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        children,
+        ExecutionConcurrency::Concurrent,
+    );
+    group.max_concurrency = Some(1);
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Group(group_result) = result else {
+        panic!("Expected Group result");
+    };
+    assert_eq!(group_result.request_success_count, 4);
+    assert_eq!(max_active.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_run_max_concurrency_limits_dispatches() {
+    let (url, max_active) = start_counting_server().await;
+
+    let mut req = make_request("req-1", "Request 1", &url);
+    req.runs = 3;
+    req.multi_run_execution = ExecutionConcurrency::Concurrent;
+    let group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![
+            RequestEntry::Request(req),
+            RequestEntry::Request(make_request("req-2", "Request 2", &url)),
+        ],
+        ExecutionConcurrency::Concurrent,
+    );
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace: ws,
+        executing_request_or_group_id: "test-run",
+        max_concurrency: Some(2),
+        ..Default::default()
+    }));
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Group(group_result) = result else {
+        panic!("Expected Group result");
+    };
+    // Each of the first request's runs is tallied
+    assert_eq!(group_result.request_success_count, 4);
+    assert!(max_active.load(std::sync::atomic::Ordering::SeqCst) <= 2);
+}
+
+// =============================================================================
+// Load profile tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_request_load_profile_aggregates_iterations() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .expect(4)
+        .create_async()
+        .await;
+
+    let mut req = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    req.load = Some(apicize_lib::LoadProfile {
+        virtual_users: 2,
+        rate: Some(20.0),
+        ramp_up: 0,
+        duration: 200,
+        ramp_down: 0,
+    });
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(request_result) = result else {
+        panic!("Expected Request result");
+    };
+    let ApicizeRequestResultContent::Load { load } = request_result.content else {
+        panic!("Expected Load content");
+    };
+    assert_eq!(load.iterations, 4);
+    assert_eq!(load.error_rate, 0.0);
+    assert_eq!(load.latency.unwrap().count, 4);
+    assert!(load.success);
+    assert_eq!(request_result.request_success_count, 4);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_group_load_profile_skips_after_each() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .expect(4)
+        .create_async()
+        .await;
+
+    let req = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(req)],
+        ExecutionConcurrency::Sequential,
+    );
+    group.after_each = Some("console.log('after')".to_string());
+    group.load = Some(apicize_lib::LoadProfile {
+        virtual_users: 2,
+        rate: Some(20.0),
+        ramp_up: 0,
+        duration: 200,
+        ramp_down: 0,
+    });
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Group(group_result) = result else {
+        panic!("Expected Group result");
+    };
+    assert!(group_result.scripts.is_none());
+    assert_eq!(group_result.request_success_count, 4);
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Latency statistics tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_latency_statistics_available_to_later_tests() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .expect(4)
+        .create_async()
+        .await;
+
+    let url = format!("{}/api/test", server.url());
+    let mut req = make_request("req-1", "Request 1", &url);
+    req.runs = 3;
+    req.key = Some("orders".to_string());
+    let mut check = make_request("req-2", "Request 2", &url);
+    check.test = Some(
+        r#"describe('latency', () => {
+            it('is available', () => {
+                expect(latency['req-1'].count).to.equal(3)
+                expect(latency.orders.p95).to.be.at.least(latency.orders.p50)
+                expect(latency['Request 1']).to.be.undefined
+            })
+        })"#
+        .to_string(),
+    );
+    let group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(req), RequestEntry::Request(check)],
+        ExecutionConcurrency::Sequential,
+    );
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Group(group_result) = &result else {
+        panic!("Expected Group result");
+    };
+    let apicize_lib::ApicizeGroupResultContent::Results { results } = &group_result.content else {
+        panic!("Expected Results content");
+    };
+    let ApicizeResult::Request(runs_result) = &results[0] else {
+        panic!("Expected Request result");
+    };
+    assert_eq!(runs_result.latency.as_ref().unwrap().count, 3);
+
+    let execution = get_child_execution(&result, 1);
+    assert!(execution.success);
+    assert_eq!(execution.test_pass_count, 1);
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Pre-request script tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_pre_request_output_substituted_and_available_to_tests() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/orders/abc")
+        .match_header("x-signature", "sig-abc")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let mut req = make_request_with_test(
+        "req-1",
+        "Request 1",
+        &format!("{}/api/orders/{{{{orderId}}}}", server.url()),
+        r#"describe('pre-request', () => {
+            it('outputs final values', () => {
+                expect($.signature).to.equal('sig-abc')
+                expect(request.url).to.match(/\/api\/orders\/abc$/)
+            })
+        })"#,
+    );
+    req.pre_request = Some(
+        r#"const orderId = 'abc'
+        output('orderId', orderId)
+        output('signature', `sig-${orderId}`)"#
+            .to_string(),
+    );
+    req.headers = Some(vec![NameValuePair {
+        name: "X-Signature".to_string(),
+        value: "{{signature}}".to_string(),
+        disabled: None,
+    }]);
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(req_result) = result else {
+        panic!("Expected Request result");
+    };
+    assert!(req_result.success);
+    assert_eq!(req_result.test_pass_count, 1);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_pre_request_error_skips_dispatch() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mut req = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    req.pre_request = Some("throw new Error('no signing key')".to_string());
+    let ws = build_workspace(vec![RequestEntry::Request(req)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let error = get_execution_error(result).expect("Expected pre-request error");
+    assert!(error.to_string().contains("no signing key"));
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Group after-each and teardown script tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_group_after_each_and_teardown_run_after_failures() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/users")
+        .with_status(201)
+        .create_async()
+        .await;
+
+    let mut create = make_request_with_test(
+        "req-1",
+        "Create user",
+        &format!("{}/api/users", server.url()),
+        "output('userId', 42)",
+    );
+    create.method = Some("POST".to_string());
+    let broken = make_request("req-2", "Broken", "");
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(create), RequestEntry::Request(broken)],
+        ExecutionConcurrency::Sequential,
+    );
+    group.after_each =
+        Some("console.log(`after ${response ? response.status : 'error'}`)".to_string());
+    group.teardown = Some(
+        r#"describe('teardown', () => {
+            it('sees child output', () => {
+                expect($.userId).to.equal(42)
+            })
+        })
+        console.log(`deleting user ${$.userId}`)"#
+            .to_string(),
+    );
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Group(group_result) = result else {
+        panic!("Expected Group result");
+    };
+    assert!(!group_result.success);
+    assert_eq!(group_result.test_pass_count, 1);
+
+    let scripts = group_result.scripts.as_ref().unwrap();
+    assert_eq!(scripts.len(), 3);
+    assert_eq!(scripts[0].script, ApicizeGroupScript::AfterEach);
+    assert_eq!(scripts[0].child_name.as_deref(), Some("Create user"));
+    assert_eq!(scripts[0].logs, Some(vec!["after 201".to_string()]));
+    assert_eq!(scripts[1].logs, Some(vec!["after error".to_string()]));
+    assert_eq!(scripts[2].script, ApicizeGroupScript::Teardown);
+    assert!(scripts[2].success);
+    assert_eq!(scripts[2].logs, Some(vec!["deleting user 42".to_string()]));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_group_teardown_error_fails_group() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/test")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let req = make_request("req-1", "Request 1", &format!("{}/api/test", server.url()));
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(req)],
+        ExecutionConcurrency::Concurrent,
+    );
+    group.teardown = Some("throw new Error('cleanup failed')".to_string());
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Group(group_result) = result else {
+        panic!("Expected Group result");
+    };
+    assert!(!group_result.success);
+    assert_eq!(group_result.request_success_count, 1);
+    let teardown = &group_result.scripts.as_ref().unwrap()[0];
+    assert!(
+        teardown
+            .error
+            .as_ref()
+            .unwrap()
+            .to_string()
+            .contains("cleanup failed")
+    );
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Dependency graph tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_group_children_run_in_dependency_order_with_merged_output() {
+    let mut server = mockito::Server::new_async().await;
+    let user_mock = server
+        .mock("POST", "/api/users")
+        .with_status(201)
+        .create_async()
+        .await;
+    let product_mock = server
+        .mock("POST", "/api/products")
+        .with_status(201)
+        .create_async()
+        .await;
+    let order_mock = server
+        .mock("POST", "/api/orders/7/11")
+        .with_status(201)
+        .create_async()
+        .await;
+
+    let mut order = make_request(
+        "req-order",
+        "Create order",
+        &format!(
+            "{}/api/orders/{{{{userId}}}}/{{{{productId}}}}",
+            server.url()
+        ),
+    );
+    order.method = Some("POST".to_string());
+    order.depends_on = Some(vec!["user".to_string(), "req-product".to_string()]);
+    let mut user = make_request_with_test(
+        "req-user",
+        "Create user",
+        &format!("{}/api/users", server.url()),
+        "output('userId', 7)",
+    );
+    user.method = Some("POST".to_string());
+    user.key = Some("user".to_string());
+    let mut product = make_request_with_test(
+        "req-product",
+        "Create product",
+        &format!("{}/api/products", server.url()),
+        "output('productId', 11)",
+    );
+    product.method = Some("POST".to_string());
+
+    let group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![
+            RequestEntry::Request(order),
+            RequestEntry::Request(user),
+            RequestEntry::Request(product),
+        ],
+        ExecutionConcurrency::Sequential,
+    );
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Group(group_result) = &result else {
+        panic!("Expected Group result");
+    };
+    assert!(group_result.success);
+    assert_eq!(group_result.request_success_count, 3);
+
+    // Results remain in child order
+    let apicize_lib::ApicizeGroupResultContent::Results { results } = &group_result.content else {
+        panic!("Expected Results content");
+    };
+    let ids = results.iter().map(|r| r.get_id()).collect::<Vec<&str>>();
+    assert_eq!(ids, vec!["req-order", "req-user", "req-product"]);
+
+    user_mock.assert_async().await;
+    product_mock.assert_async().await;
+    order_mock.assert_async().await;
+}
+
+// =============================================================================
+// Run filter tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_run_filter_skips_unselected_requests_and_keeps_group_setup() {
+    let mut server = mockito::Server::new_async().await;
+    let selected_mock = server
+        .mock("GET", "/api/orders/5")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+    let skipped_mock = server
+        .mock("GET", "/api/slow")
+        .with_status(200)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let selected = make_request(
+        "req-selected",
+        "Selected",
+        &format!("{}/api/orders/{{{{orderId}}}}", server.url()),
+    );
+    let mut skipped = make_request(
+        "req-skipped",
+        "Skipped",
+        &format!("{}/api/slow", server.url()),
+    );
+    skipped.tags = Some(vec!["slow".to_string()]);
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![
+            RequestEntry::Request(selected),
+            RequestEntry::Request(skipped),
+        ],
+        ExecutionConcurrency::Sequential,
+    );
+    group.tags = Some(vec!["smoke".to_string()]);
+    group.setup = Some("output('orderId', 5)".to_string());
+    let other = make_request("req-other", "Other", &format!("{}/api/slow", server.url()));
+
+    let ws = build_workspace(vec![
+        RequestEntry::Group(group),
+        RequestEntry::Request(other),
+    ]);
+    let ctx = Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace: ws,
+        executing_request_or_group_id: "test-run",
+        filter: Some(apicize_lib::RunFilter::parse("smoke && !slow").unwrap()),
+        ..Default::default()
+    }));
+
+    let mut results = ctx
+        .run(vec!["grp-1".to_string(), "req-other".to_string()])
+        .await;
+    assert_eq!(results.len(), 1);
+    let ApicizeResult::Group(group_result) = results.remove(0).unwrap() else {
+        panic!("Expected Group result");
+    };
+    assert!(group_result.success);
+    let apicize_lib::ApicizeGroupResultContent::Results { results } = &group_result.content else {
+        panic!("Expected Results content");
+    };
+    let ids = results.iter().map(|r| r.get_id()).collect::<Vec<&str>>();
+    assert_eq!(ids, vec!["req-selected"]);
+
+    selected_mock.assert_async().await;
+    skipped_mock.assert_async().await;
+}
+
+// =============================================================================
+// Run policy tests
+// =============================================================================
+
+/// Helper to list the IDs of a group's results and whether each was skipped
+fn get_child_skipped(result: &ApicizeResult) -> Vec<(&str, bool)> {
+    let ApicizeResult::Group(group_result) = result else {
+        panic!("Expected Group result");
+    };
+    let apicize_lib::ApicizeGroupResultContent::Results { results } = &group_result.content else {
+        panic!("Expected Results content");
+    };
+    results
+        .iter()
+        .map(|r| (r.get_id(), r.is_skipped()))
+        .collect()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_group_stop_on_failure_skips_remaining_requests() {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = server
+        .mock("GET", "/api/login")
+        .with_status(401)
+        .expect(1)
+        .create_async()
+        .await;
+    let skipped_mock = server
+        .mock("GET", "/api/orders")
+        .with_status(200)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let login = make_request_with_test(
+        "req-login",
+        "Login",
+        &format!("{}/api/login", server.url()),
+        r#"
+        describe('login', () => {
+            it('should succeed', () => {
+                expect(response.status).to.equal(200)
+            })
+        })
+        "#,
+    );
+    let orders = make_request(
+        "req-orders",
+        "Orders",
+        &format!("{}/api/orders", server.url()),
+    );
+    let nested = make_group(
+        "grp-nested",
+        "Nested",
+        vec![RequestEntry::Request(make_request(
+            "req-nested",
+            "Nested orders",
+            &format!("{}/api/orders", server.url()),
+        ))],
+        ExecutionConcurrency::Sequential,
+    );
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![
+            RequestEntry::Request(login),
+            RequestEntry::Request(orders),
+            RequestEntry::Group(nested),
+        ],
+        ExecutionConcurrency::Sequential,
+    );
+    group.run_policy = Some(apicize_lib::RunPolicy::StopGroupOnFailure);
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    assert_eq!(
+        get_child_skipped(&result),
+        vec![
+            ("req-login", false),
+            ("req-orders", true),
+            ("grp-nested", true)
+        ]
+    );
+    let ApicizeResult::Group(group_result) = &result else {
+        panic!("Expected Group result");
+    };
+    assert!(!group_result.success);
+    assert_eq!(group_result.request_failure_count, 1);
+
+    failing_mock.assert_async().await;
+    skipped_mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_run_stop_on_error_skips_remaining_run() {
+    let mut server = mockito::Server::new_async().await;
+    let skipped_mock = server
+        .mock("GET", "/api/orders")
+        .with_status(200)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![
+            RequestEntry::Request(make_request("req-login", "Login", "")),
+            RequestEntry::Request(make_request(
+                "req-orders",
+                "Orders",
+                &format!("{}/api/orders", server.url()),
+            )),
+        ],
+        ExecutionConcurrency::Sequential,
+    );
+    let other = make_request(
+        "req-other",
+        "Other",
+        &format!("{}/api/orders", server.url()),
+    );
+    let ws = build_workspace(vec![
+        RequestEntry::Group(group),
+        RequestEntry::Request(other),
+    ]);
+    let ctx = Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace: ws,
+        executing_request_or_group_id: "test-run",
+        run_policy: Some(apicize_lib::RunPolicy::StopRunOnError),
+        ..Default::default()
+    }));
+
+    let mut results = ctx
+        .run(vec!["grp-1".to_string(), "req-other".to_string()])
+        .await;
+    assert_eq!(results.len(), 2);
+    let group_result = results.remove(0).unwrap();
+    assert_eq!(
+        get_child_skipped(&group_result),
+        vec![("req-login", false), ("req-orders", true)]
+    );
+    assert!(results.remove(0).unwrap().is_skipped());
+
+    skipped_mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_run_stop_group_on_failure_does_not_skip_other_entries() {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = server
+        .mock("GET", "/api/login")
+        .with_status(401)
+        .expect(1)
+        .create_async()
+        .await;
+    let executed_mock = server
+        .mock("GET", "/api/orders")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let login = make_request_with_test(
+        "req-login",
+        "Login",
+        &format!("{}/api/login", server.url()),
+        r#"
+        describe('login', () => {
+            it('should succeed', () => {
+                expect(response.status).to.equal(200)
+            })
+        })
+        "#,
+    );
+    let orders = make_request(
+        "req-orders",
+        "Orders",
+        &format!("{}/api/orders", server.url()),
+    );
+    let ws = build_workspace(vec![
+        RequestEntry::Request(login),
+        RequestEntry::Request(orders),
+    ]);
+    let ctx = Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace: ws,
+        executing_request_or_group_id: "test-run",
+        run_policy: Some(apicize_lib::RunPolicy::StopGroupOnFailure),
+        ..Default::default()
+    }));
+
+    let mut results = ctx
+        .run(vec!["req-login".to_string(), "req-orders".to_string()])
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(!results.remove(0).unwrap().is_skipped());
+    assert!(!results.remove(0).unwrap().is_skipped());
+
+    failing_mock.assert_async().await;
+    executed_mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_skip_remaining_siblings_continues_nested_group() {
+    let mut server = mockito::Server::new_async().await;
+    let executed_mock = server
+        .mock("GET", "/api/ok")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+    let skipped_mock = server
+        .mock("GET", "/api/skipped")
+        .with_status(200)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mut nested = make_group(
+        "grp-nested",
+        "Nested",
+        vec![
+            RequestEntry::Request(make_request("req-error", "Error", "")),
+            RequestEntry::Request(make_request(
+                "req-ok",
+                "OK",
+                &format!("{}/api/ok", server.url()),
+            )),
+        ],
+        ExecutionConcurrency::Sequential,
+    );
+    nested.run_policy = Some(apicize_lib::RunPolicy::Continue);
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![
+            RequestEntry::Group(nested),
+            RequestEntry::Request(make_request(
+                "req-skipped",
+                "Skipped",
+                &format!("{}/api/skipped", server.url()),
+            )),
+        ],
+        ExecutionConcurrency::Sequential,
+    );
+    group.run_policy = Some(apicize_lib::RunPolicy::SkipRemainingSiblings);
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    assert_eq!(
+        get_child_skipped(&result),
+        vec![("grp-nested", false), ("req-skipped", true)]
+    );
+
+    executed_mock.assert_async().await;
+    skipped_mock.assert_async().await;
+}
+
+// =============================================================================
+// Conditional execution tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_condition_skips_request_with_reason() {
+    let mut server = mockito::Server::new_async().await;
+    let executed_mock = server
+        .mock("GET", "/api/off")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+    let skipped_mock = server
+        .mock("GET", "/api/on")
+        .with_status(200)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mut on = make_request("req-on", "On", &format!("{}/api/on", server.url()));
+    on.condition = Some("$.featureFlag === 'on'".to_string());
+    let mut off = make_request("req-off", "Off", &format!("{}/api/off", server.url()));
+    off.condition = Some("$.featureFlag === 'off'".to_string());
+    let mut group = make_group(
+        "grp-1",
+        "Group 1",
+        vec![RequestEntry::Request(on), RequestEntry::Request(off)],
+        ExecutionConcurrency::Sequential,
+    );
+    group.setup = Some("output('featureFlag', 'off')".to_string());
+    let ws = build_workspace(vec![RequestEntry::Group(group)]);
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["grp-1".to_string()]).await.remove(0).unwrap();
+    assert_eq!(
+        get_child_skipped(&result),
+        vec![("req-on", true), ("req-off", false)]
+    );
+    let ApicizeResult::Group(group_result) = &result else {
+        panic!("Expected Group result");
+    };
+    assert!(group_result.success);
+    let apicize_lib::ApicizeGroupResultContent::Results { results } = &group_result.content else {
+        panic!("Expected Results content");
+    };
+    let ApicizeResult::Request(skipped) = &results[0] else {
+        panic!("Expected Request result");
+    };
+    assert_eq!(
+        skipped.skip_reason.as_deref(),
+        Some("Condition \"$.featureFlag === 'on'\" is false")
+    );
+
+    executed_mock.assert_async().await;
+    skipped_mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_condition_selects_data_rows() {
+    let mut server = mockito::Server::new_async().await;
+    let admin_mock = server
+        .mock("GET", "/api/users/admin")
+        .with_status(200)
+        .expect(2)
+        .create_async()
+        .await;
+    let user_mock = server
+        .mock("GET", "/api/users/user")
+        .with_status(200)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mut req = make_request(
+        "req-1",
+        "Users",
+        &format!("{}/api/users/{{{{type}}}}", server.url()),
+    );
+    req.condition = Some("$.type === 'admin'".to_string());
+    req.selected_data = apicize_lib::Selection {
+        id: "data-1".to_string(),
+        name: "Users".to_string(),
+    };
+    let mut ws = build_workspace(vec![RequestEntry::Request(req)]);
+    ws.data = IndexedEntities::<apicize_lib::DataSet>::new(Some(vec![apicize_lib::DataSet {
+        id: "data-1".to_string(),
+        name: "Users".to_string(),
+        source_type: apicize_lib::DataSourceType::JSON,
+        source: r#"[{"type": "admin"}, {"type": "user"}, {"type": "admin"}]"#.to_string(),
+        source_error: None,
+        validation_state: Default::default(),
+        validation_warnings: None,
+        validation_errors: None,
+    }]));
+    let ctx = build_context(ws, None);
+
+    let result = ctx.run(vec!["req-1".to_string()]).await.remove(0).unwrap();
+    let ApicizeResult::Request(request_result) = result else {
+        panic!("Expected Request result");
+    };
+    assert!(request_result.skip_reason.is_none());
+    let ApicizeRequestResultContent::Rows { rows } = &request_result.content else {
+        panic!("Expected Rows content");
+    };
+    assert_eq!(rows.len(), 2);
+
+    admin_mock.assert_async().await;
+    user_mock.assert_async().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_condition_error_reported_with_entry() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/next")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut broken = make_request(
+        "req-broken",
+        "Broken",
+        &format!("{}/api/broken", server.url()),
+    );
+    broken.condition = Some("$.missing.flag".to_string());
+    let next = make_request("req-next", "Next", &format!("{}/api/next", server.url()));
+    let mut nested = make_group(
+        "grp-nested",
+        "Nested",
+        vec![],
+        ExecutionConcurrency::Sequential,
+    );
+    nested.condition = Some("undefinedFunction()".to_string());
+    let ws = build_workspace(vec![
+        RequestEntry::Request(broken),
+        RequestEntry::Group(nested),
+        RequestEntry::Request(next),
+    ]);
+    let ctx = build_context(ws, None);
+
+    let mut results = ctx
+        .run(vec![
+            "req-broken".to_string(),
+            "grp-nested".to_string(),
+            "req-next".to_string(),
+        ])
+        .await;
+    let Ok(ApicizeResult::Request(broken)) = results.remove(0) else {
+        panic!("Expected Request result");
+    };
+    assert!(!broken.success);
+    assert_eq!(broken.request_error_count, 1);
+    assert!(broken.skip_reason.is_none());
+    let ApicizeRequestResultContent::Execution { execution } = &broken.content else {
+        panic!("Expected Execution content");
+    };
+    assert!(execution.error.is_some());
+
+    let Ok(ApicizeResult::Group(nested)) = results.remove(0) else {
+        panic!("Expected Group result");
+    };
+    assert!(!nested.success);
+    let scripts = nested.scripts.as_ref().unwrap();
+    assert_eq!(scripts[0].script, ApicizeGroupScript::Condition);
+    assert!(scripts[0].error.is_some());
+
+    // Other entries are still executed
+    assert!(results.remove(0).is_ok());
+    mock.assert_async().await;
+}
+
+// =============================================================================
+// Strict substitution tests
+// =============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_strict_substitution_fails_unresolved_requests() {
+    let mut server = mockito::Server::new_async().await;
+    let unresolved_mock = server
+        .mock("GET", "/api/unresolved")
+        .expect(0)
+        .create_async()
+        .await;
+    let default_mock = server
+        .mock("GET", "/api/regions/us")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut unresolved = make_request(
+        "req-1",
+        "Unresolved",
+        &format!("{}/api/unresolved", server.url()),
+    );
+    unresolved.headers = Some(vec![NameValuePair {
+        name: "Authorization".to_string(),
+        value: "Bearer {{token}}".to_string(),
+        disabled: None,
+    }]);
+    let defaulted = make_request(
+        "req-2",
+        "Defaulted",
+        &format!(
+            "{}/api/regions/{{{{region | default: 'us'}}}}",
+            server.url()
+        ),
+    );
+    let ws = build_workspace(vec![
+        RequestEntry::Request(unresolved),
+        RequestEntry::Request(defaulted),
+    ]);
+    let ctx = Arc::new(TestRunnerContext::new(TestRunnerContextInit {
+        workspace: ws,
+        executing_request_or_group_id: "test-run",
+        strict_substitution: true,
+        ..Default::default()
+    }));
+
+    let mut results = ctx
+        .run(vec!["req-1".to_string(), "req-2".to_string()])
+        .await
+        .into_iter();
+    match get_execution_error(results.next().unwrap().unwrap()) {
+        Some(ApicizeError::Error { description }) => {
+            assert_eq!(description, "Unresolved placeholders: {{token}}")
+        }
+        _ => panic!("Expected unresolved placeholder error"),
+    }
+    assert!(get_execution_error(results.next().unwrap().unwrap()).is_none());
+
+    unresolved_mock.assert_async().await;
+    default_mock.assert_async().await;
+}
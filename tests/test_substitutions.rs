@@ -0,0 +1,114 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use apicize_lib::Substitutions;
+use serde_json::{Map, Value, json};
+
+fn build_substitutions(values: Value) -> Substitutions {
+    let Value::Object(values) = values else {
+        panic!("Expected object");
+    };
+    Substitutions::new(Some(Arc::new(values)))
+}
+
+#[test]
+fn test_substitute_variables() {
+    let subs = build_substitutions(json!({"name": "Jane", "count": 3, "active": true}));
+    assert_eq!(
+        subs.substitute("{{name}} has {{count}} items ({{ active }})"),
+        "Jane has 3 items (true)"
+    );
+    assert_eq!(subs.substitute("{{missing}} {{name"), "{{missing}} {{name");
+}
+
+#[test]
+fn test_substitute_ignores_whitespace_around_names() {
+    // Intentional: placeholders written with padding resolve like unpadded ones
+    let subs = build_substitutions(json!({"name": "Jane", "first name": "J"}));
+    assert_eq!(
+        subs.substitute("{{ name }}|{{  name}}|{{name  }}"),
+        "Jane|Jane|Jane"
+    );
+    assert_eq!(subs.substitute("{{ first name }}"), "J");
+    assert_eq!(subs.substitute("{{ missing }}"), "{{ missing }}");
+}
+
+#[test]
+fn test_substitute_nested_paths() {
+    let subs = build_substitutions(json!({
+        "user": {"address": {"city": "Denver"}, "roles": ["admin", "editor"]},
+        "items": [{"id": 7}],
+        "config.url": "http://localhost"
+    }));
+    assert_eq!(subs.substitute("{{user.address.city}}"), "Denver");
+    assert_eq!(subs.substitute("{{user.roles.1}}"), "editor");
+    assert_eq!(subs.substitute("{{items[0].id}}"), "7");
+    assert_eq!(subs.substitute("{{user.address}}"), r#"{"city":"Denver"}"#);
+    // Variables named with periods take precedence over paths
+    assert_eq!(subs.substitute("{{config.url}}"), "http://localhost");
+    assert_eq!(
+        subs.substitute("{{user.address.zip}} {{items[3].id}} {{items[0}}"),
+        "{{user.address.zip}} {{items[3].id}} {{items[0}}"
+    );
+}
+
+#[test]
+fn test_substitute_defaults_and_filters() {
+    let subs = build_substitutions(json!({
+        "name": "  Jane Doe  ",
+        "empty": "",
+        "query": "a b&c",
+        "user": {"id": 1}
+    }));
+    assert_eq!(subs.substitute("{{missing | default: 'guest'}}"), "guest");
+    assert_eq!(subs.substitute("{{empty | default: \"none\"}}"), "none");
+    assert_eq!(
+        subs.substitute("{{name | default: 'guest'}}"),
+        "  Jane Doe  "
+    );
+    assert_eq!(subs.substitute("{{name | trim | upper}}"), "JANE DOE");
+    assert_eq!(subs.substitute("{{name | trim | lower}}"), "jane doe");
+    assert_eq!(subs.substitute("{{query | urlencode}}"), "a%20b%26c");
+    assert_eq!(subs.substitute("{{query | base64}}"), "YSBiJmM=");
+    assert_eq!(subs.substitute("{{user | json}}"), r#"{"id":1}"#);
+    assert_eq!(subs.substitute("{{query | json}}"), r#""a b&c""#);
+    assert_eq!(
+        subs.substitute("{{missing | default: 'a|b' | upper}}"),
+        "A|B"
+    );
+    // Unknown filters leave the placeholder unresolved
+    assert_eq!(subs.substitute("{{name | reverse}}"), "{{name | reverse}}");
+    // Defaults apply even when there are no variables
+    assert_eq!(
+        Substitutions::new(None).substitute("{{region | default: 'us'}}"),
+        "us"
+    );
+}
+
+#[test]
+fn test_substitute_json() {
+    let subs = build_substitutions(json!({"title": "Say \"hi\"", "id": 5}));
+    assert_eq!(
+        subs.substitute_json(r#"{"title": "{{title}}", "id": {{id}}}"#),
+        r#"{"title": "Say \"hi\"", "id": 5}"#
+    );
+}
+
+#[test]
+fn test_find_unresolved() {
+    let subs = build_substitutions(json!({"user": {"id": 1}}));
+    let mut unresolved = BTreeSet::new();
+    subs.find_unresolved(
+        "{{user.id}}/{{user.name}}/{{token | default: 'x'}}/{{key}}/{{key}}",
+        &mut unresolved,
+    );
+    assert_eq!(
+        unresolved.into_iter().collect::<Vec<String>>(),
+        vec!["{{key}}", "{{user.name}}"]
+    );
+    assert!(Substitutions::default().resolve("anything").is_none());
+    assert_eq!(
+        Substitutions::new(Some(Arc::new(Map::new()))).resolve("x | default: 1"),
+        Some("1".to_string())
+    );
+}